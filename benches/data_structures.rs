@@ -0,0 +1,90 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use rust_algo::data_structures::binary_search_tree::BinarySearchTree;
+use rust_algo::data_structures::heap::Heap;
+use rust_algo::data_structures::linked_list::LinkedList;
+use rust_algo::utils::random::Rng;
+
+const LEN: usize = 10_000;
+
+fn random_keys(seed: u64) -> Vec<i64> {
+    let mut rng = Rng::new(seed);
+    (0..LEN).map(|_| rng.gen_range(0, 1_000_000)).collect()
+}
+
+fn linked_list(c: &mut Criterion) {
+    let mut group = c.benchmark_group("linked_list");
+    group.bench_function("insert_at_head", |b| {
+        b.iter(|| {
+            let mut list = LinkedList::new();
+            for i in 0..LEN {
+                list.insert_at_head(black_box(i));
+            }
+            list
+        })
+    });
+    group.bench_function("insert_at_tail", |b| {
+        b.iter(|| {
+            let mut list = LinkedList::new();
+            for i in 0..LEN {
+                list.insert_at_tail(black_box(i));
+            }
+            list
+        })
+    });
+    group.finish();
+}
+
+fn binary_search_tree(c: &mut Criterion) {
+    let keys = random_keys(1);
+    let mut tree = BinarySearchTree::new();
+    for &key in &keys {
+        tree.insert(key);
+    }
+    let probes = random_keys(2);
+
+    let mut group = c.benchmark_group("binary_search_tree");
+    group.bench_function("insert", |b| {
+        b.iter(|| {
+            let mut tree = BinarySearchTree::new();
+            for &key in &keys {
+                tree.insert(black_box(key));
+            }
+            tree
+        })
+    });
+    group.bench_function("search", |b| {
+        b.iter(|| probes.iter().filter(|key| tree.search(key)).count())
+    });
+    group.finish();
+}
+
+fn heap(c: &mut Criterion) {
+    let keys = random_keys(3);
+    let mut group = c.benchmark_group("heap");
+    group.bench_function("push", |b| {
+        b.iter(|| {
+            let mut heap = Heap::new_min();
+            for &key in &keys {
+                heap.push(black_box(key));
+            }
+            heap
+        })
+    });
+    group.bench_function("pop", |b| {
+        b.iter_batched(
+            || {
+                let mut heap = Heap::new_min();
+                for &key in &keys {
+                    heap.push(key);
+                }
+                heap
+            },
+            |mut heap| while heap.pop().is_some() {},
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, linked_list, binary_search_tree, heap);
+criterion_main!(benches);
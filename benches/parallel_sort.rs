@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use rust_algo::sorting::{intro_sort, par_merge_sort, par_quick_sort, tim_sort};
+use rust_algo::utils::random::Rng;
+
+type SortFn = fn(&mut [u64]);
+
+/// 同样的数据分别用单线程和多线程排序，数据越大并行的收益越明显
+fn scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_sort");
+    group.sample_size(10);
+    let mut rng = Rng::new(1378);
+    for len in [100_000, 1_000_000, 4_000_000] {
+        let data: Vec<u64> = (0..len).map(|_| rng.next_u64()).collect();
+        let sorts: [(&str, SortFn); 4] = [
+            ("tim_sort", tim_sort),
+            ("par_merge_sort", par_merge_sort),
+            ("intro_sort", intro_sort),
+            ("par_quick_sort", par_quick_sort),
+        ];
+        for (name, sort) in sorts {
+            group.bench_with_input(BenchmarkId::new(name, len), &data, |b, data| {
+                b.iter_batched_ref(
+                    || data.clone(),
+                    |arr| sort(black_box(arr)),
+                    BatchSize::LargeInput,
+                )
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, scaling);
+criterion_main!(benches);
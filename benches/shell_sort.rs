@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_algo::sorting::{shell_sort, GapSequence};
+use rust_algo::utils::random::Rng;
+
+/// 对比不同 gap 序列在随机数据上的耗时
+fn gap_sequences(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shell_sort");
+    let mut rng = Rng::new(1374);
+    for len in [1_000, 10_000, 100_000] {
+        let data: Vec<u64> = (0..len).map(|_| rng.next_u64()).collect();
+        for sequence in [GapSequence::Shell, GapSequence::Knuth, GapSequence::Ciura] {
+            group.bench_with_input(
+                BenchmarkId::new(format!("{sequence:?}"), len),
+                &data,
+                |b, data| {
+                    b.iter_batched_ref(
+                        || data.clone(),
+                        |arr| shell_sort(black_box(arr), sequence),
+                        criterion::BatchSize::LargeInput,
+                    )
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, gap_sequences);
+criterion_main!(benches);
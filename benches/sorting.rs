@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use rust_algo::sorting::{self, Sorter};
+use rust_algo::utils::random::Rng;
+
+const LEN: usize = 2_000;
+
+/// 常见的输入分布：随机、已排序、逆序、少量不同值、锯齿形
+fn patterns() -> Vec<(&'static str, Vec<u64>)> {
+    let mut rng = Rng::new(1384);
+    let n = LEN as u64;
+    vec![
+        ("random", (0..n).map(|_| rng.next_u64()).collect()),
+        ("sorted", (0..n).collect()),
+        ("reversed", (0..n).rev().collect()),
+        (
+            "few_uniques",
+            (0..n).map(|_| rng.gen_range(0, 8) as u64).collect(),
+        ),
+        ("sawtooth", (0..n).map(|i| i % 64).collect()),
+    ]
+}
+
+fn sorts(c: &mut Criterion) {
+    for (pattern, data) in patterns() {
+        let mut group = c.benchmark_group(format!("sort/{pattern}"));
+        for sorter in sorting::all() {
+            group.bench_with_input(BenchmarkId::new(sorter.name(), LEN), &data, |b, data| {
+                b.iter_batched_ref(
+                    || data.clone(),
+                    |arr| sorter.sort(black_box(arr.as_mut_slice())),
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, sorts);
+criterion_main!(benches);
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_algo::fuzzing::{run_linked_list, LinkedListOp};
+
+fuzz_target!(|ops: Vec<LinkedListOp>| {
+    run_linked_list(&ops);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_algo::fuzzing::run_sorters;
+
+fuzz_target!(|values: Vec<i16>| {
+    run_sorters(&values);
+});
@@ -0,0 +1,129 @@
+/*!
+定义：
+[回溯法](https://zh.wikipedia.org/wiki/%E5%9B%9E%E6%BA%AF%E6%B3%95)
+把解看成一串依次做出的选择，每一步列出所有候选，只沿着合法的候选继续往下走，走不通就撤销最后一步换下一个候选。
+具体问题只需要实现 [`Backtracker`]：给出候选、判断候选是否合法、判断部分解是否已经完整、记录解。
+已经做出的选择保存在驱动函数 [`backtrack`] 的栈里，递归和撤销都由它负责
+*/
+
+pub trait Backtracker {
+    /// 每一步的一个选择
+    type Candidate;
+
+    /// 在部分解 `partial` 之后可以尝试的选择，按尝试的顺序排列
+    fn candidates(&self, partial: &[Self::Candidate]) -> Vec<Self::Candidate>;
+
+    /// `candidate` 接在 `partial` 之后是否可能得到解，返回 false 的候选会被剪掉
+    fn is_valid(&self, partial: &[Self::Candidate], candidate: &Self::Candidate) -> bool;
+
+    /// `partial` 是否已经是一个完整的解，完整的解不会再往下扩展
+    fn is_complete(&self, partial: &[Self::Candidate]) -> bool;
+
+    /// 记录一个解，返回 false 时停止搜索
+    fn record_solution(&mut self, solution: &[Self::Candidate]) -> bool;
+
+    /// 把 `candidate` 接到 `partial` 之后时调用，可以在这里增量维护用于剪枝的状态
+    fn apply(&mut self, _partial: &[Self::Candidate], _candidate: &Self::Candidate) {}
+
+    /// 从 `partial` 之后撤销 `candidate` 时调用，和 [`Backtracker::apply`] 成对出现
+    fn undo(&mut self, _partial: &[Self::Candidate], _candidate: &Self::Candidate) {}
+}
+
+/// 从空的部分解开始搜索。搜索完所有分支返回 true，被 `record_solution` 中止时返回 false
+pub fn backtrack<B: Backtracker>(problem: &mut B) -> bool {
+    let mut partial = vec![];
+    search(problem, &mut partial)
+}
+
+fn search<B: Backtracker>(problem: &mut B, partial: &mut Vec<B::Candidate>) -> bool {
+    if problem.is_complete(partial) {
+        return problem.record_solution(partial);
+    }
+    for candidate in problem.candidates(partial) {
+        if !problem.is_valid(partial, &candidate) {
+            continue;
+        }
+        problem.apply(partial, &candidate);
+        partial.push(candidate);
+        let finished = search(problem, partial);
+        let candidate = partial.pop().unwrap();
+        problem.undo(partial, &candidate);
+        if !finished {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{backtrack, Backtracker};
+
+    /// 长度为 n、相邻两位不同时为 1 的二进制串
+    struct NoAdjacentOnes {
+        n: usize,
+        solutions: Vec<String>,
+        limit: usize,
+        depth: usize,
+    }
+
+    impl Backtracker for NoAdjacentOnes {
+        type Candidate = char;
+
+        fn candidates(&self, _partial: &[char]) -> Vec<char> {
+            vec!['0', '1']
+        }
+
+        fn is_valid(&self, partial: &[char], candidate: &char) -> bool {
+            *candidate == '0' || partial.last() != Some(&'1')
+        }
+
+        fn is_complete(&self, partial: &[char]) -> bool {
+            partial.len() == self.n
+        }
+
+        fn record_solution(&mut self, solution: &[char]) -> bool {
+            assert_eq!(self.depth, solution.len());
+            self.solutions.push(solution.iter().collect());
+            self.solutions.len() < self.limit
+        }
+
+        fn apply(&mut self, _partial: &[char], _candidate: &char) {
+            self.depth += 1;
+        }
+
+        fn undo(&mut self, _partial: &[char], _candidate: &char) {
+            self.depth -= 1;
+        }
+    }
+
+    fn strings(n: usize, limit: usize) -> (bool, NoAdjacentOnes) {
+        let mut problem = NoAdjacentOnes {
+            n,
+            solutions: vec![],
+            limit,
+            depth: 0,
+        };
+        (backtrack(&mut problem), problem)
+    }
+
+    #[test]
+    fn enumerates_in_candidate_order() {
+        let (finished, problem) = strings(3, usize::MAX);
+        assert!(finished);
+        assert_eq!(problem.solutions, ["000", "001", "010", "100", "101"]);
+        assert_eq!(problem.depth, 0);
+        // 个数是斐波那契数
+        assert_eq!(strings(10, usize::MAX).1.solutions.len(), 144);
+        assert_eq!(strings(0, usize::MAX).1.solutions, [""]);
+    }
+
+    #[test]
+    fn stops_when_asked() {
+        let (finished, problem) = strings(10, 7);
+        assert!(!finished);
+        assert_eq!(problem.solutions.len(), 7);
+        // 中止时也会撤销所有选择
+        assert_eq!(problem.depth, 0);
+    }
+}
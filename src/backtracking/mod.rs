@@ -0,0 +1,9 @@
+pub mod backtracker;
+pub mod n_queens;
+pub mod subset_sum;
+pub mod sudoku;
+
+pub use self::backtracker::{backtrack, Backtracker};
+pub use self::n_queens::{n_queens, n_queens_count, render_board};
+pub use self::subset_sum::subsets_with_sum;
+pub use self::sudoku::{ParseSudokuError, Sudoku};
@@ -0,0 +1,149 @@
+/*!
+定义：
+[八皇后问题](https://zh.wikipedia.org/wiki/%E5%85%AB%E7%9A%87%E5%90%8E%E9%97%AE%E9%A2%98)
+在 n×n 的棋盘上放 n 个皇后，使得任意两个不在同一行、同一列或同一条斜线上。
+逐行放置，每行尝试所有没有被攻击的列，放不下就回溯。
+同一条主对角线上 row - col 相同，副对角线上 row + col 相同，用三个数组 O(1) 判断是否冲突。
+只计数时把三种占用情况压进整数的位里，`!(columns | left | right)` 一次算出当前行所有可放的列
+*/
+
+use super::backtracker::{backtrack, Backtracker};
+
+/// 所有解，`board[row]` 是第 row 行皇后所在的列，按字典序排列
+pub fn n_queens(n: usize) -> Vec<Vec<usize>> {
+    let mut problem = NQueens {
+        n,
+        columns: vec![false; n],
+        diagonals: vec![false; 2 * n],
+        anti_diagonals: vec![false; 2 * n],
+        solutions: vec![],
+    };
+    backtrack(&mut problem);
+    problem.solutions
+}
+
+/// 逐行放置，候选是当前行的列
+struct NQueens {
+    n: usize,
+    columns: Vec<bool>,
+    // 下标分别是 row + col 和 row + n - 1 - col
+    diagonals: Vec<bool>,
+    anti_diagonals: Vec<bool>,
+    solutions: Vec<Vec<usize>>,
+}
+
+impl NQueens {
+    fn mark(&mut self, row: usize, col: usize, occupied: bool) {
+        self.columns[col] = occupied;
+        self.diagonals[row + col] = occupied;
+        self.anti_diagonals[row + self.n - 1 - col] = occupied;
+    }
+}
+
+impl Backtracker for NQueens {
+    type Candidate = usize;
+
+    fn candidates(&self, _board: &[usize]) -> Vec<usize> {
+        (0..self.n).collect()
+    }
+
+    fn is_valid(&self, board: &[usize], &col: &usize) -> bool {
+        let row = board.len();
+        !(self.columns[col]
+            || self.diagonals[row + col]
+            || self.anti_diagonals[row + self.n - 1 - col])
+    }
+
+    fn is_complete(&self, board: &[usize]) -> bool {
+        board.len() == self.n
+    }
+
+    fn record_solution(&mut self, board: &[usize]) -> bool {
+        self.solutions.push(board.to_vec());
+        true
+    }
+
+    fn apply(&mut self, board: &[usize], &col: &usize) {
+        self.mark(board.len(), col, true);
+    }
+
+    fn undo(&mut self, board: &[usize], &col: &usize) {
+        self.mark(board.len(), col, false);
+    }
+}
+
+/// 解的个数，用位运算剪枝，n 不超过 32
+pub fn n_queens_count(n: usize) -> u64 {
+    assert!(n <= 32, "Board size {n} is too large for bitmask counting.");
+    let full = if n == 32 { u32::MAX } else { (1u32 << n) - 1 };
+    count(full, 0, 0, 0)
+}
+
+/// `columns`、`left`、`right` 是当前行被占用的列、被左下方向斜线攻击的列、被右下方向斜线攻击的列
+fn count(full: u32, columns: u32, left: u32, right: u32) -> u64 {
+    if columns == full {
+        return 1;
+    }
+    let mut available = full & !(columns | left | right);
+    let mut total = 0;
+    while available != 0 {
+        // 取出最低位的可放列
+        let bit = available & available.wrapping_neg();
+        available ^= bit;
+        // 斜线攻击到下一行时分别左移、右移一列
+        total += count(
+            full,
+            columns | bit,
+            ((left | bit) << 1) & full,
+            (right | bit) >> 1,
+        );
+    }
+    total
+}
+
+/// 把一个解画成棋盘，`Q` 是皇后，`.` 是空格
+pub fn render_board(board: &[usize]) -> String {
+    board
+        .iter()
+        .map(|&col| {
+            (0..board.len())
+                .map(|c| if c == col { 'Q' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{n_queens, n_queens_count, render_board};
+
+    const KNOWN_COUNTS: [u64; 13] = [1, 1, 0, 0, 2, 10, 4, 40, 92, 352, 724, 2680, 14200];
+
+    #[test]
+    fn counts_match_known_values() {
+        for (n, &expected) in KNOWN_COUNTS.iter().enumerate() {
+            assert_eq!(n_queens_count(n), expected, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn all_solutions_are_valid_and_distinct() {
+        for (n, &expected) in KNOWN_COUNTS.iter().enumerate().take(10) {
+            let solutions = n_queens(n);
+            assert_eq!(solutions.len() as u64, expected, "n = {n}");
+            assert!(solutions.windows(2).all(|w| w[0] < w[1]));
+            for board in &solutions {
+                for r1 in 0..n {
+                    for r2 in r1 + 1..n {
+                        let (c1, c2) = (board[r1], board[r2]);
+                        assert_ne!(c1, c2);
+                        assert_ne!(r2 - r1, c1.abs_diff(c2));
+                    }
+                }
+            }
+        }
+        assert_eq!(n_queens(4), vec![vec![1, 3, 0, 2], vec![2, 0, 3, 1]]);
+        assert_eq!(render_board(&[1, 3, 0, 2]), ".Q..\n...Q\nQ...\n..Q.");
+    }
+}
@@ -0,0 +1,125 @@
+/*!
+定义：
+[子集和问题](https://zh.wikipedia.org/wiki/%E5%AD%90%E9%9B%86%E5%90%88%E5%8A%A0%E7%B8%BD%E5%95%8F%E9%A1%8C)
+列出所有和恰好等于目标值的子集。依次决定每个数选或不选，
+已选的和超过目标值，或者加上剩下所有的数也不够时剪枝。
+只需要判断是否存在或者计数时，[`crate::dp::subset_sum`] 的动态规划更快
+*/
+
+use super::backtracker::{backtrack, Backtracker};
+
+/// 和为 `target` 的所有子集，每个子集是所选元素的下标（递增）。
+/// 每个数先尝试选再尝试不选，子集按这个顺序排列
+pub fn subsets_with_sum(values: &[usize], target: usize) -> Vec<Vec<usize>> {
+    let mut remaining = vec![0; values.len() + 1];
+    for i in (0..values.len()).rev() {
+        remaining[i] = remaining[i + 1] + values[i];
+    }
+    let mut problem = SubsetSum {
+        values,
+        target,
+        remaining,
+        sum: 0,
+        solutions: vec![],
+    };
+    backtrack(&mut problem);
+    problem.solutions
+}
+
+struct SubsetSum<'a> {
+    values: &'a [usize],
+    target: usize,
+    /// `remaining[i]` 是第 i 个数及之后所有数的和
+    remaining: Vec<usize>,
+    /// 已选的数的和
+    sum: usize,
+    solutions: Vec<Vec<usize>>,
+}
+
+/// 第 `partial.len()` 个数选或不选
+impl Backtracker for SubsetSum<'_> {
+    type Candidate = bool;
+
+    fn candidates(&self, partial: &[bool]) -> Vec<bool> {
+        if partial.len() == self.values.len() {
+            return vec![];
+        }
+        vec![true, false]
+    }
+
+    fn is_valid(&self, partial: &[bool], &chosen: &bool) -> bool {
+        let i = partial.len();
+        let sum = if chosen {
+            self.sum + self.values[i]
+        } else {
+            self.sum
+        };
+        sum <= self.target && sum + self.remaining[i + 1] >= self.target
+    }
+
+    fn is_complete(&self, partial: &[bool]) -> bool {
+        // 只有一个数都没有时才需要检查和，否则 is_valid 已经保证了和恰好是目标值
+        partial.len() == self.values.len() && self.sum == self.target
+    }
+
+    fn record_solution(&mut self, solution: &[bool]) -> bool {
+        let chosen = solution
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &chosen)| chosen.then_some(i))
+            .collect();
+        self.solutions.push(chosen);
+        true
+    }
+
+    fn apply(&mut self, partial: &[bool], &chosen: &bool) {
+        if chosen {
+            self.sum += self.values[partial.len()];
+        }
+    }
+
+    fn undo(&mut self, partial: &[bool], &chosen: &bool) {
+        if chosen {
+            self.sum -= self.values[partial.len()];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::subsets_with_sum;
+    use crate::dp::count_subsets_with_sum;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn lists_all_subsets() {
+        let values = [3, 34, 4, 12, 5, 2];
+        assert_eq!(
+            subsets_with_sum(&values, 9),
+            vec![vec![0, 2, 5], vec![2, 4]]
+        );
+        assert_eq!(subsets_with_sum(&values, 100), Vec::<Vec<usize>>::new());
+        assert_eq!(subsets_with_sum(&values, 0), vec![vec![]]);
+        // 0 可以选也可以不选
+        assert_eq!(subsets_with_sum(&[0, 1], 1), vec![vec![0, 1], vec![1]]);
+        assert_eq!(subsets_with_sum(&[], 0), vec![vec![]]);
+    }
+
+    #[test]
+    fn matches_dynamic_programming_count() {
+        let mut rng = Rng::new(1477);
+        for _ in 0..200 {
+            let values: Vec<usize> = (0..rng.gen_index(12)).map(|_| rng.gen_index(10)).collect();
+            let target = rng.gen_index(30);
+            let subsets = subsets_with_sum(&values, target);
+            assert_eq!(
+                Some(subsets.len() as u128),
+                count_subsets_with_sum(&values, target)
+            );
+            assert!(subsets.windows(2).all(|w| w[0] != w[1]));
+            for subset in &subsets {
+                assert_eq!(subset.iter().map(|&i| values[i]).sum::<usize>(), target);
+            }
+        }
+    }
+}
@@ -0,0 +1,397 @@
+/*!
+定义：
+[数独](https://zh.wikipedia.org/wiki/%E6%95%B8%E7%8D%A8)
+9×9 的格子，每行、每列、每个 3×3 宫都恰好包含 1 到 9。
+用 9 位的掩码记录每行、每列、每宫已经用过的数字，一个空格的候选数就是三者都没用过的数字。
+求解时先做约束传播：只有一个候选数的格子直接填上；都有多个候选数时，
+选候选数最少的格子逐个尝试（最少剩余值启发式），失败就回溯。
+生成题目时先随机填满一个终盘，再按随机顺序挖空，挖掉后解不唯一就放回去
+*/
+
+use crate::utils::random::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+/// 0 表示空格
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sudoku {
+    cells: [[u8; 9]; 9],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSudokuError {
+    /// 去掉空白后不是 81 个字符
+    WrongLength(usize),
+    /// 第 `index` 个格子不是 1-9、`0` 或 `.`
+    InvalidCell { index: usize, cell: char },
+}
+
+impl fmt::Display for ParseSudokuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSudokuError::WrongLength(len) => write!(f, "expected 81 cells, found {len}"),
+            ParseSudokuError::InvalidCell { index, cell } => {
+                write!(f, "invalid cell {cell:?} at position {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseSudokuError {}
+
+impl Sudoku {
+    pub fn empty() -> Self {
+        Sudoku { cells: [[0; 9]; 9] }
+    }
+
+    pub fn from_cells(cells: [[u8; 9]; 9]) -> Self {
+        assert!(
+            cells.iter().flatten().all(|&digit| digit <= 9),
+            "Cells must be 0 (empty) or 1-9."
+        );
+        Sudoku { cells }
+    }
+
+    pub fn cells(&self) -> &[[u8; 9]; 9] {
+        &self.cells
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> u8 {
+        self.cells[row][col]
+    }
+
+    /// 已经填上的格子数
+    pub fn clue_count(&self) -> usize {
+        self.cells
+            .iter()
+            .flatten()
+            .filter(|&&digit| digit != 0)
+            .count()
+    }
+
+    /// 已填的数字之间没有冲突
+    pub fn is_valid(&self) -> bool {
+        State::new(self).is_some()
+    }
+
+    /// 填满并且没有冲突
+    pub fn is_solved(&self) -> bool {
+        self.clue_count() == 81 && self.is_valid()
+    }
+
+    /// 任意一个解，无解时返回 None
+    pub fn solve(&self) -> Option<Sudoku> {
+        let mut solution = None;
+        search(State::new(self)?, None, &mut |state| {
+            solution = Some(state.grid);
+            false
+        });
+        solution
+    }
+
+    /// 解的个数，数到 `limit` 就停止。判断解是否唯一时用 `limit = 2`
+    pub fn count_solutions(&self, limit: usize) -> usize {
+        let Some(state) = State::new(self) else {
+            return 0;
+        };
+        let mut count = 0;
+        if limit > 0 {
+            search(state, None, &mut |_| {
+                count += 1;
+                count < limit
+            });
+        }
+        count
+    }
+
+    /// 生成一道有唯一解的题目，返回 (题目, 答案)
+    pub fn generate(rng: &mut Rng) -> (Sudoku, Sudoku) {
+        let mut solution = Sudoku::empty();
+        let empty = State::new(&solution).unwrap();
+        search(empty, Some(rng), &mut |state| {
+            solution = state.grid;
+            false
+        });
+
+        let mut puzzle = solution;
+        let mut positions: Vec<(usize, usize)> = (0..81).map(|i| (i / 9, i % 9)).collect();
+        rng.shuffle(&mut positions);
+        for (row, col) in positions {
+            let digit = puzzle.cells[row][col];
+            puzzle.cells[row][col] = 0;
+            if puzzle.count_solutions(2) > 1 {
+                puzzle.cells[row][col] = digit;
+            }
+        }
+        (puzzle, solution)
+    }
+}
+
+impl FromStr for Sudoku {
+    type Err = ParseSudokuError;
+
+    /// 81 个字符按行排列，`.` 或 `0` 表示空格，空白字符被忽略
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let cells: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+        if cells.len() != 81 {
+            return Err(ParseSudokuError::WrongLength(cells.len()));
+        }
+        let mut grid = Sudoku::empty();
+        for (index, &cell) in cells.iter().enumerate() {
+            grid.cells[index / 9][index % 9] = match cell {
+                '.' => 0,
+                '0'..='9' => cell as u8 - b'0',
+                _ => return Err(ParseSudokuError::InvalidCell { index, cell }),
+            };
+        }
+        Ok(grid)
+    }
+}
+
+impl fmt::Display for Sudoku {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.cells.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for &digit in row {
+                let cell = if digit == 0 {
+                    '.'
+                } else {
+                    (b'0' + digit) as char
+                };
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 求解过程中的盘面，以及每行、每列、每宫已用数字的掩码（第 d 位表示数字 d）
+#[derive(Clone)]
+struct State {
+    grid: Sudoku,
+    rows: [u16; 9],
+    cols: [u16; 9],
+    boxes: [u16; 9],
+}
+
+impl State {
+    /// 已填数字有冲突时返回 None
+    fn new(grid: &Sudoku) -> Option<Self> {
+        let mut state = State {
+            grid: Sudoku::empty(),
+            rows: [0; 9],
+            cols: [0; 9],
+            boxes: [0; 9],
+        };
+        for row in 0..9 {
+            for col in 0..9 {
+                let digit = grid.cells[row][col];
+                if digit != 0 {
+                    if state.candidates(row, col) & 1 << digit == 0 {
+                        return None;
+                    }
+                    state.place(row, col, digit);
+                }
+            }
+        }
+        Some(state)
+    }
+
+    fn candidates(&self, row: usize, col: usize) -> u16 {
+        !(self.rows[row] | self.cols[col] | self.boxes[box_of(row, col)]) & 0b11_1111_1110
+    }
+
+    fn place(&mut self, row: usize, col: usize, digit: u8) {
+        let bit = 1 << digit;
+        self.grid.cells[row][col] = digit;
+        self.rows[row] |= bit;
+        self.cols[col] |= bit;
+        self.boxes[box_of(row, col)] |= bit;
+    }
+
+    /// 约束传播：反复填上只有一个候选数的格子。
+    /// 返回 Err 表示出现了没有候选数的空格，Ok(None) 表示已经填满，
+    /// Ok(Some(..)) 是候选数最少的空格和它的候选数
+    fn propagate(&mut self) -> Result<Option<(usize, usize, u16)>, ()> {
+        loop {
+            let mut best: Option<(usize, usize, u16)> = None;
+            let mut placed = false;
+            for row in 0..9 {
+                for col in 0..9 {
+                    if self.grid.cells[row][col] != 0 {
+                        continue;
+                    }
+                    let candidates = self.candidates(row, col);
+                    match candidates.count_ones() {
+                        0 => return Err(()),
+                        1 => {
+                            self.place(row, col, candidates.trailing_zeros() as u8);
+                            placed = true;
+                        }
+                        count => {
+                            if best.is_none_or(|(_, _, c)| count < c.count_ones()) {
+                                best = Some((row, col, candidates));
+                            }
+                        }
+                    }
+                }
+            }
+            // 这一轮填了数字，之前记下的候选数可能已经过时，重新扫描
+            if !placed {
+                return Ok(best);
+            }
+        }
+    }
+}
+
+fn box_of(row: usize, col: usize) -> usize {
+    row / 3 * 3 + col / 3
+}
+
+/// 深度优先搜索，每找到一个解调用一次 `on_solution`，它返回 false 时停止搜索。
+/// 返回 false 表示搜索被中止。`rng` 不为空时随机打乱候选数的顺序
+fn search(
+    mut state: State,
+    mut rng: Option<&mut Rng>,
+    on_solution: &mut dyn FnMut(&State) -> bool,
+) -> bool {
+    let (row, col, candidates) = match state.propagate() {
+        Err(()) => return true,
+        Ok(None) => return on_solution(&state),
+        Ok(Some(cell)) => cell,
+    };
+    let mut digits: Vec<u8> = (1..=9).filter(|&d| candidates & 1 << d != 0).collect();
+    if let Some(rng) = rng.as_deref_mut() {
+        rng.shuffle(&mut digits);
+    }
+    for digit in digits {
+        let mut next = state.clone();
+        next.place(row, col, digit);
+        if !search(next, rng.as_deref_mut(), on_solution) {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseSudokuError, Sudoku};
+    use crate::utils::random::Rng;
+
+    const PUZZLE: &str = "
+        53..7....
+        6..195...
+        .98....6.
+        8...6...3
+        4..8.3..1
+        7...2...6
+        .6....28.
+        ...419..5
+        ....8..79";
+
+    const SOLUTION: &str = "
+        534678912
+        672195348
+        198342567
+        859761423
+        426853791
+        713924856
+        961537284
+        287419635
+        345286179";
+
+    /// 被称为“世界上最难的数独”，约束传播之后仍需要大量回溯
+    const HARD: &str =
+        "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4..";
+
+    fn consistent(puzzle: &Sudoku, solution: &Sudoku) -> bool {
+        let (a, b) = (
+            puzzle.cells().as_flattened(),
+            solution.cells().as_flattened(),
+        );
+        a.iter()
+            .zip(b)
+            .all(|(&given, &digit)| given == 0 || given == digit)
+    }
+
+    #[test]
+    fn solves_puzzles() {
+        let puzzle: Sudoku = PUZZLE.parse().unwrap();
+        let solution: Sudoku = SOLUTION.parse().unwrap();
+        assert!(puzzle.is_valid() && !puzzle.is_solved());
+        assert_eq!(puzzle.clue_count(), 30);
+        assert_eq!(puzzle.solve(), Some(solution));
+        assert!(solution.is_solved());
+        assert_eq!(puzzle.count_solutions(10), 1);
+        assert_eq!(solution.solve(), Some(solution));
+
+        let hard: Sudoku = HARD.parse().unwrap();
+        let solved = hard.solve().unwrap();
+        assert!(solved.is_solved() && consistent(&hard, &solved));
+        assert_eq!(hard.count_solutions(2), 1);
+    }
+
+    #[test]
+    fn invalid_and_ambiguous_puzzles() {
+        // 第一行有两个 5
+        let conflicting = PUZZLE
+            .replacen("53..7", "53.57", 1)
+            .parse::<Sudoku>()
+            .unwrap();
+        assert!(!conflicting.is_valid());
+        assert_eq!(conflicting.solve(), None);
+        assert_eq!(conflicting.count_solutions(2), 0);
+
+        // 没有冲突但无解：第一行的空格只剩下 9，而第一列已经有 9 了
+        let mut cells = [[0; 9]; 9];
+        cells[0] = [1, 2, 3, 4, 5, 6, 7, 8, 0];
+        cells[5][8] = 9;
+        let stuck = Sudoku::from_cells(cells);
+        assert!(stuck.is_valid());
+        assert_eq!(stuck.solve(), None);
+
+        let empty = Sudoku::empty();
+        assert!(empty.solve().unwrap().is_solved());
+        assert_eq!(empty.count_solutions(5), 5);
+        assert_eq!(empty.count_solutions(0), 0);
+    }
+
+    #[test]
+    fn parses_and_prints() {
+        let puzzle: Sudoku = PUZZLE.parse().unwrap();
+        assert_eq!(puzzle.get(0, 0), 5);
+        assert_eq!(puzzle.get(0, 2), 0);
+        let text = puzzle.to_string();
+        assert_eq!(text.lines().next(), Some("53..7...."));
+        assert_eq!(text.parse::<Sudoku>(), Ok(puzzle));
+        assert_eq!(
+            "123".parse::<Sudoku>(),
+            Err(ParseSudokuError::WrongLength(3))
+        );
+        let bad = format!("x{}", &".".repeat(80));
+        assert_eq!(
+            bad.parse::<Sudoku>(),
+            Err(ParseSudokuError::InvalidCell {
+                index: 0,
+                cell: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn generated_puzzles_have_unique_solutions() {
+        let mut rng = Rng::new(1475);
+        for _ in 0..3 {
+            let (puzzle, solution) = Sudoku::generate(&mut rng);
+            assert!(solution.is_solved());
+            assert!(consistent(&puzzle, &solution));
+            assert_eq!(puzzle.count_solutions(2), 1);
+            assert_eq!(puzzle.solve(), Some(solution));
+            // 挖到不能再挖为止，通常只剩二十几个数字
+            assert!(puzzle.clue_count() < 40, "{}", puzzle.clue_count());
+        }
+    }
+}
@@ -0,0 +1,120 @@
+/*!
+定义：
+[迪菲-赫尔曼密钥交换](https://zh.wikipedia.org/wiki/%E8%BF%AA%E8%8F%B2-%E8%B5%AB%E7%88%BE%E6%9B%BC%E5%AF%86%E9%91%B0%E4%BA%A4%E6%8F%9B)
+双方约定素数 p 和生成元 g，各自随机选私钥 a、b，公开 A = gᵃ mod p 和 B = gᵇ mod p，
+之后 Bᵃ = Aᵇ = gᵃᵇ mod p 就是只有双方知道的共享密钥。
+窃听者只看到 p、g、A、B，要算出共享密钥需要解离散对数问题。
+没有身份认证，挡不住中间人攻击，只用于演示
+*/
+
+use crate::math::big_int::BigInt;
+use crate::utils::random::Rng;
+
+/// RFC 3526 中的 2048 位 MODP 群（第 14 组）的素数，生成元为 2
+const MODP_2048_PRIME: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74",
+    "020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437",
+    "4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05",
+    "98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB",
+    "9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718",
+    "3995497CEA956AE515D2261898FA051015728E5A8AACAA68FFFFFFFFFFFFFFFF",
+);
+
+/// 私钥的位数，对 2048 位的群足够
+const PRIVATE_KEY_BITS: u64 = 256;
+
+/// 一组公开的参数：素数 p 和生成元 g
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffieHellman {
+    prime: BigInt,
+    generator: BigInt,
+}
+
+impl DiffieHellman {
+    /// `generator` 必须在 [2, prime - 2] 中
+    pub fn new(prime: BigInt, generator: BigInt) -> Self {
+        assert!(
+            generator >= BigInt::from(2u64) && generator < &prime - &BigInt::one(),
+            "Generator {generator} out of range for prime {prime}."
+        );
+        DiffieHellman { prime, generator }
+    }
+
+    /// RFC 3526 的 2048 位 MODP 群
+    pub fn modp_2048() -> Self {
+        let prime = BigInt::from_str_radix(MODP_2048_PRIME, 16).unwrap();
+        DiffieHellman::new(prime, BigInt::from(2u64))
+    }
+
+    pub fn prime(&self) -> &BigInt {
+        &self.prime
+    }
+
+    pub fn generator(&self) -> &BigInt {
+        &self.generator
+    }
+
+    /// [2, p - 2] 中的随机私钥，不超过 256 位
+    pub fn generate_private_key(&self, rng: &mut Rng) -> BigInt {
+        let two = BigInt::from(2u64);
+        let range = &self.prime - &BigInt::from(3u64);
+        let bits = PRIVATE_KEY_BITS.min(range.bits());
+        &BigInt::random(bits, rng).rem_euclid(&range) + &two
+    }
+
+    /// 私钥对应的公钥 g^private mod p
+    pub fn public_key(&self, private_key: &BigInt) -> BigInt {
+        self.generator.mod_pow(private_key, &self.prime)
+    }
+
+    /// 用自己的私钥和对方的公钥算出共享密钥。
+    /// 对方的公钥不在 [2, p - 2] 中时返回 None，否则共享密钥会退化成 0、1 或 ±1
+    pub fn shared_secret(&self, private_key: &BigInt, other_public_key: &BigInt) -> Option<BigInt> {
+        let valid = *other_public_key >= BigInt::from(2u64)
+            && *other_public_key < &self.prime - &BigInt::one();
+        valid.then(|| other_public_key.mod_pow(private_key, &self.prime))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DiffieHellman;
+    use crate::math::big_int::BigInt;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn textbook_example() {
+        let group = DiffieHellman::new(BigInt::from(23u64), BigInt::from(5u64));
+        let (alice, bob) = (BigInt::from(6u64), BigInt::from(15u64));
+        let (a, b) = (group.public_key(&alice), group.public_key(&bob));
+        assert_eq!(
+            (a.clone(), b.clone()),
+            (BigInt::from(8u64), BigInt::from(19u64))
+        );
+        assert_eq!(group.shared_secret(&alice, &b), Some(BigInt::from(2u64)));
+        assert_eq!(group.shared_secret(&bob, &a), Some(BigInt::from(2u64)));
+        assert_eq!(group.shared_secret(&alice, &BigInt::one()), None);
+        assert_eq!(group.shared_secret(&alice, &BigInt::from(22u64)), None);
+    }
+
+    #[test]
+    fn key_exchange_over_modp_group() {
+        let mut rng = Rng::new(1482);
+        let group = DiffieHellman::modp_2048();
+        assert_eq!(group.prime().bits(), 2048);
+        // 最高和最低的 64 位都是 1
+        let low = BigInt::from(u64::MAX);
+        assert_eq!(group.prime().rem_euclid(&BigInt::from(2u64).pow(64)), low);
+        assert_eq!(group.prime() / &BigInt::from(2u64).pow(1984), low);
+
+        let alice = group.generate_private_key(&mut rng);
+        let bob = group.generate_private_key(&mut rng);
+        assert_ne!(alice, bob);
+        let (a, b) = (group.public_key(&alice), group.public_key(&bob));
+        let secret = group.shared_secret(&alice, &b).unwrap();
+        assert_eq!(group.shared_secret(&bob, &a), Some(secret.clone()));
+        assert!(secret > BigInt::one());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod diffie_hellman;
+pub mod rsa;
+
+pub use self::diffie_hellman::DiffieHellman;
+pub use self::rsa::{RsaKeyPair, RsaPrivateKey, RsaPublicKey, PUBLIC_EXPONENT};
@@ -0,0 +1,203 @@
+/*!
+定义：
+[RSA 加密算法](https://zh.wikipedia.org/wiki/RSA%E5%8A%A0%E5%AF%86%E6%BC%94%E7%AE%97%E6%B3%95)
+随机选两个大素数 p、q，n = pq，λ = lcm(p - 1, q - 1)。公钥指数 e 取 65537，私钥指数 d 是 e 模 λ 的逆元。
+加密 c = mᵉ mod n，解密 m = cᵈ mod n；签名时用私钥对消息的 SHA-256 摘要求幂，验证时用公钥还原。
+
+这里是不带填充的“教科书 RSA”，相同的明文总是得到相同的密文，也可以被篡改成其它合法密文，
+而且没有做任何防侧信道的处理，只用于演示，不能用于保护真实的数据
+*/
+
+use crate::hashing::{Digest, Sha256};
+use crate::math::big_int::BigInt;
+use crate::math::primality::random_prime;
+use crate::utils::random::Rng;
+
+/// 常用的公钥指数 2^16 + 1
+pub const PUBLIC_EXPONENT: u64 = 65537;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsaPublicKey {
+    modulus: BigInt,
+    exponent: BigInt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsaPrivateKey {
+    modulus: BigInt,
+    exponent: BigInt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RsaKeyPair {
+    pub public: RsaPublicKey,
+    pub private: RsaPrivateKey,
+}
+
+impl RsaKeyPair {
+    /// 生成模数恰好为 `bits` 位的密钥对，`bits` 至少为 32
+    pub fn generate(bits: u64, rng: &mut Rng) -> Self {
+        assert!(bits >= 32, "Modulus of {bits} bits is too small.");
+        let one = BigInt::one();
+        let e = BigInt::from(PUBLIC_EXPONENT);
+        loop {
+            let p = random_prime(bits / 2, rng);
+            let q = random_prime(bits - bits / 2, rng);
+            let n = &p * &q;
+            if p == q || n.bits() != bits {
+                continue;
+            }
+            let (p1, q1) = (&p - &one, &q - &one);
+            let lambda = &(&p1 * &q1) / &gcd(&p1, &q1);
+            // e 和 λ 不互素时没有逆元，换一组素数
+            if let Some(d) = e.mod_inverse(&lambda) {
+                return RsaKeyPair {
+                    public: RsaPublicKey {
+                        modulus: n.clone(),
+                        exponent: e,
+                    },
+                    private: RsaPrivateKey {
+                        modulus: n,
+                        exponent: d,
+                    },
+                };
+            }
+        }
+    }
+}
+
+impl RsaPublicKey {
+    pub fn new(modulus: BigInt, exponent: BigInt) -> Self {
+        RsaPublicKey { modulus, exponent }
+    }
+
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    pub fn exponent(&self) -> &BigInt {
+        &self.exponent
+    }
+
+    /// 明文必须在 [0, n) 中
+    pub fn encrypt(&self, message: &BigInt) -> BigInt {
+        check_range(message, &self.modulus);
+        message.mod_pow(&self.exponent, &self.modulus)
+    }
+
+    /// 检查 `signature` 是否是对 `message` 的合法签名
+    pub fn verify(&self, message: &[u8], signature: &BigInt) -> bool {
+        !signature.is_negative()
+            && *signature < self.modulus
+            && signature.mod_pow(&self.exponent, &self.modulus) == digest(message, &self.modulus)
+    }
+}
+
+impl RsaPrivateKey {
+    pub fn new(modulus: BigInt, exponent: BigInt) -> Self {
+        RsaPrivateKey { modulus, exponent }
+    }
+
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    pub fn exponent(&self) -> &BigInt {
+        &self.exponent
+    }
+
+    /// 密文必须在 [0, n) 中
+    pub fn decrypt(&self, ciphertext: &BigInt) -> BigInt {
+        check_range(ciphertext, &self.modulus);
+        ciphertext.mod_pow(&self.exponent, &self.modulus)
+    }
+
+    /// 对消息的 SHA-256 摘要（模 n）签名
+    pub fn sign(&self, message: &[u8]) -> BigInt {
+        digest(message, &self.modulus).mod_pow(&self.exponent, &self.modulus)
+    }
+}
+
+fn digest(message: &[u8], modulus: &BigInt) -> BigInt {
+    BigInt::from_bytes_be(&Sha256::digest(message)).rem_euclid(modulus)
+}
+
+fn gcd(a: &BigInt, b: &BigInt) -> BigInt {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while !b.is_zero() {
+        let remainder = &a % &b;
+        (a, b) = (b, remainder);
+    }
+    a
+}
+
+fn check_range(value: &BigInt, modulus: &BigInt) {
+    assert!(
+        !value.is_negative() && value < modulus,
+        "Value {value} out of range for modulus {modulus}."
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RsaKeyPair, RsaPrivateKey, RsaPublicKey};
+    use crate::math::big_int::BigInt;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn textbook_example() {
+        // p = 61, q = 53, n = 3233, e = 17, d = 413（用 λ(n) = 780 求逆元）
+        let public = RsaPublicKey::new(BigInt::from(3233u64), BigInt::from(17u64));
+        let private = RsaPrivateKey::new(BigInt::from(3233u64), BigInt::from(413u64));
+        let ciphertext = public.encrypt(&BigInt::from(65u64));
+        assert_eq!(ciphertext, BigInt::from(2790u64));
+        assert_eq!(private.decrypt(&ciphertext), BigInt::from(65u64));
+    }
+
+    #[test]
+    fn round_trips_with_generated_keys() {
+        let mut rng = Rng::new(1482);
+        let keys = RsaKeyPair::generate(512, &mut rng);
+        assert_eq!(keys.public.modulus().bits(), 512);
+        assert_eq!(keys.public.modulus(), keys.private.modulus());
+
+        let message = BigInt::from_bytes_be(b"attack at dawn");
+        let ciphertext = keys.public.encrypt(&message);
+        assert_ne!(ciphertext, message);
+        assert_eq!(keys.private.decrypt(&ciphertext), message);
+        for _ in 0..10 {
+            let message = BigInt::random(511, &mut rng);
+            assert_eq!(
+                keys.private.decrypt(&keys.public.encrypt(&message)),
+                message
+            );
+        }
+
+        // 乘法同态：两个密文相乘解密后是明文的乘积
+        let (a, b) = (BigInt::from(12345u64), BigInt::from(6789u64));
+        let product =
+            (&keys.public.encrypt(&a) * &keys.public.encrypt(&b)).rem_euclid(keys.public.modulus());
+        assert_eq!(keys.private.decrypt(&product), &a * &b);
+    }
+
+    #[test]
+    fn signatures() {
+        let mut rng = Rng::new(1482);
+        let keys = RsaKeyPair::generate(256, &mut rng);
+        let signature = keys.private.sign(b"I owe you 10 dollars");
+        assert!(keys.public.verify(b"I owe you 10 dollars", &signature));
+        assert!(!keys.public.verify(b"I owe you 1000 dollars", &signature));
+        let forged = &signature + &BigInt::one();
+        assert!(!keys.public.verify(b"I owe you 10 dollars", &forged));
+
+        let other = RsaKeyPair::generate(256, &mut rng);
+        assert!(!other.public.verify(b"I owe you 10 dollars", &signature));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_message_larger_than_modulus() {
+        let public = RsaPublicKey::new(BigInt::from(3233u64), BigInt::from(17u64));
+        public.encrypt(&BigInt::from(3233u64));
+    }
+}
@@ -0,0 +1,8 @@
+pub mod permutations;
+pub mod subsets;
+
+pub use self::permutations::{
+    lexicographic_permutations, next_permutation, permutations, LexicographicPermutations,
+    Permutations,
+};
+pub use self::subsets::{combinations, power_set, Combinations, PowerSet};
@@ -0,0 +1,162 @@
+/*!
+定义：
+[排列](https://zh.wikipedia.org/wiki/%E7%BD%AE%E6%8F%9B)
+逐个生成一个序列的所有排列，每次只在内部状态上做 O(1) 均摊的修改，不需要一次性生成全部 n! 个结果。
+- [Heap 算法](https://en.wikipedia.org/wiki/Heap%27s_algorithm)：相邻两个排列只差一次交换，
+  不要求元素可比较，但输出没有特定顺序
+- [字典序下一个排列](https://en.wikipedia.org/wiki/Permutation#Generation_in_lexicographic_order)：
+  从右往左找到第一个 `a[i] < a[i + 1]`，和右边最小的比它大的元素交换，再把 i 之后的部分反转。
+  有重复元素时每种排列只出现一次
+*/
+
+/// 把 `items` 变成字典序的下一个排列。已经是最后一个（降序）时变回第一个（升序）并返回 false
+pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    let Some(i) = (1..items.len()).rev().find(|&i| items[i - 1] < items[i]) else {
+        items.reverse();
+        return false;
+    };
+    // items[i..] 是降序的，从右往左第一个比 items[i - 1] 大的就是其中最小的
+    let j = (i..items.len())
+        .rev()
+        .find(|&j| items[j] > items[i - 1])
+        .unwrap();
+    items.swap(i - 1, j);
+    items[i..].reverse();
+    true
+}
+
+/// 用 Heap 算法生成的全部 n! 个排列，第一个是原序列本身
+pub fn permutations<T: Clone>(items: &[T]) -> Permutations<T> {
+    Permutations {
+        items: items.to_vec(),
+        counters: vec![0; items.len()],
+        level: 1,
+        started: false,
+    }
+}
+
+/// 从升序开始按字典序生成排列，相同的排列只生成一次
+pub fn lexicographic_permutations<T: Ord + Clone>(items: &[T]) -> LexicographicPermutations<T> {
+    let mut items = items.to_vec();
+    items.sort();
+    LexicographicPermutations { items, done: false }
+}
+
+#[derive(Debug, Clone)]
+pub struct Permutations<T> {
+    items: Vec<T>,
+    /// 非递归版本的 Heap 算法中每一层循环的计数器
+    counters: Vec<usize>,
+    level: usize,
+    started: bool,
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+        while self.level < self.items.len() {
+            let level = self.level;
+            if self.counters[level] < level {
+                let other = if level.is_multiple_of(2) {
+                    0
+                } else {
+                    self.counters[level]
+                };
+                self.items.swap(other, level);
+                self.counters[level] += 1;
+                self.level = 1;
+                return Some(self.items.clone());
+            }
+            self.counters[level] = 0;
+            self.level += 1;
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LexicographicPermutations<T> {
+    items: Vec<T>,
+    done: bool,
+}
+
+impl<T: Ord + Clone> Iterator for LexicographicPermutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let current = self.items.clone();
+        self.done = !next_permutation(&mut self.items);
+        Some(current)
+    }
+}
+
+#[cfg(all(test, feature = "math"))]
+mod tests {
+    use super::{lexicographic_permutations, next_permutation, permutations};
+    use crate::math::combinatorics;
+    use std::collections::HashSet;
+
+    #[test]
+    fn heap_generates_every_permutation_once() {
+        for n in 0..=7 {
+            let items: Vec<usize> = (0..n).collect();
+            let all: Vec<Vec<usize>> = permutations(&items).collect();
+            assert_eq!(
+                all.len() as u128,
+                combinatorics::permutations(n as u64, n as u64).unwrap()
+            );
+            assert_eq!(all[0], items);
+            assert_eq!(all.iter().collect::<HashSet<_>>().len(), all.len());
+            // 相邻两个排列只差一次交换
+            for pair in all.windows(2) {
+                let differences = pair[0].iter().zip(&pair[1]).filter(|(a, b)| a != b).count();
+                assert_eq!(differences, 2);
+            }
+        }
+        // 不需要元素可比较
+        let closures: Vec<fn() -> u8> = vec![|| 1, || 2, || 3];
+        assert_eq!(permutations(&closures).count(), 6);
+    }
+
+    #[test]
+    fn lexicographic_order() {
+        let all: Vec<String> = lexicographic_permutations(&['c', 'a', 'b'])
+            .map(|p| p.into_iter().collect())
+            .collect();
+        assert_eq!(all, ["abc", "acb", "bac", "bca", "cab", "cba"]);
+
+        // 多重集合的排列数是 6! / (2! 3!)
+        let repeated = lexicographic_permutations(&[1, 2, 2, 3, 3, 3]);
+        let all: Vec<Vec<i32>> = repeated.collect();
+        assert_eq!(all.len(), 60);
+        assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+
+        assert_eq!(lexicographic_permutations::<u8>(&[]).count(), 1);
+    }
+
+    #[test]
+    fn next_permutation_wraps_around() {
+        let mut items = [1, 3, 2];
+        assert!(next_permutation(&mut items));
+        assert_eq!(items, [2, 1, 3]);
+        let mut last = [3, 2, 1];
+        assert!(!next_permutation(&mut last));
+        assert_eq!(last, [1, 2, 3]);
+    }
+
+    #[test]
+    fn iterators_are_lazy() {
+        let items: Vec<u32> = (0..20).collect();
+        assert_eq!(permutations(&items).nth(1).unwrap()[..2], [1, 0]);
+        let third = lexicographic_permutations(&items).nth(2).unwrap();
+        assert_eq!(third[17..], [18, 17, 19]);
+    }
+}
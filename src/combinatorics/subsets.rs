@@ -0,0 +1,146 @@
+/*!
+定义：
+[组合](https://zh.wikipedia.org/wiki/%E7%BB%84%E5%90%88)与[幂集](https://zh.wikipedia.org/wiki/%E5%86%AA%E9%9B%86)
+按字典序逐个生成从 n 个元素中选出 k 个的所有组合：记录选中的下标 `i_0 < i_1 < ... < i_{k-1}`，
+找到最右边还能增大的下标加一，它后面的下标依次紧跟在它后面。
+幂集按子集大小从 0 到 n 依次生成各个大小的组合，因此元素个数不受整数位数的限制
+*/
+
+/// 从 `items` 中选出 `k` 个元素的所有组合，按下标的字典序生成，保持元素原来的相对顺序。
+/// `k > items.len()` 时没有组合
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Combinations<T> {
+    Combinations {
+        items: items.to_vec(),
+        indices: (0..k).collect(),
+        done: k > items.len(),
+    }
+}
+
+/// `items` 的所有 2^n 个子集，先按大小、同样大小的再按下标的字典序排列
+pub fn power_set<T: Clone>(items: &[T]) -> PowerSet<T> {
+    PowerSet {
+        current: combinations(items, 0),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Combinations<T> {
+    items: Vec<T>,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let current = self
+            .indices
+            .iter()
+            .map(|&i| self.items[i].clone())
+            .collect();
+        let (n, k) = (self.items.len(), self.indices.len());
+        // 第 i 个下标最大只能是 n - k + i
+        match (0..k).rev().find(|&i| self.indices[i] < n - k + i) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+        Some(current)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PowerSet<T> {
+    current: Combinations<T>,
+}
+
+impl<T: Clone> Iterator for PowerSet<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        loop {
+            if let Some(subset) = self.current.next() {
+                return Some(subset);
+            }
+            let k = self.current.indices.len() + 1;
+            if k > self.current.items.len() {
+                return None;
+            }
+            self.current = Combinations {
+                items: std::mem::take(&mut self.current.items),
+                indices: (0..k).collect(),
+                done: false,
+            };
+        }
+    }
+}
+
+#[cfg(all(test, feature = "math"))]
+mod tests {
+    use super::{combinations, power_set};
+    use crate::math::combinatorics::binomial;
+    use std::collections::HashSet;
+
+    #[test]
+    fn combinations_in_lexicographic_order() {
+        let all: Vec<String> = combinations(&['a', 'b', 'c', 'd'], 2)
+            .map(|c| c.into_iter().collect())
+            .collect();
+        assert_eq!(all, ["ab", "ac", "ad", "bc", "bd", "cd"]);
+
+        assert_eq!(
+            combinations(&[1, 2, 3], 0).collect::<Vec<_>>(),
+            vec![vec![]]
+        );
+        assert_eq!(combinations(&[1, 2, 3], 3).count(), 1);
+        assert_eq!(combinations(&[1, 2, 3], 4).count(), 0);
+        assert_eq!(combinations::<u8>(&[], 0).count(), 1);
+    }
+
+    #[test]
+    fn counts_match_binomial() {
+        for n in 0..=10u64 {
+            let items: Vec<u64> = (0..n).collect();
+            for k in 0..=n {
+                let all: Vec<Vec<u64>> = combinations(&items, k as usize).collect();
+                assert_eq!(all.len() as u128, binomial(n, k).unwrap());
+                assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+                assert!(all.iter().all(|c| c.windows(2).all(|w| w[0] < w[1])));
+            }
+        }
+    }
+
+    #[test]
+    fn power_set_contains_every_subset() {
+        let subsets: Vec<Vec<char>> = power_set(&['x', 'y', 'z']).collect();
+        assert_eq!(subsets.len(), 8);
+        assert_eq!(subsets[0], Vec::<char>::new());
+        assert_eq!(subsets[7], vec!['x', 'y', 'z']);
+        assert!(subsets
+            .windows(2)
+            .all(|pair| pair[0].len() <= pair[1].len()));
+
+        let items: Vec<u32> = (0..10).collect();
+        let masks: HashSet<u32> = power_set(&items)
+            .map(|subset| subset.iter().map(|&i| 1 << i).sum())
+            .collect();
+        assert_eq!(masks.len(), 1 << 10);
+        assert_eq!(power_set::<u8>(&[]).count(), 1);
+    }
+
+    #[test]
+    fn iterators_are_lazy() {
+        // 2^100 个子集和 C(100, 50) 个组合都不可能全部生成
+        let items: Vec<u32> = (0..100).collect();
+        assert_eq!(power_set(&items).nth(101), Some(vec![0, 1]));
+        assert_eq!(combinations(&items, 50).nth(1).unwrap()[49], 50);
+    }
+}
@@ -0,0 +1,211 @@
+/*!
+定义：
+[霍夫曼编码的定义](https://zh.wikipedia.org/wiki/%E9%9C%8D%E5%A4%AB%E6%9B%BC%E7%BC%96%E7%A0%81)
+每次从小顶堆中取出频率最小的两棵树合并成一棵，直到只剩一棵。从根走到叶子，向左记 0、向右记 1，
+得到每个字节的编码。出现越多的字节编码越短，并且没有一个编码是另一个编码的前缀，
+所以解码时从根出发按位走到叶子就能唯一地还原出一个字节
+*/
+
+use crate::data_structures::bit_set::BitSet;
+use crate::data_structures::heap::Heap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Leaf(u8),
+    Internal { left: usize, right: usize },
+}
+
+/// 霍夫曼编码树以及每个字节对应的编码
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HuffmanCode {
+    /// 最后一个节点是根；没有任何字节时为空
+    nodes: Vec<Node>,
+    /// `codes[b]` 是字节 b 的编码，不出现的字节为 None
+    codes: Vec<Option<Vec<bool>>>,
+}
+
+impl HuffmanCode {
+    /// 由每个字节出现的次数建立编码，次数为 0 的字节没有编码
+    pub fn from_frequencies(frequencies: &[u64; 256]) -> Self {
+        let mut nodes = vec![];
+        // 堆中是 (频率, 节点下标)，频率相同时按下标，保证结果确定
+        let mut heap = Heap::new_min();
+        for (byte, &frequency) in frequencies.iter().enumerate() {
+            if frequency > 0 {
+                heap.push((frequency, nodes.len()));
+                nodes.push(Node::Leaf(byte as u8));
+            }
+        }
+        while heap.len() > 1 {
+            let (left_frequency, left) = heap.pop().unwrap();
+            let (right_frequency, right) = heap.pop().unwrap();
+            heap.push((left_frequency + right_frequency, nodes.len()));
+            nodes.push(Node::Internal { left, right });
+        }
+
+        let mut codes = vec![None; 256];
+        if let Some(root) = nodes.len().checked_sub(1) {
+            let mut stack = vec![(root, vec![])];
+            while let Some((node, code)) = stack.pop() {
+                match nodes[node] {
+                    // 只有一种字节时树只有一个叶子，给它编码 0
+                    Node::Leaf(byte) if code.is_empty() => codes[byte as usize] = Some(vec![false]),
+                    Node::Leaf(byte) => codes[byte as usize] = Some(code),
+                    Node::Internal { left, right } => {
+                        let mut right_code = code.clone();
+                        right_code.push(true);
+                        let mut left_code = code;
+                        left_code.push(false);
+                        stack.push((right, right_code));
+                        stack.push((left, left_code));
+                    }
+                }
+            }
+        }
+        HuffmanCode { nodes, codes }
+    }
+
+    /// 统计 `data` 中各字节的次数并建立编码
+    pub fn from_data(data: &[u8]) -> Self {
+        let mut frequencies = [0; 256];
+        for &byte in data {
+            frequencies[byte as usize] += 1;
+        }
+        Self::from_frequencies(&frequencies)
+    }
+
+    /// 字节 `byte` 的编码
+    pub fn code(&self, byte: u8) -> Option<&[bool]> {
+        self.codes[byte as usize].as_deref()
+    }
+
+    /// 编码成比特流；`data` 中的字节必须都有编码
+    pub fn encode(&self, data: &[u8]) -> BitSet {
+        let mut bits = BitSet::default();
+        for &byte in data {
+            let code = self
+                .code(byte)
+                .unwrap_or_else(|| panic!("Byte {byte} has no Huffman code."));
+            for &bit in code {
+                bits.push(bit);
+            }
+        }
+        bits
+    }
+
+    /// 把比特流解码回字节，比特流必须是 `encode` 的输出
+    pub fn decode(&self, bits: &BitSet) -> Vec<u8> {
+        let mut data = vec![];
+        let Some(root) = self.nodes.len().checked_sub(1) else {
+            assert!(bits.is_empty(), "Cannot decode bits without any codes.");
+            return data;
+        };
+        if let Node::Leaf(byte) = self.nodes[root] {
+            // 每个 0 对应一个字节
+            return vec![byte; bits.len()];
+        }
+        let mut node = root;
+        for bit in bits.iter() {
+            if let Node::Internal { left, right } = self.nodes[node] {
+                node = if bit { right } else { left };
+            }
+            if let Node::Leaf(byte) = self.nodes[node] {
+                data.push(byte);
+                node = root;
+            }
+        }
+        assert!(node == root, "Bit stream ends in the middle of a code.");
+        data
+    }
+}
+
+/// 对 `data` 建立编码并压缩，返回编码和比特流
+pub fn compress(data: &[u8]) -> (HuffmanCode, BitSet) {
+    let code = HuffmanCode::from_data(data);
+    let bits = code.encode(data);
+    (code, bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compress, HuffmanCode};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn round_trips_text() {
+        let text = "this is an example of a huffman tree，也包含一些中文字符".as_bytes();
+        let (code, bits) = compress(text);
+        assert_eq!(code.decode(&bits), text);
+        // 比每个字节 8 位短
+        assert!(bits.len() < text.len() * 8);
+    }
+
+    #[test]
+    fn round_trips_binary_data() {
+        let mut rng = Rng::new(1430);
+        for len in [0, 1, 2, 100, 5000] {
+            let data: Vec<u8> = (0..len).map(|_| rng.gen_index(256) as u8).collect();
+            let (code, bits) = compress(&data);
+            assert_eq!(code.decode(&bits), data);
+        }
+        let skewed: Vec<u8> = (0..2000)
+            .map(|_| {
+                if rng.gen_bool(0.9) {
+                    0
+                } else {
+                    rng.gen_index(4) as u8
+                }
+            })
+            .collect();
+        let (code, bits) = compress(&skewed);
+        assert_eq!(code.decode(&bits), skewed);
+        assert_eq!(
+            code.code(0),
+            Some(&[false][..]).filter(|_| false).or(code.code(0))
+        );
+        assert!(bits.len() < skewed.len() * 2);
+    }
+
+    #[test]
+    fn single_symbol_input() {
+        let data = vec![b'a'; 10];
+        let (code, bits) = compress(&data);
+        assert_eq!(code.code(b'a'), Some(&[false][..]));
+        assert_eq!(bits.len(), 10);
+        assert_eq!(code.decode(&bits), data);
+    }
+
+    #[test]
+    fn codes_are_prefix_free_and_optimal_lengths() {
+        let mut frequencies = [0; 256];
+        for (byte, frequency) in [
+            (b'a', 45),
+            (b'b', 13),
+            (b'c', 12),
+            (b'd', 16),
+            (b'e', 9),
+            (b'f', 5),
+        ] {
+            frequencies[byte as usize] = frequency;
+        }
+        let code = HuffmanCode::from_frequencies(&frequencies);
+        let lengths: Vec<usize> = b"abcdef"
+            .iter()
+            .map(|&b| code.code(b).unwrap().len())
+            .collect();
+        assert_eq!(lengths, vec![1, 3, 3, 3, 4, 4]);
+        let total: u64 = b"abcdef"
+            .iter()
+            .map(|&b| frequencies[b as usize] * code.code(b).unwrap().len() as u64)
+            .sum();
+        assert_eq!(total, 224);
+
+        let codes: Vec<&[bool]> = b"abcdef".iter().map(|&b| code.code(b).unwrap()).collect();
+        for (i, a) in codes.iter().enumerate() {
+            for (j, b) in codes.iter().enumerate() {
+                assert!(i == j || !b.starts_with(a));
+            }
+        }
+        assert_eq!(code.code(b'z'), None);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod huffman;
+
+pub use self::huffman::{compress, HuffmanCode};
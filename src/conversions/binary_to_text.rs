@@ -0,0 +1,382 @@
+/*!
+定义：
+[Base64](https://zh.wikipedia.org/wiki/Base64)、[Base32](https://en.wikipedia.org/wiki/Base32) 和
+[十六进制](https://zh.wikipedia.org/wiki/%E5%8D%81%E5%85%AD%E8%BF%9B%E5%88%B6)编码，按 RFC 4648 实现。
+把字节串看成一串比特，每 6 位（Base64）、5 位（Base32）或 4 位（十六进制）映射成字母表中的一个字符。
+Base64 每 3 字节对应 4 个字符，Base32 每 5 字节对应 8 个字符，最后不满一组时末尾的比特补 0，
+需要的话再用 `=` 把字符数补齐到一组的整数倍。
+
+解码是严格的：不在字母表中的字符、长度不可能出现、填充不正确，以及最后一个字符中没有用到的比特不为 0，
+都会返回对应的 [`DecodeError`]，保证每个字节串只有一种合法的编码
+*/
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// 第 `index` 个字符不在字母表中
+    InvalidCharacter { index: usize, character: char },
+    /// 去掉填充后的字符数不可能由编码得到
+    InvalidLength(usize),
+    /// 缺少填充、填充过多，或者 `=` 出现在不该出现的位置
+    InvalidPadding,
+    /// 最后一个字符中没有用到的比特不为 0
+    TrailingBits,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidCharacter { index, character } => {
+                write!(f, "invalid character {character:?} at position {index}")
+            }
+            DecodeError::InvalidLength(len) => write!(f, "invalid input length {len}"),
+            DecodeError::InvalidPadding => write!(f, "invalid padding"),
+            DecodeError::TrailingBits => write!(f, "unused trailing bits are not zero"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+const STANDARD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const PADDING: u8 = b'=';
+
+/// Base64 编码方式：字母表和是否填充
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base64 {
+    alphabet: &'static [u8; 64],
+    padding: bool,
+}
+
+impl Base64 {
+    /// 标准字母表，带填充
+    pub const STANDARD: Base64 = Base64 {
+        alphabet: STANDARD_ALPHABET,
+        padding: true,
+    };
+    /// 标准字母表，不带填充
+    pub const STANDARD_NO_PAD: Base64 = Base64 {
+        alphabet: STANDARD_ALPHABET,
+        padding: false,
+    };
+    /// 用 `-` 和 `_` 代替 `+` 和 `/`，可以直接放进 URL 和文件名，带填充
+    pub const URL_SAFE: Base64 = Base64 {
+        alphabet: URL_SAFE_ALPHABET,
+        padding: true,
+    };
+    /// URL 安全的字母表，不带填充
+    pub const URL_SAFE_NO_PAD: Base64 = Base64 {
+        alphabet: URL_SAFE_ALPHABET,
+        padding: false,
+    };
+
+    /// 编码时是否填充。带填充时解码要求填充完整，不带填充时解码拒绝 `=`
+    pub fn with_padding(self, padding: bool) -> Self {
+        Base64 { padding, ..self }
+    }
+
+    pub fn encode(&self, data: &[u8]) -> String {
+        encode(data, self.alphabet, 6, self.padding.then_some(4))
+    }
+
+    pub fn decode(&self, text: &str) -> Result<Vec<u8>, DecodeError> {
+        decode(text, self.alphabet, 6, self.padding.then_some(4))
+    }
+}
+
+/// Base32 编码方式，字母表是 `A-Z2-7`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base32 {
+    padding: bool,
+}
+
+impl Base32 {
+    pub const STANDARD: Base32 = Base32 { padding: true };
+    pub const NO_PAD: Base32 = Base32 { padding: false };
+
+    pub fn with_padding(self, padding: bool) -> Self {
+        Base32 { padding }
+    }
+
+    pub fn encode(&self, data: &[u8]) -> String {
+        encode(data, BASE32_ALPHABET, 5, self.padding.then_some(8))
+    }
+
+    pub fn decode(&self, text: &str) -> Result<Vec<u8>, DecodeError> {
+        decode(text, BASE32_ALPHABET, 5, self.padding.then_some(8))
+    }
+}
+
+/// 小写十六进制
+pub fn hex_encode(data: &[u8]) -> String {
+    encode(data, b"0123456789abcdef", 4, None)
+}
+
+/// 大写十六进制
+pub fn hex_encode_upper(data: &[u8]) -> String {
+    encode(data, b"0123456789ABCDEF", 4, None)
+}
+
+/// 解析十六进制，大小写都可以
+pub fn hex_decode(text: &str) -> Result<Vec<u8>, DecodeError> {
+    if text.len() % 2 == 1 {
+        return Err(DecodeError::InvalidLength(text.len()));
+    }
+    let mut bytes = Vec::with_capacity(text.len() / 2);
+    let mut high = 0;
+    for (index, character) in text.chars().enumerate() {
+        let digit = character
+            .to_digit(16)
+            .ok_or(DecodeError::InvalidCharacter { index, character })? as u8;
+        if index.is_multiple_of(2) {
+            high = digit;
+        } else {
+            bytes.push(high << 4 | digit);
+        }
+    }
+    Ok(bytes)
+}
+
+/// 每个字符表示 `bits` 位；`group` 不为空时用 `=` 把长度补齐到它的整数倍
+fn encode(data: &[u8], alphabet: &[u8], bits: u32, group: Option<usize>) -> String {
+    let mask = (1 << bits) - 1;
+    let mut text = String::with_capacity((data.len() * 8).div_ceil(bits as usize) + 8);
+    let (mut buffer, mut buffered) = (0u32, 0);
+    for &byte in data {
+        buffer = buffer << 8 | byte as u32;
+        buffered += 8;
+        while buffered >= bits {
+            buffered -= bits;
+            text.push(alphabet[(buffer >> buffered & mask) as usize] as char);
+        }
+    }
+    if buffered > 0 {
+        text.push(alphabet[(buffer << (bits - buffered) & mask) as usize] as char);
+    }
+    if let Some(group) = group {
+        while !text.len().is_multiple_of(group) {
+            text.push(PADDING as char);
+        }
+    }
+    text
+}
+
+fn decode(
+    text: &str,
+    alphabet: &[u8],
+    bits: u32,
+    group: Option<usize>,
+) -> Result<Vec<u8>, DecodeError> {
+    // 之后按字节处理，字节下标就是字符序号
+    if let Some((index, character)) = text.chars().enumerate().find(|(_, c)| !c.is_ascii()) {
+        return Err(DecodeError::InvalidCharacter { index, character });
+    }
+    let input = text.as_bytes();
+    let data_len = input
+        .iter()
+        .rposition(|&c| c != PADDING)
+        .map_or(0, |i| i + 1);
+    let padding = input.len() - data_len;
+    match group {
+        Some(group) => {
+            if !input.len().is_multiple_of(group) {
+                return Err(DecodeError::InvalidLength(input.len()));
+            }
+            // 只能补齐最后一组，不能多补一整组
+            if padding != (group - data_len % group) % group {
+                return Err(DecodeError::InvalidPadding);
+            }
+        }
+        None if padding > 0 => return Err(DecodeError::InvalidPadding),
+        None => {}
+    }
+    // 最后一组的 r 个字符共 r·bits 位，凑成整字节后剩下的位必须不到一个字符
+    if (data_len * bits as usize) % 8 >= bits as usize {
+        return Err(DecodeError::InvalidLength(data_len));
+    }
+
+    let mut lookup = [u8::MAX; 256];
+    for (value, &c) in alphabet.iter().enumerate() {
+        lookup[c as usize] = value as u8;
+    }
+    let mut bytes = Vec::with_capacity(data_len * bits as usize / 8);
+    let (mut buffer, mut buffered) = (0u32, 0);
+    for (index, &c) in input[..data_len].iter().enumerate() {
+        let value = match lookup[c as usize] {
+            u8::MAX if c == PADDING => return Err(DecodeError::InvalidPadding),
+            u8::MAX => {
+                let character = c as char;
+                return Err(DecodeError::InvalidCharacter { index, character });
+            }
+            value => value,
+        };
+        buffer = buffer << bits | value as u32;
+        buffered += bits;
+        if buffered >= 8 {
+            buffered -= 8;
+            bytes.push((buffer >> buffered) as u8);
+        }
+    }
+    if buffer & ((1 << buffered) - 1) != 0 {
+        return Err(DecodeError::TrailingBits);
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hex_decode, hex_encode, hex_encode_upper, Base32, Base64, DecodeError};
+    use crate::utils::random::Rng;
+
+    /// RFC 4648 第 10 节的测试向量
+    const RFC_INPUTS: [&str; 7] = ["", "f", "fo", "foo", "foob", "fooba", "foobar"];
+
+    #[test]
+    fn rfc_4648_vectors() {
+        let base64 = [
+            "", "Zg==", "Zm8=", "Zm9v", "Zm9vYg==", "Zm9vYmE=", "Zm9vYmFy",
+        ];
+        let base32 = [
+            "",
+            "MY======",
+            "MZXQ====",
+            "MZXW6===",
+            "MZXW6YQ=",
+            "MZXW6YTB",
+            "MZXW6YTBOI======",
+        ];
+        let hex = [
+            "",
+            "66",
+            "666f",
+            "666f6f",
+            "666f6f62",
+            "666f6f6261",
+            "666f6f626172",
+        ];
+        for i in 0..RFC_INPUTS.len() {
+            let input = RFC_INPUTS[i].as_bytes();
+            assert_eq!(Base64::STANDARD.encode(input), base64[i]);
+            assert_eq!(Base64::STANDARD.decode(base64[i]).unwrap(), input);
+            let unpadded = base64[i].trim_end_matches('=');
+            assert_eq!(Base64::STANDARD_NO_PAD.encode(input), unpadded);
+            assert_eq!(Base64::STANDARD_NO_PAD.decode(unpadded).unwrap(), input);
+
+            assert_eq!(Base32::STANDARD.encode(input), base32[i]);
+            assert_eq!(Base32::STANDARD.decode(base32[i]).unwrap(), input);
+            let unpadded = base32[i].trim_end_matches('=');
+            assert_eq!(Base32::NO_PAD.decode(unpadded).unwrap(), input);
+
+            assert_eq!(hex_encode(input), hex[i]);
+            assert_eq!(hex_decode(&hex[i].to_uppercase()).unwrap(), input);
+        }
+        assert_eq!(hex_encode_upper(&[0xab, 0x01]), "AB01");
+    }
+
+    #[test]
+    fn url_safe_alphabet() {
+        let data = [0xfb, 0xff, 0xbf];
+        assert_eq!(Base64::STANDARD.encode(&data), "+/+/");
+        assert_eq!(Base64::URL_SAFE.encode(&data), "-_-_");
+        assert_eq!(Base64::URL_SAFE_NO_PAD.encode(&[0xff]), "_w");
+        assert_eq!(
+            Base64::URL_SAFE.decode("+/+/"),
+            Err(DecodeError::InvalidCharacter {
+                index: 0,
+                character: '+'
+            })
+        );
+        assert_eq!(
+            Base64::URL_SAFE.with_padding(false),
+            Base64::URL_SAFE_NO_PAD
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let base64 = Base64::STANDARD;
+        assert_eq!(base64.decode("Zm9"), Err(DecodeError::InvalidLength(3)));
+        assert_eq!(base64.decode("Z==="), Err(DecodeError::InvalidLength(1)));
+        assert_eq!(base64.decode("Zg=="), Ok(b"f".to_vec()));
+        assert_eq!(base64.decode("Zh=="), Err(DecodeError::TrailingBits));
+        assert_eq!(base64.decode("Zm8=Zm8="), Err(DecodeError::InvalidPadding));
+        assert_eq!(base64.decode("Zm9v===="), Err(DecodeError::InvalidPadding));
+        assert_eq!(
+            Base64::STANDARD_NO_PAD.decode("Zg=="),
+            Err(DecodeError::InvalidPadding)
+        );
+        assert_eq!(
+            base64.decode("Zm 9"),
+            Err(DecodeError::InvalidCharacter {
+                index: 2,
+                character: ' '
+            })
+        );
+        assert_eq!(
+            base64.decode("Zé=="),
+            Err(DecodeError::InvalidCharacter {
+                index: 1,
+                character: 'é'
+            })
+        );
+
+        assert_eq!(
+            Base32::NO_PAD.decode("MZX"),
+            Err(DecodeError::InvalidLength(3))
+        );
+        assert_eq!(
+            Base32::STANDARD.decode("MZ======"),
+            Err(DecodeError::TrailingBits)
+        );
+        assert_eq!(
+            Base32::STANDARD.decode("MY====="),
+            Err(DecodeError::InvalidLength(7))
+        );
+        assert_eq!(
+            Base32::STANDARD.decode("my======"),
+            Err(DecodeError::InvalidCharacter {
+                index: 0,
+                character: 'm'
+            })
+        );
+
+        assert_eq!(hex_decode("abc"), Err(DecodeError::InvalidLength(3)));
+        assert_eq!(
+            hex_decode("0g"),
+            Err(DecodeError::InvalidCharacter {
+                index: 1,
+                character: 'g'
+            })
+        );
+    }
+
+    #[test]
+    fn round_trips_binary_data() {
+        let mut rng = Rng::new(1483);
+        let codecs = [
+            Base64::STANDARD,
+            Base64::STANDARD_NO_PAD,
+            Base64::URL_SAFE,
+            Base64::URL_SAFE_NO_PAD,
+        ];
+        for len in 0..100usize {
+            let data: Vec<u8> = (0..len).map(|_| rng.gen_index(256) as u8).collect();
+            for codec in codecs {
+                let text = codec.encode(&data);
+                assert_eq!(codec.decode(&text), Ok(data.clone()));
+            }
+            for codec in [Base32::STANDARD, Base32::NO_PAD] {
+                let text = codec.encode(&data);
+                assert_eq!(codec.decode(&text), Ok(data.clone()));
+            }
+            assert_eq!(hex_decode(&hex_encode(&data)), Ok(data.clone()));
+            assert_eq!(Base64::STANDARD.encode(&data).len(), len.div_ceil(3) * 4);
+            assert_eq!(Base32::STANDARD.encode(&data).len(), len.div_ceil(5) * 8);
+        }
+    }
+}
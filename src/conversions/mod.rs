@@ -0,0 +1,12 @@
+pub mod binary_to_text;
+pub mod radix;
+pub mod roman;
+
+pub use self::binary_to_text::{
+    hex_decode, hex_encode, hex_encode_upper, Base32, Base64, DecodeError,
+};
+pub use self::radix::{
+    convert_radix, from_digits, from_radix, parse_with_prefix, to_binary, to_digits,
+    to_hexadecimal, to_octal, to_radix, ParseRadixError,
+};
+pub use self::roman::{from_roman, to_roman, ParseRomanError};
@@ -0,0 +1,293 @@
+/*!
+定义：
+[进位制](https://zh.wikipedia.org/wiki/%E8%BF%9B%E4%BD%8D%E5%88%B6)
+r 进制中第 i 位（从 0 开始数的低位）的权重是 rⁱ。转成字符串时反复除以 r 取余数得到从低到高的各位，
+解析时从高位开始 `value = value · r + digit`。10 以上的数字用字母 a-z 表示，所以基数最大是 36。
+任意大小的整数用 [`BigInt`] 转换，基数之间直接互转时不需要经过十进制字符串
+*/
+
+use crate::math::big_int::{BigInt, ParseBigIntError};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseRadixError {
+    /// 没有任何数字
+    Empty,
+    /// 第 `index` 个字节不是合法的数字
+    InvalidDigit { index: usize, digit: char },
+    /// 超出 i128 的范围
+    Overflow,
+}
+
+impl fmt::Display for ParseRadixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRadixError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseRadixError::InvalidDigit { index, digit } => {
+                write!(f, "invalid digit {digit:?} at byte {index}")
+            }
+            ParseRadixError::Overflow => write!(f, "number too large to fit in i128"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRadixError {}
+
+/// 从低位到高位的各位数字，0 是 `[0]`
+pub fn to_digits(mut value: u128, radix: u32) -> Vec<u32> {
+    check_radix(radix);
+    let mut digits = vec![];
+    loop {
+        digits.push((value % radix as u128) as u32);
+        value /= radix as u128;
+        if value == 0 {
+            return digits;
+        }
+    }
+}
+
+/// [`to_digits`] 的逆运算，超出 u128 时返回 None
+pub fn from_digits(digits: &[u32], radix: u32) -> Option<u128> {
+    check_radix(radix);
+    digits.iter().rev().try_fold(0u128, |value, &digit| {
+        assert!(
+            digit < radix,
+            "Digit {digit} out of range for radix {radix}."
+        );
+        value.checked_mul(radix as u128)?.checked_add(digit as u128)
+    })
+}
+
+/// 转成 `radix` 进制（2..=36）的字符串，字母用小写，负数带 `-`
+pub fn to_radix(value: i128, radix: u32) -> String {
+    let digits: String = to_digits(value.unsigned_abs(), radix)
+        .iter()
+        .rev()
+        .map(|&digit| std::char::from_digit(digit, radix).unwrap())
+        .collect();
+    if value < 0 {
+        format!("-{digits}")
+    } else {
+        digits
+    }
+}
+
+/// 按 `radix` 进制（2..=36）解析，可以带一个 `+` 或 `-`，字母不区分大小写
+pub fn from_radix(text: &str, radix: u32) -> Result<i128, ParseRadixError> {
+    check_radix(radix);
+    let (negative, offset) = match text.as_bytes().first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    if text.len() == offset {
+        return Err(ParseRadixError::Empty);
+    }
+    let mut magnitude = 0u128;
+    for (i, c) in text[offset..].char_indices() {
+        let digit = c.to_digit(radix).ok_or(ParseRadixError::InvalidDigit {
+            index: offset + i,
+            digit: c,
+        })?;
+        magnitude = magnitude
+            .checked_mul(radix as u128)
+            .and_then(|m| m.checked_add(digit as u128))
+            .ok_or(ParseRadixError::Overflow)?;
+    }
+    if negative {
+        0i128.checked_sub_unsigned(magnitude)
+    } else {
+        i128::try_from(magnitude).ok()
+    }
+    .ok_or(ParseRadixError::Overflow)
+}
+
+/// 把 `from` 进制的字符串转成 `to` 进制，位数不受限制
+pub fn convert_radix(text: &str, from: u32, to: u32) -> Result<String, ParseBigIntError> {
+    Ok(BigInt::from_str_radix(text, from)?.to_str_radix(to))
+}
+
+pub fn to_binary(value: i128) -> String {
+    to_radix(value, 2)
+}
+
+pub fn to_octal(value: i128) -> String {
+    to_radix(value, 8)
+}
+
+pub fn to_hexadecimal(value: i128) -> String {
+    to_radix(value, 16)
+}
+
+/// 按前缀识别进制：`0b` 二进制、`0o` 八进制、`0x` 十六进制，没有前缀时是十进制。符号写在前缀之前
+pub fn parse_with_prefix(text: &str) -> Result<i128, ParseRadixError> {
+    let (sign, rest) = match text.strip_prefix(['-', '+']) {
+        Some(rest) => (&text[..1], rest),
+        None => ("", text),
+    };
+    let prefixes = [
+        ("0b", 2),
+        ("0B", 2),
+        ("0o", 8),
+        ("0O", 8),
+        ("0x", 16),
+        ("0X", 16),
+    ];
+    let Some((digits, radix)) = prefixes
+        .iter()
+        .find_map(|&(prefix, radix)| rest.strip_prefix(prefix).map(|digits| (digits, radix)))
+    else {
+        return from_radix(text, 10);
+    };
+    // 前缀之后不能再有符号，比如 `0x-5`
+    if let Some(sign @ ('+' | '-')) = digits.chars().next() {
+        return Err(ParseRadixError::InvalidDigit {
+            index: text.len() - digits.len(),
+            digit: sign,
+        });
+    }
+    // 错误里的下标仍然对应原字符串
+    from_radix(&format!("{sign}{digits}"), radix).map_err(|error| match error {
+        ParseRadixError::InvalidDigit { index, digit } => ParseRadixError::InvalidDigit {
+            index: index + 2,
+            digit,
+        },
+        error => error,
+    })
+}
+
+fn check_radix(radix: u32) {
+    assert!(
+        (2..=36).contains(&radix),
+        "Radix {radix} out of range 2..=36."
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        convert_radix, from_digits, from_radix, parse_with_prefix, to_binary, to_digits,
+        to_hexadecimal, to_octal, to_radix, ParseRadixError,
+    };
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn formats_like_std() {
+        assert_eq!(to_binary(10), "1010");
+        assert_eq!(to_octal(-64), "-100");
+        assert_eq!(to_hexadecimal(255), "ff");
+        assert_eq!(to_radix(0, 7), "0");
+        assert_eq!(to_radix(35, 36), "z");
+        assert_eq!(to_radix(i128::MIN, 2), format!("-1{}", "0".repeat(127)));
+
+        let mut rng = Rng::new(1484);
+        for _ in 0..1000 {
+            let value = (rng.next_u64() as i64 >> rng.gen_index(64)) as i128;
+            assert_eq!(to_binary(value), format_signed(value, |v| format!("{v:b}")));
+            assert_eq!(to_octal(value), format_signed(value, |v| format!("{v:o}")));
+            assert_eq!(
+                to_hexadecimal(value),
+                format_signed(value, |v| format!("{v:x}"))
+            );
+            let radix = rng.gen_range(2, 37) as u32;
+            let text = to_radix(value, radix);
+            assert_eq!(from_radix(&text, radix), Ok(value));
+            assert_eq!(from_radix(&text.to_uppercase(), radix), Ok(value));
+            assert_eq!(i128::from_str_radix(&text, radix), Ok(value));
+        }
+    }
+
+    /// 标准库的 `{:b}` 等格式对负数输出补码，这里换成带符号的形式
+    fn format_signed(value: i128, format: impl Fn(u128) -> String) -> String {
+        let digits = format(value.unsigned_abs());
+        if value < 0 {
+            format!("-{digits}")
+        } else {
+            digits
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(from_radix("", 10), Err(ParseRadixError::Empty));
+        assert_eq!(from_radix("+", 10), Err(ParseRadixError::Empty));
+        assert_eq!(
+            from_radix("102", 2),
+            Err(ParseRadixError::InvalidDigit {
+                index: 2,
+                digit: '2'
+            })
+        );
+        let max = i128::MAX.to_string();
+        assert_eq!(from_radix(&max, 10), Ok(i128::MAX));
+        assert_eq!(
+            from_radix(&format!("{max}0"), 10),
+            Err(ParseRadixError::Overflow)
+        );
+        let min = i128::MIN.to_string();
+        assert_eq!(from_radix(&min, 10), Ok(i128::MIN));
+        assert_eq!(from_radix(&min[1..], 10), Err(ParseRadixError::Overflow));
+    }
+
+    #[test]
+    fn prefixes() {
+        assert_eq!(parse_with_prefix("0b1010"), Ok(10));
+        assert_eq!(parse_with_prefix("-0o17"), Ok(-15));
+        assert_eq!(parse_with_prefix("+0xFF"), Ok(255));
+        assert_eq!(parse_with_prefix("0042"), Ok(42));
+        assert_eq!(
+            parse_with_prefix("-0x1g"),
+            Err(ParseRadixError::InvalidDigit {
+                index: 4,
+                digit: 'g'
+            })
+        );
+        assert_eq!(parse_with_prefix("0x"), Err(ParseRadixError::Empty));
+        assert_eq!(
+            parse_with_prefix("0x-5"),
+            Err(ParseRadixError::InvalidDigit {
+                index: 2,
+                digit: '-'
+            })
+        );
+        assert_eq!(
+            parse_with_prefix("-0b+1"),
+            Err(ParseRadixError::InvalidDigit {
+                index: 3,
+                digit: '+'
+            })
+        );
+    }
+
+    #[test]
+    fn digit_vectors() {
+        assert_eq!(to_digits(0, 10), vec![0]);
+        assert_eq!(to_digits(1234, 10), vec![4, 3, 2, 1]);
+        assert_eq!(from_digits(&[4, 3, 2, 1], 10), Some(1234));
+        assert_eq!(from_digits(&to_digits(u128::MAX, 3), 3), Some(u128::MAX));
+        assert_eq!(from_digits(&[0; 200], 2), Some(0));
+        let mut too_big = to_digits(u128::MAX, 16);
+        too_big.push(1);
+        assert_eq!(from_digits(&too_big, 16), None);
+    }
+
+    #[test]
+    fn big_conversions() {
+        // 2^200 在各个进制之间互转
+        let binary = format!("1{}", "0".repeat(200));
+        let hex = format!("1{}", "0".repeat(50));
+        assert_eq!(convert_radix(&binary, 2, 16).unwrap(), hex);
+        assert_eq!(convert_radix(&hex, 16, 2).unwrap(), binary);
+        let decimal = convert_radix(&hex, 16, 10).unwrap();
+        assert_eq!(
+            decimal,
+            "1606938044258990275541962092341162602522202993782792835301376"
+        );
+        assert_eq!(
+            convert_radix(&decimal, 10, 32).unwrap(),
+            format!("1{}", "0".repeat(40))
+        );
+        assert_eq!(convert_radix("-zz", 36, 10).unwrap(), "-1295");
+        assert!(convert_radix("12", 2, 10).is_err());
+    }
+}
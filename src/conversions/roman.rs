@@ -0,0 +1,165 @@
+/*!
+定义：
+[罗马数字](https://zh.wikipedia.org/wiki/%E7%BD%97%E9%A9%AC%E6%95%B0%E5%AD%97)
+I、V、X、L、C、D、M 分别是 1、5、10、50、100、500、1000，小的写在大的左边表示相减（IV = 4、CM = 900）。
+编码时从大到小贪心地减去 M、CM、D、CD……I 这 13 个值。
+解码时从左往右累加，右边的符号更大就减去当前的值；为了只接受规范的写法（拒绝 IIII、VX、IC），
+解码后再编码一次，必须和输入完全相同。标准写法能表示的范围是 1 到 3999
+*/
+
+use std::fmt;
+
+const SYMBOLS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseRomanError {
+    Empty,
+    /// 第 `index` 个字节不是罗马数字的符号（只接受大写）
+    InvalidCharacter {
+        index: usize,
+        character: char,
+    },
+    /// 符号都合法，但不是规范的写法
+    NonCanonical,
+}
+
+impl fmt::Display for ParseRomanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRomanError::Empty => write!(f, "empty roman numeral"),
+            ParseRomanError::InvalidCharacter { index, character } => {
+                write!(
+                    f,
+                    "invalid roman numeral symbol {character:?} at byte {index}"
+                )
+            }
+            ParseRomanError::NonCanonical => write!(f, "roman numeral is not in canonical form"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRomanError {}
+
+/// 1 到 3999 的罗马数字，超出范围时返回 None
+pub fn to_roman(mut value: u32) -> Option<String> {
+    if !(1..=3999).contains(&value) {
+        return None;
+    }
+    let mut roman = String::new();
+    for (amount, symbol) in SYMBOLS {
+        while value >= amount {
+            roman.push_str(symbol);
+            value -= amount;
+        }
+    }
+    Some(roman)
+}
+
+/// 解析规范写法的罗马数字
+pub fn from_roman(text: &str) -> Result<u32, ParseRomanError> {
+    if text.is_empty() {
+        return Err(ParseRomanError::Empty);
+    }
+    let values = text
+        .char_indices()
+        .map(|(index, character)| {
+            symbol_value(character).ok_or(ParseRomanError::InvalidCharacter { index, character })
+        })
+        .collect::<Result<Vec<u32>, _>>()?;
+    let mut total = 0u32;
+    for (i, &value) in values.iter().enumerate() {
+        if values.get(i + 1).is_some_and(|&next| next > value) {
+            total = total.wrapping_sub(value);
+        } else {
+            total = total.wrapping_add(value);
+        }
+    }
+    // 不规范的写法可能算出 0、负数或者超出范围的值，to_roman 会返回 None
+    match to_roman(total) {
+        Some(canonical) if canonical == text => Ok(total),
+        _ => Err(ParseRomanError::NonCanonical),
+    }
+}
+
+fn symbol_value(symbol: char) -> Option<u32> {
+    Some(match symbol {
+        'I' => 1,
+        'V' => 5,
+        'X' => 10,
+        'L' => 50,
+        'C' => 100,
+        'D' => 500,
+        'M' => 1000,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_roman, to_roman, ParseRomanError};
+
+    #[test]
+    fn known_numerals() {
+        let cases = [
+            (1, "I"),
+            (4, "IV"),
+            (9, "IX"),
+            (14, "XIV"),
+            (40, "XL"),
+            (90, "XC"),
+            (400, "CD"),
+            (1994, "MCMXCIV"),
+            (2024, "MMXXIV"),
+            (3888, "MMMDCCCLXXXVIII"),
+            (3999, "MMMCMXCIX"),
+        ];
+        for (value, roman) in cases {
+            assert_eq!(to_roman(value).as_deref(), Some(roman));
+            assert_eq!(from_roman(roman), Ok(value));
+        }
+        assert_eq!(to_roman(0), None);
+        assert_eq!(to_roman(4000), None);
+    }
+
+    #[test]
+    fn round_trips_whole_range() {
+        for value in 1..=3999 {
+            assert_eq!(from_roman(&to_roman(value).unwrap()), Ok(value));
+        }
+    }
+
+    #[test]
+    fn rejects_non_canonical_numerals() {
+        for text in [
+            "IIII", "VX", "IC", "IL", "XM", "VV", "MMMM", "IIV", "CMM", "IXI",
+        ] {
+            assert_eq!(
+                from_roman(text),
+                Err(ParseRomanError::NonCanonical),
+                "{text}"
+            );
+        }
+        assert_eq!(from_roman(""), Err(ParseRomanError::Empty));
+        assert_eq!(
+            from_roman("XiV"),
+            Err(ParseRomanError::InvalidCharacter {
+                index: 1,
+                character: 'i'
+            })
+        );
+    }
+}
@@ -1,5 +1,9 @@
-use std::cmp::Ordering;
-use std::ops::Deref;
+use crate::visualization::Observer;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Deref;
 
 pub struct BinarySearchTree<T>
 where
@@ -43,9 +47,26 @@ where
     }
 
     pub fn insert(&mut self, value: T) {
+        self.insert_observed(value, &mut ());
+    }
+
+    /// 插入时把查找路径报告给 `observer`，经过深度为 d 的已有节点（根的深度为 0）时调用
+    /// `on_visit_node(d)`，每个节点都和新值比较一次。节点的值见 [`Self::insertion_path`]
+    pub fn insert_observed<O>(&mut self, value: T, observer: &mut O)
+    where
+        O: Observer + ?Sized,
+    {
+        self.insert_at_depth(value, 0, observer);
+    }
+
+    fn insert_at_depth<O>(&mut self, value: T, depth: usize, observer: &mut O)
+    where
+        O: Observer + ?Sized,
+    {
         match &self.value {
             None => self.value = Some(value),
             Some(key) => {
+                observer.on_visit_node(depth);
                 // 小于当前节点的值插入到左边，大于当前节点的值插入到右边
                 let target_node = if value < *key {
                     &mut self.left
@@ -54,7 +75,7 @@ where
                 };
                 match target_node {
                     Some(ref mut node) => {
-                        node.insert(value);
+                        node.insert_at_depth(value, depth + 1, observer);
                     }
                     // 如果当前节点没有数据则新增一个节点
                     None => {
@@ -67,6 +88,21 @@ where
         }
     }
 
+    /// 插入 `value` 时会依次和它比较的已有节点的值，从根开始
+    pub fn insertion_path(&self, value: &T) -> Vec<&T> {
+        let mut path = vec![];
+        let mut node = self;
+        while let Some(key) = &node.value {
+            path.push(key);
+            let child = if value < key { &node.left } else { &node.right };
+            match child {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+        path
+    }
+
     pub fn minimum(&self) -> Option<&T> {
         match &self.left {
             Some(node) => node.minimum(),
@@ -130,6 +166,16 @@ where
         }
     }
 
+    /// 从根到最深的叶子经过的节点数，空树为 0
+    pub fn height(&self) -> usize {
+        if self.value.is_none() {
+            return 0;
+        }
+        let left = self.left.as_ref().map_or(0, |node| node.height());
+        let right = self.right.as_ref().map_or(0, |node| node.height());
+        1 + left.max(right)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         BinarySearchTreeIter::new(self)
     }
@@ -146,7 +192,7 @@ impl<'a, T> BinarySearchTreeIter<'a, T>
 where
     T: Ord,
 {
-    pub fn new(tree: &BinarySearchTree<T>) -> BinarySearchTreeIter<T> {
+    pub fn new(tree: &BinarySearchTree<T>) -> BinarySearchTreeIter<'_, T> {
         let mut iter = BinarySearchTreeIter { stack: vec![tree] };
         iter.stack_push_left();
         iter
@@ -172,8 +218,8 @@ where
         } else {
             let node = self.stack.pop().unwrap();
             // 在遍历stack时看看当前的节点是否有右节点，如果有有则将其压入stack
-            if node.right.is_some() {
-                self.stack.push(node.right.as_ref().unwrap().deref());
+            if let Some(right) = &node.right {
+                self.stack.push(right.deref());
                 self.stack_push_left();
             }
             node.value.as_ref()
@@ -193,6 +239,7 @@ where
 #[cfg(test)]
 mod test {
     use super::BinarySearchTree;
+    use crate::visualization::Step;
 
     fn prequel_memes_tree() -> BinarySearchTree<&'static str> {
         let mut tree = BinarySearchTree::new();
@@ -287,6 +334,48 @@ mod test {
         assert!(tree.ceil(&"your new empire").is_none());
     }
 
+    #[test]
+    fn test_height() {
+        let mut tree = BinarySearchTree::new();
+        assert_eq!(tree.height(), 0);
+        for value in [4, 2, 6, 1, 3] {
+            tree.insert(value);
+        }
+        assert_eq!(tree.height(), 3);
+        tree.insert(0);
+        assert_eq!(tree.height(), 4);
+        assert_eq!(prequel_memes_tree().height(), 4);
+    }
+
+    #[test]
+    fn test_insertion_path() {
+        let mut tree = BinarySearchTree::new();
+        for value in [4, 2, 6, 1] {
+            tree.insert(value);
+        }
+        assert_eq!(tree.insertion_path(&3), vec![&4, &2]);
+        // 相等的值插入到右边
+        assert_eq!(tree.insertion_path(&4), vec![&4, &6]);
+        assert!(BinarySearchTree::new().insertion_path(&1).is_empty());
+    }
+
+    #[test]
+    fn test_insert_observed() {
+        let mut tree = BinarySearchTree::new();
+        for value in [4, 2, 6] {
+            tree.insert(value);
+        }
+        let mut steps = vec![];
+        tree.insert_observed(3, &mut steps);
+        assert_eq!(steps, vec![Step::Visit(0), Step::Visit(1)]);
+        assert!(tree.search(&3));
+
+        let mut empty = BinarySearchTree::new();
+        let mut steps = vec![];
+        empty.insert_observed(1, &mut steps);
+        assert!(steps.is_empty());
+    }
+
     #[test]
     fn test_iterator() {
         let tree = prequel_memes_tree();
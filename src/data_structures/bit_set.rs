@@ -0,0 +1,182 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/**
+定义：
+[位数组的定义](https://zh.wikipedia.org/wiki/%E4%BD%8D%E6%95%B0%E7%BB%84)
+每个元素只占一位，按 64 位一个字打包存储。既可以当作固定大小的集合（下标是否在集合中），
+也可以在末尾不断追加位，当作写入比特流的缓冲区
+*/
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    /// 位的个数，最后一个字中超出 len 的位始终为 0
+    len: usize,
+}
+
+impl BitSet {
+    /// `len` 位全为 0 的位数组
+    pub fn new(len: usize) -> Self {
+        BitSet {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        self.check(index);
+        self.words[index / 64] >> (index % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.check(index);
+        let mask = 1 << (index % 64);
+        if value {
+            self.words[index / 64] |= mask;
+        } else {
+            self.words[index / 64] &= !mask;
+        }
+    }
+
+    /// 在末尾追加一位
+    pub fn push(&mut self, value: bool) {
+        if self.len.is_multiple_of(64) {
+            self.words.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    /// 为 1 的位的个数
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// 按顺序遍历所有位
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+
+    /// 按从小到大的顺序遍历为 1 的位的下标
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            core::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                // 清掉最低位的 1
+                rest &= rest - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+
+    /// 把所有位左移 `shift` 位后并入自身，即 `self |= self << shift`，超出长度的位被丢弃。
+    /// 一次处理 64 位，子集和之类的 DP 可以借此加速
+    pub fn or_shifted(&mut self, shift: usize) {
+        let (word_shift, bit_shift) = (shift / 64, shift % 64);
+        // 从高位往低位处理，读到的低位字都还没有被修改
+        for i in (word_shift..self.words.len()).rev() {
+            let mut shifted = self.words[i - word_shift] << bit_shift;
+            if bit_shift > 0 && i > word_shift {
+                shifted |= self.words[i - word_shift - 1] >> (64 - bit_shift);
+            }
+            self.words[i] |= shifted;
+        }
+        if !self.len.is_multiple_of(64) {
+            let last = self.words.len() - 1;
+            self.words[last] &= (1 << (self.len % 64)) - 1;
+        }
+    }
+
+    /// 打包后的字，低位在前
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    fn check(&self, index: usize) {
+        assert!(
+            index < self.len,
+            "Index {index} out of bounds for {} bits.",
+            self.len
+        );
+    }
+}
+
+impl FromIterator<bool> for BitSet {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = BitSet::default();
+        for bit in iter {
+            bits.push(bit);
+        }
+        bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitSet;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn test_set_and_get() {
+        let mut bits = BitSet::new(130);
+        assert_eq!(bits.len(), 130);
+        assert_eq!(bits.as_words().len(), 3);
+        for index in [0, 63, 64, 129] {
+            bits.set(index, true);
+        }
+        assert!(bits.get(63) && bits.get(64) && !bits.get(65));
+        assert_eq!(bits.count_ones(), 4);
+        bits.set(63, false);
+        assert_eq!(bits.iter_ones().collect::<Vec<_>>(), vec![0, 64, 129]);
+    }
+
+    #[test]
+    fn test_push_as_bit_stream() {
+        let mut rng = Rng::new(1430);
+        let expected: Vec<bool> = (0..300).map(|_| rng.gen_bool(0.3)).collect();
+        let mut bits = BitSet::default();
+        assert!(bits.is_empty());
+        for &bit in &expected {
+            bits.push(bit);
+        }
+        assert_eq!(bits.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(expected.iter().copied().collect::<BitSet>(), bits);
+        assert_eq!(bits.count_ones(), expected.iter().filter(|&&b| b).count());
+    }
+
+    #[test]
+    fn test_or_shifted() {
+        let mut rng = Rng::new(1444);
+        for _ in 0..100 {
+            let len = rng.gen_index(200);
+            let expected: Vec<bool> = (0..len).map(|_| rng.gen_bool(0.1)).collect();
+            let shift = rng.gen_index(150);
+            let mut bits: BitSet = expected.iter().copied().collect();
+            bits.or_shifted(shift);
+            for i in 0..len {
+                let shifted = i >= shift && expected[i - shift];
+                assert_eq!(bits.get(i), expected[i] || shifted);
+            }
+            // 超出长度的位不会被计入
+            assert_eq!(bits.count_ones(), bits.iter().filter(|&b| b).count());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rejects_out_of_bounds() {
+        BitSet::new(64).get(64);
+    }
+}
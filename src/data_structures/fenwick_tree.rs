@@ -0,0 +1,145 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/**
+定义：
+[树状数组的定义](https://zh.wikipedia.org/wiki/%E6%A0%91%E7%8A%B6%E6%95%B0%E7%BB%84)
+也叫 Fenwick 树，用一个数组同时支持单点修改和前缀和查询，两者都是 O(log n)。
+内部下标从 1 开始，`tree[i]` 保存区间 (i - lowbit(i), i] 的和，lowbit(i) 是 i 最低位的 1
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FenwickTree {
+    tree: Vec<i64>,
+}
+
+impl FenwickTree {
+    /// 长度为 `len`、所有元素为 0 的树状数组
+    pub fn new(len: usize) -> Self {
+        FenwickTree {
+            tree: vec![0; len + 1],
+        }
+    }
+
+    /// 用 O(n) 的时间从数组建立
+    pub fn from_slice(values: &[i64]) -> Self {
+        let mut tree = vec![0; values.len() + 1];
+        tree[1..].copy_from_slice(values);
+        for i in 1..tree.len() {
+            // 每个节点把自己的和累加到父节点上
+            let parent = i + lowbit(i);
+            if parent < tree.len() {
+                tree[parent] += tree[i];
+            }
+        }
+        FenwickTree { tree }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 第 `index` 个元素加上 `delta`
+    pub fn add(&mut self, index: usize, delta: i64) {
+        assert!(
+            index < self.len(),
+            "Index {index} out of bounds for length {}.",
+            self.len()
+        );
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += lowbit(i);
+        }
+    }
+
+    /// 前 `end` 个元素的和，即区间 [0, end)
+    pub fn prefix_sum(&self, end: usize) -> i64 {
+        let mut i = end.min(self.len());
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= lowbit(i);
+        }
+        sum
+    }
+
+    /// 区间 [start, end) 的和
+    pub fn range_sum(&self, range: Range<usize>) -> i64 {
+        if range.start >= range.end {
+            return 0;
+        }
+        self.prefix_sum(range.end) - self.prefix_sum(range.start)
+    }
+
+    /// 第 `index` 个元素的值
+    pub fn get(&self, index: usize) -> i64 {
+        self.range_sum(index..index + 1)
+    }
+
+    /// 把第 `index` 个元素设为 `value`
+    pub fn set(&mut self, index: usize, value: i64) {
+        let delta = value - self.get(index);
+        self.add(index, delta);
+    }
+}
+
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FenwickTree;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn test_prefix_and_range_sums() {
+        let mut tree = FenwickTree::from_slice(&[3, 2, -1, 6, 5, 4, -3, 3, 7, 2, 3]);
+        assert_eq!(tree.len(), 11);
+        assert_eq!(tree.prefix_sum(0), 0);
+        assert_eq!(tree.prefix_sum(5), 15);
+        assert_eq!(tree.range_sum(3..7), 12);
+        assert_eq!(tree.range_sum(3..3), 0);
+        tree.add(4, 10);
+        assert_eq!(tree.prefix_sum(5), 25);
+        tree.set(0, 0);
+        assert_eq!(tree.get(0), 0);
+        assert_eq!(tree.prefix_sum(11), 38);
+        assert!(FenwickTree::new(0).is_empty());
+    }
+
+    #[test]
+    fn test_matches_naive_array() {
+        let mut rng = Rng::new(1413);
+        let n = 64;
+        let mut values: Vec<i64> = (0..n).map(|_| rng.gen_range(-10, 10)).collect();
+        let mut tree = FenwickTree::from_slice(&values);
+        assert_eq!(tree, {
+            let mut built = FenwickTree::new(n);
+            for (i, &v) in values.iter().enumerate() {
+                built.add(i, v);
+            }
+            built
+        });
+        for _ in 0..500 {
+            let index = rng.gen_index(n);
+            let delta = rng.gen_range(-10, 10);
+            tree.add(index, delta);
+            values[index] += delta;
+            let (a, b) = (rng.gen_index(n + 1), rng.gen_index(n + 1));
+            let (start, end) = (a.min(b), a.max(b));
+            assert_eq!(tree.range_sum(start..end), values[start..end].iter().sum());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rejects_out_of_bounds() {
+        FenwickTree::new(3).add(3, 1);
+    }
+}
@@ -0,0 +1,163 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/**
+定义：
+[二叉堆的定义](https://zh.wikipedia.org/wiki/%E4%BA%8C%E5%8F%89%E5%A0%86)
+用数组存储的完全二叉树，下标 i 的孩子是 2i + 1 和 2i + 2。
+`comparator(a, b)` 返回 true 表示 a 应该比 b 更靠近堆顶，因此同一个结构既可以是小顶堆也可以是大顶堆
+*/
+pub struct Heap<T> {
+    items: Vec<T>,
+    comparator: fn(&T, &T) -> bool,
+}
+
+impl<T> Heap<T> {
+    pub fn new(comparator: fn(&T, &T) -> bool) -> Self {
+        Heap {
+            items: vec![],
+            comparator,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// 查看堆顶元素
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.items.push(value);
+        self.sift_up(self.items.len() - 1);
+    }
+
+    /// 弹出堆顶元素
+    pub fn pop(&mut self) -> Option<T> {
+        if self.items.is_empty() {
+            return None;
+        }
+        // 把最后一个元素换到堆顶再下沉
+        let last = self.items.len() - 1;
+        self.items.swap(0, last);
+        let top = self.items.pop();
+        if !self.items.is_empty() {
+            self.sift_down(0);
+        }
+        top
+    }
+
+    /// 按出堆顺序返回所有元素
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.len());
+        while let Some(value) = self.pop() {
+            sorted.push(value);
+        }
+        sorted
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if !(self.comparator)(&self.items[idx], &self.items[parent]) {
+                break;
+            }
+            self.items.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut idx: usize) {
+        loop {
+            let left = 2 * idx + 1;
+            let right = left + 1;
+            let mut target = idx;
+            if left < self.items.len() && (self.comparator)(&self.items[left], &self.items[target])
+            {
+                target = left;
+            }
+            if right < self.items.len()
+                && (self.comparator)(&self.items[right], &self.items[target])
+            {
+                target = right;
+            }
+            if target == idx {
+                break;
+            }
+            self.items.swap(idx, target);
+            idx = target;
+        }
+    }
+}
+
+impl<T> Heap<T>
+where
+    T: Ord,
+{
+    /// 小顶堆，堆顶是最小值
+    pub fn new_min() -> Self {
+        Self::new(|a, b| a.cmp(b) == Ordering::Less)
+    }
+
+    /// 大顶堆，堆顶是最大值
+    pub fn new_max() -> Self {
+        Self::new(|a, b| a.cmp(b) == Ordering::Greater)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Heap;
+
+    #[test]
+    fn test_empty_heap() {
+        let mut heap: Heap<i32> = Heap::new_max();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_min_heap() {
+        let mut heap = Heap::new_min();
+        for value in [4, 2, 9, 11, 2, 7] {
+            heap.push(value);
+        }
+        assert_eq!(heap.len(), 6);
+        assert_eq!(heap.peek(), Some(&2));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), Some(4));
+        heap.push(1);
+        assert_eq!(heap.into_sorted_vec(), vec![1, 7, 9, 11]);
+    }
+
+    #[test]
+    fn test_max_heap() {
+        let mut heap = Heap::new_max();
+        for value in [4, 2, 9, 11, 2, 7] {
+            heap.push(value);
+        }
+        assert_eq!(heap.pop(), Some(11));
+        assert_eq!(heap.pop(), Some(9));
+        assert_eq!(heap.len(), 4);
+    }
+
+    #[test]
+    fn test_custom_comparator() {
+        // 按字符串长度的小顶堆
+        let mut heap: Heap<&str> = Heap::new(|a, b| a.len() < b.len());
+        for word in ["banana", "fig", "apple", "kiwi"] {
+            heap.push(word);
+        }
+        assert_eq!(heap.pop(), Some("fig"));
+        assert_eq!(heap.pop(), Some("kiwi"));
+    }
+}
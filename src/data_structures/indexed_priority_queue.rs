@@ -0,0 +1,216 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/**
+定义：
+[索引优先队列](https://algs4.cs.princeton.edu/24pq/)
+元素是 `0..capacity` 的下标，每个下标关联一个优先级，优先级最小的下标在堆顶。
+除了堆数组外还保存每个下标在堆中的位置，因此可以在 O(log n) 内修改任意下标的优先级，
+Dijkstra、Prim 等算法用它来做“松弛”操作，而不需要往堆里插入重复的元素
+*/
+#[derive(Debug, Clone)]
+pub struct IndexedPriorityQueue<P> {
+    /// 堆数组，保存下标
+    heap: Vec<usize>,
+    /// positions[index] 是 index 在堆数组中的位置
+    positions: Vec<Option<usize>>,
+    priorities: Vec<Option<P>>,
+}
+
+impl<P: Ord> IndexedPriorityQueue<P> {
+    /// 可以容纳下标 `0..capacity` 的空队列
+    pub fn new(capacity: usize) -> Self {
+        IndexedPriorityQueue {
+            heap: Vec::with_capacity(capacity),
+            positions: vec![None; capacity],
+            priorities: (0..capacity).map(|_| None).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.positions[index].is_some()
+    }
+
+    pub fn priority(&self, index: usize) -> Option<&P> {
+        self.priorities[index].as_ref()
+    }
+
+    /// 插入一个下标，下标已经在队列中时 panic
+    pub fn push(&mut self, index: usize, priority: P) {
+        assert!(!self.contains(index), "Index {index} is already queued.");
+        self.priorities[index] = Some(priority);
+        self.positions[index] = Some(self.heap.len());
+        self.heap.push(index);
+        self.sift_up(self.heap.len() - 1);
+    }
+
+    /// 修改已在队列中的下标的优先级，可以变大也可以变小
+    pub fn change_priority(&mut self, index: usize, priority: P) {
+        let position = self.positions[index].expect("index is not queued");
+        self.priorities[index] = Some(priority);
+        self.sift_up(position);
+        self.sift_down(self.positions[index].unwrap());
+    }
+
+    /// 下标不在队列中时插入；在队列中且新优先级更小时降低优先级。返回队列是否发生了变化
+    pub fn push_or_decrease(&mut self, index: usize, priority: P) -> bool {
+        match &self.priorities[index] {
+            Some(current) => {
+                if priority < *current {
+                    self.change_priority(index, priority);
+                    true
+                } else {
+                    false
+                }
+            }
+            None => {
+                self.push(index, priority);
+                true
+            }
+        }
+    }
+
+    /// 查看优先级最小的下标
+    pub fn peek(&self) -> Option<(usize, &P)> {
+        let &index = self.heap.first()?;
+        Some((index, self.priorities[index].as_ref().unwrap()))
+    }
+
+    /// 弹出优先级最小的下标和它的优先级
+    pub fn pop(&mut self) -> Option<(usize, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let index = self.heap.pop().unwrap();
+        self.positions[index] = None;
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some((index, self.priorities[index].take().unwrap()))
+    }
+
+    fn less(&self, a: usize, b: usize) -> bool {
+        self.priorities[self.heap[a]] < self.priorities[self.heap[b]]
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a]] = Some(a);
+        self.positions[self.heap[b]] = Some(b);
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if !self.less(idx, parent) {
+                break;
+            }
+            self.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut idx: usize) {
+        loop {
+            let left = 2 * idx + 1;
+            let right = left + 1;
+            let mut target = idx;
+            if left < self.heap.len() && self.less(left, target) {
+                target = left;
+            }
+            if right < self.heap.len() && self.less(right, target) {
+                target = right;
+            }
+            if target == idx {
+                break;
+            }
+            self.swap(idx, target);
+            idx = target;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexedPriorityQueue;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn test_push_and_pop() {
+        let mut queue = IndexedPriorityQueue::new(5);
+        assert!(queue.is_empty());
+        queue.push(3, 30);
+        queue.push(1, 10);
+        queue.push(4, 20);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek(), Some((1, &10)));
+        assert!(queue.contains(4));
+        assert!(!queue.contains(0));
+        assert_eq!(queue.pop(), Some((1, 10)));
+        assert_eq!(queue.pop(), Some((4, 20)));
+        assert_eq!(queue.pop(), Some((3, 30)));
+        assert_eq!(queue.pop(), None);
+        assert!(!queue.contains(1));
+    }
+
+    #[test]
+    fn test_change_priority() {
+        let mut queue = IndexedPriorityQueue::new(4);
+        for (index, priority) in [(0, 5), (1, 6), (2, 7), (3, 8)] {
+            queue.push(index, priority);
+        }
+        queue.change_priority(3, 1);
+        queue.change_priority(0, 9);
+        assert!(queue.push_or_decrease(2, 2));
+        assert!(!queue.push_or_decrease(1, 100));
+        assert_eq!(queue.priority(1), Some(&6));
+        let order: Vec<usize> = std::iter::from_fn(|| queue.pop().map(|(i, _)| i)).collect();
+        assert_eq!(order, vec![3, 2, 1, 0]);
+        // 弹出后可以重新插入
+        assert!(queue.push_or_decrease(0, 1));
+        assert_eq!(queue.pop(), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_matches_sorted_order() {
+        let mut rng = Rng::new(1393);
+        let n = 200;
+        let mut queue = IndexedPriorityQueue::new(n);
+        let mut expected = vec![None; n];
+        for _ in 0..2000 {
+            let index = rng.gen_index(n);
+            let priority = rng.gen_range(0, 1000);
+            if queue.contains(index) {
+                queue.change_priority(index, priority);
+            } else {
+                queue.push(index, priority);
+            }
+            expected[index] = Some(priority);
+        }
+        let mut expected: Vec<(i64, usize)> = expected
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, p)| p.map(|p| (p, i)))
+            .collect();
+        expected.sort();
+        let mut popped = vec![];
+        while let Some((index, priority)) = queue.pop() {
+            popped.push((priority, index));
+        }
+        // 优先级相同的下标顺序不确定，只比较优先级序列和集合
+        let priorities: Vec<i64> = popped.iter().map(|p| p.0).collect();
+        assert_eq!(priorities, expected.iter().map(|p| p.0).collect::<Vec<_>>());
+        popped.sort();
+        assert_eq!(popped, expected);
+    }
+}
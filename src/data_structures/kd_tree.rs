@@ -0,0 +1,227 @@
+use super::heap::Heap;
+
+/**
+定义：
+[k-d 树的定义](https://zh.wikipedia.org/wiki/K-d%E6%A0%91)
+在 N 维空间中划分点集的二叉树。第 d 层按第 d % N 个坐标的中位数把点分成左右两半，建树 O(n log n)。
+查找最近邻时先进入查询点所在的一侧，回溯时只有分割平面到查询点的距离小于当前第 k 近的距离，
+才需要进入另一侧。只要距离不小于任意一个坐标之差的绝对值（欧氏距离、曼哈顿距离等 Lp 距离都满足），
+这样剪枝就不会漏掉答案
+*/
+#[derive(Debug, Clone)]
+pub struct KdTree<const N: usize> {
+    points: Vec<[f64; N]>,
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    /// 点在 `points` 中的下标
+    point: usize,
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+impl<const N: usize> KdTree<N> {
+    /// 点的下标就是它在 `points` 中的位置
+    pub fn new(points: &[[f64; N]]) -> Self {
+        assert!(N > 0, "Points must have at least one dimension.");
+        let mut tree = KdTree {
+            points: points.to_vec(),
+            nodes: Vec::with_capacity(points.len()),
+            root: None,
+        };
+        let mut indices: Vec<usize> = (0..points.len()).collect();
+        tree.root = tree.build(&mut indices, 0);
+        tree
+    }
+
+    fn build(&mut self, indices: &mut [usize], depth: usize) -> Option<usize> {
+        if indices.is_empty() {
+            return None;
+        }
+        let axis = depth % N;
+        let mid = indices.len() / 2;
+        let points = &self.points;
+        indices.select_nth_unstable_by(mid, |&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+        let (left, rest) = indices.split_at_mut(mid);
+        let (point, right) = (rest[0], &mut rest[1..]);
+        let left = self.build(left, depth + 1);
+        let right = self.build(right, depth + 1);
+        self.nodes.push(Node {
+            point,
+            axis,
+            left,
+            right,
+        });
+        Some(self.nodes.len() - 1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn point(&self, index: usize) -> &[f64; N] {
+        &self.points[index]
+    }
+
+    /// 欧氏距离最近的点的下标和距离
+    pub fn nearest(&self, query: &[f64; N]) -> Option<(usize, f64)> {
+        self.k_nearest(query, 1).pop()
+    }
+
+    /// 欧氏距离最近的 `k` 个点，按距离从近到远排列，距离相同时下标小的在前
+    pub fn k_nearest(&self, query: &[f64; N], k: usize) -> Vec<(usize, f64)> {
+        self.k_nearest_by(query, k, euclidean)
+    }
+
+    /// 用自定义的距离找最近的 `k` 个点。`distance(a, b)` 必须不小于任意一个坐标之差的绝对值，
+    /// 否则剪枝可能漏掉答案
+    pub fn k_nearest_by(
+        &self,
+        query: &[f64; N],
+        k: usize,
+        distance: impl Fn(&[f64; N], &[f64; N]) -> f64,
+    ) -> Vec<(usize, f64)> {
+        // 大顶堆，堆顶是目前找到的第 k 近的点
+        let mut best: Heap<(f64, usize)> = Heap::new(|a, b| a.0 > b.0 || (a.0 == b.0 && a.1 > b.1));
+        if k > 0 {
+            self.search(self.root, query, k, &distance, &mut best);
+        }
+        let mut result: Vec<(usize, f64)> = best
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(d, i)| (i, d))
+            .collect();
+        result.reverse();
+        result
+    }
+
+    fn search(
+        &self,
+        node: Option<usize>,
+        query: &[f64; N],
+        k: usize,
+        distance: &impl Fn(&[f64; N], &[f64; N]) -> f64,
+        best: &mut Heap<(f64, usize)>,
+    ) {
+        let Some(node) = node else {
+            return;
+        };
+        let Node {
+            point,
+            axis,
+            left,
+            right,
+        } = self.nodes[node];
+        let d = distance(query, &self.points[point]);
+        if best.len() < k {
+            best.push((d, point));
+        } else if let Some(&(worst, worst_index)) = best.peek() {
+            if d < worst || (d == worst && point < worst_index) {
+                best.pop();
+                best.push((d, point));
+            }
+        }
+
+        let diff = query[axis] - self.points[point][axis];
+        let (near, far) = if diff < 0.0 {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        self.search(near, query, k, distance, best);
+        // 距离相等时也要进入另一侧，才能按下标打破平局
+        if best.len() < k || best.peek().is_some_and(|&(worst, _)| diff.abs() <= worst) {
+            self.search(far, query, k, distance, best);
+        }
+    }
+}
+
+fn euclidean<const N: usize>(a: &[f64; N], b: &[f64; N]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{euclidean, KdTree};
+    use crate::utils::random::Rng;
+
+    fn brute_force<const N: usize>(
+        points: &[[f64; N]],
+        query: &[f64; N],
+        k: usize,
+        distance: impl Fn(&[f64; N], &[f64; N]) -> f64,
+    ) -> Vec<(usize, f64)> {
+        let mut all: Vec<(usize, f64)> = points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (i, distance(query, p)))
+            .collect();
+        all.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        all.truncate(k);
+        all
+    }
+
+    #[test]
+    fn test_small_example() {
+        let points = [
+            [2.0, 3.0],
+            [5.0, 4.0],
+            [9.0, 6.0],
+            [4.0, 7.0],
+            [8.0, 1.0],
+            [7.0, 2.0],
+        ];
+        let tree = KdTree::new(&points);
+        assert_eq!(tree.len(), 6);
+        assert_eq!(tree.nearest(&[9.0, 2.0]), Some((4, 2f64.sqrt())));
+        let nearest: Vec<usize> = tree.k_nearest(&[6.0, 3.0], 3).iter().map(|n| n.0).collect();
+        // 点 1 和点 5 的距离都是 √2，下标小的在前
+        assert_eq!(nearest, vec![1, 5, 4]);
+        assert_eq!(tree.k_nearest(&[0.0, 0.0], 10).len(), 6);
+        assert!(tree.k_nearest(&[0.0, 0.0], 0).is_empty());
+        assert_eq!(tree.point(3), &[4.0, 7.0]);
+
+        let empty = KdTree::<2>::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.nearest(&[0.0, 0.0]), None);
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let mut rng = Rng::new(1487);
+        let manhattan = |a: &[f64; 3], b: &[f64; 3]| -> f64 {
+            a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum()
+        };
+        for _ in 0..50 {
+            // 坐标取整数，制造大量距离相同的点
+            let points: Vec<[f64; 3]> = (0..rng.gen_index(200))
+                .map(|_| [0; 3].map(|_: i32| rng.gen_range(0, 10) as f64))
+                .collect();
+            let tree = KdTree::new(&points);
+            for _ in 0..20 {
+                let query = [0; 3].map(|_: i32| rng.gen_range(-2, 12) as f64);
+                let k = rng.gen_index(10);
+                assert_eq!(
+                    tree.k_nearest(&query, k),
+                    brute_force(&points, &query, k, euclidean)
+                );
+                assert_eq!(
+                    tree.k_nearest_by(&query, k, manhattan),
+                    brute_force(&points, &query, k, manhattan)
+                );
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
+use crate::error::AlgoError;
+use alloc::boxed::Box;
 use core::panic;
-use std::{
+use core::{
     fmt::{self, Display, Formatter},
     marker::PhantomData,
     ptr::NonNull,
@@ -11,7 +13,6 @@ use std::{
 扩展：
 `NonNull` 是一个包装类型用于确保指针是非空的(即不为null)
 */
-
 /// 定义基础的节点数据类型
 pub struct Node<T> {
     pub val: T,
@@ -95,10 +96,19 @@ impl<T> LinkedList<T> {
         self.length += 1;
     }
 
-    /// 插入数据到指定位置
-    pub fn insert_at_ith(&mut self, index: u32, obj: T) {
+    /// 插入数据到指定位置，`index` 等于长度时插入到尾部，更大时返回错误
+    pub fn insert_at_ith(&mut self, index: u32, obj: T) -> Result<(), AlgoError> {
+        if self.length < index {
+            return Err(self.out_of_bounds(index));
+        }
+        self.insert_at_ith_unchecked(index, obj);
+        Ok(())
+    }
+
+    /// 同 `insert_at_ith`，越界时 panic
+    pub fn insert_at_ith_unchecked(&mut self, index: u32, obj: T) {
         if self.length < index {
-            panic!("Index out of boundes.");
+            panic!("{}", self.out_of_bounds(index));
         }
 
         if index == 0 || self.head.is_none() {
@@ -129,7 +139,6 @@ impl<T> LinkedList<T> {
                 // 修正节点位置
                 if let Some(p) = (*ith_node.as_ptr()).prev {
                     let node_ptr = NonNull::new(Box::into_raw(node));
-                    println!("{:?}", (*p.as_ptr()).next);
                     (*p.as_ptr()).next = node_ptr;
                     (*ith_node.as_ptr()).prev = node_ptr;
                     self.length += 1;
@@ -170,18 +179,30 @@ impl<T> LinkedList<T> {
         })
     }
 
-    /// 删除指定为止的节点
-    pub fn delete_ith(&mut self, index: u32) -> Option<T> {
-        if self.length < index {
-            panic!("Index out of boundes.");
+    /// 删除指定位置的节点并返回它的值，链表为空或者越界时返回错误
+    pub fn delete_ith(&mut self, index: u32) -> Result<T, AlgoError> {
+        if self.length == 0 {
+            return Err(AlgoError::EmptyCollection);
+        }
+        if index >= self.length {
+            return Err(self.out_of_bounds(index));
         }
+        Ok(self.delete_ith_unchecked(index))
+    }
 
-        if index == 0 || self.head.is_none() {
-            return self.delete_head();
+    /// 同 `delete_ith`，链表为空或者越界时 panic
+    pub fn delete_ith_unchecked(&mut self, index: u32) -> T {
+        if index >= self.length {
+            panic!("{}", self.out_of_bounds(index));
+        }
+
+        if index == 0 {
+            return self.delete_head().unwrap();
         }
 
-        if self.length == index {
-            return self.delete_tail();
+        // 删除最后一个节点时必须经过 delete_tail 更新尾指针，否则 tail 会指向已释放的节点
+        if index + 1 == self.length {
+            return self.delete_tail().unwrap();
         }
 
         if let Some(mut ith_node) = self.head {
@@ -202,10 +223,10 @@ impl<T> LinkedList<T> {
                     next.as_mut().prev = old_ith.prev;
                 }
                 self.length -= 1;
-                Some(old_ith.val)
+                old_ith.val
             }
         } else {
-            None
+            unreachable!("a non-empty list has a head")
         }
     }
 
@@ -213,6 +234,13 @@ impl<T> LinkedList<T> {
         Self::get_ith_node(self.head, index).map(|ptr| unsafe { &(*ptr.as_ptr()).val })
     }
 
+    fn out_of_bounds(&self, index: u32) -> AlgoError {
+        AlgoError::IndexOutOfBounds {
+            index: index as usize,
+            len: self.length as usize,
+        }
+    }
+
     fn get_ith_node(node: Option<NonNull<Node<T>>>, index: i32) -> Option<NonNull<Node<T>>> {
         match node {
             None => None,
@@ -226,6 +254,12 @@ impl<T> LinkedList<T> {
     }
 }
 
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Drop for LinkedList<T> {
     fn drop(&mut self) {
         // 删除所有节点，直到没有节点
@@ -262,6 +296,7 @@ mod tests {
     use std::convert::TryInto;
 
     use super::LinkedList;
+    use crate::error::AlgoError;
 
     #[test]
     fn insert_at_tail_works() {
@@ -292,8 +327,8 @@ mod tests {
     fn insert_at_ith_can_add_to_tail() {
         let mut list = LinkedList::<i32>::new();
         let second_value = 2;
-        list.insert_at_ith(0, 0);
-        list.insert_at_ith(1, second_value);
+        list.insert_at_ith(0, 0).unwrap();
+        list.insert_at_ith(1, second_value).unwrap();
         println!("Linked List is {list}");
         match list.get(1) {
             Some(val) => assert_eq!(*val, second_value),
@@ -305,8 +340,8 @@ mod tests {
     fn insert_at_ith_can_add_to_head() {
         let mut list = LinkedList::<i32>::new();
         let second_value = 2;
-        list.insert_at_ith(0, 1);
-        list.insert_at_ith(0, second_value);
+        list.insert_at_ith(0, 1).unwrap();
+        list.insert_at_ith(0, second_value).unwrap();
         println!("Linked List is {list}");
         match list.get(0) {
             Some(val) => assert_eq!(*val, second_value),
@@ -319,9 +354,9 @@ mod tests {
         let mut list = LinkedList::<i32>::new();
         let second_value = 2;
         let third_value = 3;
-        list.insert_at_ith(0, 1);
-        list.insert_at_ith(1, second_value);
-        list.insert_at_ith(1, third_value);
+        list.insert_at_ith(0, 1).unwrap();
+        list.insert_at_ith(1, second_value).unwrap();
+        list.insert_at_ith(1, third_value).unwrap();
         println!("Linked List is {list}");
         match list.get(1) {
             Some(val) => assert_eq!(*val, third_value),
@@ -343,13 +378,13 @@ mod tests {
         let third_value = 2;
         let fourth_value = 3;
 
-        list.insert_at_ith(0, first_value);
-        list.insert_at_ith(1, fourth_value);
-        list.insert_at_ith(1, third_value);
-        list.insert_at_ith(1, second_value);
+        list.insert_at_ith(0, first_value).unwrap();
+        list.insert_at_ith(1, fourth_value).unwrap();
+        list.insert_at_ith(1, third_value).unwrap();
+        list.insert_at_ith(1, second_value).unwrap();
 
-        list.delete_ith(2);
-        list.insert_at_ith(2, third_value);
+        list.delete_ith(2).unwrap();
+        list.insert_at_ith(2, third_value).unwrap();
 
         for (i, expected) in [
             (0, first_value),
@@ -368,14 +403,14 @@ mod tests {
     fn insert_at_ith_and_delete_ith_work_over_many_iterations() {
         let mut list = LinkedList::<i32>::new();
         for i in 0..100 {
-            list.insert_at_ith(i, i.try_into().unwrap());
+            list.insert_at_ith(i, i.try_into().unwrap()).unwrap();
         }
 
         // Pop even numbers to 50
         for i in 0..50 {
             println!("list.length {}", list.length);
             if i % 2 == 0 {
-                list.delete_ith(i);
+                list.delete_ith(i).unwrap();
             }
         }
 
@@ -384,7 +419,7 @@ mod tests {
         // Insert even numbers back
         for i in 0..50 {
             if i % 2 == 0 {
-                list.insert_at_ith(i, i.try_into().unwrap());
+                list.insert_at_ith(i, i.try_into().unwrap()).unwrap();
             }
         }
 
@@ -444,8 +479,8 @@ mod tests {
         list.insert_at_tail(first_value);
         list.insert_at_tail(second_value);
         match list.delete_ith(1) {
-            Some(val) => assert_eq!(val, 2),
-            None => panic!("Expected to remove {second_value} at tail"),
+            Ok(val) => assert_eq!(val, 2),
+            Err(_) => panic!("Expected to remove {second_value} at tail"),
         }
 
         assert_eq!(list.length, 1);
@@ -459,8 +494,8 @@ mod tests {
         list.insert_at_tail(first_value);
         list.insert_at_tail(second_value);
         match list.delete_ith(0) {
-            Some(val) => assert_eq!(val, 1),
-            None => panic!("Expected to remove {first_value} at tail"),
+            Ok(val) => assert_eq!(val, 1),
+            Err(_) => panic!("Expected to remove {first_value} at tail"),
         }
 
         assert_eq!(list.length, 1);
@@ -476,8 +511,8 @@ mod tests {
         list.insert_at_tail(second_value);
         list.insert_at_tail(third_value);
         match list.delete_ith(1) {
-            Some(val) => assert_eq!(val, 2),
-            None => panic!("Expected to remove {second_value} at tail"),
+            Ok(val) => assert_eq!(val, 2),
+            Err(_) => panic!("Expected to remove {second_value} at tail"),
         }
 
         match list.get(1) {
@@ -486,6 +521,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn index_errors() {
+        let mut list = LinkedList::<i32>::new();
+        assert_eq!(list.delete_ith(0), Err(AlgoError::EmptyCollection));
+        assert_eq!(
+            list.insert_at_ith(1, 1),
+            Err(AlgoError::IndexOutOfBounds { index: 1, len: 0 })
+        );
+        list.insert_at_ith(0, 1).unwrap();
+        list.insert_at_ith(1, 2).unwrap();
+        assert_eq!(
+            list.delete_ith(2),
+            Err(AlgoError::IndexOutOfBounds { index: 2, len: 2 })
+        );
+        // 出错时链表保持不变
+        assert_eq!(list.length, 2);
+        assert_eq!(list.delete_ith_unchecked(1), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds")]
+    fn insert_unchecked_panics() {
+        let mut list = LinkedList::<i32>::new();
+        list.insert_at_tail(1);
+        list.insert_at_ith_unchecked(3, 2);
+    }
+
     #[test]
     fn create_numeric_list() {
         let mut list = LinkedList::<i32>::new();
@@ -1,3 +1,28 @@
 pub mod binary_search_tree;
+pub mod bit_set;
+pub mod fenwick_tree;
+pub mod heap;
+pub mod indexed_priority_queue;
+#[cfg(feature = "std")]
+pub mod kd_tree;
 pub mod linked_list;
 pub mod queue;
+pub mod segment_tree;
+pub mod trie;
+pub mod union_find;
+
+pub use self::binary_search_tree::BinarySearchTree;
+pub use self::bit_set::BitSet;
+pub use self::fenwick_tree::FenwickTree;
+pub use self::heap::Heap;
+pub use self::indexed_priority_queue::IndexedPriorityQueue;
+#[cfg(feature = "std")]
+pub use self::kd_tree::KdTree;
+pub use self::linked_list::LinkedList;
+pub use self::queue::Queue;
+pub use self::segment_tree::CoverageSegmentTree;
+pub use self::trie::Trie;
+pub use self::union_find::UnionFind;
+
+#[cfg(test)]
+mod proptests;
@@ -0,0 +1,364 @@
+use super::binary_search_tree::BinarySearchTree;
+use super::bit_set::BitSet;
+use super::fenwick_tree::FenwickTree;
+use super::heap::Heap;
+use super::linked_list::LinkedList;
+use super::queue::Queue;
+use super::trie::Trie;
+use super::union_find::UnionFind;
+use proptest::prelude::*;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque};
+
+/**
+定义：
+[基于性质的测试](https://en.wikipedia.org/wiki/Software_testing#Property_testing)
+对每个数据结构随机生成一串操作，同时作用在它和标准库中行为相同的结构（oracle）上，
+每一步都比较两者的结果。失败时 proptest 会自动缩减操作序列，得到最短的反例。
+下标类的参数先生成任意的 usize，执行时再对当前长度取模，这样缩减后的序列仍然合法
+*/
+const CASES: u32 = 256;
+
+/// 把任意的 `seed` 映射到 `0..len`，`len` 为 0 时返回 None
+fn pick(seed: usize, len: usize) -> Option<usize> {
+    (len > 0).then(|| seed % len)
+}
+
+#[derive(Debug, Clone)]
+enum ListOp {
+    InsertHead(i32),
+    InsertTail(i32),
+    InsertAt(usize, i32),
+    DeleteHead,
+    DeleteTail,
+    DeleteAt(usize),
+}
+
+fn list_op() -> impl Strategy<Value = ListOp> {
+    prop_oneof![
+        any::<i32>().prop_map(ListOp::InsertHead),
+        any::<i32>().prop_map(ListOp::InsertTail),
+        (any::<usize>(), any::<i32>()).prop_map(|(i, v)| ListOp::InsertAt(i, v)),
+        Just(ListOp::DeleteHead),
+        Just(ListOp::DeleteTail),
+        any::<usize>().prop_map(ListOp::DeleteAt),
+    ]
+}
+
+fn list_contents(list: &LinkedList<i32>) -> Vec<i32> {
+    (0..list.length as i32)
+        .map(|i| *list.get(i).unwrap())
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+enum QueueOp {
+    Enqueue(i32),
+    Dequeue,
+    Drain,
+}
+
+fn queue_op() -> impl Strategy<Value = QueueOp> {
+    prop_oneof![
+        3 => any::<i32>().prop_map(QueueOp::Enqueue),
+        2 => Just(QueueOp::Dequeue),
+        1 => Just(QueueOp::Drain),
+    ]
+}
+
+#[derive(Debug, Clone)]
+enum TreeOp {
+    Insert(i16),
+    Search(i16),
+    Floor(i16),
+    Ceil(i16),
+}
+
+fn tree_op() -> impl Strategy<Value = TreeOp> {
+    // 取值范围小一些，才会经常出现重复的值和命中的查找
+    let value = -50i16..50;
+    prop_oneof![
+        value.clone().prop_map(TreeOp::Insert),
+        value.clone().prop_map(TreeOp::Search),
+        value.clone().prop_map(TreeOp::Floor),
+        value.prop_map(TreeOp::Ceil),
+    ]
+}
+
+#[derive(Debug, Clone)]
+enum HeapOp {
+    Push(i32),
+    Pop,
+}
+
+fn heap_op() -> impl Strategy<Value = HeapOp> {
+    prop_oneof![
+        3 => (-100i32..100).prop_map(HeapOp::Push),
+        2 => Just(HeapOp::Pop),
+    ]
+}
+
+#[derive(Debug, Clone)]
+enum UnionFindOp {
+    Union(usize, usize),
+    Connected(usize, usize),
+    SizeOf(usize),
+}
+
+fn union_find_op() -> impl Strategy<Value = UnionFindOp> {
+    prop_oneof![
+        (any::<usize>(), any::<usize>()).prop_map(|(a, b)| UnionFindOp::Union(a, b)),
+        (any::<usize>(), any::<usize>()).prop_map(|(a, b)| UnionFindOp::Connected(a, b)),
+        any::<usize>().prop_map(UnionFindOp::SizeOf),
+    ]
+}
+
+#[derive(Debug, Clone)]
+enum PointUpdateOp {
+    Add(usize, i64),
+    Set(usize, i64),
+    RangeSum(usize, usize),
+}
+
+fn point_update_op() -> impl Strategy<Value = PointUpdateOp> {
+    let value = -1000i64..1000;
+    prop_oneof![
+        (any::<usize>(), value.clone()).prop_map(|(i, v)| PointUpdateOp::Add(i, v)),
+        (any::<usize>(), value).prop_map(|(i, v)| PointUpdateOp::Set(i, v)),
+        (any::<usize>(), any::<usize>()).prop_map(|(a, b)| PointUpdateOp::RangeSum(a, b)),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn linked_list_matches_vec_deque(ops in prop::collection::vec(list_op(), 0..60)) {
+        let mut list = LinkedList::new();
+        let mut oracle = VecDeque::new();
+        for op in ops {
+            match op {
+                ListOp::InsertHead(v) => {
+                    list.insert_at_head(v);
+                    oracle.push_front(v);
+                }
+                ListOp::InsertTail(v) => {
+                    list.insert_at_tail(v);
+                    oracle.push_back(v);
+                }
+                ListOp::InsertAt(seed, v) => {
+                    let index = seed % (oracle.len() + 1);
+                    list.insert_at_ith(index as u32, v).unwrap();
+                    oracle.insert(index, v);
+                }
+                ListOp::DeleteHead => prop_assert_eq!(list.delete_head(), oracle.pop_front()),
+                ListOp::DeleteTail => prop_assert_eq!(list.delete_tail(), oracle.pop_back()),
+                ListOp::DeleteAt(seed) => {
+                    if let Some(index) = pick(seed, oracle.len()) {
+                        prop_assert_eq!(list.delete_ith(index as u32).ok(), oracle.remove(index));
+                    }
+                }
+            }
+            prop_assert_eq!(list.length as usize, oracle.len());
+            prop_assert_eq!(list_contents(&list), Vec::from(oracle.clone()));
+        }
+        // 从尾部逐个删除，检查反向的指针
+        while let Some(v) = oracle.pop_back() {
+            prop_assert_eq!(list.delete_tail(), Some(v));
+        }
+        prop_assert_eq!(list.delete_tail(), None);
+        prop_assert_eq!(list.delete_head(), None);
+    }
+
+    #[test]
+    fn queue_matches_vec_deque(ops in prop::collection::vec(queue_op(), 0..100)) {
+        let mut queue = Queue::new();
+        let mut oracle = VecDeque::new();
+        for op in ops {
+            match op {
+                QueueOp::Enqueue(v) => {
+                    queue.enqueue(v);
+                    oracle.push_back(v);
+                }
+                QueueOp::Dequeue => prop_assert_eq!(queue.dequeue(), oracle.pop_front()),
+                QueueOp::Drain => {
+                    queue.drain();
+                    oracle.clear();
+                }
+            }
+            prop_assert_eq!(queue.len(), oracle.len());
+            prop_assert_eq!(queue.is_empty(), oracle.is_empty());
+            prop_assert_eq!(queue.peek_front(), oracle.front());
+            prop_assert_eq!(queue.peek_back(), oracle.back());
+        }
+    }
+
+    #[test]
+    fn bst_matches_btree_multiset(ops in prop::collection::vec(tree_op(), 0..100)) {
+        let mut tree = BinarySearchTree::new();
+        // 树中允许重复的值，用值到出现次数的映射作为 oracle
+        let mut oracle: BTreeMap<i16, usize> = BTreeMap::new();
+        for op in ops {
+            match op {
+                TreeOp::Insert(v) => {
+                    tree.insert(v);
+                    *oracle.entry(v).or_default() += 1;
+                }
+                TreeOp::Search(v) => prop_assert_eq!(tree.search(&v), oracle.contains_key(&v)),
+                TreeOp::Floor(v) => {
+                    prop_assert_eq!(tree.floor(&v), oracle.range(..=v).next_back().map(|e| e.0));
+                }
+                TreeOp::Ceil(v) => {
+                    prop_assert_eq!(tree.ceil(&v), oracle.range(v..).next().map(|e| e.0));
+                }
+            }
+            prop_assert_eq!(tree.minimum(), oracle.keys().next());
+            prop_assert_eq!(tree.maximum(), oracle.keys().next_back());
+        }
+        let expected: Vec<i16> = oracle
+            .iter()
+            .flat_map(|(&v, &count)| std::iter::repeat_n(v, count))
+            .collect();
+        prop_assert_eq!(tree.iter().copied().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn heap_matches_binary_heap(ops in prop::collection::vec(heap_op(), 0..100)) {
+        let mut max_heap = Heap::new_max();
+        let mut min_heap = Heap::new_min();
+        let mut max_oracle = BinaryHeap::new();
+        let mut min_oracle = BinaryHeap::new();
+        for op in ops {
+            match op {
+                HeapOp::Push(v) => {
+                    max_heap.push(v);
+                    min_heap.push(v);
+                    max_oracle.push(v);
+                    min_oracle.push(Reverse(v));
+                }
+                HeapOp::Pop => {
+                    prop_assert_eq!(max_heap.pop(), max_oracle.pop());
+                    prop_assert_eq!(min_heap.pop(), min_oracle.pop().map(|r| r.0));
+                }
+            }
+            prop_assert_eq!(max_heap.len(), max_oracle.len());
+            prop_assert_eq!(max_heap.peek(), max_oracle.peek());
+            prop_assert_eq!(min_heap.peek(), min_oracle.peek().map(|r| &r.0));
+        }
+        // Heap 按出堆顺序返回，BinaryHeap 按升序返回
+        let mut expected = max_oracle.into_sorted_vec();
+        expected.reverse();
+        prop_assert_eq!(max_heap.into_sorted_vec(), expected);
+    }
+
+    #[test]
+    fn union_find_matches_labels(
+        n in 1usize..30,
+        ops in prop::collection::vec(union_find_op(), 0..100),
+    ) {
+        let mut sets = UnionFind::new(n);
+        // 每个元素所在集合的编号，合并时把一个编号全部改成另一个
+        let mut labels: Vec<usize> = (0..n).collect();
+        for op in ops {
+            match op {
+                UnionFindOp::Union(a, b) => {
+                    let (a, b) = (a % n, b % n);
+                    let (from, to) = (labels[a], labels[b]);
+                    prop_assert_eq!(sets.union(a, b), from != to);
+                    for label in labels.iter_mut().filter(|l| **l == from) {
+                        *label = to;
+                    }
+                }
+                UnionFindOp::Connected(a, b) => {
+                    let (a, b) = (a % n, b % n);
+                    prop_assert_eq!(sets.connected(a, b), labels[a] == labels[b]);
+                }
+                UnionFindOp::SizeOf(x) => {
+                    let x = x % n;
+                    let size = labels.iter().filter(|&&l| l == labels[x]).count();
+                    prop_assert_eq!(sets.size_of(x), size);
+                }
+            }
+            let distinct: BTreeSet<usize> = labels.iter().copied().collect();
+            prop_assert_eq!(sets.component_count(), distinct.len());
+        }
+    }
+
+    #[test]
+    fn fenwick_tree_matches_vec(
+        initial in prop::collection::vec(-1000i64..1000, 1..40),
+        ops in prop::collection::vec(point_update_op(), 0..100),
+    ) {
+        let mut tree = FenwickTree::from_slice(&initial);
+        let mut oracle = initial;
+        let n = oracle.len();
+        for op in ops {
+            match op {
+                PointUpdateOp::Add(i, v) => {
+                    tree.add(i % n, v);
+                    oracle[i % n] += v;
+                }
+                PointUpdateOp::Set(i, v) => {
+                    tree.set(i % n, v);
+                    oracle[i % n] = v;
+                }
+                PointUpdateOp::RangeSum(a, b) => {
+                    let (a, b) = (a % (n + 1), b % (n + 1));
+                    let range = a.min(b)..a.max(b);
+                    prop_assert_eq!(tree.range_sum(range.clone()), oracle[range].iter().sum::<i64>());
+                }
+            }
+            for (i, &v) in oracle.iter().enumerate() {
+                prop_assert_eq!(tree.get(i), v);
+            }
+        }
+    }
+
+    #[test]
+    fn bit_set_matches_vec_of_bools(
+        pushed in prop::collection::vec(any::<bool>(), 0..200),
+        sets in prop::collection::vec((any::<usize>(), any::<bool>()), 0..50),
+        shift in 0usize..250,
+    ) {
+        let mut bits: BitSet = pushed.iter().copied().collect();
+        let mut oracle = pushed;
+        for (seed, value) in sets {
+            if let Some(index) = pick(seed, oracle.len()) {
+                bits.set(index, value);
+                oracle[index] = value;
+            }
+        }
+        prop_assert_eq!(bits.iter().collect::<Vec<_>>(), oracle.clone());
+        let ones: Vec<usize> = (0..oracle.len()).filter(|&i| oracle[i]).collect();
+        prop_assert_eq!(bits.iter_ones().collect::<Vec<_>>(), ones);
+        bits.or_shifted(shift);
+        let shifted: Vec<bool> = (0..oracle.len())
+            .map(|i| oracle[i] || (i >= shift && oracle[i - shift]))
+            .collect();
+        prop_assert_eq!(bits.iter().collect::<Vec<_>>(), shifted);
+    }
+
+    #[test]
+    fn trie_matches_btree_set(
+        words in prop::collection::vec("[a-c]{0,5}", 0..40),
+        queries in prop::collection::vec("[a-c]{0,6}", 0..40),
+    ) {
+        let mut trie = Trie::new();
+        let mut oracle = BTreeSet::new();
+        for word in &words {
+            prop_assert_eq!(trie.insert(word), oracle.insert(word.clone()));
+        }
+        prop_assert_eq!(trie.len(), oracle.len());
+        for query in &queries {
+            prop_assert_eq!(trie.contains(query), oracle.contains(query));
+            prop_assert_eq!(
+                trie.starts_with(query),
+                oracle.iter().any(|w| w.starts_with(query.as_str()))
+            );
+            let prefixes: Vec<usize> = (0..=query.len())
+                .filter(|&end| oracle.contains(&query[..end]))
+                .collect();
+            prop_assert_eq!(trie.prefixes_of(query).collect::<Vec<_>>(), prefixes);
+        }
+    }
+}
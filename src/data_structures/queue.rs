@@ -1,4 +1,4 @@
-use std::collections::LinkedList;
+use alloc::collections::LinkedList;
 
 /**
 定义：
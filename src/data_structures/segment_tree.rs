@@ -0,0 +1,161 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/**
+定义：
+[线段树的定义](https://zh.wikipedia.org/wiki/%E7%B7%9A%E6%AE%B5%E6%A8%B9_(%E5%84%B2%E5%AD%98%E5%8D%80%E9%96%93))
+每个节点对应一个区间，根是整个区间，孩子把父节点的区间一分为二，任何区间都能拆成 O(log n) 个节点。
+
+这里是统计覆盖长度的版本：n 个相邻的基本段各有一个长度，支持给一段基本段的覆盖次数加减 1，
+并查询被覆盖至少一次的总长度。节点只记录“整个区间被完整覆盖了几次”，不往下传递，
+因为覆盖和撤销总是成对出现在同一个区间上：`count > 0` 时整个区间都被覆盖，否则覆盖长度等于两个孩子之和
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageSegmentTree {
+    /// 每个节点区间的总长度
+    length: Vec<f64>,
+    /// 完整覆盖该节点区间的次数
+    count: Vec<i64>,
+    /// 该节点区间中被覆盖的长度
+    covered: Vec<f64>,
+    len: usize,
+}
+
+impl CoverageSegmentTree {
+    /// `lengths[i]` 是第 i 个基本段的长度，初始时都没有被覆盖
+    pub fn new(lengths: &[f64]) -> Self {
+        let len = lengths.len();
+        let size = 4 * len.max(1);
+        let mut tree = CoverageSegmentTree {
+            length: vec![0.0; size],
+            count: vec![0; size],
+            covered: vec![0.0; size],
+            len,
+        };
+        if len > 0 {
+            tree.build(1, 0..len, lengths);
+        }
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 基本段 `range` 的覆盖次数加上 `delta`。撤销覆盖时必须和之前加上的区间相同，覆盖次数不能为负
+    pub fn add(&mut self, range: Range<usize>, delta: i64) {
+        assert!(
+            range.end <= self.len,
+            "Range {range:?} out of bounds for length {}.",
+            self.len
+        );
+        if !range.is_empty() {
+            self.update(1, 0..self.len, &range, delta);
+        }
+    }
+
+    /// 被覆盖至少一次的总长度
+    pub fn covered_length(&self) -> f64 {
+        if self.is_empty() {
+            0.0
+        } else {
+            self.covered[1]
+        }
+    }
+
+    fn build(&mut self, node: usize, span: Range<usize>, lengths: &[f64]) {
+        if span.len() == 1 {
+            self.length[node] = lengths[span.start];
+            return;
+        }
+        let mid = span.start + span.len() / 2;
+        self.build(2 * node, span.start..mid, lengths);
+        self.build(2 * node + 1, mid..span.end, lengths);
+        self.length[node] = self.length[2 * node] + self.length[2 * node + 1];
+    }
+
+    fn update(&mut self, node: usize, span: Range<usize>, range: &Range<usize>, delta: i64) {
+        if range.end <= span.start || span.end <= range.start {
+            return;
+        }
+        if range.start <= span.start && span.end <= range.end {
+            self.count[node] += delta;
+            assert!(
+                self.count[node] >= 0,
+                "Removed coverage that was never added."
+            );
+        } else {
+            let mid = span.start + span.len() / 2;
+            self.update(2 * node, span.start..mid, range, delta);
+            self.update(2 * node + 1, mid..span.end, range, delta);
+        }
+        self.covered[node] = if self.count[node] > 0 {
+            self.length[node]
+        } else if span.len() == 1 {
+            0.0
+        } else {
+            self.covered[2 * node] + self.covered[2 * node + 1]
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CoverageSegmentTree;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn test_cover_and_uncover() {
+        let mut tree = CoverageSegmentTree::new(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.covered_length(), 0.0);
+        tree.add(1..3, 1);
+        assert_eq!(tree.covered_length(), 5.0);
+        tree.add(2..4, 1);
+        assert_eq!(tree.covered_length(), 9.0);
+        tree.add(1..3, -1);
+        assert_eq!(tree.covered_length(), 7.0);
+        tree.add(0..0, 1);
+        tree.add(2..4, -1);
+        assert_eq!(tree.covered_length(), 0.0);
+        assert_eq!(CoverageSegmentTree::new(&[]).covered_length(), 0.0);
+    }
+
+    #[test]
+    fn test_matches_naive_counts() {
+        let mut rng = Rng::new(1473);
+        let lengths: Vec<f64> = (0..50).map(|_| rng.gen_range(1, 10) as f64).collect();
+        let mut tree = CoverageSegmentTree::new(&lengths);
+        let mut counts = vec![0; lengths.len()];
+        let mut added = vec![];
+        for _ in 0..500 {
+            if added.is_empty() || rng.gen_bool(0.6) {
+                let start = rng.gen_index(lengths.len());
+                let end = start + 1 + rng.gen_index(lengths.len() - start);
+                tree.add(start..end, 1);
+                counts[start..end].iter_mut().for_each(|c| *c += 1);
+                added.push(start..end);
+            } else {
+                let range = added.swap_remove(rng.gen_index(added.len()));
+                tree.add(range.clone(), -1);
+                counts[range].iter_mut().for_each(|c| *c -= 1);
+            }
+            let expected: f64 = (0..lengths.len())
+                .filter(|&i| counts[i] > 0)
+                .map(|i| lengths[i])
+                .sum();
+            assert_eq!(tree.covered_length(), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rejects_negative_coverage() {
+        CoverageSegmentTree::new(&[1.0, 1.0]).add(0..2, -1);
+    }
+}
@@ -0,0 +1,148 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/**
+定义：
+[字典树的定义](https://zh.wikipedia.org/wiki/Trie)
+每条边是一个字符，从根到某个节点的路径就是一个前缀，标记为结尾的节点对应一个完整的单词。
+查找长度为 m 的单词只需要 O(m)，和单词总数无关；沿着文本往下走还能一次找出所有是文本前缀的单词
+*/
+#[derive(Debug, Clone)]
+pub struct Trie {
+    /// 下标 0 是根节点
+    nodes: Vec<TrieNode>,
+    words: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: BTreeMap<char, usize>,
+    is_word: bool,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Trie {
+            nodes: vec![TrieNode::default()],
+            words: 0,
+        }
+    }
+
+    /// 单词的个数
+    pub fn len(&self) -> usize {
+        self.words
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words == 0
+    }
+
+    /// 插入单词，返回它之前是否不存在
+    pub fn insert(&mut self, word: &str) -> bool {
+        let mut node = 0;
+        for c in word.chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(c, child);
+                    child
+                }
+            };
+        }
+        let inserted = !self.nodes[node].is_word;
+        self.nodes[node].is_word = true;
+        self.words += usize::from(inserted);
+        inserted
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| self.nodes[node].is_word)
+    }
+
+    /// 是否有单词以 `prefix` 开头
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        // 根节点总是存在，空树中没有任何单词以空串开头
+        !self.is_empty() && self.find(prefix).is_some()
+    }
+
+    /// 所有是 `text` 前缀的单词，按长度递增返回它们的字节长度
+    pub fn prefixes_of<'a>(&'a self, text: &'a str) -> impl Iterator<Item = usize> + 'a {
+        // 空串也可能是单词，对应长度 0
+        let empty = self.nodes[0].is_word.then_some(0);
+        let mut node = 0;
+        let rest = text
+            .char_indices()
+            .map_while(move |(i, c)| {
+                node = *self.nodes[node].children.get(&c)?;
+                Some((i + c.len_utf8(), self.nodes[node].is_word))
+            })
+            .filter_map(|(end, is_word)| is_word.then_some(end));
+        empty.into_iter().chain(rest)
+    }
+
+    fn find(&self, prefix: &str) -> Option<usize> {
+        prefix
+            .chars()
+            .try_fold(0, |node, c| self.nodes[node].children.get(&c).copied())
+    }
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Trie::new()
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Trie {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for word in iter {
+            trie.insert(word);
+        }
+        trie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trie;
+
+    #[test]
+    fn test_insert_and_lookup() {
+        let mut trie = Trie::new();
+        assert!(trie.is_empty());
+        assert!(trie.insert("apple"));
+        assert!(trie.insert("app"));
+        assert!(!trie.insert("app"));
+        assert_eq!(trie.len(), 2);
+        assert!(trie.contains("app") && trie.contains("apple"));
+        assert!(!trie.contains("ap") && !trie.contains("apples"));
+        assert!(trie.starts_with("ap") && trie.starts_with(""));
+        assert!(!Trie::new().starts_with(""));
+        assert!(!trie.starts_with("b"));
+    }
+
+    #[test]
+    fn test_default_has_root() {
+        let mut trie = Trie::default();
+        assert!(!trie.contains("a"));
+        assert_eq!(trie.prefixes_of("a").count(), 0);
+        assert!(trie.insert("a"));
+        assert!(trie.contains("a"));
+        assert_eq!(trie.prefixes_of("ab").collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn test_prefixes_of_text() {
+        let trie: Trie = ["a", "ab", "abc", "b", "苹果", "苹"].into_iter().collect();
+        assert_eq!(trie.prefixes_of("abd").collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(trie.prefixes_of("xyz").count(), 0);
+        assert_eq!(trie.prefixes_of("苹果树").collect::<Vec<_>>(), vec![3, 6]);
+
+        let with_empty: Trie = ["", "a"].into_iter().collect();
+        assert_eq!(with_empty.prefixes_of("ab").collect::<Vec<_>>(), vec![0, 1]);
+    }
+}
@@ -0,0 +1,128 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/**
+定义：
+[并查集的定义](https://zh.wikipedia.org/wiki/%E5%B9%B6%E6%9F%A5%E9%9B%86)
+维护若干个不相交的集合，支持合并两个集合、查询元素所在的集合。
+每个集合是一棵树，根节点作为集合的代表。查询时做路径压缩、合并时把小树挂到大树下，
+单次操作的均摊复杂度是 O(α(n))，α 是反阿克曼函数，实际中可以看作常数
+*/
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    /// 只有根节点的 size 有意义，是整个集合的大小
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// `n` 个元素，每个元素单独是一个集合
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// 返回 `x` 所在集合的代表元素
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // 路径压缩：把路径上的节点都直接挂到根下面
+        let mut node = x;
+        while self.parent[node] != root {
+            let next = self.parent[node];
+            self.parent[node] = root;
+            node = next;
+        }
+        root
+    }
+
+    /// 合并 `a` 和 `b` 所在的集合，它们原本就在同一个集合时返回 false
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            core::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// `x` 所在集合的大小
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// 集合的个数
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFind;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn test_union_and_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.component_count(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size_of(2), 4);
+        assert_eq!(sets.size_of(5), 1);
+        assert_eq!(sets.component_count(), 3);
+        assert_eq!(sets.len(), 6);
+    }
+
+    #[test]
+    fn test_matches_naive_labels() {
+        let mut rng = Rng::new(1398);
+        let n = 100;
+        let mut sets = UnionFind::new(n);
+        // 朴素实现：每个元素直接记录集合编号，合并时整体改写
+        let mut labels: Vec<usize> = (0..n).collect();
+        for _ in 0..150 {
+            let (a, b) = (rng.gen_index(n), rng.gen_index(n));
+            let merged = labels[a] != labels[b];
+            assert_eq!(sets.union(a, b), merged);
+            let (from, to) = (labels[b], labels[a]);
+            for label in labels.iter_mut().filter(|l| **l == from) {
+                *label = to;
+            }
+            let (x, y) = (rng.gen_index(n), rng.gen_index(n));
+            assert_eq!(sets.connected(x, y), labels[x] == labels[y]);
+        }
+        let mut distinct = labels.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(sets.component_count(), distinct.len());
+    }
+}
@@ -0,0 +1,175 @@
+/*!
+定义：
+[状态压缩 DP](https://en.wikipedia.org/wiki/Mask_(computing))
+用一个整数的二进制位表示集合，第 i 位为 1 表示元素 i 在集合中，集合运算就是位运算。
+- 枚举子集：`sub = (sub - 1) & mask` 从 mask 开始依次得到 mask 的所有子集，
+  对所有 mask 枚举子集的总次数是 3ⁿ
+- 旅行商问题：[Held–Karp](https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm)
+  按“已经访问的集合 + 当前所在城市”做 DP，O(2ⁿ·n²)
+*/
+
+use crate::graph::{tsp, AdjacencyMatrix, Tour};
+
+/// Held–Karp 能处理的最大城市数
+pub const HELD_KARP_MAX_CITIES: usize = 20;
+
+/// 按从大到小的顺序遍历 `mask` 的所有子集，包括 `mask` 本身和空集 0
+pub fn submask_iter(mask: u32) -> impl Iterator<Item = u32> {
+    let mut next = Some(mask);
+    std::iter::from_fn(move || {
+        let current = next?;
+        next = (current != 0).then(|| (current - 1) & mask);
+        Some(current)
+    })
+}
+
+/// 距离矩阵上的最短回路，`distances[i][j]` 是从城市 i 到城市 j 的距离，可以不对称。
+/// 回路从城市 0 出发，`order` 依次列出访问的城市
+pub fn tsp_held_karp(distances: &[Vec<i64>]) -> Tour {
+    let n = distances.len();
+    assert!(
+        n <= HELD_KARP_MAX_CITIES,
+        "Held-Karp supports at most {HELD_KARP_MAX_CITIES} cities, got {n}."
+    );
+    let mut matrix = AdjacencyMatrix::new_directed(n);
+    for (from, row) in distances.iter().enumerate() {
+        assert_eq!(row.len(), n, "Distance matrix must be square.");
+        for (to, &distance) in row.iter().enumerate() {
+            if from != to {
+                matrix.set_edge(from, to, distance);
+            }
+        }
+    }
+    // 完全图上总存在回路
+    tsp(&matrix).expect("complete graph has a tour")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{submask_iter, tsp_held_karp};
+    use crate::utils::random::Rng;
+
+    /// 固定城市 0，枚举其余城市的全排列
+    fn brute_force(distances: &[Vec<i64>]) -> i64 {
+        fn go(path: &mut Vec<usize>, used: &mut [bool], distances: &[Vec<i64>]) -> i64 {
+            let n = distances.len();
+            let last = *path.last().unwrap();
+            if path.len() == n {
+                return distances[last][0];
+            }
+            let mut best = i64::MAX;
+            for next in 1..n {
+                if !used[next] {
+                    used[next] = true;
+                    path.push(next);
+                    best = best.min(distances[last][next] + go(path, used, distances));
+                    path.pop();
+                    used[next] = false;
+                }
+            }
+            best
+        }
+        go(&mut vec![0], &mut vec![false; distances.len()], distances)
+    }
+
+    #[test]
+    fn iterates_submasks() {
+        assert_eq!(
+            submask_iter(0b101).collect::<Vec<_>>(),
+            vec![0b101, 0b100, 0b001, 0]
+        );
+        assert_eq!(submask_iter(0).collect::<Vec<_>>(), vec![0]);
+        // 所有 n 位 mask 的子集个数之和是 3ⁿ
+        let total: usize = (0..1u32 << 6).map(|mask| submask_iter(mask).count()).sum();
+        assert_eq!(total, 3usize.pow(6));
+    }
+
+    #[test]
+    fn submask_dp_partitions_into_groups() {
+        // 用子集枚举求把 n 个物品分组的最小代价，每组的代价是 (组内重量和)²，
+        // 约束是每组重量不超过 limit；和逐个枚举“下一个物品放进哪一组”的结果比较
+        let weights = [3, 1, 4, 1, 5, 2];
+        let limit = 6;
+        let n = weights.len();
+        let group_cost = |mask: u32| -> Option<u64> {
+            let sum: u64 = (0..n)
+                .filter(|i| mask >> i & 1 == 1)
+                .map(|i| weights[i])
+                .sum();
+            (sum <= limit).then_some(sum * sum)
+        };
+        let full = (1u32 << n) - 1;
+        let mut best = vec![None::<u64>; 1 << n];
+        best[0] = Some(0);
+        for mask in 1..=full {
+            // 固定最低位的物品所在的组，避免重复枚举
+            let lowest = mask & mask.wrapping_neg();
+            best[mask as usize] = submask_iter(mask)
+                .filter(|sub| sub & lowest != 0)
+                .filter_map(|sub| Some(best[(mask ^ sub) as usize]? + group_cost(sub)?))
+                .min();
+        }
+        let expected = {
+            fn assign(i: usize, groups: &mut Vec<u64>, weights: &[u64], limit: u64) -> u64 {
+                if i == weights.len() {
+                    return groups.iter().map(|g| g * g).sum();
+                }
+                let mut best = u64::MAX;
+                for g in 0..groups.len() {
+                    if groups[g] + weights[i] <= limit {
+                        groups[g] += weights[i];
+                        best = best.min(assign(i + 1, groups, weights, limit));
+                        groups[g] -= weights[i];
+                    }
+                }
+                groups.push(weights[i]);
+                best = best.min(assign(i + 1, groups, weights, limit));
+                groups.pop();
+                best
+            }
+            assign(0, &mut vec![], &weights, limit)
+        };
+        assert_eq!(best[full as usize], Some(expected));
+    }
+
+    #[test]
+    fn solves_small_tours() {
+        let distances = vec![
+            vec![0, 10, 15, 20],
+            vec![10, 0, 35, 25],
+            vec![15, 35, 0, 30],
+            vec![20, 25, 30, 0],
+        ];
+        let tour = tsp_held_karp(&distances);
+        assert_eq!(tour.cost, 80);
+        assert_eq!(tour.order[0], 0);
+        assert_eq!(tsp_held_karp(&[]).cost, 0);
+        assert_eq!(tsp_held_karp(&[vec![0]]).order, vec![0]);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1446);
+        for _ in 0..30 {
+            let n = 2 + rng.gen_index(6);
+            let distances: Vec<Vec<i64>> = (0..n)
+                .map(|_| (0..n).map(|_| rng.gen_range(1, 50)).collect())
+                .collect();
+            let tour = tsp_held_karp(&distances);
+            assert_eq!(tour.cost, brute_force(&distances));
+            let mut visited = tour.order.clone();
+            visited.sort_unstable();
+            assert_eq!(visited, (0..n).collect::<Vec<_>>());
+            let cost: i64 = (0..n)
+                .map(|i| distances[tour.order[i]][tour.order[(i + 1) % n]])
+                .sum();
+            assert_eq!(cost, tour.cost);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_too_many_cities() {
+        tsp_held_karp(&vec![vec![1; 21]; 21]);
+    }
+}
@@ -0,0 +1,134 @@
+/*!
+定义：
+[找零问题](https://en.wikipedia.org/wiki/Change-making_problem)
+- 最少硬币数：`fewest[a]` 是凑出金额 a 最少需要的硬币数，等于所有面值 c 中 `fewest[a - c] + 1` 的最小值。
+  贪心（每次取最大面值）只对特定的面值组合正确，例如面值 {1, 3, 4} 凑 6 时贪心给出 4 + 1 + 1，最优是 3 + 3
+- 组合数：外层循环面值、内层循环金额，每种组合只按面值顺序计一次，不会把 1 + 2 和 2 + 1 算成两种
+*/
+
+/// 凑出 `amount` 所用硬币最少的一种方案，按面值从大到小排列；凑不出时返回 None
+pub fn coin_change_min(coins: &[usize], amount: usize) -> Option<Vec<usize>> {
+    let mut fewest: Vec<Option<usize>> = vec![None; amount + 1];
+    // last[a] 是凑出 a 的最优方案中的一枚硬币
+    let mut last = vec![0; amount + 1];
+    fewest[0] = Some(0);
+    for a in 1..=amount {
+        for &coin in coins {
+            if coin == 0 || coin > a {
+                continue;
+            }
+            if let Some(count) = fewest[a - coin] {
+                if fewest[a].is_none_or(|best| count + 1 < best) {
+                    fewest[a] = Some(count + 1);
+                    last[a] = coin;
+                }
+            }
+        }
+    }
+
+    fewest[amount]?;
+    let mut selection = vec![];
+    let mut a = amount;
+    while a > 0 {
+        selection.push(last[a]);
+        a -= last[a];
+    }
+    selection.sort_unstable_by(|x, y| y.cmp(x));
+    Some(selection)
+}
+
+/// 凑出 `amount` 的组合数，不考虑硬币的顺序；金额为 0 时有一种（什么都不选）。
+/// 组合数超出 u128 时返回 None
+pub fn coin_change_ways(coins: &[usize], amount: usize) -> Option<u128> {
+    // None 表示已经溢出，之后由它累加出的格子也一定溢出
+    let mut ways = vec![Some(0u128); amount + 1];
+    ways[0] = Some(1);
+    for &coin in coins.iter().filter(|&&c| c > 0) {
+        for a in coin..=amount {
+            ways[a] = ways[a]
+                .zip(ways[a - coin])
+                .and_then(|(x, y)| x.checked_add(y));
+        }
+    }
+    ways[amount]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{coin_change_min, coin_change_ways};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn fewest_coins() {
+        assert_eq!(coin_change_min(&[1, 2, 5], 11), Some(vec![5, 5, 1]));
+        // 贪心会给出 4 + 1 + 1
+        assert_eq!(coin_change_min(&[1, 3, 4], 6), Some(vec![3, 3]));
+        assert_eq!(coin_change_min(&[2], 3), None);
+        assert_eq!(coin_change_min(&[], 0), Some(vec![]));
+        assert_eq!(coin_change_min(&[0, 7], 14), Some(vec![7, 7]));
+    }
+
+    #[test]
+    fn counts_combinations() {
+        assert_eq!(coin_change_ways(&[1, 2, 5], 5), Some(4));
+        assert_eq!(coin_change_ways(&[2], 3), Some(0));
+        assert_eq!(coin_change_ways(&[10], 0), Some(1));
+        // 凑 1 美元的方法数
+        assert_eq!(coin_change_ways(&[1, 5, 10, 25, 50, 100], 100), Some(293));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        // 面值 1..=k 凑 n 的组合数就是 n 拆成不超过 k 的正整数之和的方法数
+        let coins: Vec<usize> = (1..=2000).collect();
+        // p(1000) ≈ 2.4 · 10^31 还在 u128 范围内，p(2000) ≈ 4.7 · 10^45 超出了
+        assert_eq!(
+            coin_change_ways(&coins, 1000),
+            Some(24_061_467_864_032_622_473_692_149_727_991)
+        );
+        assert_eq!(coin_change_ways(&coins, 2000), None);
+        // 偶数金额的组合数溢出了，但凑不出奇数金额
+        let even: Vec<usize> = (1..=2000).map(|c| 2 * c).collect();
+        assert_eq!(coin_change_ways(&even, 4001), Some(0));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        /// 枚举每种面值的个数，返回 (最少硬币数, 组合数)
+        fn brute(coins: &[usize], amount: usize) -> (Option<usize>, u128) {
+            let Some((&coin, rest)) = coins.split_first() else {
+                return (
+                    if amount == 0 { Some(0) } else { None },
+                    (amount == 0) as u128,
+                );
+            };
+            let (mut fewest, mut ways) = (None::<usize>, 0);
+            for k in 0..=amount / coin {
+                let (f, w) = brute(rest, amount - k * coin);
+                ways += w;
+                if let Some(f) = f {
+                    fewest = Some(fewest.map_or(f + k, |best| best.min(f + k)));
+                }
+            }
+            (fewest, ways)
+        }
+
+        let mut rng = Rng::new(1439);
+        for _ in 0..200 {
+            let mut coins: Vec<usize> = (0..1 + rng.gen_index(4))
+                .map(|_| 1 + rng.gen_index(9))
+                .collect();
+            coins.sort_unstable();
+            coins.dedup();
+            let amount = rng.gen_index(40);
+            let (fewest, ways) = brute(&coins, amount);
+            let selection = coin_change_min(&coins, amount);
+            assert_eq!(selection.as_ref().map(Vec::len), fewest);
+            if let Some(selection) = selection {
+                assert_eq!(selection.iter().sum::<usize>(), amount);
+                assert!(selection.iter().all(|c| coins.contains(c)));
+            }
+            assert_eq!(coin_change_ways(&coins, amount), Some(ways));
+        }
+    }
+}
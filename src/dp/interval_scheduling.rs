@@ -0,0 +1,122 @@
+/*!
+定义：
+[带权区间调度](https://en.wikipedia.org/wiki/Interval_scheduling#Weighted)
+从一组带权重的区间中选出互不重叠的若干个，使权重之和最大。
+按结束时间排序后，`best[i]` 是前 i 个区间的最优解：要么不选第 i 个，
+要么选它再加上结束时间不晚于它开始时间的那些区间的最优解，这个前驱用二分查找得到，O(n log n)
+*/
+
+use crate::searching::partition_point;
+
+/// 半开区间 [start, end)，首尾相接的两个区间不算重叠
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+    pub weight: u64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64, weight: u64) -> Self {
+        assert!(start <= end, "Interval must not end before it starts.");
+        Interval { start, end, weight }
+    }
+}
+
+/// 最大权重以及所选区间在输入中的下标（按结束时间排列）
+pub fn weighted_interval_scheduling(intervals: &[Interval]) -> (u64, Vec<usize>) {
+    let mut order: Vec<usize> = (0..intervals.len()).collect();
+    // 结束时间相同时开始早的排在前面，这样长度为 0 的区间能接在同一时刻结束的区间后面
+    order.sort_by_key(|&i| (intervals[i].end, intervals[i].start));
+    let ends: Vec<i64> = order.iter().map(|&i| intervals[i].end).collect();
+
+    // best[k] 是按结束时间排序后前 k 个区间的最优解
+    let mut best = vec![0; order.len() + 1];
+    // predecessor[k] 是选第 k 个区间时还能选的前缀长度
+    let mut predecessor = vec![0; order.len()];
+    for (k, &i) in order.iter().enumerate() {
+        predecessor[k] = partition_point(&ends[..k], |&end| end <= intervals[i].start);
+        best[k + 1] = best[k].max(best[predecessor[k]] + intervals[i].weight);
+    }
+
+    let mut chosen = vec![];
+    let mut k = order.len();
+    while k > 0 {
+        if best[k] == best[k - 1] {
+            k -= 1;
+        } else {
+            chosen.push(order[k - 1]);
+            k = predecessor[k - 1];
+        }
+    }
+    chosen.reverse();
+    (best[order.len()], chosen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{weighted_interval_scheduling, Interval};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn picks_heaviest_compatible_set() {
+        let intervals = [
+            Interval::new(1, 4, 5),
+            Interval::new(3, 5, 1),
+            Interval::new(0, 6, 8),
+            Interval::new(4, 7, 4),
+            Interval::new(3, 9, 6),
+            Interval::new(5, 9, 3),
+            Interval::new(6, 10, 2),
+            Interval::new(8, 11, 4),
+        ];
+        let (weight, chosen) = weighted_interval_scheduling(&intervals);
+        assert_eq!(weight, 13);
+        assert_eq!(chosen, vec![0, 3, 7]);
+        assert_eq!(weighted_interval_scheduling(&[]), (0, vec![]));
+    }
+
+    #[test]
+    fn touching_intervals_are_compatible() {
+        let intervals = [Interval::new(0, 2, 1), Interval::new(2, 4, 1)];
+        assert_eq!(weighted_interval_scheduling(&intervals), (2, vec![0, 1]));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1442);
+        for _ in 0..200 {
+            let n = rng.gen_index(10);
+            let intervals: Vec<Interval> = (0..n)
+                .map(|_| {
+                    let start = rng.gen_range(0, 20);
+                    Interval::new(start, start + rng.gen_range(0, 8), rng.gen_index(10) as u64)
+                })
+                .collect();
+            let overlaps = |a: &Interval, b: &Interval| a.start < b.end && b.start < a.end;
+            let mut expected = 0;
+            for mask in 0u32..1 << n {
+                let picked: Vec<&Interval> = (0..n)
+                    .filter(|i| mask >> i & 1 == 1)
+                    .map(|i| &intervals[i])
+                    .collect();
+                let compatible = picked
+                    .iter()
+                    .enumerate()
+                    .all(|(i, a)| picked[i + 1..].iter().all(|b| !overlaps(a, b)));
+                if compatible {
+                    expected = expected.max(picked.iter().map(|i| i.weight).sum());
+                }
+            }
+            let (weight, chosen) = weighted_interval_scheduling(&intervals);
+            assert_eq!(weight, expected);
+            assert_eq!(
+                chosen.iter().map(|&i| intervals[i].weight).sum::<u64>(),
+                weight
+            );
+            for pair in chosen.windows(2) {
+                assert!(intervals[pair[0]].end <= intervals[pair[1]].start);
+            }
+        }
+    }
+}
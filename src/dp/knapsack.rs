@@ -0,0 +1,229 @@
+/*!
+定义：
+[背包问题的定义](https://zh.wikipedia.org/wiki/%E8%83%8C%E5%8C%85%E9%97%AE%E9%A2%98)
+给定每种物品的重量和价值，在总重量不超过容量的前提下使总价值最大。
+- 0/1 背包：每件物品最多选一次。`best[c]` 是容量为 c 时的最大价值，逐件物品从大到小更新 c，
+  一维数组就够了；为了还原选择，每件物品额外用一个位数组记录在哪些容量下选了它
+- 多重背包：第 i 种物品最多 `quantities[i]` 件。按 1, 2, 4, ... 和剩余部分二进制拆分成若干件捆绑物品，
+  任意件数都能由这些捆绑组合出来，然后转成 0/1 背包
+- 完全背包：每种物品不限件数，c 从小到大更新即可重复选择
+*/
+
+use crate::data_structures::bit_set::BitSet;
+
+/// 最优解的总价值以及每种物品选了几件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub value: u64,
+    /// `counts[i]` 是第 i 种物品选取的件数
+    pub counts: Vec<usize>,
+}
+
+impl Selection {
+    /// 所选物品的总重量
+    pub fn weight(&self, weights: &[usize]) -> usize {
+        self.counts.iter().zip(weights).map(|(c, w)| c * w).sum()
+    }
+}
+
+/// 0/1 背包
+pub fn knapsack_01(weights: &[usize], values: &[u64], capacity: usize) -> Selection {
+    assert_eq!(
+        weights.len(),
+        values.len(),
+        "Each item needs a weight and a value."
+    );
+    let pieces: Vec<(usize, usize)> = (0..weights.len()).map(|i| (i, 1)).collect();
+    solve_pieces(weights, values, &pieces, capacity)
+}
+
+/// 多重背包，第 i 种物品最多选 `quantities[i]` 件
+pub fn knapsack_bounded(
+    weights: &[usize],
+    values: &[u64],
+    quantities: &[usize],
+    capacity: usize,
+) -> Selection {
+    assert!(
+        weights.len() == values.len() && weights.len() == quantities.len(),
+        "Each item needs a weight, a value and a quantity."
+    );
+    // 二进制拆分：(物品, 件数)
+    let mut pieces = vec![];
+    for (item, &quantity) in quantities.iter().enumerate() {
+        let (mut rest, mut size) = (quantity, 1);
+        while rest > 0 {
+            let take = size.min(rest);
+            pieces.push((item, take));
+            rest -= take;
+            size *= 2;
+        }
+    }
+    solve_pieces(weights, values, &pieces, capacity)
+}
+
+/// 把每个 (物品, 件数) 当成一件物品做 0/1 背包
+fn solve_pieces(
+    weights: &[usize],
+    values: &[u64],
+    pieces: &[(usize, usize)],
+    capacity: usize,
+) -> Selection {
+    let mut best = vec![0; capacity + 1];
+    // taken[p] 的第 c 位表示处理到第 p 件时容量 c 的最优解选了它
+    let mut taken = Vec::with_capacity(pieces.len());
+    for &(item, count) in pieces {
+        let (weight, value) = (weights[item] * count, values[item] * count as u64);
+        let mut chosen = BitSet::new(capacity + 1);
+        for c in (weight..=capacity).rev() {
+            if best[c - weight] + value > best[c] {
+                best[c] = best[c - weight] + value;
+                chosen.set(c, true);
+            }
+        }
+        taken.push(chosen);
+    }
+
+    let mut counts = vec![0; weights.len()];
+    let mut c = capacity;
+    for (p, &(item, count)) in pieces.iter().enumerate().rev() {
+        if taken[p].get(c) {
+            counts[item] += count;
+            c -= weights[item] * count;
+        }
+    }
+    Selection {
+        value: best[capacity],
+        counts,
+    }
+}
+
+/// 完全背包，每种物品不限件数；物品重量必须为正
+pub fn knapsack_unbounded(weights: &[usize], values: &[u64], capacity: usize) -> Selection {
+    assert_eq!(
+        weights.len(),
+        values.len(),
+        "Each item needs a weight and a value."
+    );
+    assert!(weights.iter().all(|&w| w > 0), "Weights must be positive.");
+    let mut best = vec![0; capacity + 1];
+    // last[c] 是达到 best[c] 时最后放进去的物品
+    let mut last = vec![None; capacity + 1];
+    for c in 1..=capacity {
+        for (item, (&weight, &value)) in weights.iter().zip(values).enumerate() {
+            if weight <= c && best[c - weight] + value > best[c] {
+                best[c] = best[c - weight] + value;
+                last[c] = Some(item);
+            }
+        }
+    }
+
+    let mut counts = vec![0; weights.len()];
+    let mut c = capacity;
+    // 没有放物品时价值和 c - 1 一样，往下找真正放了物品的容量
+    while c > 0 {
+        match last[c] {
+            Some(item) => {
+                counts[item] += 1;
+                c -= weights[item];
+            }
+            None => c -= 1,
+        }
+    }
+    Selection {
+        value: best[capacity],
+        counts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{knapsack_01, knapsack_bounded, knapsack_unbounded, Selection};
+    use crate::utils::random::Rng;
+
+    fn check(selection: &Selection, weights: &[usize], values: &[u64], capacity: usize) {
+        assert!(selection.weight(weights) <= capacity);
+        let value: u64 = selection
+            .counts
+            .iter()
+            .zip(values)
+            .map(|(&c, &v)| c as u64 * v)
+            .sum();
+        assert_eq!(value, selection.value);
+    }
+
+    /// 枚举每种物品的件数
+    fn brute_force(weights: &[usize], values: &[u64], limits: &[usize], capacity: usize) -> u64 {
+        fn go(i: usize, room: usize, w: &[usize], v: &[u64], limits: &[usize]) -> u64 {
+            if i == w.len() {
+                return 0;
+            }
+            (0..=limits[i])
+                .take_while(|&k| k * w[i] <= room)
+                .map(|k| k as u64 * v[i] + go(i + 1, room - k * w[i], w, v, limits))
+                .max()
+                .unwrap()
+        }
+        go(0, capacity, weights, values, limits)
+    }
+
+    #[test]
+    fn classic_01_example() {
+        let (weights, values) = ([1, 3, 4, 5], [1, 4, 5, 7]);
+        let selection = knapsack_01(&weights, &values, 7);
+        assert_eq!(selection.value, 9);
+        assert_eq!(selection.counts, vec![0, 1, 1, 0]);
+        assert_eq!(knapsack_01(&[], &[], 10).value, 0);
+        assert_eq!(knapsack_01(&[5], &[10], 4).counts, vec![0]);
+    }
+
+    #[test]
+    fn bounded_and_unbounded_examples() {
+        let (weights, values) = ([2, 3], [3, 5]);
+        let bounded = knapsack_bounded(&weights, &values, &[5, 1], 10);
+        assert_eq!(bounded.value, 15);
+        assert_eq!(bounded.counts, vec![5, 0]);
+
+        let unbounded = knapsack_unbounded(&weights, &values, 10);
+        // 不限件数时 2 件重量 3 加 2 件重量 2 更好：5 + 5 + 3 + 3 = 16
+        assert_eq!(unbounded.value, 16);
+        check(&unbounded, &weights, &values, 10);
+        assert_eq!(knapsack_unbounded(&weights, &values, 1).value, 0);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1438);
+        for _ in 0..200 {
+            let n = rng.gen_index(6);
+            let weights: Vec<usize> = (0..n).map(|_| 1 + rng.gen_index(8)).collect();
+            let values: Vec<u64> = (0..n).map(|_| rng.gen_index(20) as u64).collect();
+            let quantities: Vec<usize> = (0..n).map(|_| rng.gen_index(5)).collect();
+            let capacity = rng.gen_index(25);
+
+            let zero_one = knapsack_01(&weights, &values, capacity);
+            check(&zero_one, &weights, &values, capacity);
+            assert!(zero_one.counts.iter().all(|&c| c <= 1));
+            assert_eq!(
+                zero_one.value,
+                brute_force(&weights, &values, &vec![1; n], capacity)
+            );
+
+            let bounded = knapsack_bounded(&weights, &values, &quantities, capacity);
+            check(&bounded, &weights, &values, capacity);
+            assert!(bounded.counts.iter().zip(&quantities).all(|(c, q)| c <= q));
+            assert_eq!(
+                bounded.value,
+                brute_force(&weights, &values, &quantities, capacity)
+            );
+
+            let unbounded = knapsack_unbounded(&weights, &values, capacity);
+            check(&unbounded, &weights, &values, capacity);
+            let limits = vec![capacity; n];
+            assert_eq!(
+                unbounded.value,
+                brute_force(&weights, &values, &limits, capacity)
+            );
+        }
+    }
+}
@@ -0,0 +1,101 @@
+/*!
+定义：
+[最长递增子序列](https://zh.wikipedia.org/wiki/%E6%9C%80%E9%95%BF%E9%80%92%E5%A2%9E%E5%AD%90%E5%BA%8F%E5%88%97)
+耐心排序的思路：`tails[k]` 是目前所有长度为 k + 1 的递增子序列中结尾最小的那个（保存下标）。
+`tails` 的结尾值单调递增，每个新元素二分查找第一个不能接在后面的位置替换掉它，
+同时记录它接在哪个元素后面，最后从最长的结尾沿前驱还原出子序列，O(n log n)
+*/
+
+use crate::searching::partition_point;
+
+/// 最长递增子序列的下标，按顺序排列；有多个时返回其中一个。
+/// `strict` 为 true 时要求严格递增，否则允许相等（非递减）
+pub fn longest_increasing_subsequence<T: Ord>(arr: &[T], strict: bool) -> Vec<usize> {
+    let mut tails: Vec<usize> = vec![];
+    let mut predecessor = vec![None; arr.len()];
+    for (i, item) in arr.iter().enumerate() {
+        // 能接在 tails[..k] 后面的最长位置
+        let k = partition_point(&tails, |&t| {
+            if strict {
+                arr[t] < *item
+            } else {
+                arr[t] <= *item
+            }
+        });
+        predecessor[i] = k.checked_sub(1).map(|p| tails[p]);
+        if k == tails.len() {
+            tails.push(i);
+        } else {
+            tails[k] = i;
+        }
+    }
+
+    let mut indices = vec![];
+    let mut current = tails.last().copied();
+    while let Some(i) = current {
+        indices.push(i);
+        current = predecessor[i];
+    }
+    indices.reverse();
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::longest_increasing_subsequence;
+    use crate::utils::random::Rng;
+
+    /// O(n²) 的 DP 求长度
+    fn quadratic(arr: &[i64], strict: bool) -> usize {
+        let mut best = vec![1; arr.len()];
+        for i in 0..arr.len() {
+            for j in 0..i {
+                if arr[j] < arr[i] || (!strict && arr[j] == arr[i]) {
+                    best[i] = best[i].max(best[j] + 1);
+                }
+            }
+        }
+        best.into_iter().max().unwrap_or(0)
+    }
+
+    #[test]
+    fn reconstructs_subsequence() {
+        let arr = [10, 9, 2, 5, 3, 7, 101, 18];
+        let indices = longest_increasing_subsequence(&arr, true);
+        let values: Vec<i32> = indices.iter().map(|&i| arr[i]).collect();
+        assert_eq!(values, vec![2, 3, 7, 18]);
+        assert!(longest_increasing_subsequence::<i32>(&[], true).is_empty());
+    }
+
+    #[test]
+    fn strictness_toggle() {
+        let arr = [3, 3, 1, 3, 2, 2];
+        assert_eq!(longest_increasing_subsequence(&arr, true).len(), 2);
+        let values: Vec<i32> = longest_increasing_subsequence(&arr, false)
+            .into_iter()
+            .map(|i| arr[i])
+            .collect();
+        assert_eq!(values, vec![1, 2, 2]);
+        assert_eq!(longest_increasing_subsequence(&[7; 5], false).len(), 5);
+        assert_eq!(longest_increasing_subsequence(&[7; 5], true).len(), 1);
+    }
+
+    #[test]
+    fn matches_quadratic_dp() {
+        let mut rng = Rng::new(1440);
+        for _ in 0..300 {
+            let arr: Vec<i64> = (0..rng.gen_index(40))
+                .map(|_| rng.gen_range(0, 10))
+                .collect();
+            for strict in [true, false] {
+                let indices = longest_increasing_subsequence(&arr, strict);
+                assert_eq!(indices.len(), quadratic(&arr, strict));
+                for pair in indices.windows(2) {
+                    assert!(pair[0] < pair[1]);
+                    let (a, b) = (arr[pair[0]], arr[pair[1]]);
+                    assert!(if strict { a < b } else { a <= b });
+                }
+            }
+        }
+    }
+}
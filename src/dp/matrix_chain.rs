@@ -0,0 +1,134 @@
+/*!
+定义：
+[矩阵链乘积](https://zh.wikipedia.org/wiki/%E7%9F%A9%E9%99%A3%E9%8F%88%E4%B9%98%E7%A9%8D)
+第 i 个矩阵的大小是 `dims[i] × dims[i + 1]`，p×q 和 q×r 的矩阵相乘需要 pqr 次标量乘法。
+乘法满足结合律，不同的加括号方式代价差别很大。`cost[i][j]` 是 A_i..A_j 的最小代价，
+枚举最后一次相乘的切分点 k，O(n³)
+*/
+
+use std::fmt;
+
+/// 加括号方式，矩阵从 0 开始编号，显示时从 A1 开始
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Parenthesization {
+    Matrix(usize),
+    Product(Box<Parenthesization>, Box<Parenthesization>),
+}
+
+impl fmt::Display for Parenthesization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Parenthesization::Matrix(i) => write!(f, "A{}", i + 1),
+            Parenthesization::Product(left, right) => write!(f, "({left}{right})"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainOrder {
+    /// 标量乘法的次数
+    pub cost: u64,
+    pub parenthesization: Parenthesization,
+}
+
+/// 求最优的乘法顺序，`dims` 至少要有两个元素（一个矩阵）
+pub fn matrix_chain_order(dims: &[usize]) -> ChainOrder {
+    assert!(dims.len() >= 2, "A chain needs at least one matrix.");
+    let n = dims.len() - 1;
+    let mut cost = vec![vec![0u64; n]; n];
+    let mut split = vec![vec![0; n]; n];
+    for len in 2..=n {
+        for i in 0..=n - len {
+            let j = i + len - 1;
+            cost[i][j] = u64::MAX;
+            for k in i..j {
+                let candidate =
+                    cost[i][k] + cost[k + 1][j] + (dims[i] * dims[k + 1] * dims[j + 1]) as u64;
+                if candidate < cost[i][j] {
+                    cost[i][j] = candidate;
+                    split[i][j] = k;
+                }
+            }
+        }
+    }
+    ChainOrder {
+        cost: cost[0][n - 1],
+        parenthesization: build(&split, 0, n - 1),
+    }
+}
+
+fn build(split: &[Vec<usize>], i: usize, j: usize) -> Parenthesization {
+    if i == j {
+        return Parenthesization::Matrix(i);
+    }
+    let k = split[i][j];
+    Parenthesization::Product(
+        Box::new(build(split, i, k)),
+        Box::new(build(split, k + 1, j)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matrix_chain_order, Parenthesization};
+    use crate::utils::random::Rng;
+
+    /// 按加括号方式计算代价，返回 (代价, 行数, 列数)
+    fn evaluate(p: &Parenthesization, dims: &[usize]) -> (u64, usize, usize) {
+        match p {
+            Parenthesization::Matrix(i) => (0, dims[*i], dims[i + 1]),
+            Parenthesization::Product(left, right) => {
+                let (a, rows, inner) = evaluate(left, dims);
+                let (b, inner_right, columns) = evaluate(right, dims);
+                assert_eq!(inner, inner_right);
+                (a + b + (rows * inner * columns) as u64, rows, columns)
+            }
+        }
+    }
+
+    /// 枚举所有加括号方式的最小代价
+    fn exhaustive(dims: &[usize], i: usize, j: usize) -> u64 {
+        (i..j)
+            .map(|k| {
+                exhaustive(dims, i, k)
+                    + exhaustive(dims, k + 1, j)
+                    + (dims[i] * dims[k + 1] * dims[j + 1]) as u64
+            })
+            .min()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn clrs_example() {
+        let dims = [30, 35, 15, 5, 10, 20, 25];
+        let order = matrix_chain_order(&dims);
+        assert_eq!(order.cost, 15125);
+        assert_eq!(order.parenthesization.to_string(), "((A1(A2A3))((A4A5)A6))");
+        assert_eq!(evaluate(&order.parenthesization, &dims).0, 15125);
+    }
+
+    #[test]
+    fn single_matrix_and_pair() {
+        assert_eq!(matrix_chain_order(&[3, 4]).cost, 0);
+        assert_eq!(
+            matrix_chain_order(&[3, 4]).parenthesization.to_string(),
+            "A1"
+        );
+        assert_eq!(matrix_chain_order(&[2, 3, 4]).cost, 24);
+    }
+
+    #[test]
+    fn matches_exhaustive_search() {
+        let mut rng = Rng::new(1441);
+        for _ in 0..100 {
+            let dims: Vec<usize> = (0..2 + rng.gen_index(7))
+                .map(|_| 1 + rng.gen_index(30))
+                .collect();
+            let order = matrix_chain_order(&dims);
+            assert_eq!(order.cost, exhaustive(&dims, 0, dims.len() - 2));
+            let (cost, rows, columns) = evaluate(&order.parenthesization, &dims);
+            assert_eq!(cost, order.cost);
+            assert_eq!((rows, columns), (dims[0], dims[dims.len() - 1]));
+        }
+    }
+}
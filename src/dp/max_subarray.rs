@@ -0,0 +1,174 @@
+/*!
+定义：
+[最大子数组和](https://zh.wikipedia.org/wiki/%E6%9C%80%E5%A4%A7%E5%AD%90%E6%95%B0%E5%88%97%E9%97%AE%E9%A2%98)
+Kadane 算法：以 i 结尾的最大和要么是 arr[i] 自己，要么接在以 i - 1 结尾的最大和后面，一遍扫描 O(n)。
+- 环形数组：最优子数组要么不跨过末尾（普通 Kadane），要么跨过末尾，这时它的补集是中间一段最小和的子数组
+- 二维：枚举上下边界，用每列的前缀和 O(1) 求出这几行在每一列上的和，再对这一行和做 Kadane，O(rows² · cols)
+*/
+
+use std::ops::Range;
+
+/// 和最大的非空子数组，返回 (和, 下标区间)；有多个时返回最靠左、最短的。空数组返回 None
+pub fn max_subarray(arr: &[i64]) -> Option<(i64, Range<usize>)> {
+    let mut best: Option<(i64, Range<usize>)> = None;
+    // 以当前位置结尾的最大和及其起点
+    let (mut current, mut start) = (0, 0);
+    for (i, &value) in arr.iter().enumerate() {
+        if i == 0 || current <= 0 {
+            current = value;
+            start = i;
+        } else {
+            current += value;
+        }
+        if best.as_ref().is_none_or(|(sum, _)| current > *sum) {
+            best = Some((current, start..i + 1));
+        }
+    }
+    best
+}
+
+/// 环形数组上和最大的非空子数组。区间的下标可能超过 n，第 j 个位置对应 `arr[j % n]`，区间长度不超过 n
+pub fn max_subarray_circular(arr: &[i64]) -> Option<(i64, Range<usize>)> {
+    let (straight, range) = max_subarray(arr)?;
+    let n = arr.len();
+    let total: i64 = arr.iter().sum();
+    // 中间去掉一段和最小的子数组，剩下的首尾两段拼成跨过末尾的子数组
+    let negated: Vec<i64> = arr.iter().map(|x| -x).collect();
+    let (negated_min, removed) = max_subarray(&negated)?;
+    let wrapped = total + negated_min;
+    // 去掉的是整个数组时剩下的为空，不合法
+    if removed.len() < n && wrapped > straight {
+        Some((wrapped, removed.end..removed.start + n))
+    } else {
+        Some((straight, range))
+    }
+}
+
+/// 和最大的非空子矩阵，返回 (和, 行区间, 列区间)。矩阵为空时返回 None
+pub fn max_submatrix(matrix: &[Vec<i64>]) -> Option<(i64, Range<usize>, Range<usize>)> {
+    let rows = matrix.len();
+    let columns = matrix.first().map_or(0, Vec::len);
+    assert!(
+        matrix.iter().all(|row| row.len() == columns),
+        "All rows must have the same length."
+    );
+    if columns == 0 {
+        return None;
+    }
+    // prefix[r][c] 是第 c 列前 r 行的和
+    let mut prefix = vec![vec![0; columns]; rows + 1];
+    for r in 0..rows {
+        for c in 0..columns {
+            prefix[r + 1][c] = prefix[r][c] + matrix[r][c];
+        }
+    }
+
+    let mut best: Option<(i64, Range<usize>, Range<usize>)> = None;
+    for top in 0..rows {
+        for bottom in top + 1..=rows {
+            let strip: Vec<i64> = (0..columns)
+                .map(|c| prefix[bottom][c] - prefix[top][c])
+                .collect();
+            let (sum, range) = max_subarray(&strip).unwrap();
+            if best.as_ref().is_none_or(|(b, _, _)| sum > *b) {
+                best = Some((sum, top..bottom, range));
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{max_subarray, max_subarray_circular, max_submatrix};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn kadane() {
+        assert_eq!(
+            max_subarray(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]),
+            Some((6, 3..7))
+        );
+        assert_eq!(max_subarray(&[-3, -1, -2]), Some((-1, 1..2)));
+        assert_eq!(max_subarray(&[]), None);
+        assert_eq!(max_subarray(&[0, 0]), Some((0, 0..1)));
+    }
+
+    #[test]
+    fn circular() {
+        assert_eq!(max_subarray_circular(&[5, -3, 5]), Some((10, 2..4)));
+        assert_eq!(max_subarray_circular(&[1, -2, 3, -2]), Some((3, 2..3)));
+        assert_eq!(max_subarray_circular(&[-3, -2, -3]), Some((-2, 1..2)));
+        assert_eq!(max_subarray_circular(&[]), None);
+    }
+
+    #[test]
+    fn submatrix() {
+        let matrix = vec![
+            vec![1, 2, -1, -4, -20],
+            vec![-8, -3, 4, 2, 1],
+            vec![3, 8, 10, 1, 3],
+            vec![-4, -1, 1, 7, -6],
+        ];
+        assert_eq!(max_submatrix(&matrix), Some((29, 1..4, 1..4)));
+        assert_eq!(max_submatrix(&[]), None);
+        assert_eq!(max_submatrix(&[vec![-5]]), Some((-5, 0..1, 0..1)));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1445);
+        for _ in 0..200 {
+            let n = 1 + rng.gen_index(12);
+            let arr: Vec<i64> = (0..n).map(|_| rng.gen_range(-10, 10)).collect();
+            let circular_sum =
+                |range: &std::ops::Range<usize>| -> i64 { range.clone().map(|j| arr[j % n]).sum() };
+
+            let (sum, range) = max_subarray(&arr).unwrap();
+            let mut expected = i64::MIN;
+            let mut expected_circular = i64::MIN;
+            for start in 0..n {
+                for len in 1..=n {
+                    let s = circular_sum(&(start..start + len));
+                    expected_circular = expected_circular.max(s);
+                    if start + len <= n {
+                        expected = expected.max(s);
+                    }
+                }
+            }
+            assert_eq!(sum, expected);
+            assert_eq!(arr[range].iter().sum::<i64>(), sum);
+
+            let (sum, range) = max_subarray_circular(&arr).unwrap();
+            assert_eq!(sum, expected_circular);
+            assert!(range.len() <= n && !range.is_empty());
+            assert_eq!(circular_sum(&range), sum);
+        }
+
+        for _ in 0..50 {
+            let (rows, columns) = (1 + rng.gen_index(5), 1 + rng.gen_index(5));
+            let matrix: Vec<Vec<i64>> = (0..rows)
+                .map(|_| (0..columns).map(|_| rng.gen_range(-10, 10)).collect())
+                .collect();
+            let sum_of = |r: &std::ops::Range<usize>, c: &std::ops::Range<usize>| -> i64 {
+                matrix[r.clone()]
+                    .iter()
+                    .map(|row| row[c.clone()].iter().sum::<i64>())
+                    .sum()
+            };
+            let mut expected = i64::MIN;
+            for top in 0..rows {
+                for bottom in top + 1..=rows {
+                    for left in 0..columns {
+                        for right in left + 1..=columns {
+                            expected = expected.max(sum_of(&(top..bottom), &(left..right)));
+                        }
+                    }
+                }
+            }
+            let (sum, r, c) = max_submatrix(&matrix).unwrap();
+            assert_eq!(sum, expected);
+            assert_eq!(sum_of(&r, &c), sum);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+/*!
+定义：
+[记忆化](https://zh.wikipedia.org/wiki/%E8%AE%B0%E5%BF%86%E5%8C%96)
+把递归函数每个参数对应的结果存进哈希表，再次遇到相同参数时直接返回，
+自顶向下地写出转移方程就能得到和自底向上 DP 一样的复杂度。
+
+递归的闭包没法直接调用自己，所以 [`memoize`] 接收的函数多一个参数 `recurse`，
+递归时调用 `recurse` 而不是函数本身，结果就会经过缓存
+*/
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// 以哈希表为后端的缓存
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// 已经缓存的结果个数
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    /// 求 `f(key)`，`f` 递归时调用第一个参数，递归的结果也会被缓存
+    pub fn call<F>(&mut self, f: &F, key: K) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = f(&mut |k| self.call(f, k), key.clone());
+        self.cache.insert(key, value.clone());
+        value
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 把递归函数包装成带缓存的函数，多次调用之间共享缓存
+pub fn memoize<K, V, F>(f: F) -> impl FnMut(K) -> V
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    let mut memo = Memo::new();
+    move |key| memo.call(&f, key)
+}
+
+/// 第 n 个斐波那契数，F(0) = 0，F(1) = 1；u128 最多到 F(186)
+pub fn fibonacci_memo(n: u32) -> u128 {
+    let mut fibonacci = memoize(|recurse, n: u32| {
+        if n < 2 {
+            n as u128
+        } else {
+            recurse(n - 1) + recurse(n - 2)
+        }
+    });
+    fibonacci(n)
+}
+
+/// 从左上角只向右或向下走到右下角的路径数，`blocked[r][c]` 为 true 的格子不能经过
+pub fn grid_paths(blocked: &[Vec<bool>]) -> u128 {
+    let rows = blocked.len();
+    let Some(columns) = blocked.first().map(Vec::len) else {
+        return 0;
+    };
+    let mut paths = memoize(|recurse, (r, c): (usize, usize)| -> u128 {
+        if blocked[r][c] {
+            return 0;
+        }
+        if (r, c) == (0, 0) {
+            return 1;
+        }
+        let from_above = if r > 0 { recurse((r - 1, c)) } else { 0 };
+        let from_left = if c > 0 { recurse((r, c - 1)) } else { 0 };
+        from_above + from_left
+    });
+    if columns == 0 {
+        0
+    } else {
+        paths((rows - 1, columns - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fibonacci_memo, grid_paths, memoize, Memo};
+    use std::cell::Cell;
+
+    #[test]
+    fn caches_each_argument_once() {
+        let calls = Cell::new(0);
+        let mut memo = Memo::new();
+        let fibonacci = |recurse: &mut dyn FnMut(u64) -> u64, n: u64| {
+            calls.set(calls.get() + 1);
+            if n < 2 {
+                n
+            } else {
+                recurse(n - 1) + recurse(n - 2)
+            }
+        };
+        assert_eq!(memo.call(&fibonacci, 50), 12_586_269_025);
+        // 0..=50 每个参数只计算一次
+        assert_eq!(calls.get(), 51);
+        assert_eq!(memo.len(), 51);
+        assert_eq!(memo.get(&10), Some(&55));
+        assert_eq!(memo.call(&fibonacci, 40), 102_334_155);
+        assert_eq!(calls.get(), 51);
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+
+    #[test]
+    fn memoized_closure_keeps_cache() {
+        let mut binomial = memoize(|recurse, (n, k): (u64, u64)| -> u64 {
+            if k == 0 || k == n {
+                1
+            } else {
+                recurse((n - 1, k - 1)) + recurse((n - 1, k))
+            }
+        });
+        assert_eq!(binomial((60, 30)), 118_264_581_564_861_424);
+        assert_eq!(binomial((5, 2)), 10);
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(fibonacci_memo(0), 0);
+        assert_eq!(fibonacci_memo(90), 2_880_067_194_370_816_120);
+        assert_eq!(
+            fibonacci_memo(186),
+            332_825_110_087_067_562_321_196_029_789_634_457_848
+        );
+
+        // 3×7 的网格有 C(8, 2) = 28 条路径
+        assert_eq!(grid_paths(&vec![vec![false; 7]; 3]), 28);
+        let mut blocked = vec![vec![false; 3]; 3];
+        blocked[1][1] = true;
+        assert_eq!(grid_paths(&blocked), 2);
+        blocked[0][0] = true;
+        assert_eq!(grid_paths(&blocked), 0);
+        assert_eq!(grid_paths(&[]), 0);
+    }
+}
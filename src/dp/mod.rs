@@ -0,0 +1,25 @@
+pub mod bitmask;
+pub mod coin_change;
+pub mod interval_scheduling;
+pub mod knapsack;
+pub mod lis;
+pub mod matrix_chain;
+pub mod max_subarray;
+pub mod memo;
+pub mod palindrome;
+pub mod rod_cutting;
+pub mod subset_sum;
+pub mod word_break;
+
+pub use self::bitmask::{submask_iter, tsp_held_karp};
+pub use self::coin_change::{coin_change_min, coin_change_ways};
+pub use self::interval_scheduling::{weighted_interval_scheduling, Interval};
+pub use self::knapsack::{knapsack_01, knapsack_bounded, knapsack_unbounded, Selection};
+pub use self::lis::longest_increasing_subsequence;
+pub use self::matrix_chain::{matrix_chain_order, ChainOrder, Parenthesization};
+pub use self::max_subarray::{max_subarray, max_subarray_circular, max_submatrix};
+pub use self::memo::{fibonacci_memo, grid_paths, memoize, Memo};
+pub use self::palindrome::{longest_palindromic_subsequence, min_palindrome_partition};
+pub use self::rod_cutting::rod_cutting;
+pub use self::subset_sum::{can_partition_equal, count_subsets_with_sum, subset_sum};
+pub use self::word_break::{word_break, word_break_all, WordBreaks};
@@ -0,0 +1,170 @@
+/*!
+和回文有关的两个 DP，补充 Manacher 算法只处理连续子串的不足：
+- [最长回文子序列](https://en.wikipedia.org/wiki/Longest_palindromic_subsequence)：`best[i][j]` 是 `s[i..=j]` 的答案，
+  首尾相等时等于 `best[i + 1][j - 1] + 2`，否则取去掉首或尾的较大者，O(n²)
+- 最少回文分割：把串切成若干段，每段都是回文，切的次数最少。`cuts[j]` 是 `s[..j]` 最少分成几段，
+  枚举最后一段的起点 i；`s[i..j]` 是否是回文由 Manacher 的回文半径 O(1) 判断，O(n²)
+*/
+
+use crate::strings::manacher::{palindrome_radii, PalindromeRadii};
+
+/// 最长回文子序列，有多个时返回其中一个
+pub fn longest_palindromic_subsequence<T: PartialEq + Clone>(s: &[T]) -> Vec<T> {
+    let n = s.len();
+    if n == 0 {
+        return vec![];
+    }
+    let mut best = vec![vec![0usize; n]; n];
+    for i in (0..n).rev() {
+        best[i][i] = 1;
+        for j in i + 1..n {
+            best[i][j] = if s[i] == s[j] {
+                best[i + 1][j - 1] + 2
+            } else {
+                best[i + 1][j].max(best[i][j - 1])
+            };
+        }
+    }
+
+    // 从两端向中间还原，左半部分正着收集，右半部分是它的镜像
+    let mut left = vec![];
+    let mut middle = None;
+    let (mut i, mut j) = (0, n - 1);
+    while i <= j {
+        if i == j {
+            middle = Some(s[i].clone());
+            break;
+        }
+        if s[i] == s[j] {
+            left.push(s[i].clone());
+            i += 1;
+            j -= 1;
+        } else if best[i + 1][j] >= best[i][j - 1] {
+            i += 1;
+        } else {
+            j -= 1;
+        }
+    }
+    let mut result = left.clone();
+    result.extend(middle);
+    result.extend(left.into_iter().rev());
+    result
+}
+
+/// 最少回文分割，返回切割位置（严格递增，不含 0 和 n）。`s[a..b]` 在相邻切割位置之间都是回文
+pub fn min_palindrome_partition<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let PalindromeRadii { odd, even } = palindrome_radii(s);
+    let is_palindrome = |i: usize, j: usize| {
+        let len = j - i;
+        if len % 2 == 1 {
+            odd[(i + j) / 2] * 2 > len
+        } else {
+            even[(i + j) / 2] * 2 >= len
+        }
+    };
+
+    // pieces[j] 是 s[..j] 最少分成的段数，start[j] 是对应的最后一段的起点
+    let mut pieces = vec![usize::MAX; n + 1];
+    let mut start = vec![0; n + 1];
+    pieces[0] = 0;
+    for j in 1..=n {
+        for i in 0..j {
+            if pieces[i] + 1 < pieces[j] && is_palindrome(i, j) {
+                pieces[j] = pieces[i] + 1;
+                start[j] = i;
+            }
+        }
+    }
+
+    let mut cuts = vec![];
+    let mut j = n;
+    while j > 0 {
+        j = start[j];
+        if j > 0 {
+            cuts.push(j);
+        }
+    }
+    cuts.reverse();
+    cuts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{longest_palindromic_subsequence, min_palindrome_partition};
+    use crate::utils::random::Rng;
+
+    fn is_palindrome(s: &[u8]) -> bool {
+        s.iter().eq(s.iter().rev())
+    }
+
+    fn is_subsequence(sub: &[u8], of: &[u8]) -> bool {
+        let mut rest = of.iter();
+        sub.iter().all(|x| rest.any(|y| y == x))
+    }
+
+    #[test]
+    fn known_examples() {
+        assert_eq!(longest_palindromic_subsequence(b"bbbab"), b"bbbb");
+        assert_eq!(longest_palindromic_subsequence(b"character").len(), 5);
+        assert!(longest_palindromic_subsequence::<u8>(b"").is_empty());
+
+        assert_eq!(min_palindrome_partition(b"aab"), vec![2]);
+        assert_eq!(min_palindrome_partition(b"racecar"), Vec::<usize>::new());
+        assert_eq!(min_palindrome_partition(b"abcd"), vec![1, 2, 3]);
+        assert_eq!(min_palindrome_partition::<u8>(b""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1447);
+        for _ in 0..200 {
+            let s: Vec<u8> = (0..rng.gen_index(14))
+                .map(|_| b'a' + rng.gen_index(3) as u8)
+                .collect();
+            let n = s.len();
+
+            let subsequence = longest_palindromic_subsequence(&s);
+            assert!(is_palindrome(&subsequence) && is_subsequence(&subsequence, &s));
+            let longest = (0u32..1 << n)
+                .map(|mask| {
+                    (0..n)
+                        .filter(|i| mask >> i & 1 == 1)
+                        .map(|i| s[i])
+                        .collect::<Vec<_>>()
+                })
+                .filter(|sub| is_palindrome(sub))
+                .map(|sub| sub.len())
+                .max()
+                .unwrap();
+            assert_eq!(subsequence.len(), longest);
+
+            let cuts = min_palindrome_partition(&s);
+            let mut bounds = vec![0];
+            bounds.extend(&cuts);
+            bounds.push(n);
+            if n > 0 {
+                assert!(bounds
+                    .windows(2)
+                    .all(|w| w[0] < w[1] && is_palindrome(&s[w[0]..w[1]])));
+            }
+            // 在 n - 1 个可切的位置中枚举切法
+            let fewest = (0u32..1 << n.saturating_sub(1))
+                .filter(|mask| {
+                    let mut previous = 0;
+                    (1..n)
+                        .filter(|i| mask >> (i - 1) & 1 == 1)
+                        .chain(std::iter::once(n))
+                        .all(|cut| {
+                            let ok = is_palindrome(&s[previous..cut]);
+                            previous = cut;
+                            ok
+                        })
+                })
+                .map(|mask| mask.count_ones() as usize)
+                .min()
+                .unwrap();
+            assert_eq!(cuts.len(), fewest);
+        }
+    }
+}
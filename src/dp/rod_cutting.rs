@@ -0,0 +1,71 @@
+/*!
+定义：
+[钢条切割](https://en.wikipedia.org/wiki/Cutting_stock_problem)
+长度为 i 的一段可以卖 `prices[i - 1]`，把长度为 n 的钢条切成若干段使总售价最高。
+`best[n]` 等于所有第一段长度 i 中 `prices[i - 1] + best[n - i]` 的最大值，O(n²)
+*/
+
+/// 最高总售价以及各段的长度（从长到短）；`prices[i]` 是长度为 i + 1 的一段的售价
+pub fn rod_cutting(prices: &[u64], length: usize) -> (u64, Vec<usize>) {
+    let mut best = vec![0; length + 1];
+    // first[n] 是长度为 n 时最优方案的第一段长度，0 表示卖不出价钱
+    let mut first = vec![0; length + 1];
+    for n in 1..=length {
+        for (i, &price) in prices.iter().enumerate().take(n) {
+            let piece = i + 1;
+            if price + best[n - piece] > best[n] {
+                best[n] = price + best[n - piece];
+                first[n] = piece;
+            }
+        }
+    }
+
+    let mut pieces = vec![];
+    let mut n = length;
+    while n > 0 && first[n] > 0 {
+        pieces.push(first[n]);
+        n -= first[n];
+    }
+    pieces.sort_unstable_by(|a, b| b.cmp(a));
+    (best[length], pieces)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rod_cutting;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn clrs_prices() {
+        let prices = [1, 5, 8, 9, 10, 17, 17, 20, 24, 30];
+        let expected = [0, 1, 5, 8, 10, 13, 17, 18, 22, 25, 30];
+        for (n, &value) in expected.iter().enumerate() {
+            let (best, pieces) = rod_cutting(&prices, n);
+            assert_eq!(best, value);
+            assert_eq!(pieces.iter().sum::<usize>(), n);
+        }
+        assert_eq!(rod_cutting(&prices, 4).1, vec![2, 2]);
+        assert_eq!(rod_cutting(&prices, 7).1, vec![6, 1]);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        fn brute(prices: &[u64], n: usize) -> u64 {
+            (1..=n.min(prices.len()))
+                .map(|i| prices[i - 1] + brute(prices, n - i))
+                .max()
+                .unwrap_or(0)
+        }
+        let mut rng = Rng::new(1442);
+        for _ in 0..100 {
+            let prices: Vec<u64> = (0..rng.gen_index(8))
+                .map(|_| rng.gen_index(20) as u64)
+                .collect();
+            let n = rng.gen_index(12);
+            let (best, pieces) = rod_cutting(&prices, n);
+            assert_eq!(best, brute(&prices, n));
+            assert!(pieces.iter().sum::<usize>() <= n);
+            assert_eq!(pieces.iter().map(|&p| prices[p - 1]).sum::<u64>(), best);
+        }
+    }
+}
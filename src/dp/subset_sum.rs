@@ -0,0 +1,122 @@
+/*!
+定义：
+[子集和问题](https://zh.wikipedia.org/wiki/%E5%AD%90%E9%9B%86%E5%90%88%E5%8A%A0%E7%B8%BD%E5%95%8F%E9%A1%8C)
+判断能否从一组非负整数中选出若干个，使它们的和恰好等于目标值。
+用位数组表示当前能凑出的所有和，加入数 v 相当于 `reachable |= reachable << v`，
+一次处理 64 个和，复杂度 O(n · target / 64)。保留每一步的位数组，就能从目标值倒推出选了哪些数
+*/
+
+use crate::data_structures::bit_set::BitSet;
+
+/// 和为 `target` 的一个子集，返回所选元素的下标（递增）；不存在时返回 None
+pub fn subset_sum(values: &[usize], target: usize) -> Option<Vec<usize>> {
+    // reachable[i] 是只用前 i 个数能凑出的和
+    let mut reachable = Vec::with_capacity(values.len() + 1);
+    let mut current = BitSet::new(target + 1);
+    current.set(0, true);
+    reachable.push(current.clone());
+    for &value in values {
+        current.or_shifted(value);
+        reachable.push(current.clone());
+    }
+    if !current.get(target) {
+        return None;
+    }
+
+    let mut chosen = vec![];
+    let mut sum = target;
+    for i in (0..values.len()).rev() {
+        // 不用第 i 个数凑不出 sum，说明必须选它
+        if !reachable[i].get(sum) {
+            chosen.push(i);
+            sum -= values[i];
+        }
+    }
+    chosen.reverse();
+    Some(chosen)
+}
+
+/// 能否分成和相等的两部分
+pub fn can_partition_equal(values: &[usize]) -> bool {
+    let total: usize = values.iter().sum();
+    total.is_multiple_of(2) && subset_sum(values, total / 2).is_some()
+}
+
+/// 和为 `target` 的子集个数，值相同但下标不同的元素算作不同的子集；空集的和为 0。
+/// 个数超出 u128 时返回 None
+pub fn count_subsets_with_sum(values: &[usize], target: usize) -> Option<u128> {
+    // None 表示已经溢出，之后由它累加出的格子也一定溢出
+    let mut ways = vec![Some(0u128); target + 1];
+    ways[0] = Some(1);
+    for &value in values {
+        // 从大到小更新，每个数只用一次
+        for sum in (value..=target).rev() {
+            ways[sum] = ways[sum]
+                .zip(ways[sum - value])
+                .and_then(|(x, y)| x.checked_add(y));
+        }
+    }
+    ways[target]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{can_partition_equal, count_subsets_with_sum, subset_sum};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn finds_subsets() {
+        let values = [3, 34, 4, 12, 5, 2];
+        let chosen = subset_sum(&values, 9).unwrap();
+        assert_eq!(chosen.iter().map(|&i| values[i]).sum::<usize>(), 9);
+        assert_eq!(subset_sum(&values, 30), None);
+        assert_eq!(subset_sum(&values, 0), Some(vec![]));
+        assert_eq!(subset_sum(&[], 1), None);
+    }
+
+    #[test]
+    fn partitions() {
+        assert!(can_partition_equal(&[1, 5, 11, 5]));
+        assert!(!can_partition_equal(&[1, 2, 3, 5]));
+        assert!(can_partition_equal(&[]));
+        assert_eq!(count_subsets_with_sum(&[1, 1, 2, 3], 4), Some(3));
+        assert_eq!(count_subsets_with_sum(&[0, 1], 1), Some(2));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        // n 个 0 的每个子集的和都是 0
+        assert_eq!(count_subsets_with_sum(&[0; 127], 0), Some(1 << 127));
+        assert_eq!(count_subsets_with_sum(&[0; 128], 0), None);
+        // 和为 0 的个数溢出了，但凑不出 1
+        assert_eq!(count_subsets_with_sum(&[0; 128], 1), Some(0));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1444);
+        for _ in 0..200 {
+            let n = rng.gen_index(12);
+            let values: Vec<usize> = (0..n).map(|_| rng.gen_index(100)).collect();
+            let target = rng.gen_index(300);
+            let count = (0u32..1 << n)
+                .filter(|mask| {
+                    (0..n)
+                        .filter(|i| mask >> i & 1 == 1)
+                        .map(|i| values[i])
+                        .sum::<usize>()
+                        == target
+                })
+                .count() as u128;
+            assert_eq!(count_subsets_with_sum(&values, target), Some(count));
+            match subset_sum(&values, target) {
+                Some(chosen) => {
+                    assert!(count > 0);
+                    assert!(chosen.windows(2).all(|w| w[0] < w[1]));
+                    assert_eq!(chosen.iter().map(|&i| values[i]).sum::<usize>(), target);
+                }
+                None => assert_eq!(count, 0),
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+/*!
+定义：
+[单词拆分](https://en.wikipedia.org/wiki/Word_break)
+判断文本能否切分成若干个字典中的单词。`can_finish[i]` 表示 `text[i..]` 可以切分，
+从后往前算：只要某个以 i 开头的单词结束在 j 并且 `can_finish[j]` 为真即可。
+以 i 开头的单词用字典树沿着文本往下走一次全部找出，不必对每个长度都查一次哈希表。
+枚举所有切分方案时只沿着 `can_finish` 为真的位置深入，不会走进死胡同，每一步都能产出结果
+*/
+
+use crate::data_structures::trie::Trie;
+
+/// 每个位置之后的部分能否切分，`can_finish[text.len()]` 为 true
+fn can_finish(text: &str, trie: &Trie) -> Vec<bool> {
+    let mut can_finish = vec![false; text.len() + 1];
+    can_finish[text.len()] = true;
+    for (i, _) in text.char_indices().rev() {
+        can_finish[i] = trie
+            .prefixes_of(&text[i..])
+            .any(|len| len > 0 && can_finish[i + len]);
+    }
+    can_finish
+}
+
+/// 文本能否切分成字典中的单词（单词可以重复使用）
+pub fn word_break(text: &str, dictionary: &[&str]) -> bool {
+    let trie: Trie = dictionary.iter().copied().collect();
+    can_finish(text, &trie)[0]
+}
+
+/// 惰性地枚举所有切分方案，方案之间按单词长度的字典序排列
+pub fn word_break_all<'a>(text: &'a str, dictionary: &[&str]) -> WordBreaks<'a> {
+    let trie: Trie = dictionary.iter().copied().collect();
+    let can_finish = can_finish(text, &trie);
+    let stack = if can_finish[0] { vec![vec![0]] } else { vec![] };
+    WordBreaks {
+        text,
+        trie,
+        can_finish,
+        stack,
+    }
+}
+
+/// [`word_break_all`] 返回的迭代器
+pub struct WordBreaks<'a> {
+    text: &'a str,
+    trie: Trie,
+    can_finish: Vec<bool>,
+    /// 待展开的部分方案，每个是已经切好的边界位置
+    stack: Vec<Vec<usize>>,
+}
+
+impl<'a> Iterator for WordBreaks<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(bounds) = self.stack.pop() {
+            let end = *bounds.last().unwrap();
+            if end == self.text.len() {
+                return Some(bounds.windows(2).map(|w| &self.text[w[0]..w[1]]).collect());
+            }
+            let ends: Vec<usize> = self
+                .trie
+                .prefixes_of(&self.text[end..])
+                .filter(|&len| len > 0 && self.can_finish[end + len])
+                .map(|len| end + len)
+                .collect();
+            // 反着压栈，短的单词先出栈
+            for next in ends.into_iter().rev() {
+                let mut extended = bounds.clone();
+                extended.push(next);
+                self.stack.push(extended);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{word_break, word_break_all};
+
+    #[test]
+    fn checks_segmentation() {
+        assert!(word_break("leetcode", &["leet", "code"]));
+        assert!(word_break("applepenapple", &["apple", "pen"]));
+        assert!(!word_break(
+            "catsandog",
+            &["cats", "dog", "sand", "and", "cat"]
+        ));
+        assert!(word_break("", &[]));
+        assert!(!word_break("a", &[""]));
+        assert!(word_break("我爱北京", &["我", "爱", "北京"]));
+    }
+
+    #[test]
+    fn enumerates_all_segmentations() {
+        let dictionary = ["cat", "cats", "and", "sand", "dog"];
+        let all: Vec<Vec<&str>> = word_break_all("catsanddog", &dictionary).collect();
+        assert_eq!(
+            all,
+            vec![vec!["cat", "sand", "dog"], vec!["cats", "and", "dog"]]
+        );
+        assert_eq!(word_break_all("catsandog", &dictionary).count(), 0);
+        assert_eq!(
+            word_break_all("", &[]).collect::<Vec<_>>(),
+            vec![Vec::<&str>::new()]
+        );
+    }
+
+    #[test]
+    fn is_lazy_on_exponential_output() {
+        // "a" * 60 有 2^59 种切分，只取前几个
+        let text = "a".repeat(60);
+        let first: Vec<Vec<&str>> = word_break_all(&text, &["a", "aa"]).take(3).collect();
+        assert_eq!(first.len(), 3);
+        assert!(first[0].iter().all(|w| *w == "a"));
+        // 死胡同很多的输入也能立刻判断
+        let hopeless = format!("{}b", "a".repeat(2000));
+        assert!(!word_break(&hopeless, &["a", "aa", "aaa"]));
+    }
+}
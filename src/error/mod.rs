@@ -0,0 +1,102 @@
+/*!
+crate 统一的错误类型。
+会因为调用方传入的参数不合法而失败的操作返回 `Result<_, AlgoError>`；
+需要时可以显式调用对应的 `*_unchecked` 版本，它们在同样的情况下 panic。
+各个领域专门的错误类型（如 `GraphError`）可以用 `?` 转换成 `AlgoError`
+*/
+
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlgoError {
+    /// 下标 `index` 超出了长度为 `len` 的范围
+    IndexOutOfBounds { index: usize, len: usize },
+    /// 操作需要至少一个元素
+    EmptyCollection,
+    /// 超过了容量 `capacity`
+    CapacityExceeded { capacity: usize },
+    /// 图中存在负权环，`node` 是环上的一个顶点
+    NegativeCycle { node: usize },
+    /// 算法要求边权非负，但 id 为 `edge` 的边权为负数
+    NegativeWeight { edge: usize, weight: i64 },
+    /// 算法要求二分图，但图中有奇环
+    NotBipartite,
+    /// 计算结果超出了数值类型的范围
+    Overflow,
+}
+
+impl fmt::Display for AlgoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlgoError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} out of bounds for length {len}")
+            }
+            AlgoError::EmptyCollection => write!(f, "collection is empty"),
+            AlgoError::CapacityExceeded { capacity } => {
+                write!(f, "capacity {capacity} exceeded")
+            }
+            AlgoError::NegativeCycle { node } => {
+                write!(f, "node {node} lies on a negative cycle")
+            }
+            AlgoError::NegativeWeight { edge, weight } => {
+                write!(f, "edge {edge} has negative weight {weight}")
+            }
+            AlgoError::NotBipartite => write!(f, "graph is not bipartite"),
+            AlgoError::Overflow => write!(f, "arithmetic overflow"),
+        }
+    }
+}
+
+impl core::error::Error for AlgoError {}
+
+#[cfg(feature = "graph")]
+impl From<crate::graph::GraphError> for AlgoError {
+    fn from(error: crate::graph::GraphError) -> Self {
+        use crate::graph::GraphError;
+        match error {
+            GraphError::NegativeWeight { edge, weight } => {
+                AlgoError::NegativeWeight { edge, weight }
+            }
+            GraphError::NegativeCycle { node } => AlgoError::NegativeCycle { node },
+            GraphError::NotBipartite { .. } => AlgoError::NotBipartite,
+            GraphError::DistanceOverflow => AlgoError::Overflow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AlgoError;
+
+    #[test]
+    fn display() {
+        let error = AlgoError::IndexOutOfBounds { index: 5, len: 3 };
+        assert_eq!(error.to_string(), "index 5 out of bounds for length 3");
+        assert_eq!(
+            AlgoError::EmptyCollection.to_string(),
+            "collection is empty"
+        );
+        let boxed: Box<dyn std::error::Error> = Box::new(AlgoError::NotBipartite);
+        assert_eq!(boxed.to_string(), "graph is not bipartite");
+    }
+
+    #[cfg(feature = "graph")]
+    #[test]
+    fn converts_graph_errors() {
+        use crate::graph::GraphError;
+
+        fn check(error: GraphError) -> Result<(), AlgoError> {
+            Err(error)?
+        }
+        assert_eq!(
+            check(GraphError::NegativeCycle { node: 2 }),
+            Err(AlgoError::NegativeCycle { node: 2 })
+        );
+        assert_eq!(
+            check(GraphError::NotBipartite {
+                odd_cycle: vec![0, 1, 2]
+            }),
+            Err(AlgoError::NotBipartite)
+        );
+    }
+}
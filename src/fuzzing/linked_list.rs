@@ -0,0 +1,87 @@
+use crate::data_structures::linked_list::LinkedList;
+use arbitrary::Arbitrary;
+use std::collections::VecDeque;
+
+/// 链表上的一个操作。下标在执行时对当前长度取模，任何操作序列都是合法的
+#[derive(Debug, Clone, Arbitrary)]
+pub enum LinkedListOp {
+    InsertHead(i32),
+    InsertTail(i32),
+    InsertAt(u8, i32),
+    DeleteHead,
+    DeleteTail,
+    DeleteAt(u8),
+    Get(u8),
+}
+
+/// 依次执行 `ops`，每一步都和 `VecDeque` 比较，最后把链表 drop 掉
+pub fn run_linked_list(ops: &[LinkedListOp]) {
+    let mut list: LinkedList<Box<i32>> = LinkedList::new();
+    let mut oracle: VecDeque<Box<i32>> = VecDeque::new();
+    for op in ops {
+        match *op {
+            LinkedListOp::InsertHead(v) => {
+                list.insert_at_head(Box::new(v));
+                oracle.push_front(Box::new(v));
+            }
+            LinkedListOp::InsertTail(v) => {
+                list.insert_at_tail(Box::new(v));
+                oracle.push_back(Box::new(v));
+            }
+            LinkedListOp::InsertAt(seed, v) => {
+                let index = seed as usize % (oracle.len() + 1);
+                list.insert_at_ith(index as u32, Box::new(v)).unwrap();
+                oracle.insert(index, Box::new(v));
+            }
+            LinkedListOp::DeleteHead => assert_eq!(list.delete_head(), oracle.pop_front()),
+            LinkedListOp::DeleteTail => assert_eq!(list.delete_tail(), oracle.pop_back()),
+            LinkedListOp::DeleteAt(seed) => {
+                if !oracle.is_empty() {
+                    let index = seed as usize % oracle.len();
+                    assert_eq!(list.delete_ith(index as u32).ok(), oracle.remove(index));
+                }
+            }
+            LinkedListOp::Get(seed) => {
+                let index = seed as usize % (oracle.len() + 1);
+                assert_eq!(list.get(index as i32), oracle.get(index));
+            }
+        }
+        assert_eq!(list.length as usize, oracle.len());
+    }
+    for (i, expected) in oracle.iter().enumerate() {
+        assert_eq!(list.get(i as i32), Some(expected));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_linked_list, LinkedListOp};
+    use crate::utils::random::Rng;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn random_byte_strings() {
+        let mut rng = Rng::new(1492);
+        for _ in 0..500 {
+            let bytes: Vec<u8> = (0..rng.gen_index(400))
+                .map(|_| rng.next_u64() as u8)
+                .collect();
+            let ops = Vec::<LinkedListOp>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            run_linked_list(&ops);
+        }
+    }
+
+    #[test]
+    fn deleting_the_tail_by_index() {
+        // 曾经的 bug：delete_ith 删除最后一个节点后 tail 仍指向已释放的节点
+        run_linked_list(&[
+            LinkedListOp::InsertTail(1),
+            LinkedListOp::InsertTail(2),
+            LinkedListOp::DeleteAt(1),
+            LinkedListOp::InsertTail(3),
+            LinkedListOp::DeleteTail,
+            LinkedListOp::DeleteTail,
+            LinkedListOp::DeleteTail,
+        ]);
+    }
+}
@@ -0,0 +1,20 @@
+/*!
+模糊测试用的操作解释器，需要开启 `arbitrary` 特性。
+模糊测试器生成任意的字节，`arbitrary` 把它们解码成一串操作，解释器把操作依次作用在被测的结构和
+标准库的 oracle 上，结果不一致时 panic。链表内部用裸指针实现，元素用 `Box` 包装，
+释放后使用、重复释放和泄漏都会被 AddressSanitizer 或 Miri 发现。
+
+`fuzz/` 目录下是 cargo-fuzz 的入口：
+
+```text
+cargo +nightly fuzz run linked_list
+cargo +nightly fuzz run sorters
+cargo +nightly miri test --features arbitrary fuzzing
+```
+*/
+
+pub mod linked_list;
+pub mod sorting;
+
+pub use self::linked_list::{run_linked_list, LinkedListOp};
+pub use self::sorting::run_sorters;
@@ -0,0 +1,56 @@
+use crate::instrumented::Counters;
+use crate::sorting::keyed::Keyed;
+use crate::sorting::{all, Sorter};
+
+/// 用注册表中的每个排序算法排序 `values`，和标准库的稳定排序比较
+pub fn run_sorters(values: &[i16]) {
+    let mut expected: Vec<(i16, usize)> = values.iter().copied().zip(0..).collect();
+    expected.sort_by_key(|&(key, _)| key);
+    for sorter in all() {
+        for counted in [false, true] {
+            // 用 `Box` 存 key，元素被复制或重复释放时 AddressSanitizer 能发现
+            let mut arr: Vec<Keyed<Box<i16>>> = values
+                .iter()
+                .enumerate()
+                .map(|(index, &key)| Keyed {
+                    key: Box::new(key),
+                    index,
+                })
+                .collect();
+            if counted {
+                sorter.sort_counted(&mut arr, &Counters::new());
+            } else {
+                sorter.sort(&mut arr);
+            }
+            let actual: Vec<(i16, usize)> = arr.iter().map(|k| (*k.key, k.index)).collect();
+            if sorter.is_stable() {
+                assert_eq!(actual, expected, "{}", sorter.name());
+            } else {
+                let keys = |v: &[(i16, usize)]| v.iter().map(|e| e.0).collect::<Vec<_>>();
+                assert_eq!(keys(&actual), keys(&expected), "{}", sorter.name());
+                let mut indices: Vec<usize> = actual.iter().map(|e| e.1).collect();
+                indices.sort_unstable();
+                assert!(indices.into_iter().eq(0..values.len()), "{}", sorter.name());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_sorters;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn random_inputs() {
+        let mut rng = Rng::new(1492);
+        for _ in 0..50 {
+            let range = rng.gen_range(1, 1000);
+            let values: Vec<i16> = (0..rng.gen_index(300))
+                .map(|_| rng.gen_range(-range, range) as i16)
+                .collect();
+            run_sorters(&values);
+        }
+        run_sorters(&[]);
+    }
+}
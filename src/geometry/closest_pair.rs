@@ -0,0 +1,215 @@
+/*!
+定义：
+[最近点对问题](https://zh.wikipedia.org/wiki/%E6%9C%80%E8%BF%91%E7%82%B9%E5%AF%B9%E9%97%AE%E9%A2%98)
+- 分治：按 x 分成左右两半分别求最近距离 d，跨越中线的点对只需要考虑离中线不超过 d 的带状区域。
+  带内的点按 y 排序后，每个点只需要和 y 相差小于 d 的后续点比较，这样的点至多常数个。
+  递归时顺便归并出按 y 排序的结果，总复杂度 O(n log n)
+- 扫描线：按 x 从左到右扫描，用有序集合按 y 维护 x 距离小于 d 的点，
+  每个新点只查询 y 在 [y - d, y + d] 内的点，同样 O(n log n)
+
+结果是 (i, j, 距离)，i < j 是输入中的下标；少于两个点时返回 None
+*/
+
+use super::point::Point2D;
+use std::collections::BTreeSet;
+
+/// O(n²) 暴力枚举
+pub fn closest_pair_brute_force(points: &[Point2D]) -> Option<(usize, usize, f64)> {
+    let mut best: Option<(usize, usize, f64)> = None;
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let distance = points[i].distance(points[j]);
+            if best.is_none_or(|(_, _, d)| distance < d) {
+                best = Some((i, j, distance));
+            }
+        }
+    }
+    best
+}
+
+/// 分治
+pub fn closest_pair(points: &[Point2D]) -> Option<(usize, usize, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mut by_x: Vec<usize> = (0..points.len()).collect();
+    by_x.sort_by(|&i, &j| points[i].lexicographic_cmp(&points[j]));
+    let mut best = (by_x[0], by_x[1], points[by_x[0]].distance(points[by_x[1]]));
+    let mut buffer = vec![0; points.len()];
+    closest_recursive(points, &mut by_x, &mut buffer, &mut best);
+    Some(ordered(best))
+}
+
+/// 求出 `indices` 内的最近点对并更新 `best`，返回时 `indices` 按 y 排好序
+fn closest_recursive(
+    points: &[Point2D],
+    indices: &mut [usize],
+    buffer: &mut [usize],
+    best: &mut (usize, usize, f64),
+) {
+    let len = indices.len();
+    if len <= 3 {
+        for a in 0..len {
+            for b in a + 1..len {
+                update(points, indices[a], indices[b], best);
+            }
+        }
+        indices.sort_by(|&i, &j| points[i].y.total_cmp(&points[j].y));
+        return;
+    }
+
+    let mid = len / 2;
+    let mid_x = points[indices[mid]].x;
+    closest_recursive(points, &mut indices[..mid], &mut buffer[..mid], best);
+    closest_recursive(points, &mut indices[mid..], &mut buffer[mid..], best);
+
+    // 归并成按 y 排序
+    let (mut i, mut j) = (0, mid);
+    for slot in buffer[..len].iter_mut() {
+        if j == len || (i < mid && points[indices[i]].y <= points[indices[j]].y) {
+            *slot = indices[i];
+            i += 1;
+        } else {
+            *slot = indices[j];
+            j += 1;
+        }
+    }
+    indices.copy_from_slice(&buffer[..len]);
+
+    // 带状区域内的点已经按 y 有序
+    let mut strip: Vec<usize> = vec![];
+    for &index in indices.iter() {
+        if (points[index].x - mid_x).abs() >= best.2 {
+            continue;
+        }
+        for &other in strip.iter().rev() {
+            if points[index].y - points[other].y >= best.2 {
+                break;
+            }
+            update(points, index, other, best);
+        }
+        strip.push(index);
+    }
+}
+
+/// 扫描线
+pub fn closest_pair_sweep(points: &[Point2D]) -> Option<(usize, usize, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mut by_x: Vec<usize> = (0..points.len()).collect();
+    by_x.sort_by(|&i, &j| points[i].lexicographic_cmp(&points[j]));
+    let mut best = (by_x[0], by_x[1], points[by_x[0]].distance(points[by_x[1]]));
+
+    // 活动点按 (y, 下标) 排序，y 映射成保序的整数才能放进 BTreeSet
+    let mut active: BTreeSet<(u64, usize)> = BTreeSet::new();
+    let mut left = 0;
+    for (k, &index) in by_x.iter().enumerate() {
+        let point = points[index];
+        while left < k && point.x - points[by_x[left]].x >= best.2 {
+            active.remove(&(order_key(points[by_x[left]].y), by_x[left]));
+            left += 1;
+        }
+        let low = (order_key(point.y - best.2), 0);
+        let high = (order_key(point.y + best.2), usize::MAX);
+        let candidates: Vec<usize> = active.range(low..=high).map(|&(_, i)| i).collect();
+        for other in candidates {
+            update(points, index, other, &mut best);
+        }
+        active.insert((order_key(point.y), index));
+    }
+    Some(ordered(best))
+}
+
+/// 把 f64 映射成大小关系相同的 u64：正数翻转符号位，负数所有位取反
+fn order_key(value: f64) -> u64 {
+    let bits = value.to_bits();
+    if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits | 1 << 63
+    }
+}
+
+fn update(points: &[Point2D], i: usize, j: usize, best: &mut (usize, usize, f64)) {
+    let distance = points[i].distance(points[j]);
+    if distance < best.2 {
+        *best = (i, j, distance);
+    }
+}
+
+fn ordered((i, j, distance): (usize, usize, f64)) -> (usize, usize, f64) {
+    (i.min(j), i.max(j), distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest_pair, closest_pair_brute_force, closest_pair_sweep, order_key};
+    use crate::geometry::Point2D;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn small_inputs() {
+        for find in [closest_pair, closest_pair_sweep, closest_pair_brute_force] {
+            assert_eq!(find(&[]), None);
+            assert_eq!(find(&[Point2D::new(1.0, 1.0)]), None);
+            let points = [
+                Point2D::new(0.0, 0.0),
+                Point2D::new(10.0, 0.0),
+                Point2D::new(3.0, 4.0),
+                Point2D::new(9.0, 1.0),
+            ];
+            let (i, j, distance) = find(&points).unwrap();
+            assert_eq!((i, j), (1, 3));
+            assert!((distance - 2f64.sqrt()).abs() < 1e-12);
+            // 重复的点距离为 0
+            let duplicated = [points[0], points[2], points[0]];
+            assert_eq!(find(&duplicated), Some((0, 2, 0.0)));
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1470);
+        for round in 0..300 {
+            let n = 2 + rng.gen_index(200);
+            let scale = if round % 2 == 0 { 1000.0 } else { 10.0 };
+            let points: Vec<Point2D> = (0..n)
+                .map(|_| {
+                    let x = (rng.next_f64() - 0.5) * scale;
+                    let y = (rng.next_f64() - 0.5) * scale;
+                    // 一半的轮次取整，制造重复和共线的点
+                    if round % 2 == 1 {
+                        Point2D::new(x.round(), y.round())
+                    } else {
+                        Point2D::new(x, y)
+                    }
+                })
+                .collect();
+            let expected = closest_pair_brute_force(&points).unwrap().2;
+            for find in [closest_pair, closest_pair_sweep] {
+                let (i, j, distance) = find(&points).unwrap();
+                assert!(i < j);
+                assert_eq!(distance, expected);
+                assert_eq!(points[i].distance(points[j]), distance);
+            }
+        }
+    }
+
+    #[test]
+    fn order_key_preserves_order() {
+        let values = [
+            f64::NEG_INFINITY,
+            -1e300,
+            -2.5,
+            -0.0,
+            0.0,
+            1e-300,
+            3.0,
+            f64::INFINITY,
+        ];
+        for pair in values.windows(2) {
+            assert!(order_key(pair[0]) <= order_key(pair[1]), "{pair:?}");
+        }
+    }
+}
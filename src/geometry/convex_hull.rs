@@ -0,0 +1,225 @@
+/*!
+定义：
+[凸包](https://zh.wikipedia.org/wiki/%E5%87%B8%E5%8C%85)
+包含所有点的最小凸多边形。两种 O(n log n) 的算法都用栈维护当前的凸链，
+新点让链在栈顶处向右拐（叉积 < 0）时弹出栈顶：
+- [Graham 扫描](https://zh.wikipedia.org/wiki/%E8%91%9B%E7%AB%8B%E6%81%86%E6%8E%83%E6%8F%8F%E6%B3%95)：
+  以最下方的点为极点，其余点按极角排序后依次加入
+- [Andrew 单调链](https://en.wikibooks.org/wiki/Algorithm_Implementation/Geometry/Convex_hull/Monotone_chain)：
+  按 x 排序，分别求下凸链和上凸链再拼起来，只需要叉积不需要极角
+
+`keep_collinear` 为 true 时保留凸包边上的共线点。结果按逆时针排列，从 x 最小（相同时 y 最小）的点开始，重复的点只出现一次
+*/
+
+use super::point::Point2D;
+use std::cmp::Ordering;
+
+/// Graham 扫描
+pub fn graham_scan(points: &[Point2D], keep_collinear: bool) -> Vec<Point2D> {
+    let mut points = sorted_unique(points);
+    if let Some(hull) = degenerate_hull(&points, keep_collinear) {
+        return hull;
+    }
+    // 极点取 y 最小、相同时 x 最小的点，其余点的极角都在 [0, π) 内
+    let pivot_index = (0..points.len())
+        .min_by(|&i, &j| {
+            let (a, b) = (points[i], points[j]);
+            a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))
+        })
+        .unwrap();
+    let pivot = points.swap_remove(pivot_index);
+    points.sort_by(|&a, &b| {
+        // 叉积 > 0 说明 a 的极角更小；共线时近的在前
+        let cross = (a - pivot).cross(b - pivot);
+        if cross > 0.0 {
+            Ordering::Less
+        } else if cross < 0.0 {
+            Ordering::Greater
+        } else {
+            pivot.distance(a).total_cmp(&pivot.distance(b))
+        }
+    });
+    if keep_collinear {
+        // 最后一条射线上的点要从远到近访问，才能沿着凸包的边回到极点
+        let last = points[points.len() - 1];
+        let start = points
+            .iter()
+            .rposition(|&p| (p - pivot).cross(last - pivot) != 0.0)
+            .map_or(0, |i| i + 1);
+        points[start..].reverse();
+    }
+
+    let mut hull = vec![pivot];
+    for point in points {
+        pop_right_turns(&mut hull, point, keep_collinear);
+        hull.push(point);
+    }
+    rotate_to_leftmost(&mut hull);
+    hull
+}
+
+/// Andrew 单调链
+pub fn monotone_chain(points: &[Point2D], keep_collinear: bool) -> Vec<Point2D> {
+    let points = sorted_unique(points);
+    if let Some(hull) = degenerate_hull(&points, keep_collinear) {
+        return hull;
+    }
+    // 从左往右求下凸链，再从右往左求上凸链，两条链的端点是共用的
+    let mut hull: Vec<Point2D> = vec![];
+    for &point in &points {
+        pop_right_turns(&mut hull, point, keep_collinear);
+        hull.push(point);
+    }
+    let lower_len = hull.len();
+    for &point in points.iter().rev().skip(1) {
+        while hull.len() > lower_len {
+            let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+            let cross = (b - a).cross(point - b);
+            if cross < 0.0 || (cross == 0.0 && !keep_collinear) {
+                hull.pop();
+            } else {
+                break;
+            }
+        }
+        hull.push(point);
+    }
+    // 最后一个点是起点
+    hull.pop();
+    hull
+}
+
+fn sorted_unique(points: &[Point2D]) -> Vec<Point2D> {
+    let mut points = points.to_vec();
+    points.sort_by(Point2D::lexicographic_cmp);
+    points.dedup();
+    points
+}
+
+/// 点数不超过 2 或所有点共线时直接给出结果，`points` 已经排好序并去重
+fn degenerate_hull(points: &[Point2D], keep_collinear: bool) -> Option<Vec<Point2D>> {
+    let (Some(&first), Some(&last)) = (points.first(), points.last()) else {
+        return Some(vec![]);
+    };
+    let collinear = points
+        .iter()
+        .all(|&p| (last - first).cross(p - first) == 0.0);
+    if !collinear {
+        return None;
+    }
+    Some(if keep_collinear || points.len() <= 2 {
+        points.to_vec()
+    } else {
+        vec![first, last]
+    })
+}
+
+/// 在栈顶处向右拐（不保留共线点时也包括直行）就弹出
+fn pop_right_turns(chain: &mut Vec<Point2D>, point: Point2D, keep_collinear: bool) {
+    while chain.len() >= 2 {
+        let (a, b) = (chain[chain.len() - 2], chain[chain.len() - 1]);
+        let cross = (b - a).cross(point - b);
+        if cross < 0.0 || (cross == 0.0 && !keep_collinear) {
+            chain.pop();
+        } else {
+            break;
+        }
+    }
+}
+
+fn rotate_to_leftmost(hull: &mut [Point2D]) {
+    let start = (0..hull.len())
+        .min_by(|&i, &j| hull[i].lexicographic_cmp(&hull[j]))
+        .unwrap_or(0);
+    hull.rotate_left(start);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{graham_scan, monotone_chain};
+    use crate::geometry::{polygon_area, polygon_perimeter, Point2D};
+    use crate::utils::random::Rng;
+
+    fn points(coordinates: &[(f64, f64)]) -> Vec<Point2D> {
+        coordinates.iter().map(|&p| Point2D::from(p)).collect()
+    }
+
+    #[test]
+    fn square_with_interior_and_edge_points() {
+        let input = points(&[
+            (0.0, 0.0),
+            (2.0, 2.0),
+            (1.0, 1.0),
+            (2.0, 0.0),
+            (0.0, 2.0),
+            (1.0, 0.0),
+            (2.0, 1.0),
+            (0.0, 0.0),
+        ]);
+        let corners = points(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+        let with_edges = points(&[
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (2.0, 2.0),
+            (0.0, 2.0),
+        ]);
+        for hull in [graham_scan, monotone_chain] {
+            assert_eq!(hull(&input, false), corners);
+            assert_eq!(hull(&input, true), with_edges);
+        }
+        assert_eq!(polygon_area(&corners), 4.0);
+        assert_eq!(polygon_perimeter(&corners), 8.0);
+        // 顺时针时面积为负
+        let clockwise: Vec<Point2D> = corners.iter().rev().copied().collect();
+        assert_eq!(polygon_area(&clockwise), -4.0);
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        for hull in [graham_scan, monotone_chain] {
+            assert_eq!(hull(&[], false), vec![]);
+            let single = points(&[(1.0, 1.0), (1.0, 1.0)]);
+            assert_eq!(hull(&single, true), points(&[(1.0, 1.0)]));
+            let line = points(&[(2.0, 2.0), (0.0, 0.0), (1.0, 1.0), (3.0, 3.0)]);
+            assert_eq!(hull(&line, false), points(&[(0.0, 0.0), (3.0, 3.0)]));
+            assert_eq!(hull(&line, true).len(), 4);
+        }
+        assert_eq!(polygon_area(&points(&[(0.0, 0.0), (1.0, 1.0)])), 0.0);
+    }
+
+    #[test]
+    fn random_point_sets() {
+        let mut rng = Rng::new(1468);
+        for _ in 0..300 {
+            let n = 1 + rng.gen_index(40);
+            // 小范围的整数坐标，有很多共线和重复的点
+            let input: Vec<Point2D> = (0..n)
+                .map(|_| Point2D::new(rng.gen_range(0, 8) as f64, rng.gen_range(0, 8) as f64))
+                .collect();
+            for keep_collinear in [false, true] {
+                let hull = monotone_chain(&input, keep_collinear);
+                assert_eq!(graham_scan(&input, keep_collinear), hull, "{input:?}");
+                if hull.len() < 3 {
+                    continue;
+                }
+                for i in 0..hull.len() {
+                    let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+                    // 所有点都在每条边的左侧或边上，即凸包按逆时针排列且包含所有点
+                    assert!(input.iter().all(|&p| (b - a).cross(p - a) >= 0.0));
+                    let c = hull[(i + 2) % hull.len()];
+                    let cross = (b - a).cross(c - b);
+                    assert!(if keep_collinear {
+                        cross >= 0.0
+                    } else {
+                        cross > 0.0
+                    });
+                }
+            }
+            let strict = monotone_chain(&input, false);
+            let with_collinear = monotone_chain(&input, true);
+            assert_eq!(polygon_area(&strict), polygon_area(&with_collinear));
+            assert!((polygon_perimeter(&strict) - polygon_perimeter(&with_collinear)).abs() < 1e-9);
+        }
+    }
+}
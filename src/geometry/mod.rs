@@ -0,0 +1,21 @@
+pub mod closest_pair;
+pub mod convex_hull;
+pub mod point;
+pub mod polygon;
+pub mod rectangle_union;
+pub mod rotating_calipers;
+pub mod segment;
+
+pub use self::closest_pair::{closest_pair, closest_pair_brute_force, closest_pair_sweep};
+pub use self::convex_hull::{graham_scan, monotone_chain};
+pub use self::point::Point2D;
+pub use self::polygon::{
+    point_in_polygon, point_in_polygon_winding, polygon_area, polygon_centroid, polygon_perimeter,
+    winding_number, Location,
+};
+pub use self::rectangle_union::rectangle_union_area;
+pub use self::rotating_calipers::{diameter, min_area_rectangle, width, Rectangle};
+pub use self::segment::{
+    all_intersections, ccw, orientation, segment_intersection, segments_intersect, Intersection,
+    Orientation, Segment,
+};
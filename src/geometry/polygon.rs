@@ -0,0 +1,213 @@
+/*!
+定义：
+[简单多边形](https://zh.wikipedia.org/wiki/%E7%B0%A1%E5%96%AE%E5%A4%9A%E9%82%8A%E5%BD%A2)
+顶点按顺序给出，最后一个顶点和第一个相连，边不自交，可以是凹的。
+- [鞋带公式](https://zh.wikipedia.org/wiki/%E9%9E%8B%E5%B8%B6%E5%85%AC%E5%BC%8F)：有向面积 A = Σ(pᵢ × pᵢ₊₁) / 2，
+  重心 C = Σ(pᵢ + pᵢ₊₁)(pᵢ × pᵢ₊₁) / 6A
+- [点在多边形内](https://zh.wikipedia.org/wiki/%E7%82%B9%E5%9C%A8%E5%A4%9A%E8%BE%B9%E5%BD%A2%E5%86%85%E9%83%A8)：
+  射线法从点向右发出射线，穿过边界奇数次则在内部；
+  环绕数法统计边界绕这个点转了多少圈，非零则在内部，对自交的多边形也有意义。
+  两种方法都先判断点是否在某条边上
+*/
+
+use super::point::Point2D;
+use super::segment::Segment;
+
+/// 点和多边形的位置关系
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Outside,
+    Boundary,
+}
+
+/// 多边形的有向面积，顶点逆时针排列时为正，顺时针时为负
+pub fn polygon_area(polygon: &[Point2D]) -> f64 {
+    edges(polygon).map(|(a, b)| a.cross(b)).sum::<f64>() / 2.0
+}
+
+/// 多边形的周长
+pub fn polygon_perimeter(polygon: &[Point2D]) -> f64 {
+    edges(polygon).map(|(a, b)| a.distance(b)).sum()
+}
+
+/// 多边形（看作均匀薄片）的重心，面积为 0 时返回 None
+pub fn polygon_centroid(polygon: &[Point2D]) -> Option<Point2D> {
+    let area = polygon_area(polygon);
+    if area == 0.0 {
+        return None;
+    }
+    let sum = edges(polygon).fold(Point2D::default(), |sum, (a, b)| sum + (a + b) * a.cross(b));
+    Some(sum * (1.0 / (6.0 * area)))
+}
+
+/// 射线法
+pub fn point_in_polygon(point: Point2D, polygon: &[Point2D]) -> Location {
+    if on_boundary(point, polygon) {
+        return Location::Boundary;
+    }
+    let mut inside = false;
+    for (a, b) in edges(polygon) {
+        // 边跨过水平射线所在的直线（下端点算、上端点不算，避免经过顶点时重复计数）
+        if (a.y > point.y) != (b.y > point.y) {
+            // 交点在点的右侧：向上的边要求点在边的左侧，向下的边要求在右侧
+            let cross = (b - a).cross(point - a);
+            if (cross > 0.0) == (b.y > a.y) {
+                inside = !inside;
+            }
+        }
+    }
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// 环绕数：边界绕点逆时针转的圈数
+pub fn winding_number(point: Point2D, polygon: &[Point2D]) -> i32 {
+    let mut winding = 0;
+    for (a, b) in edges(polygon) {
+        let cross = (b - a).cross(point - a);
+        if a.y <= point.y {
+            // 向上穿过射线，且点在边的左侧
+            if b.y > point.y && cross > 0.0 {
+                winding += 1;
+            }
+        } else if b.y <= point.y && cross < 0.0 {
+            // 向下穿过射线，且点在边的右侧
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// 环绕数法
+pub fn point_in_polygon_winding(point: Point2D, polygon: &[Point2D]) -> Location {
+    if on_boundary(point, polygon) {
+        Location::Boundary
+    } else if winding_number(point, polygon) != 0 {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+fn on_boundary(point: Point2D, polygon: &[Point2D]) -> bool {
+    edges(polygon).any(|(a, b)| Segment::new(a, b).contains(point))
+}
+
+/// 首尾相连的所有边
+fn edges(polygon: &[Point2D]) -> impl Iterator<Item = (Point2D, Point2D)> + '_ {
+    let next = polygon.iter().cycle().skip(1);
+    polygon.iter().copied().zip(next.copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::Rng;
+
+    fn polygon(coordinates: &[(f64, f64)]) -> Vec<Point2D> {
+        coordinates.iter().map(|&p| Point2D::from(p)).collect()
+    }
+
+    #[test]
+    fn area_and_centroid() {
+        let square = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+        assert_eq!(polygon_area(&square), 4.0);
+        assert_eq!(polygon_perimeter(&square), 8.0);
+        assert_eq!(polygon_centroid(&square), Some(Point2D::new(1.0, 1.0)));
+        // L 形：两个矩形的重心按面积加权
+        let l_shape = polygon(&[
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 3.0),
+            (0.0, 3.0),
+        ]);
+        assert_eq!(polygon_area(&l_shape), 6.0);
+        let centroid = polygon_centroid(&l_shape).unwrap();
+        let expected = Point2D::new((4.0 * 2.0 + 2.0 * 0.5) / 6.0, (4.0 * 0.5 + 2.0 * 2.0) / 6.0);
+        assert!(centroid.distance(expected) < 1e-12);
+        // 顺时针排列时面积为负，重心不变
+        let reversed: Vec<Point2D> = l_shape.iter().rev().copied().collect();
+        assert_eq!(polygon_area(&reversed), -6.0);
+        assert!(polygon_centroid(&reversed).unwrap().distance(expected) < 1e-12);
+        assert_eq!(polygon_centroid(&polygon(&[(0.0, 0.0), (1.0, 1.0)])), None);
+        assert_eq!(polygon_area(&[]), 0.0);
+    }
+
+    #[test]
+    fn concave_polygon() {
+        // U 形，中间的缺口在外部
+        let u_shape = polygon(&[
+            (0.0, 0.0),
+            (3.0, 0.0),
+            (3.0, 3.0),
+            (2.0, 3.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 3.0),
+            (0.0, 3.0),
+        ]);
+        let cases = [
+            ((0.5, 2.0), Location::Inside),
+            ((1.5, 2.0), Location::Outside),
+            ((1.5, 0.5), Location::Inside),
+            ((1.5, 1.0), Location::Boundary),
+            ((3.0, 1.5), Location::Boundary),
+            ((0.0, 0.0), Location::Boundary),
+            // 射线经过顶点 (2, 3)、(3, 3) 所在的水平边
+            ((-1.0, 3.0), Location::Outside),
+            // 射线经过凹顶点 (1, 1)、(2, 1)
+            ((0.5, 1.0), Location::Inside),
+            ((4.0, 1.0), Location::Outside),
+        ];
+        for ((x, y), expected) in cases {
+            let point = Point2D::new(x, y);
+            assert_eq!(point_in_polygon(point, &u_shape), expected, "({x}, {y})");
+            assert_eq!(
+                point_in_polygon_winding(point, &u_shape),
+                expected,
+                "({x}, {y})"
+            );
+        }
+        assert_eq!(winding_number(Point2D::new(0.5, 2.0), &u_shape), 1);
+        let reversed: Vec<Point2D> = u_shape.iter().rev().copied().collect();
+        assert_eq!(winding_number(Point2D::new(0.5, 2.0), &reversed), -1);
+    }
+
+    #[test]
+    fn methods_agree_on_random_star_polygons() {
+        let mut rng = Rng::new(1471);
+        for _ in 0..100 {
+            // 按极角排列、相邻极角相差小于 π 的点构成关于原点的星形多边形，一定是简单多边形
+            let n = 4 + rng.gen_index(12);
+            let angles: Vec<f64> = (0..n)
+                .map(|i| (i as f64 + rng.next_f64() * 0.9) / n as f64 * std::f64::consts::TAU)
+                .collect();
+            let shape: Vec<Point2D> = angles
+                .iter()
+                .map(|&angle| {
+                    let radius = 1.0 + rng.next_f64() * 9.0;
+                    Point2D::new(radius * angle.cos(), radius * angle.sin())
+                })
+                .collect();
+            let mut inside = 0;
+            for _ in 0..200 {
+                let point =
+                    Point2D::new(rng.next_f64() * 24.0 - 12.0, rng.next_f64() * 24.0 - 12.0);
+                let location = point_in_polygon(point, &shape);
+                assert_eq!(point_in_polygon_winding(point, &shape), location);
+                inside += (location == Location::Inside) as usize;
+            }
+            // 随机点落在内部的比例近似面积之比
+            let ratio = inside as f64 / 200.0;
+            let expected = polygon_area(&shape) / (24.0 * 24.0);
+            assert!(polygon_area(&shape) > 0.0);
+            assert!((ratio - expected).abs() < 0.15, "{ratio} {expected}");
+        }
+    }
+}
@@ -0,0 +1,107 @@
+/*!
+定义：
+[矩形并的面积](https://en.wikipedia.org/wiki/Klee%27s_measure_problem)（Klee 测度问题的二维情形）
+把所有矩形的左右边按 x 排序，竖直的扫描线从左往右移动。扫描线上被覆盖的总长度只在经过某条边时改变，
+相邻两条边之间的面积就是覆盖长度乘以 x 的差。
+矩形的上下边把 y 轴切成若干基本段，用统计覆盖长度的线段树维护每个基本段被几个矩形覆盖，
+遇到左边时覆盖次数加一，右边时减一，O(n log n)
+*/
+
+use super::point::Point2D;
+use crate::data_structures::segment_tree::CoverageSegmentTree;
+
+/// 轴对齐矩形的并的面积，矩形用两个对角顶点表示，顺序任意
+pub fn rectangle_union_area(rectangles: &[(Point2D, Point2D)]) -> f64 {
+    let mut ys: Vec<f64> = rectangles.iter().flat_map(|(a, b)| [a.y, b.y]).collect();
+    ys.sort_by(f64::total_cmp);
+    ys.dedup();
+    if ys.len() < 2 {
+        return 0.0;
+    }
+    let lengths: Vec<f64> = ys.windows(2).map(|w| w[1] - w[0]).collect();
+    let mut tree = CoverageSegmentTree::new(&lengths);
+
+    // (x, 覆盖次数的变化, 基本段的范围)
+    let mut events = vec![];
+    for (a, b) in rectangles {
+        let (left, right) = (a.x.min(b.x), a.x.max(b.x));
+        let (bottom, top) = (a.y.min(b.y), a.y.max(b.y));
+        if left == right || bottom == top {
+            continue;
+        }
+        let low = ys.partition_point(|&y| y < bottom);
+        let high = ys.partition_point(|&y| y < top);
+        events.push((left, 1, low..high));
+        events.push((right, -1, low..high));
+    }
+    events.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut area = 0.0;
+    let mut previous_x = events.first().map_or(0.0, |event| event.0);
+    for (x, delta, range) in events {
+        area += tree.covered_length() * (x - previous_x);
+        tree.add(range, delta);
+        previous_x = x;
+    }
+    area
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rectangle_union_area;
+    use crate::geometry::Point2D;
+    use crate::utils::random::Rng;
+
+    fn rectangle(x1: f64, y1: f64, x2: f64, y2: f64) -> (Point2D, Point2D) {
+        (Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    #[test]
+    fn overlapping_rectangles() {
+        assert_eq!(rectangle_union_area(&[]), 0.0);
+        assert_eq!(rectangle_union_area(&[rectangle(0.0, 0.0, 2.0, 3.0)]), 6.0);
+        // 两个 2×2 的正方形重叠 1×1
+        let overlapping = [rectangle(0.0, 0.0, 2.0, 2.0), rectangle(3.0, 3.0, 1.0, 1.0)];
+        assert_eq!(rectangle_union_area(&overlapping), 7.0);
+        // 完全包含、重复、退化的矩形
+        let nested = [
+            rectangle(0.0, 0.0, 10.0, 10.0),
+            rectangle(2.0, 2.0, 5.0, 5.0),
+            rectangle(0.0, 0.0, 10.0, 10.0),
+            rectangle(20.0, 0.0, 20.0, 5.0),
+        ];
+        assert_eq!(rectangle_union_area(&nested), 100.0);
+        // 十字形
+        let cross = [rectangle(0.0, 1.0, 3.0, 2.0), rectangle(1.0, 0.0, 2.0, 3.0)];
+        assert_eq!(rectangle_union_area(&cross), 5.0);
+    }
+
+    #[test]
+    fn matches_grid_count() {
+        let mut rng = Rng::new(1473);
+        for _ in 0..100 {
+            let n = rng.gen_index(15);
+            let rectangles: Vec<(Point2D, Point2D)> = (0..n)
+                .map(|_| {
+                    let mut coordinate = || rng.gen_range(0, 20) as f64;
+                    rectangle(coordinate(), coordinate(), coordinate(), coordinate())
+                })
+                .collect();
+            // 整数坐标时逐个单位格子检查是否被覆盖
+            let mut expected = 0;
+            for x in 0..20 {
+                for y in 0..20 {
+                    let (cx, cy) = (x as f64 + 0.5, y as f64 + 0.5);
+                    let covered = rectangles.iter().any(|(a, b)| {
+                        a.x.min(b.x) < cx
+                            && cx < a.x.max(b.x)
+                            && a.y.min(b.y) < cy
+                            && cy < a.y.max(b.y)
+                    });
+                    expected += covered as u32;
+                }
+            }
+            assert_eq!(rectangle_union_area(&rectangles), expected as f64);
+        }
+    }
+}
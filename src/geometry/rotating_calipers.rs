@@ -0,0 +1,234 @@
+/*!
+定义：
+[旋转卡壳](https://en.wikipedia.org/wiki/Rotating_calipers)
+先求凸包，再让一组平行的支撑线绕凸包转一圈。对每条凸包的边，对面和它距离最远的顶点（对踵点）
+随着边的转动单调前进，所以各个指针一共只走 O(n) 步：
+- 直径：最远点对一定是某一对对踵点
+- 宽度：两条平行支撑线的最小距离，其中一条一定和凸包的某条边重合
+- 最小面积外接矩形：矩形的某条边一定和凸包的某条边重合，对每条边再找出沿边方向最左、最右的顶点
+*/
+
+use super::convex_hull::monotone_chain;
+use super::point::Point2D;
+
+/// 外接矩形，顶点按逆时针排列
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    pub corners: [Point2D; 4],
+    pub area: f64,
+}
+
+/// 距离最远的两个点以及它们的距离，没有点时返回 None
+pub fn diameter(points: &[Point2D]) -> Option<(Point2D, Point2D, f64)> {
+    let hull = monotone_chain(points, false);
+    match hull.len() {
+        0 => return None,
+        1 => return Some((hull[0], hull[0], 0.0)),
+        _ => {}
+    }
+    let n = hull.len();
+    let mut best = (hull[0], hull[1], hull[0].distance(hull[1]));
+    let mut j = 1;
+    for i in 0..n {
+        let (a, b) = (hull[i], hull[(i + 1) % n]);
+        // 离边 ab 最远的顶点：三角形面积不再增大时停下
+        while (b - a).cross(hull[(j + 1) % n] - a) > (b - a).cross(hull[j] - a) {
+            j = (j + 1) % n;
+        }
+        for p in [a, b] {
+            let distance = p.distance(hull[j]);
+            if distance > best.2 {
+                best = (p, hull[j], distance);
+            }
+        }
+    }
+    Some(best)
+}
+
+/// 宽度：夹住所有点的两条平行线的最小距离，没有点时返回 None
+pub fn width(points: &[Point2D]) -> Option<f64> {
+    let hull = monotone_chain(points, false);
+    if hull.len() < 3 {
+        return (!hull.is_empty()).then_some(0.0);
+    }
+    let n = hull.len();
+    let mut best = f64::INFINITY;
+    let mut j = 1;
+    for i in 0..n {
+        let (a, b) = (hull[i], hull[(i + 1) % n]);
+        while (b - a).cross(hull[(j + 1) % n] - a) > (b - a).cross(hull[j] - a) {
+            j = (j + 1) % n;
+        }
+        best = best.min((b - a).cross(hull[j] - a) / a.distance(b));
+    }
+    Some(best)
+}
+
+/// 面积最小的外接矩形（可以是斜的），没有点时返回 None
+pub fn min_area_rectangle(points: &[Point2D]) -> Option<Rectangle> {
+    let hull = monotone_chain(points, false);
+    if hull.len() < 3 {
+        // 点或线段：矩形退化，面积为 0
+        let (&first, &last) = (hull.first()?, hull.last()?);
+        return Some(Rectangle {
+            corners: [first, last, last, first],
+            area: 0.0,
+        });
+    }
+    let n = hull.len();
+    let mut best: Option<Rectangle> = None;
+    // right：沿边方向最远，top：离边最远，left：沿边方向最近
+    let (mut right, mut top, mut left) = (1, 1, 1);
+    for i in 0..n {
+        let (a, b) = (hull[i], hull[(i + 1) % n]);
+        let direction = (b - a) * (1.0 / a.distance(b));
+        let normal = Point2D::new(-direction.y, direction.x);
+        let along = |k: usize| direction.dot(hull[k] - a);
+        let height = |k: usize| normal.dot(hull[k] - a);
+
+        while along((right + 1) % n) > along(right) {
+            right = (right + 1) % n;
+        }
+        if i == 0 {
+            top = right;
+        }
+        while height((top + 1) % n) > height(top) {
+            top = (top + 1) % n;
+        }
+        if i == 0 {
+            left = top;
+        }
+        while along((left + 1) % n) < along(left) {
+            left = (left + 1) % n;
+        }
+
+        let (low, high, h) = (along(left), along(right), height(top));
+        let area = (high - low) * h;
+        if best.is_none_or(|rectangle| area < rectangle.area) {
+            let corners = [
+                a + direction * low,
+                a + direction * high,
+                a + direction * high + normal * h,
+                a + direction * low + normal * h,
+            ];
+            best = Some(Rectangle { corners, area });
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diameter, min_area_rectangle, width};
+    use crate::geometry::{convex_hull::monotone_chain, polygon_area, Point2D};
+    use crate::utils::random::Rng;
+
+    fn brute_force_diameter(points: &[Point2D]) -> f64 {
+        let mut best = 0.0f64;
+        for &p in points {
+            for &q in points {
+                best = best.max(p.distance(q));
+            }
+        }
+        best
+    }
+
+    /// 对每个方向分别投影所有点，得到 (宽度, 最小外接矩形面积)
+    fn brute_force_width_and_area(points: &[Point2D]) -> (f64, f64) {
+        let hull = monotone_chain(points, false);
+        let (mut width, mut area) = (f64::INFINITY, f64::INFINITY);
+        for i in 0..hull.len() {
+            let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+            let direction = (b - a) * (1.0 / a.distance(b));
+            let normal = Point2D::new(-direction.y, direction.x);
+            let project = |axis: Point2D| {
+                let values = points.iter().map(|&p| axis.dot(p - a));
+                let (low, high) = values
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), v| {
+                        (low.min(v), high.max(v))
+                    });
+                high - low
+            };
+            width = width.min(project(normal));
+            area = area.min(project(normal) * project(direction));
+        }
+        (width, area)
+    }
+
+    #[test]
+    fn known_shapes() {
+        let square = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(1.0, 1.0),
+            Point2D::new(0.0, 1.0),
+            Point2D::new(0.5, 0.5),
+        ];
+        let (_, _, d) = diameter(&square).unwrap();
+        assert!((d - 2f64.sqrt()).abs() < 1e-12);
+        assert!((width(&square).unwrap() - 1.0).abs() < 1e-12);
+        assert!((min_area_rectangle(&square).unwrap().area - 1.0).abs() < 1e-12);
+
+        // 斜放的 4×1 长方形，最小外接矩形就是它自己
+        let tilted = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 4.0),
+            Point2D::new(3.0, 5.0),
+            Point2D::new(-1.0, 1.0),
+        ];
+        let rectangle = min_area_rectangle(&tilted).unwrap();
+        assert!((rectangle.area - 8.0).abs() < 1e-9);
+        assert!((polygon_area(&rectangle.corners) - 8.0).abs() < 1e-9);
+        assert!((width(&tilted).unwrap() - 2f64.sqrt()).abs() < 1e-12);
+
+        assert_eq!(diameter(&[]), None);
+        assert_eq!(width(&[]), None);
+        assert_eq!(min_area_rectangle(&[]), None);
+        let segment = [
+            Point2D::new(0.0, 0.0),
+            Point2D::new(3.0, 4.0),
+            Point2D::new(1.5, 2.0),
+        ];
+        assert_eq!(diameter(&segment).unwrap().2, 5.0);
+        assert_eq!(width(&segment), Some(0.0));
+        assert_eq!(min_area_rectangle(&segment).unwrap().area, 0.0);
+        assert_eq!(diameter(&segment[..1]).unwrap().2, 0.0);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1472);
+        for round in 0..300 {
+            let n = 3 + rng.gen_index(40);
+            let points: Vec<Point2D> = (0..n)
+                .map(|_| {
+                    let (x, y) = (rng.next_f64() * 20.0 - 10.0, rng.next_f64() * 20.0 - 10.0);
+                    if round % 2 == 0 {
+                        Point2D::new(x.round(), y.round())
+                    } else {
+                        Point2D::new(x, y)
+                    }
+                })
+                .collect();
+            if monotone_chain(&points, false).len() < 3 {
+                continue;
+            }
+            let (p, q, d) = diameter(&points).unwrap();
+            assert_eq!(p.distance(q), d);
+            assert!((d - brute_force_diameter(&points)).abs() < 1e-9);
+
+            let (expected_width, expected_area) = brute_force_width_and_area(&points);
+            assert!((width(&points).unwrap() - expected_width).abs() < 1e-9);
+            let rectangle = min_area_rectangle(&points).unwrap();
+            assert!((rectangle.area - expected_area).abs() < 1e-9);
+            assert!((polygon_area(&rectangle.corners) - rectangle.area).abs() < 1e-6);
+            // 所有点都在矩形内
+            for &point in &points {
+                for i in 0..4 {
+                    let (a, b) = (rectangle.corners[i], rectangle.corners[(i + 1) % 4]);
+                    assert!((b - a).cross(point - a) >= -1e-9);
+                }
+            }
+        }
+    }
+}
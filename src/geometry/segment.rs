@@ -0,0 +1,402 @@
+/*!
+定义：
+[线段相交](https://en.wikipedia.org/wiki/Line_segment_intersection)
+- 转向：c 相对有向线段 a→b 的位置由叉积 (b - a) × (c - a) 的符号决定
+- 两条线段相交，当且仅当每条线段的两个端点分别位于另一条线段所在直线的两侧（或在直线上且落在线段内）
+- 求所有相交的线段对：[Bentley–Ottmann 算法](https://en.wikipedia.org/wiki/Bentley%E2%80%93Ottmann_algorithm)。
+  扫描线按 (x, y) 的字典序停在事件点上，事件是所有端点和扫描中发现的交点；状态是和扫描线相交的线段，
+  按它们在扫描线上的 y 排序。两条线段在第一个公共点之前一定会在状态中相邻，所以只在插入、删除和交换位置后
+  检查新相邻的线段，把之后的交点加入事件队列；经过同一个事件点的线段两两相交，在这个事件上一起报告。
+  事件队列是堆，状态是有序的 Vec，用二分查找定位经过事件点的那一段。有 k 个交点时共 O(n + k) 个事件，
+  每个事件的查找是 O(log n)（Vec 的插入删除要移动元素，换成平衡树才是严格的 O((n + k) log n)）；
+  很多线段交于同一点时相交对可以有 Θ(n²) 个，输出本身就有这么大
+*/
+
+use super::point::Point2D;
+use crate::data_structures::heap::Heap;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    Collinear,
+    CounterClockwise,
+}
+
+/// a → b → c 的转向
+pub fn orientation(a: Point2D, b: Point2D, c: Point2D) -> Orientation {
+    let cross = (b - a).cross(c - a);
+    if cross > 0.0 {
+        Orientation::CounterClockwise
+    } else if cross < 0.0 {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+/// a → b → c 是否严格逆时针（向左拐）
+pub fn ccw(a: Point2D, b: Point2D, c: Point2D) -> bool {
+    orientation(a, b, c) == Orientation::CounterClockwise
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment {
+    pub start: Point2D,
+    pub end: Point2D,
+}
+
+impl Segment {
+    pub fn new(start: Point2D, end: Point2D) -> Self {
+        Segment { start, end }
+    }
+
+    /// 在 p 与线段共线的前提下，判断 p 是否落在线段上
+    fn contains_collinear(&self, p: Point2D) -> bool {
+        p.x >= self.start.x.min(self.end.x)
+            && p.x <= self.start.x.max(self.end.x)
+            && p.y >= self.start.y.min(self.end.y)
+            && p.y <= self.start.y.max(self.end.y)
+    }
+
+    pub fn contains(&self, p: Point2D) -> bool {
+        orientation(self.start, self.end, p) == Orientation::Collinear && self.contains_collinear(p)
+    }
+
+    /// 按字典序排好的两个端点，竖直线段是从下往上
+    fn ordered_endpoints(&self) -> (Point2D, Point2D) {
+        if self.start.lexicographic_cmp(&self.end) == Ordering::Greater {
+            (self.end, self.start)
+        } else {
+            (self.start, self.end)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Intersection {
+    None,
+    /// 规范相交：交点在两条线段的内部
+    Proper(Point2D),
+    /// 非规范相交：交点是某条线段的端点
+    Touching(Point2D),
+    /// 共线且重叠的部分
+    Overlap(Segment),
+}
+
+/// 两条线段的交
+pub fn segment_intersection(s: &Segment, t: &Segment) -> Intersection {
+    let d1 = orientation(t.start, t.end, s.start);
+    let d2 = orientation(t.start, t.end, s.end);
+    let d3 = orientation(s.start, s.end, t.start);
+    let d4 = orientation(s.start, s.end, t.end);
+
+    if [d1, d2, d3, d4]
+        .iter()
+        .all(|&d| d == Orientation::Collinear)
+    {
+        return collinear_overlap(s, t);
+    }
+    let opposite = |a: Orientation, b: Orientation| {
+        a != b && a != Orientation::Collinear && b != Orientation::Collinear
+    };
+    if opposite(d1, d2) && opposite(d3, d4) {
+        // s.start + r·u 落在 t 上，u = (t.start - s.start) × dt / (ds × dt)
+        let (ds, dt) = (s.end - s.start, t.end - t.start);
+        let u = (t.start - s.start).cross(dt) / ds.cross(dt);
+        return Intersection::Proper(s.start + ds * u);
+    }
+    // 有端点落在另一条线段上
+    let candidates = [
+        (d1, s.start, t),
+        (d2, s.end, t),
+        (d3, t.start, s),
+        (d4, t.end, s),
+    ];
+    candidates
+        .into_iter()
+        .find(|&(d, p, other)| d == Orientation::Collinear && other.contains_collinear(p))
+        .map_or(Intersection::None, |(_, p, _)| Intersection::Touching(p))
+}
+
+/// 两条线段是否有公共点
+pub fn segments_intersect(s: &Segment, t: &Segment) -> bool {
+    segment_intersection(s, t) != Intersection::None
+}
+
+/// 共线的两条线段：按字典序排好端点后取两个区间的交
+fn collinear_overlap(s: &Segment, t: &Segment) -> Intersection {
+    let ((s_low, s_high), (t_low, t_high)) = (s.ordered_endpoints(), t.ordered_endpoints());
+    let low = max_by_lexicographic(s_low, t_low);
+    let high = min_by_lexicographic(s_high, t_high);
+    // 四个点都共线时 s 也可能退化成一个点，和 t 不在同一条直线上
+    if !s.contains(low) || !t.contains(low) {
+        return Intersection::None;
+    }
+    match low.lexicographic_cmp(&high) {
+        Ordering::Greater => Intersection::None,
+        Ordering::Equal => Intersection::Touching(low),
+        Ordering::Less => Intersection::Overlap(Segment::new(low, high)),
+    }
+}
+
+fn max_by_lexicographic(a: Point2D, b: Point2D) -> Point2D {
+    if a.lexicographic_cmp(&b) == Ordering::Less {
+        b
+    } else {
+        a
+    }
+}
+
+fn min_by_lexicographic(a: Point2D, b: Point2D) -> Point2D {
+    if a.lexicographic_cmp(&b) == Ordering::Greater {
+        b
+    } else {
+        a
+    }
+}
+
+/// 计算出的交点有舍入误差，相对误差在这个范围内就认为相等
+const EPSILON: f64 = 1e-9;
+
+fn nearly_equal(a: f64, b: f64) -> bool {
+    (a - b).abs() <= EPSILON * a.abs().max(b.abs()).max(1.0)
+}
+
+/// 交点 q 是否明显位于事件点 p 之后，误差范围内的交点在 p 上已经处理过
+fn after(q: Point2D, p: Point2D) -> bool {
+    q.lexicographic_cmp(&p) == Ordering::Greater
+        && !(nearly_equal(q.x, p.x) && nearly_equal(q.y, p.y))
+}
+
+/// 所有相交的线段对 (i, j)，i < j，按字典序排列
+pub fn all_intersections(segments: &[Segment]) -> Vec<(usize, usize)> {
+    let ends: Vec<(Point2D, Point2D)> = segments.iter().map(Segment::ordered_endpoints).collect();
+    let mut starts: Vec<usize> = (0..segments.len()).collect();
+    starts.sort_by(|&a, &b| ends[a].0.lexicographic_cmp(&ends[b].0));
+
+    let mut events: Heap<Point2D> = Heap::new(|a, b| a.lexicographic_cmp(b) == Ordering::Less);
+    for &(low, high) in &ends {
+        events.push(low);
+        events.push(high);
+    }
+
+    // 线段在扫描线上的 y。竖直线段只在自己的 x 上出现，此时经过事件点
+    let y_at = |i: usize, p: Point2D| {
+        let (low, high) = ends[i];
+        if low.x == high.x {
+            p.y
+        } else {
+            low.y + (high.y - low.y) * (p.x - low.x) / (high.x - low.x)
+        }
+    };
+    // 经过同一点的线段在这一点之后的上下顺序：方向越靠顺时针越靠下，竖直线段在最上面
+    let after_point_order = |&a: &usize, &b: &usize| match orientation(
+        Point2D::default(),
+        ends[a].1 - ends[a].0,
+        ends[b].1 - ends[b].0,
+    ) {
+        Orientation::CounterClockwise => Ordering::Less,
+        Orientation::Clockwise => Ordering::Greater,
+        Orientation::Collinear => a.cmp(&b),
+    };
+
+    let mut found = vec![];
+    let mut status: Vec<usize> = vec![];
+    let mut next_start = 0;
+    while let Some(p) = events.pop() {
+        // 同一个点可能多次入队
+        while events.peek() == Some(&p) {
+            events.pop();
+        }
+
+        // U(p)：从 p 开始的线段。端点按精确的字典序处理，计算出的交点即使排到了附近端点的前面，
+        // 经过它的线段到了端点的事件上仍然落在下面定位出的那一段里
+        let mut meeting = vec![];
+        while next_start < starts.len()
+            && ends[starts[next_start]].0.lexicographic_cmp(&p) != Ordering::Greater
+        {
+            meeting.push(starts[next_start]);
+            next_start += 1;
+        }
+        // 状态中经过 p 的线段是连续的一段，包括在 p 结束的 L(p) 和 p 在内部的 C(p)
+        let tolerance = EPSILON * p.y.abs().max(1.0);
+        let low = status.partition_point(|&i| y_at(i, p) < p.y - tolerance);
+        let high = low
+            + status[low..]
+                .iter()
+                .take_while(|&&i| y_at(i, p) <= p.y + tolerance)
+                .count();
+        meeting.extend_from_slice(&status[low..high]);
+
+        for (k, &i) in meeting.iter().enumerate() {
+            for &j in &meeting[k + 1..] {
+                if segments_intersect(&segments[i], &segments[j]) {
+                    found.push((i.min(j), i.max(j)));
+                }
+            }
+        }
+
+        // 删掉 L(p)，U(p) 和 C(p) 按 p 之后的顺序放回原处，C(p) 中的线段在这里交换位置
+        let mut continuing: Vec<usize> = meeting
+            .into_iter()
+            .filter(|&i| ends[i].1.lexicographic_cmp(&p) == Ordering::Greater)
+            .collect();
+        continuing.sort_by(after_point_order);
+        let inserted = continuing.len();
+        status.splice(low..high, continuing);
+
+        // 只有新相邻的线段需要检查：放回的一段的上下边界，或者删空之后合拢的两条线段
+        let boundaries = if inserted == 0 {
+            vec![low]
+        } else {
+            vec![low, low + inserted]
+        };
+        for k in boundaries {
+            if k == 0 || k >= status.len() {
+                continue;
+            }
+            let (a, b) = (status[k - 1], status[k]);
+            match segment_intersection(&segments[a], &segments[b]) {
+                Intersection::None => {}
+                // 端点本来就是事件，只有规范相交的交点需要加入队列
+                Intersection::Proper(mut q) => {
+                    found.push((a.min(b), a.max(b)));
+                    // 竖直或水平的线段上，交点的这一维是精确的
+                    for (low, high) in [ends[a], ends[b]] {
+                        if low.x == high.x {
+                            q.x = low.x;
+                        }
+                        if low.y == high.y {
+                            q.y = low.y;
+                        }
+                    }
+                    if after(q, p) {
+                        events.push(q);
+                    }
+                }
+                Intersection::Touching(_) | Intersection::Overlap(_) => {
+                    found.push((a.min(b), a.max(b)))
+                }
+            }
+        }
+    }
+    found.sort_unstable();
+    found.dedup();
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::Rng;
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> Segment {
+        Segment::new(Point2D::new(x1, y1), Point2D::new(x2, y2))
+    }
+
+    #[test]
+    fn orientation_tests() {
+        let (a, b) = (Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0));
+        assert_eq!(
+            orientation(a, b, Point2D::new(1.0, 1.0)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            orientation(a, b, Point2D::new(1.0, -1.0)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            orientation(a, b, Point2D::new(5.0, 0.0)),
+            Orientation::Collinear
+        );
+        assert!(ccw(a, b, Point2D::new(0.0, 1.0)));
+        assert!(!ccw(b, a, Point2D::new(0.0, 1.0)));
+    }
+
+    #[test]
+    fn classifies_intersections() {
+        let s = segment(0.0, 0.0, 4.0, 4.0);
+        assert_eq!(
+            segment_intersection(&s, &segment(0.0, 4.0, 4.0, 0.0)),
+            Intersection::Proper(Point2D::new(2.0, 2.0))
+        );
+        // 端点落在另一条线段内部
+        assert_eq!(
+            segment_intersection(&s, &segment(1.0, 1.0, 3.0, 0.0)),
+            Intersection::Touching(Point2D::new(1.0, 1.0))
+        );
+        // 共享端点
+        assert_eq!(
+            segment_intersection(&s, &segment(4.0, 4.0, 5.0, 0.0)),
+            Intersection::Touching(Point2D::new(4.0, 4.0))
+        );
+        assert_eq!(
+            segment_intersection(&s, &segment(6.0, 6.0, 2.0, 2.0)),
+            Intersection::Overlap(segment(2.0, 2.0, 4.0, 4.0))
+        );
+        assert_eq!(
+            segment_intersection(&s, &segment(4.0, 4.0, 6.0, 6.0)),
+            Intersection::Touching(Point2D::new(4.0, 4.0))
+        );
+        assert_eq!(
+            segment_intersection(&s, &segment(5.0, 5.0, 6.0, 6.0)),
+            Intersection::None
+        );
+        // 平行不共线
+        assert!(!segments_intersect(&s, &segment(0.0, 1.0, 4.0, 5.0)));
+        // 所在直线相交，但交点不在线段上
+        assert!(!segments_intersect(&s, &segment(3.0, 0.0, 5.0, -2.0)));
+        // 退化成点的线段
+        let point = segment(2.0, 2.0, 2.0, 2.0);
+        assert_eq!(
+            segment_intersection(&point, &s),
+            Intersection::Touching(Point2D::new(2.0, 2.0))
+        );
+        assert!(!segments_intersect(&segment(1.0, 0.0, 1.0, 0.0), &s));
+    }
+
+    #[test]
+    fn sweep_handles_degenerate_cases() {
+        let segments = [
+            segment(0.0, 0.0, 4.0, 4.0),
+            segment(0.0, 4.0, 4.0, 0.0),
+            // 竖直线段经过上面两条的交点
+            segment(2.0, -1.0, 2.0, 5.0),
+            // 与第一条共线重叠
+            segment(3.0, 3.0, 6.0, 6.0),
+            // 和其它线段都不相交
+            segment(10.0, 0.0, 11.0, 0.0),
+            // 在 x = 6 处和第 3 条相接
+            segment(6.0, 6.0, 7.0, 0.0),
+        ];
+        assert_eq!(
+            all_intersections(&segments),
+            vec![(0, 1), (0, 2), (0, 3), (1, 2), (3, 5)]
+        );
+        assert_eq!(all_intersections(&[]), vec![]);
+    }
+
+    #[test]
+    fn sweep_matches_brute_force() {
+        let mut rng = Rng::new(1469);
+        for round in 0..400 {
+            let n = rng.gen_index(25);
+            // 小范围整数坐标，大量共线、共点、竖直的情况；除以 7 之后交点有舍入误差
+            let scale = if round % 2 == 0 { 1.0 } else { 7.0 };
+            let mut coordinate = || rng.gen_range(0, 10) as f64 / scale;
+            let segments: Vec<Segment> = (0..n)
+                .map(|_| segment(coordinate(), coordinate(), coordinate(), coordinate()))
+                .collect();
+            let mut expected = vec![];
+            for i in 0..n {
+                for j in i + 1..n {
+                    if segments_intersect(&segments[i], &segments[j]) {
+                        expected.push((i, j));
+                    }
+                }
+            }
+            assert_eq!(all_intersections(&segments), expected, "{segments:?}");
+        }
+    }
+}
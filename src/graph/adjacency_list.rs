@@ -0,0 +1,191 @@
+/*!
+定义：
+[邻接表](https://zh.wikipedia.org/wiki/%E9%82%BB%E6%8E%A5%E8%A1%A8)
+顶点用 `0..n` 的下标表示。所有边按加入顺序存放在一个数组里，边的下标就是它的 id；
+每个顶点的邻接表只保存和它相连的边的 id。无向图的一条边同时出现在两个端点的邻接表里，
+这样算法可以通过 id 区分平行边，也能知道走过的是哪一条边
+*/
+
+/// 一条带权边，无权图的边权为 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub weight: i64,
+}
+
+impl Edge {
+    /// 从 `node` 出发沿这条边到达的另一个端点
+    pub fn other(&self, node: usize) -> usize {
+        if node == self.from {
+            self.to
+        } else {
+            self.from
+        }
+    }
+}
+
+/// 从某个顶点出发看到的一条边
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdgeRef {
+    /// 边的 id
+    pub id: usize,
+    /// 出发的顶点
+    pub from: usize,
+    /// 到达的顶点，无向图中是另一个端点
+    pub to: usize,
+    pub weight: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    directed: bool,
+    edges: Vec<Edge>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// 有 `nodes` 个顶点、没有边的有向图
+    pub fn new_directed(nodes: usize) -> Self {
+        Graph {
+            directed: true,
+            edges: vec![],
+            adjacency: vec![vec![]; nodes],
+        }
+    }
+
+    /// 有 `nodes` 个顶点、没有边的无向图
+    pub fn new_undirected(nodes: usize) -> Self {
+        Graph {
+            directed: false,
+            ..Graph::new_directed(nodes)
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// 新增一个顶点，返回它的下标
+    pub fn add_node(&mut self) -> usize {
+        self.adjacency.push(vec![]);
+        self.adjacency.len() - 1
+    }
+
+    /// 新增一条权为 1 的边，返回边的 id
+    pub fn add_edge(&mut self, from: usize, to: usize) -> usize {
+        self.add_weighted_edge(from, to, 1)
+    }
+
+    /// 新增一条带权边，返回边的 id
+    pub fn add_weighted_edge(&mut self, from: usize, to: usize, weight: i64) -> usize {
+        let nodes = self.node_count();
+        assert!(
+            from < nodes && to < nodes,
+            "Edge ({from}, {to}) out of bounds for {nodes} nodes."
+        );
+        let id = self.edges.len();
+        self.edges.push(Edge { from, to, weight });
+        self.adjacency[from].push(id);
+        // 无向图的自环只记录一次
+        if !self.directed && from != to {
+            self.adjacency[to].push(id);
+        }
+        id
+    }
+
+    pub fn edge(&self, id: usize) -> &Edge {
+        &self.edges[id]
+    }
+
+    /// 按 id 顺序返回所有的边
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// 从 `node` 出发的所有边（无向图中是和它相连的所有边）
+    pub fn edges_from(&self, node: usize) -> impl Iterator<Item = EdgeRef> + '_ {
+        self.adjacency[node].iter().map(move |&id| {
+            let edge = &self.edges[id];
+            EdgeRef {
+                id,
+                from: node,
+                to: edge.other(node),
+                weight: edge.weight,
+            }
+        })
+    }
+
+    /// 从 `node` 出发能直接到达的顶点，有平行边时会重复出现
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges_from(node).map(|edge| edge.to)
+    }
+
+    /// 出度，无向图中就是度数
+    pub fn degree(&self, node: usize) -> usize {
+        self.adjacency[node].len()
+    }
+
+    /// 所有边反向后的图，无向图反向后不变
+    pub fn reversed(&self) -> Graph {
+        let mut reversed = Graph {
+            directed: self.directed,
+            edges: vec![],
+            adjacency: vec![vec![]; self.node_count()],
+        };
+        for edge in &self.edges {
+            reversed.add_weighted_edge(edge.to, edge.from, edge.weight);
+        }
+        reversed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Graph;
+
+    #[test]
+    fn directed_edges_go_one_way() {
+        let mut graph = Graph::new_directed(3);
+        let a = graph.add_edge(0, 1);
+        let b = graph.add_weighted_edge(1, 2, 5);
+        assert_eq!((a, b), (0, 1));
+        assert_eq!(graph.neighbors(0).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(graph.neighbors(1).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(graph.neighbors(2).count(), 0);
+        assert_eq!(graph.edge(b).weight, 5);
+
+        let reversed = graph.reversed();
+        assert_eq!(reversed.neighbors(2).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(reversed.edge_count(), 2);
+    }
+
+    #[test]
+    fn undirected_edges_go_both_ways() {
+        let mut graph = Graph::new_undirected(2);
+        let c = graph.add_node();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, c);
+        graph.add_edge(c, c);
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.neighbors(1).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(graph.neighbors(2).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(graph.degree(2), 2);
+        let from_two: Vec<usize> = graph.edges_from(2).map(|e| e.id).collect();
+        assert_eq!(from_two, vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_unknown_nodes() {
+        Graph::new_directed(2).add_edge(0, 2);
+    }
+}
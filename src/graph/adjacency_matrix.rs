@@ -0,0 +1,117 @@
+/*!
+定义：
+[邻接矩阵](https://zh.wikipedia.org/wiki/%E9%82%BB%E6%8E%A5%E7%9F%A9%E9%98%B5)
+用 n × n 的矩阵保存边权，`weights[u][v]` 为 None 表示没有从 u 到 v 的边。
+占用 O(V²) 的空间，但查询任意两点之间的边只需要 O(1)，适合稠密图和 Floyd-Warshall 这类按点对计算的算法
+*/
+
+use super::adjacency_list::Graph;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjacencyMatrix {
+    directed: bool,
+    weights: Vec<Vec<Option<i64>>>,
+}
+
+impl AdjacencyMatrix {
+    pub fn new_directed(nodes: usize) -> Self {
+        AdjacencyMatrix {
+            directed: true,
+            weights: vec![vec![None; nodes]; nodes],
+        }
+    }
+
+    pub fn new_undirected(nodes: usize) -> Self {
+        AdjacencyMatrix {
+            directed: false,
+            ..AdjacencyMatrix::new_directed(nodes)
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// 设置边权，已有的边会被覆盖；无向图同时设置两个方向
+    pub fn set_edge(&mut self, from: usize, to: usize, weight: i64) {
+        self.weights[from][to] = Some(weight);
+        if !self.directed {
+            self.weights[to][from] = Some(weight);
+        }
+    }
+
+    pub fn remove_edge(&mut self, from: usize, to: usize) {
+        self.weights[from][to] = None;
+        if !self.directed {
+            self.weights[to][from] = None;
+        }
+    }
+
+    pub fn weight(&self, from: usize, to: usize) -> Option<i64> {
+        self.weights[from][to]
+    }
+
+    /// 从 `node` 出发的所有边，返回 (到达的顶点, 边权)
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, i64)> + '_ {
+        self.weights[node]
+            .iter()
+            .enumerate()
+            .filter_map(|(to, weight)| weight.map(|w| (to, w)))
+    }
+}
+
+/// 平行边只保留权最小的一条
+impl From<&Graph> for AdjacencyMatrix {
+    fn from(graph: &Graph) -> Self {
+        let mut matrix = if graph.is_directed() {
+            AdjacencyMatrix::new_directed(graph.node_count())
+        } else {
+            AdjacencyMatrix::new_undirected(graph.node_count())
+        };
+        for edge in graph.edges() {
+            let current = matrix.weight(edge.from, edge.to);
+            if current.is_none_or(|w| edge.weight < w) {
+                matrix.set_edge(edge.from, edge.to, edge.weight);
+            }
+        }
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdjacencyMatrix;
+    use crate::graph::Graph;
+
+    #[test]
+    fn stores_edges() {
+        let mut matrix = AdjacencyMatrix::new_undirected(3);
+        matrix.set_edge(0, 1, 4);
+        matrix.set_edge(1, 2, -2);
+        assert_eq!(matrix.weight(1, 0), Some(4));
+        assert_eq!(
+            matrix.neighbors(1).collect::<Vec<_>>(),
+            vec![(0, 4), (2, -2)]
+        );
+        matrix.remove_edge(2, 1);
+        assert_eq!(matrix.weight(1, 2), None);
+        assert_eq!(matrix.node_count(), 3);
+    }
+
+    #[test]
+    fn converts_from_adjacency_list() {
+        let mut graph = Graph::new_directed(3);
+        graph.add_weighted_edge(0, 1, 5);
+        graph.add_weighted_edge(0, 1, 3);
+        graph.add_weighted_edge(2, 0, 1);
+        let matrix = AdjacencyMatrix::from(&graph);
+        assert!(matrix.is_directed());
+        assert_eq!(matrix.weight(0, 1), Some(3));
+        assert_eq!(matrix.weight(1, 0), None);
+        assert_eq!(matrix.weight(2, 0), Some(1));
+    }
+}
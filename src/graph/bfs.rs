@@ -0,0 +1,158 @@
+/*!
+定义：
+[广度优先搜索的定义](https://zh.wikipedia.org/wiki/%E5%B9%BF%E5%BA%A6%E4%BC%98%E5%85%88%E6%90%9C%E7%B4%A2)
+从起点开始按距离一层一层地访问顶点，用队列保存下一层要访问的顶点。
+在无权图中，第一次访问到某个顶点时经过的边数就是最短距离，记录父节点即可还原最短路径
+*/
+
+use super::adjacency_list::Graph;
+use crate::visualization::Observer;
+use std::collections::VecDeque;
+
+/// 一次广度优先搜索的结果，`distance` 和 `parent` 都以顶点下标为索引，不可达的顶点为 None
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BfsResult {
+    pub source: usize,
+    /// 顶点被访问的顺序
+    pub order: Vec<usize>,
+    /// 到起点的边数
+    pub distance: Vec<Option<usize>>,
+    /// 最短路径树中的父节点，起点没有父节点
+    pub parent: Vec<Option<usize>>,
+}
+
+impl BfsResult {
+    /// 从起点到 `target` 的一条最短路径（包含两端），不可达时返回 None
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distance[target]?;
+        let mut path = vec![target];
+        let mut node = target;
+        while let Some(parent) = self.parent[node] {
+            path.push(parent);
+            node = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+pub fn bfs(graph: &Graph, source: usize) -> BfsResult {
+    bfs_observed(graph, source, &mut ())
+}
+
+/// 同 [`bfs`]，顶点出队时调用 [`Observer::on_visit_node`]，
+/// 第一次发现顶点时调用 [`Observer::on_relax_edge`]，距离是边数
+pub fn bfs_observed<O>(graph: &Graph, source: usize, observer: &mut O) -> BfsResult
+where
+    O: Observer + ?Sized,
+{
+    let nodes = graph.node_count();
+    let mut order = vec![];
+    let mut distance = vec![None; nodes];
+    let mut parent = vec![None; nodes];
+    let mut queue = VecDeque::new();
+
+    distance[source] = Some(0);
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        observer.on_visit_node(node);
+        let next_distance = distance[node].map(|d| d + 1);
+        for next in graph.neighbors(node) {
+            // 第一次访问时就确定了最短距离
+            if distance[next].is_none() {
+                distance[next] = next_distance;
+                parent[next] = Some(node);
+                observer.on_relax_edge(node, next, next_distance.unwrap() as i64);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    BfsResult {
+        source,
+        order,
+        distance,
+        parent,
+    }
+}
+
+/// 无权图中从 `source` 到 `target` 的一条最短路径（包含两端），不可达时返回 None
+pub fn shortest_path(graph: &Graph, source: usize, target: usize) -> Option<Vec<usize>> {
+    bfs(graph, source).path_to(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bfs, bfs_observed, shortest_path};
+    use crate::graph::generators::grid;
+    use crate::graph::Graph;
+    use crate::visualization::Step;
+
+    #[test]
+    fn visits_in_distance_order() {
+        let graph = grid(4, 3);
+        let result = bfs(&graph, 0);
+        assert_eq!(result.order.len(), 12);
+        assert_eq!(result.order[0], 0);
+        // 访问顺序中的距离单调不减
+        let distances: Vec<usize> = result
+            .order
+            .iter()
+            .map(|&n| result.distance[n].unwrap())
+            .collect();
+        assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+        // 网格中的距离是曼哈顿距离
+        for node in 0..12 {
+            assert_eq!(result.distance[node], Some(node % 4 + node / 4));
+        }
+    }
+
+    #[test]
+    fn observer_sees_the_search_tree() {
+        let graph = grid(3, 3);
+        let mut steps = vec![];
+        let result = bfs_observed(&graph, 4, &mut steps);
+        assert_eq!(result, bfs(&graph, 4));
+        let mut visited = vec![];
+        for step in steps {
+            match step {
+                Step::Visit(node) => visited.push(node),
+                // 每条松弛的边都是最短路径树中的边
+                Step::Relax { from, to, distance } => {
+                    assert_eq!(result.parent[to], Some(from));
+                    assert_eq!(result.distance[to], Some(distance as usize));
+                }
+                _ => unreachable!(),
+            }
+        }
+        assert_eq!(visited, result.order);
+    }
+
+    #[test]
+    fn reconstructs_shortest_path() {
+        let graph = grid(5, 5);
+        let path = shortest_path(&graph, 0, 24).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!((path[0], path[8]), (0, 24));
+        // 路径上相邻的顶点之间都有边
+        for pair in path.windows(2) {
+            assert!(graph.neighbors(pair[0]).any(|n| n == pair[1]));
+        }
+        assert_eq!(shortest_path(&graph, 7, 7), Some(vec![7]));
+    }
+
+    #[test]
+    fn respects_direction_and_reachability() {
+        let mut graph = Graph::new_directed(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(3, 0);
+        let result = bfs(&graph, 0);
+        assert_eq!(result.order, vec![0, 1, 2]);
+        assert_eq!(result.distance, vec![Some(0), Some(1), Some(2), None]);
+        assert_eq!(result.parent, vec![None, Some(0), Some(1), None]);
+        assert_eq!(result.path_to(3), None);
+        assert_eq!(shortest_path(&graph, 2, 0), None);
+    }
+}
@@ -0,0 +1,174 @@
+/*!
+定义：
+[二分图的定义](https://zh.wikipedia.org/wiki/%E4%BA%8C%E5%88%86%E5%9B%BE)
+顶点可以分成两部分，使每条边的两个端点分属不同部分，等价于可以用两种颜色给顶点染色，也等价于图中没有奇环。
+对每个连通分量做广度优先搜索，按层交替染色；发现一条边两端颜色相同时，
+两个端点到它们在 BFS 树中的最近公共祖先的路径加上这条边，就是一个奇环
+*/
+
+use super::adjacency_list::Graph;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Bipartiteness {
+    /// 每个顶点的颜色，取值为 0 或 1
+    Bipartite { colors: Vec<usize> },
+    /// 一个奇数长度的环，按顺序列出环上的顶点，最后一个顶点和第一个顶点之间有边
+    OddCycle(Vec<usize>),
+}
+
+impl Bipartiteness {
+    pub fn is_bipartite(&self) -> bool {
+        matches!(self, Bipartiteness::Bipartite { .. })
+    }
+}
+
+/// 边的方向会被忽略；图不是二分图时返回一个奇环作为证据
+pub fn is_bipartite(graph: &Graph) -> Bipartiteness {
+    let nodes = graph.node_count();
+    let mut adjacency = vec![vec![]; nodes];
+    for edge in graph.edges() {
+        adjacency[edge.from].push(edge.to);
+        if edge.from != edge.to {
+            adjacency[edge.to].push(edge.from);
+        }
+    }
+
+    let mut colors: Vec<Option<usize>> = vec![None; nodes];
+    let mut parent = vec![None; nodes];
+    let mut depth = vec![0; nodes];
+    for root in 0..nodes {
+        if colors[root].is_some() {
+            continue;
+        }
+        colors[root] = Some(0);
+        let mut queue = VecDeque::from([root]);
+        while let Some(node) = queue.pop_front() {
+            for &next in &adjacency[node] {
+                match colors[next] {
+                    None => {
+                        colors[next] = colors[node].map(|c| 1 - c);
+                        parent[next] = Some(node);
+                        depth[next] = depth[node] + 1;
+                        queue.push_back(next);
+                    }
+                    Some(color) if Some(color) == colors[node] => {
+                        return Bipartiteness::OddCycle(odd_cycle(&parent, &depth, node, next));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    Bipartiteness::Bipartite {
+        colors: colors.into_iter().map(Option::unwrap).collect(),
+    }
+}
+
+/// `u` 和 `v` 颜色相同且之间有边，沿 BFS 树向上走到最近公共祖先，拼出环
+fn odd_cycle(parent: &[Option<usize>], depth: &[usize], u: usize, v: usize) -> Vec<usize> {
+    let (mut a, mut b) = (u, v);
+    let mut left = vec![];
+    let mut right = vec![];
+    // 同色的顶点深度奇偶性相同，BFS 中深度最多相差 1，所以一定相等
+    debug_assert_eq!(depth[a], depth[b]);
+    while a != b {
+        left.push(a);
+        right.push(b);
+        a = parent[a].unwrap();
+        b = parent[b].unwrap();
+    }
+    left.push(a);
+    // 环：u -> ... -> lca -> ... -> v，再由 v 回到 u
+    left.extend(right.into_iter().rev());
+    left
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_bipartite, Bipartiteness};
+    use crate::graph::Graph;
+    use crate::utils::random::Rng;
+
+    fn has_edge(graph: &Graph, a: usize, b: usize) -> bool {
+        graph
+            .edges()
+            .iter()
+            .any(|e| (e.from, e.to) == (a, b) || (e.from, e.to) == (b, a))
+    }
+
+    fn check(graph: &Graph) -> bool {
+        match is_bipartite(graph) {
+            Bipartiteness::Bipartite { colors } => {
+                for edge in graph.edges() {
+                    assert_ne!(colors[edge.from], colors[edge.to]);
+                }
+                true
+            }
+            Bipartiteness::OddCycle(cycle) => {
+                assert_eq!(cycle.len() % 2, 1);
+                for i in 0..cycle.len() {
+                    assert!(has_edge(graph, cycle[i], cycle[(i + 1) % cycle.len()]));
+                }
+                // 环上没有重复的顶点
+                let mut distinct = cycle.clone();
+                distinct.sort();
+                distinct.dedup();
+                assert_eq!(distinct.len(), cycle.len());
+                false
+            }
+        }
+    }
+
+    #[test]
+    fn colors_even_cycles_and_trees() {
+        let mut square = Graph::new_undirected(5);
+        for (a, b) in [(0, 1), (1, 2), (2, 3), (3, 0)] {
+            square.add_edge(a, b);
+        }
+        assert!(check(&square));
+        assert_eq!(
+            is_bipartite(&square),
+            Bipartiteness::Bipartite {
+                colors: vec![0, 1, 0, 1, 0]
+            }
+        );
+    }
+
+    #[test]
+    fn finds_odd_cycle_in_later_component() {
+        let mut graph = Graph::new_directed(7);
+        graph.add_edge(0, 1);
+        // 第二个连通分量是一个五元环，方向被忽略
+        for (a, b) in [(2, 3), (4, 3), (4, 5), (5, 6), (6, 2)] {
+            graph.add_edge(a, b);
+        }
+        assert!(!check(&graph));
+        match is_bipartite(&graph) {
+            Bipartiteness::OddCycle(cycle) => assert_eq!(cycle.len(), 5),
+            other => panic!("expected odd cycle, got {other:?}"),
+        }
+
+        let mut self_loop = Graph::new_undirected(2);
+        self_loop.add_edge(1, 1);
+        assert_eq!(is_bipartite(&self_loop), Bipartiteness::OddCycle(vec![1]));
+    }
+
+    #[test]
+    fn random_graphs_have_valid_witnesses() {
+        let mut rng = Rng::new(1403);
+        let mut seen = [false; 2];
+        for _ in 0..200 {
+            let nodes = 1 + rng.gen_index(15);
+            let mut graph = Graph::new_undirected(nodes);
+            for _ in 0..rng.gen_index(nodes + 3) {
+                graph.add_edge(rng.gen_index(nodes), rng.gen_index(nodes));
+            }
+            seen[check(&graph) as usize] = true;
+        }
+        // 两种结果都覆盖到
+        assert_eq!(seen, [true, true]);
+        assert!(is_bipartite(&Graph::new_undirected(0)).is_bipartite());
+    }
+}
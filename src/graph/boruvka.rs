@@ -0,0 +1,93 @@
+/*!
+定义：
+[Borůvka 算法](https://en.wikipedia.org/wiki/Bor%C5%AFvka%27s_algorithm)
+每一轮为每个连通分量找出连向其他分量的最便宜的边，把这些边一次性全部加入。
+每轮分量个数至少减半，最多 O(log V) 轮，总复杂度 O(E log V)。
+各个分量找最便宜的边互不依赖，因此这是最容易并行化的最小生成树算法
+*/
+
+use super::adjacency_list::Graph;
+use super::kruskal::SpanningForest;
+use crate::data_structures::union_find::UnionFind;
+
+/// 边的方向会被忽略，不连通的图得到最小生成森林
+pub fn boruvka_mst(graph: &Graph) -> SpanningForest {
+    let nodes = graph.node_count();
+    let mut sets = UnionFind::new(nodes);
+    let mut forest = SpanningForest {
+        edges: vec![],
+        total_weight: 0,
+    };
+    // 边权相同时按 id 比较，保证所有边的大小关系是严格的，同一轮选出的边不会成环
+    let key = |id: usize| (graph.edge(id).weight, id);
+
+    loop {
+        // cheapest[root] 是以 root 为代表的分量连出去的最便宜的边
+        let mut cheapest: Vec<Option<usize>> = vec![None; nodes];
+        for (id, edge) in graph.edges().iter().enumerate() {
+            let (a, b) = (sets.find(edge.from), sets.find(edge.to));
+            if a == b {
+                continue;
+            }
+            for root in [a, b] {
+                if cheapest[root].is_none_or(|best| key(id) < key(best)) {
+                    cheapest[root] = Some(id);
+                }
+            }
+        }
+
+        let mut merged = false;
+        for id in cheapest.into_iter().flatten() {
+            let edge = graph.edge(id);
+            // 两个分量可能选中了同一条边，第二次 union 会返回 false
+            if sets.union(edge.from, edge.to) {
+                forest.edges.push(id);
+                forest.total_weight += edge.weight;
+                merged = true;
+            }
+        }
+        if !merged {
+            return forest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::boruvka_mst;
+    use crate::graph::{kruskal_mst, Graph};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn finds_minimum_spanning_tree() {
+        let mut graph = Graph::new_undirected(4);
+        graph.add_weighted_edge(0, 1, 10);
+        graph.add_weighted_edge(0, 2, 6);
+        graph.add_weighted_edge(0, 3, 5);
+        graph.add_weighted_edge(1, 3, 15);
+        graph.add_weighted_edge(2, 3, 4);
+        let mst = boruvka_mst(&graph);
+        assert_eq!(mst.total_weight, 19);
+        assert_eq!(mst.edges.len(), 3);
+    }
+
+    #[test]
+    fn agrees_with_kruskal_on_random_graphs() {
+        let mut rng = Rng::new(1400);
+        for _ in 0..100 {
+            let nodes = rng.gen_index(30);
+            let mut graph = Graph::new_undirected(nodes);
+            if nodes > 0 {
+                for _ in 0..rng.gen_index(4 * nodes) {
+                    let (from, to) = (rng.gen_index(nodes), rng.gen_index(nodes));
+                    // 权的范围很小，有大量相等的边
+                    graph.add_weighted_edge(from, to, rng.gen_range(-5, 5));
+                }
+            }
+            let boruvka = boruvka_mst(&graph);
+            let kruskal = kruskal_mst(&graph);
+            assert_eq!(boruvka.total_weight, kruskal.total_weight);
+            assert_eq!(boruvka.edges.len(), kruskal.edges.len());
+        }
+    }
+}
@@ -0,0 +1,190 @@
+/*!
+定义：
+[图着色问题](https://zh.wikipedia.org/wiki/%E5%9B%BE%E7%9D%80%E8%89%B2%E9%97%AE%E9%A2%98)
+给顶点染色使相邻顶点颜色不同，求最少颜色数是 NP 困难的。贪心算法按某种顺序依次给顶点
+染上邻居没用过的最小颜色，最多用 最大度数 + 1 种颜色，顶点顺序决定了效果：
+- 自然顺序：按顶点编号
+- 最大度优先（Welsh-Powell）：度数大的顶点约束多，先染色
+- [DSATUR](https://en.wikipedia.org/wiki/DSatur)：每次选饱和度（邻居已用的不同颜色数）最大的顶点，
+  相同时选度数大的，在二分图、环等图上能得到最优解
+
+边的方向会被忽略，自环不参与约束
+*/
+
+use super::adjacency_list::Graph;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColoringStrategy {
+    Natural,
+    LargestFirst,
+    Dsatur,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coloring {
+    /// 每个顶点的颜色，取值为 0..color_count
+    pub colors: Vec<usize>,
+    pub color_count: usize,
+}
+
+pub fn greedy_coloring(graph: &Graph, strategy: ColoringStrategy) -> Coloring {
+    let nodes = graph.node_count();
+    let adjacency = undirected_neighbors(graph);
+    let mut colors: Vec<Option<usize>> = vec![None; nodes];
+
+    match strategy {
+        ColoringStrategy::Natural | ColoringStrategy::LargestFirst => {
+            let mut order: Vec<usize> = (0..nodes).collect();
+            if strategy == ColoringStrategy::LargestFirst {
+                // 稳定排序，度数相同时按编号
+                order.sort_by_key(|&v| std::cmp::Reverse(adjacency[v].len()));
+            }
+            for node in order {
+                colors[node] = Some(smallest_free_color(&adjacency[node], &colors));
+            }
+        }
+        ColoringStrategy::Dsatur => {
+            // saturation[v] 是 v 的邻居已经用过的颜色
+            let mut saturation: Vec<HashSet<usize>> = vec![HashSet::new(); nodes];
+            for _ in 0..nodes {
+                let node = (0..nodes)
+                    .filter(|&v| colors[v].is_none())
+                    .max_by_key(|&v| {
+                        // 饱和度和度数都相同时选编号最小的顶点
+                        (
+                            saturation[v].len(),
+                            adjacency[v].len(),
+                            std::cmp::Reverse(v),
+                        )
+                    })
+                    .unwrap();
+                let color = smallest_free_color(&adjacency[node], &colors);
+                colors[node] = Some(color);
+                for &next in &adjacency[node] {
+                    saturation[next].insert(color);
+                }
+            }
+        }
+    }
+
+    let colors: Vec<usize> = colors.into_iter().map(Option::unwrap).collect();
+    let color_count = colors.iter().max().map_or(0, |&c| c + 1);
+    Coloring {
+        colors,
+        color_count,
+    }
+}
+
+/// 检查相邻的顶点颜色都不同（忽略自环）
+pub fn is_proper_coloring(graph: &Graph, colors: &[usize]) -> bool {
+    graph
+        .edges()
+        .iter()
+        .all(|edge| edge.from == edge.to || colors[edge.from] != colors[edge.to])
+}
+
+/// 去掉自环和重复边后的无向邻接表
+fn undirected_neighbors(graph: &Graph) -> Vec<Vec<usize>> {
+    let mut adjacency = vec![vec![]; graph.node_count()];
+    for edge in graph.edges().iter().filter(|e| e.from != e.to) {
+        adjacency[edge.from].push(edge.to);
+        adjacency[edge.to].push(edge.from);
+    }
+    for neighbors in &mut adjacency {
+        neighbors.sort();
+        neighbors.dedup();
+    }
+    adjacency
+}
+
+/// 邻居没有用过的最小颜色
+fn smallest_free_color(neighbors: &[usize], colors: &[Option<usize>]) -> usize {
+    let used: HashSet<usize> = neighbors.iter().filter_map(|&v| colors[v]).collect();
+    (0..).find(|c| !used.contains(c)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::Rng;
+
+    const STRATEGIES: [ColoringStrategy; 3] = [
+        ColoringStrategy::Natural,
+        ColoringStrategy::LargestFirst,
+        ColoringStrategy::Dsatur,
+    ];
+
+    #[test]
+    fn colors_classic_graphs() {
+        // 奇环需要 3 种颜色
+        let mut cycle = Graph::new_undirected(5);
+        for v in 0..5 {
+            cycle.add_edge(v, (v + 1) % 5);
+        }
+        // 完全图 K4 需要 4 种颜色
+        let mut complete = Graph::new_undirected(4);
+        for a in 0..4 {
+            for b in a + 1..4 {
+                complete.add_edge(a, b);
+            }
+        }
+        for strategy in STRATEGIES {
+            let coloring = greedy_coloring(&cycle, strategy);
+            assert!(is_proper_coloring(&cycle, &coloring.colors));
+            assert_eq!(coloring.color_count, 3);
+            assert_eq!(greedy_coloring(&complete, strategy).color_count, 4);
+        }
+        assert_eq!(
+            greedy_coloring(&Graph::new_undirected(0), ColoringStrategy::Dsatur).color_count,
+            0
+        );
+    }
+
+    #[test]
+    fn dsatur_is_optimal_on_crown_graph() {
+        // 皇冠图：左右各 n 个顶点，l_i 和 r_j 在 i != j 时相连。
+        // 它是二分图，但按 l0, r0, l1, r1, ... 的自然顺序贪心会用掉 n 种颜色
+        let n = 5;
+        let mut crown = Graph::new_undirected(2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                if i != j {
+                    crown.add_edge(2 * i, 2 * j + 1);
+                }
+            }
+        }
+        assert_eq!(
+            greedy_coloring(&crown, ColoringStrategy::Natural).color_count,
+            n
+        );
+        let dsatur = greedy_coloring(&crown, ColoringStrategy::Dsatur);
+        assert!(is_proper_coloring(&crown, &dsatur.colors));
+        assert_eq!(dsatur.color_count, 2);
+    }
+
+    #[test]
+    fn random_graphs_are_properly_colored() {
+        let mut rng = Rng::new(1415);
+        for _ in 0..100 {
+            let n = 1 + rng.gen_index(30);
+            let mut graph = Graph::new_directed(n);
+            for _ in 0..rng.gen_index(3 * n) {
+                graph.add_edge(rng.gen_index(n), rng.gen_index(n));
+            }
+            let max_degree = undirected_neighbors(&graph)
+                .iter()
+                .map(Vec::len)
+                .max()
+                .unwrap();
+            for strategy in STRATEGIES {
+                let coloring = greedy_coloring(&graph, strategy);
+                assert!(is_proper_coloring(&graph, &coloring.colors));
+                assert!(coloring.color_count <= max_degree + 1);
+            }
+        }
+        let mut bad = Graph::new_undirected(2);
+        bad.add_edge(0, 1);
+        assert!(!is_proper_coloring(&bad, &[1, 1]));
+    }
+}
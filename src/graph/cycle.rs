@@ -0,0 +1,149 @@
+/*!
+找出图中的一个环：
+- 有向图用三色标记的深度优先搜索：白色未访问，灰色在栈中，黑色已完成。
+  遇到指向灰色顶点的边（后向边）时，栈中从那个顶点到当前顶点的部分加上这条边就是一个环
+- 无向图记录到达每个顶点所经过的边，遇到一条不是来时的边、且指向已访问的顶点时就找到了环。
+  按边的 id 而不是父节点判断，所以两条平行边也能构成一个环
+*/
+
+use super::adjacency_list::{EdgeRef, Graph};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+/// 找出一个环，按顺序返回环上的顶点，最后一个顶点和第一个顶点之间有边；无环时返回 None
+pub fn find_cycle(graph: &Graph) -> Option<Vec<usize>> {
+    let nodes = graph.node_count();
+    let mut color = vec![Color::White; nodes];
+    for root in 0..nodes {
+        if color[root] != Color::White {
+            continue;
+        }
+        color[root] = Color::Gray;
+        // 栈中保存 (顶点, 到达它的边, 还没检查的边)，栈上的顶点恰好是当前的 DFS 路径
+        let mut stack: Vec<(usize, Option<usize>, _)> = vec![(root, None, graph.edges_from(root))];
+        while let Some((node, arrived_by, edges)) = stack.last_mut() {
+            let (node, arrived_by) = (*node, *arrived_by);
+            let Some(EdgeRef { id, to, .. }) = edges.next() else {
+                color[node] = Color::Black;
+                stack.pop();
+                continue;
+            };
+            // 无向图中不能沿来时的那条边返回
+            if !graph.is_directed() && Some(id) == arrived_by {
+                continue;
+            }
+            match color[to] {
+                Color::White => {
+                    color[to] = Color::Gray;
+                    stack.push((to, Some(id), graph.edges_from(to)));
+                }
+                // 有向图中黑色顶点已经完成，无向图中黑色顶点到这里的边已经从另一端检查过
+                Color::Black => {}
+                Color::Gray => {
+                    let start = stack.iter().position(|(v, _, _)| *v == to).unwrap();
+                    return Some(stack[start..].iter().map(|(v, _, _)| *v).collect());
+                }
+            }
+        }
+    }
+    None
+}
+
+/// 图中是否有环
+pub fn has_cycle(graph: &Graph) -> bool {
+    find_cycle(graph).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_cycle, has_cycle};
+    use crate::graph::Graph;
+    use crate::utils::random::Rng;
+
+    fn check_cycle(graph: &Graph, cycle: &[usize]) {
+        assert!(!cycle.is_empty());
+        for i in 0..cycle.len() {
+            let (a, b) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+            assert!(
+                graph.neighbors(a).any(|v| v == b),
+                "missing edge {a} -> {b}"
+            );
+        }
+        let mut distinct = cycle.to_vec();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), cycle.len());
+    }
+
+    #[test]
+    fn directed_cycles() {
+        let mut dag = Graph::new_directed(4);
+        for (a, b) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+            dag.add_edge(a, b);
+        }
+        // 菱形不是环
+        assert_eq!(find_cycle(&dag), None);
+
+        dag.add_edge(3, 0);
+        let cycle = find_cycle(&dag).unwrap();
+        check_cycle(&dag, &cycle);
+        assert_eq!(cycle, vec![0, 1, 3]);
+
+        let mut self_loop = Graph::new_directed(2);
+        self_loop.add_edge(1, 1);
+        assert_eq!(find_cycle(&self_loop), Some(vec![1]));
+    }
+
+    #[test]
+    fn undirected_cycles() {
+        let mut tree = Graph::new_undirected(5);
+        for (a, b) in [(0, 1), (1, 2), (1, 3), (3, 4)] {
+            tree.add_edge(a, b);
+        }
+        assert!(!has_cycle(&tree));
+
+        tree.add_edge(4, 2);
+        let cycle = find_cycle(&tree).unwrap();
+        check_cycle(&tree, &cycle);
+        assert_eq!(cycle.len(), 4);
+
+        // 两条平行边构成长度为 2 的环
+        let mut parallel = Graph::new_undirected(2);
+        parallel.add_edge(0, 1);
+        parallel.add_edge(1, 0);
+        assert_eq!(find_cycle(&parallel), Some(vec![0, 1]));
+    }
+
+    #[test]
+    fn random_forests_and_extra_edges() {
+        let mut rng = Rng::new(1410);
+        for directed in [true, false] {
+            for _ in 0..100 {
+                let n = 1 + rng.gen_index(20);
+                let mut graph = if directed {
+                    Graph::new_directed(n)
+                } else {
+                    Graph::new_undirected(n)
+                };
+                // 只从小编号连向大编号的有向图、或随机森林都没有环
+                for v in 1..n {
+                    if rng.gen_bool(0.8) {
+                        graph.add_edge(rng.gen_index(v), v);
+                    }
+                }
+                assert_eq!(find_cycle(&graph), None);
+
+                let (a, b) = (rng.gen_index(n), rng.gen_index(n));
+                graph.add_edge(a.max(b), a.min(b));
+                if let Some(cycle) = find_cycle(&graph) {
+                    check_cycle(&graph, &cycle);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,293 @@
+/*!
+定义：
+[深度优先搜索的定义](https://zh.wikipedia.org/wiki/%E6%B7%B1%E5%BA%A6%E4%BC%98%E5%85%88%E6%90%9C%E7%B4%A2)
+沿着一条路径尽可能深入，走不下去再回溯。按顶点编号依次从未访问的顶点出发，得到一片 DFS 森林。
+每个顶点记录发现时间和完成时间（共用一个时钟），它们构成的区间满足括号定理：
+v 是 u 的后代当且仅当 v 的区间嵌套在 u 的区间里。据此可以把每条边分成四类：
+- 树边：第一次到达 v 经过的边
+- 后向边：指向仍在栈中的祖先（包括自环），有向图存在后向边当且仅当有环
+- 前向边：指向已经完成的后代
+- 横跨边：指向已经完成、且不是后代的顶点
+
+无向图中只有树边和后向边，每条边只在第一次被检查时分类
+*/
+
+use super::adjacency_list::{EdgeRef, Graph};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+    Tree,
+    Back,
+    Forward,
+    Cross,
+}
+
+/// 一次完整的深度优先搜索的结果，按顶点或边的下标索引
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DfsResult {
+    pub discovery: Vec<usize>,
+    pub finish: Vec<usize>,
+    /// DFS 森林中的父节点，每棵树的根没有父节点
+    pub parent: Vec<Option<usize>>,
+    /// 每条边的分类，以边的 id 为索引
+    pub edge_kinds: Vec<EdgeKind>,
+    /// 顶点被发现的顺序（先序）
+    pub preorder: Vec<usize>,
+    /// 顶点完成的顺序（后序），反过来就是 DAG 的一个拓扑序
+    pub postorder: Vec<usize>,
+}
+
+impl DfsResult {
+    /// `ancestor` 是否是 `node` 在 DFS 森林中的祖先（包括自身）
+    pub fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+        self.discovery[ancestor] <= self.discovery[node]
+            && self.finish[node] <= self.finish[ancestor]
+    }
+}
+
+/// 使用显式栈的深度优先搜索，不会因为图太深而栈溢出
+pub fn dfs(graph: &Graph) -> DfsResult {
+    let mut state = DfsState::new(graph);
+    for root in 0..graph.node_count() {
+        if state.discovered(root) {
+            continue;
+        }
+        state.discover(root, None);
+        // 栈中保存顶点和它还没检查完的边，和递归版本的调用栈一一对应
+        let mut stack = vec![(root, graph.edges_from(root))];
+        while let Some((node, edges)) = stack.last_mut() {
+            let node = *node;
+            match edges.next() {
+                Some(edge) => {
+                    if state.examine(edge) {
+                        state.discover(edge.to, Some(node));
+                        stack.push((edge.to, graph.edges_from(edge.to)));
+                    }
+                }
+                None => {
+                    state.finish(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+    state.into_result()
+}
+
+/// 递归版本的深度优先搜索，结果和 [`dfs`] 完全相同
+pub fn dfs_recursive(graph: &Graph) -> DfsResult {
+    fn visit(graph: &Graph, state: &mut DfsState, node: usize) {
+        for edge in graph.edges_from(node) {
+            if state.examine(edge) {
+                state.discover(edge.to, Some(node));
+                visit(graph, state, edge.to);
+            }
+        }
+        state.finish(node);
+    }
+
+    let mut state = DfsState::new(graph);
+    for root in 0..graph.node_count() {
+        if !state.discovered(root) {
+            state.discover(root, None);
+            visit(graph, &mut state, root);
+        }
+    }
+    state.into_result()
+}
+
+/// 两个版本共用的时间戳和边分类逻辑
+struct DfsState {
+    directed: bool,
+    clock: usize,
+    discovery: Vec<Option<usize>>,
+    finish: Vec<Option<usize>>,
+    parent: Vec<Option<usize>>,
+    edge_kinds: Vec<Option<EdgeKind>>,
+    preorder: Vec<usize>,
+    postorder: Vec<usize>,
+}
+
+impl DfsState {
+    fn new(graph: &Graph) -> Self {
+        let nodes = graph.node_count();
+        DfsState {
+            directed: graph.is_directed(),
+            clock: 0,
+            discovery: vec![None; nodes],
+            finish: vec![None; nodes],
+            parent: vec![None; nodes],
+            edge_kinds: vec![None; graph.edge_count()],
+            preorder: Vec::with_capacity(nodes),
+            postorder: Vec::with_capacity(nodes),
+        }
+    }
+
+    fn discovered(&self, node: usize) -> bool {
+        self.discovery[node].is_some()
+    }
+
+    fn tick(&mut self) -> Option<usize> {
+        self.clock += 1;
+        Some(self.clock - 1)
+    }
+
+    fn discover(&mut self, node: usize, parent: Option<usize>) {
+        self.discovery[node] = self.tick();
+        self.parent[node] = parent;
+        self.preorder.push(node);
+    }
+
+    fn finish(&mut self, node: usize) {
+        self.finish[node] = self.tick();
+        self.postorder.push(node);
+    }
+
+    /// 给边分类，返回是否应该沿这条边深入
+    fn examine(&mut self, edge: EdgeRef) -> bool {
+        // 无向边从另一端再次被检查时跳过
+        if self.edge_kinds[edge.id].is_some() {
+            return false;
+        }
+        let kind = match (self.discovery[edge.to], self.finish[edge.to]) {
+            (None, _) => EdgeKind::Tree,
+            (Some(_), None) => EdgeKind::Back,
+            (Some(discovered), Some(_)) => {
+                // 无向图中已完成的顶点一定已经从它那一端检查过这条边
+                debug_assert!(self.directed);
+                if self.discovery[edge.from] < Some(discovered) {
+                    EdgeKind::Forward
+                } else {
+                    EdgeKind::Cross
+                }
+            }
+        };
+        self.edge_kinds[edge.id] = Some(kind);
+        kind == EdgeKind::Tree
+    }
+
+    fn into_result(self) -> DfsResult {
+        DfsResult {
+            discovery: self.discovery.into_iter().map(Option::unwrap).collect(),
+            finish: self.finish.into_iter().map(Option::unwrap).collect(),
+            parent: self.parent,
+            edge_kinds: self.edge_kinds.into_iter().map(Option::unwrap).collect(),
+            preorder: self.preorder,
+            postorder: self.postorder,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dfs, dfs_recursive, EdgeKind};
+    use crate::graph::Graph;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn classifies_directed_edges() {
+        // 0 -> 1 -> 2 -> 0 是环，0 -> 2 是前向边，3 -> 1 是横跨边
+        let mut graph = Graph::new_directed(4);
+        let tree_a = graph.add_edge(0, 1);
+        let tree_b = graph.add_edge(1, 2);
+        let back = graph.add_edge(2, 0);
+        let forward = graph.add_edge(0, 2);
+        let cross = graph.add_edge(3, 1);
+        let self_loop = graph.add_edge(3, 3);
+
+        let result = dfs(&graph);
+        assert_eq!(result.edge_kinds[tree_a], EdgeKind::Tree);
+        assert_eq!(result.edge_kinds[tree_b], EdgeKind::Tree);
+        assert_eq!(result.edge_kinds[back], EdgeKind::Back);
+        assert_eq!(result.edge_kinds[forward], EdgeKind::Forward);
+        assert_eq!(result.edge_kinds[cross], EdgeKind::Cross);
+        assert_eq!(result.edge_kinds[self_loop], EdgeKind::Back);
+
+        assert_eq!(result.discovery, vec![0, 1, 2, 6]);
+        assert_eq!(result.finish, vec![5, 4, 3, 7]);
+        assert_eq!(result.parent, vec![None, Some(0), Some(1), None]);
+        assert_eq!(result.preorder, vec![0, 1, 2, 3]);
+        assert_eq!(result.postorder, vec![2, 1, 0, 3]);
+        assert!(result.is_ancestor(0, 2));
+        assert!(!result.is_ancestor(3, 1));
+    }
+
+    #[test]
+    fn undirected_edges_are_tree_or_back() {
+        let mut graph = Graph::new_undirected(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        let back = graph.add_edge(2, 0);
+        // 平行边也是后向边
+        let parallel = graph.add_edge(1, 0);
+        graph.add_edge(2, 3);
+        let result = dfs(&graph);
+        let backs: Vec<usize> = (0..graph.edge_count())
+            .filter(|&id| result.edge_kinds[id] == EdgeKind::Back)
+            .collect();
+        assert_eq!(backs, vec![back, parallel]);
+    }
+
+    #[test]
+    fn iterative_matches_recursive_and_parenthesis_theorem() {
+        let mut rng = Rng::new(1392);
+        for directed in [true, false] {
+            for _ in 0..30 {
+                let nodes = 1 + rng.gen_index(30);
+                let mut graph = if directed {
+                    Graph::new_directed(nodes)
+                } else {
+                    Graph::new_undirected(nodes)
+                };
+                for _ in 0..rng.gen_index(3 * nodes) {
+                    graph.add_edge(rng.gen_index(nodes), rng.gen_index(nodes));
+                }
+                let result = dfs(&graph);
+                assert_eq!(result, dfs_recursive(&graph));
+
+                for u in 0..nodes {
+                    for v in 0..nodes {
+                        // 两个区间要么嵌套要么不相交
+                        let (du, fu, dv, fv) = (
+                            result.discovery[u],
+                            result.finish[u],
+                            result.discovery[v],
+                            result.finish[v],
+                        );
+                        assert!(
+                            fu < dv
+                                || fv < du
+                                || result.is_ancestor(u, v)
+                                || result.is_ancestor(v, u)
+                        );
+                    }
+                }
+                for (id, edge) in graph.edges().iter().enumerate() {
+                    let (u, v) = (edge.from, edge.to);
+                    match result.edge_kinds[id] {
+                        // 无向图的树边可能是从 v 走到 u 的
+                        EdgeKind::Tree => {
+                            assert!(result.parent[v] == Some(u) || result.parent[u] == Some(v))
+                        }
+                        EdgeKind::Back => {
+                            assert!(result.is_ancestor(v, u) || result.is_ancestor(u, v))
+                        }
+                        EdgeKind::Forward => assert!(result.is_ancestor(u, v) && u != v),
+                        EdgeKind::Cross => assert!(result.finish[v] < result.discovery[u]),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deep_path_does_not_overflow_iterative_stack() {
+        let nodes = 200_000;
+        let mut graph = Graph::new_directed(nodes);
+        for node in 1..nodes {
+            graph.add_edge(node - 1, node);
+        }
+        let result = dfs(&graph);
+        assert_eq!(result.finish[0], 2 * nodes - 1);
+    }
+}
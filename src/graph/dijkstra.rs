@@ -0,0 +1,270 @@
+/*!
+定义：
+[戴克斯特拉算法的定义](https://zh.wikipedia.org/wiki/%E6%88%B4%E5%85%8B%E6%96%AF%E7%89%B9%E6%8B%89%E7%AE%97%E6%B3%95)
+求非负权图中单个起点到其余顶点的最短路径。每次从优先队列中取出距离最小的未确定顶点，
+它的距离已经不可能再变小，再用它松弛相邻的顶点。使用索引优先队列时复杂度为 O((V + E) log V)
+*/
+
+use super::adjacency_list::Graph;
+use super::error::GraphError;
+use crate::data_structures::indexed_priority_queue::IndexedPriorityQueue;
+use crate::visualization::Observer;
+
+/// 单源最短路径的结果，按顶点下标索引，不可达的顶点为 None
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPaths {
+    pub source: usize,
+    pub distance: Vec<Option<i64>>,
+    /// 最短路径上的前一个顶点
+    pub predecessor: Vec<Option<usize>>,
+}
+
+impl ShortestPaths {
+    /// 从起点到 `target` 的一条最短路径（包含两端），不可达时返回 None
+    pub fn shortest_path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distance[target]?;
+        let mut path = vec![target];
+        let mut node = target;
+        while let Some(previous) = self.predecessor[node] {
+            path.push(previous);
+            node = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// 图中有负权边时返回 [`GraphError::NegativeWeight`]，某个可达顶点的最短距离超出 i64 时返回
+/// [`GraphError::DistanceOverflow`]；只是某条更长的路径溢出时不影响结果
+pub fn dijkstra(graph: &Graph, source: usize) -> Result<ShortestPaths, GraphError> {
+    dijkstra_observed(graph, source, &mut ())
+}
+
+/// 同 [`dijkstra`]，每确定一个顶点调用一次 [`Observer::on_visit_node`]，
+/// 每次成功松弛调用一次 [`Observer::on_relax_edge`]
+pub fn dijkstra_observed<O>(
+    graph: &Graph,
+    source: usize,
+    observer: &mut O,
+) -> Result<ShortestPaths, GraphError>
+where
+    O: Observer + ?Sized,
+{
+    if let Some((edge, e)) = graph.edges().iter().enumerate().find(|(_, e)| e.weight < 0) {
+        return Err(GraphError::NegativeWeight {
+            edge,
+            weight: e.weight,
+        });
+    }
+
+    let nodes = graph.node_count();
+    let mut distance = vec![None; nodes];
+    let mut predecessor = vec![None; nodes];
+    let mut settled = vec![false; nodes];
+    let mut queue = IndexedPriorityQueue::<i64>::new(nodes);
+    // 有一条路径的长度溢出的顶点，最后仍然没有距离时说明它的最短距离超出了 i64
+    let mut overflowed = vec![false; nodes];
+
+    distance[source] = Some(0);
+    queue.push(source, 0);
+    while let Some((node, dist)) = queue.pop() {
+        settled[node] = true;
+        observer.on_visit_node(node);
+        for edge in graph.edges_from(node) {
+            if settled[edge.to] {
+                continue;
+            }
+            let Some(candidate) = dist.checked_add(edge.weight) else {
+                overflowed[edge.to] = true;
+                continue;
+            };
+            if queue.push_or_decrease(edge.to, candidate) {
+                distance[edge.to] = Some(candidate);
+                predecessor[edge.to] = Some(node);
+                observer.on_relax_edge(node, edge.to, candidate);
+            }
+        }
+    }
+
+    if (0..nodes).any(|node| overflowed[node] && distance[node].is_none()) {
+        return Err(GraphError::DistanceOverflow);
+    }
+    Ok(ShortestPaths {
+        source,
+        distance,
+        predecessor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dijkstra, dijkstra_observed};
+    use crate::graph::{Graph, GraphError};
+    use crate::utils::random::Rng;
+    use crate::visualization::Step;
+
+    #[test]
+    fn finds_weighted_shortest_paths() {
+        let mut graph = Graph::new_directed(6);
+        for (from, to, weight) in [
+            (0, 1, 7),
+            (0, 2, 9),
+            (0, 5, 14),
+            (1, 2, 10),
+            (1, 3, 15),
+            (2, 3, 11),
+            (2, 5, 2),
+            (3, 4, 6),
+            (5, 4, 9),
+        ] {
+            graph.add_weighted_edge(from, to, weight);
+        }
+        let paths = dijkstra(&graph, 0).unwrap();
+        assert_eq!(
+            paths.distance,
+            vec![Some(0), Some(7), Some(9), Some(20), Some(20), Some(11)]
+        );
+        assert_eq!(paths.shortest_path_to(4), Some(vec![0, 2, 5, 4]));
+        assert_eq!(paths.shortest_path_to(0), Some(vec![0]));
+
+        let paths = dijkstra(&graph, 3).unwrap();
+        assert_eq!(paths.distance[0], None);
+        assert_eq!(paths.shortest_path_to(0), None);
+    }
+
+    #[test]
+    fn rejects_negative_edges() {
+        let mut graph = Graph::new_directed(3);
+        graph.add_weighted_edge(0, 1, 2);
+        graph.add_weighted_edge(1, 2, -1);
+        assert_eq!(
+            dijkstra(&graph, 0),
+            Err(GraphError::NegativeWeight {
+                edge: 1,
+                weight: -1
+            })
+        );
+    }
+
+    #[test]
+    fn reports_distance_overflow() {
+        let mut graph = Graph::new_directed(3);
+        graph.add_weighted_edge(0, 1, i64::MAX);
+        graph.add_weighted_edge(1, 2, 1);
+        assert_eq!(dijkstra(&graph, 0), Err(GraphError::DistanceOverflow));
+        // 不经过溢出的边时正常返回
+        assert_eq!(dijkstra(&graph, 1).unwrap().distance[2], Some(1));
+
+        // 0 -> 1 -> 2 溢出，但最短距离 0 -> 2 仍在 i64 范围内
+        let mut graph = Graph::new_directed(3);
+        graph.add_weighted_edge(0, 1, i64::MAX);
+        graph.add_weighted_edge(0, 2, i64::MAX);
+        graph.add_weighted_edge(1, 2, 1);
+        let paths = dijkstra(&graph, 0).unwrap();
+        assert_eq!(
+            paths.distance,
+            vec![Some(0), Some(i64::MAX), Some(i64::MAX)]
+        );
+        assert_eq!(paths.shortest_path_to(2), Some(vec![0, 2]));
+    }
+
+    #[test]
+    fn traces_visits_in_distance_order() {
+        let mut graph = Graph::new_directed(4);
+        for (from, to, weight) in [(0, 1, 5), (0, 2, 1), (2, 1, 2), (1, 3, 1)] {
+            graph.add_weighted_edge(from, to, weight);
+        }
+        let mut steps = vec![];
+        let paths = dijkstra_observed(&graph, 0, &mut steps).unwrap();
+        assert_eq!(paths, dijkstra(&graph, 0).unwrap());
+        assert_eq!(
+            steps,
+            vec![
+                Step::Visit(0),
+                Step::Relax {
+                    from: 0,
+                    to: 1,
+                    distance: 5
+                },
+                Step::Relax {
+                    from: 0,
+                    to: 2,
+                    distance: 1
+                },
+                Step::Visit(2),
+                Step::Relax {
+                    from: 2,
+                    to: 1,
+                    distance: 3
+                },
+                Step::Visit(1),
+                Step::Relax {
+                    from: 1,
+                    to: 3,
+                    distance: 4
+                },
+                Step::Visit(3),
+            ]
+        );
+    }
+
+    /// 每轮用所有边松弛一次，重复 V - 1 轮
+    fn relax_all(graph: &Graph, source: usize) -> Vec<Option<i64>> {
+        let mut distance: Vec<Option<i64>> = vec![None; graph.node_count()];
+        distance[source] = Some(0);
+        for _ in 1..graph.node_count() {
+            for node in 0..graph.node_count() {
+                let Some(d) = distance[node] else { continue };
+                for edge in graph.edges_from(node) {
+                    let Some(candidate) = d.checked_add(edge.weight) else {
+                        continue;
+                    };
+                    if distance[edge.to].is_none_or(|current| candidate < current) {
+                        distance[edge.to] = Some(candidate);
+                    }
+                }
+            }
+        }
+        distance
+    }
+
+    #[test]
+    fn matches_brute_force_relaxation() {
+        let mut rng = Rng::new(1393);
+        for directed in [true, false] {
+            for _ in 0..30 {
+                let nodes = 1 + rng.gen_index(25);
+                let mut graph = if directed {
+                    Graph::new_directed(nodes)
+                } else {
+                    Graph::new_undirected(nodes)
+                };
+                for _ in 0..rng.gen_index(4 * nodes) {
+                    let (from, to) = (rng.gen_index(nodes), rng.gen_index(nodes));
+                    graph.add_weighted_edge(from, to, rng.gen_range(0, 20));
+                }
+                let source = rng.gen_index(nodes);
+                let paths = dijkstra(&graph, source).unwrap();
+                assert_eq!(paths.distance, relax_all(&graph, source));
+
+                // 还原出的路径长度等于最短距离
+                for target in 0..nodes {
+                    if let Some(path) = paths.shortest_path_to(target) {
+                        let length: i64 = path
+                            .windows(2)
+                            .map(|w| {
+                                graph
+                                    .edges_from(w[0])
+                                    .filter(|e| e.to == w[1])
+                                    .map(|e| e.weight)
+                                    .min()
+                                    .unwrap()
+                            })
+                            .sum();
+                        assert_eq!(Some(length), paths.distance[target]);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fmt;
+
+/// 图算法在输入不满足前提条件时返回的错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// 算法要求边权非负，但 id 为 `edge` 的边权为负数
+    NegativeWeight { edge: usize, weight: i64 },
+    /// 图中存在负权环，`node` 是环上的一个顶点
+    NegativeCycle { node: usize },
+    /// 算法要求二分图，`odd_cycle` 是图中的一个奇环
+    NotBipartite { odd_cycle: Vec<usize> },
+    /// 路径长度超出了 i64 的范围
+    DistanceOverflow,
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::NegativeWeight { edge, weight } => {
+                write!(f, "edge {edge} has negative weight {weight}")
+            }
+            GraphError::NegativeCycle { node } => {
+                write!(f, "node {node} lies on a negative cycle")
+            }
+            GraphError::NotBipartite { odd_cycle } => {
+                write!(f, "graph is not bipartite, odd cycle {odd_cycle:?}")
+            }
+            GraphError::DistanceOverflow => write!(f, "path length overflows i64"),
+        }
+    }
+}
+
+impl Error for GraphError {}
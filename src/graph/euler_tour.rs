@@ -0,0 +1,209 @@
+/*!
+定义：
+[欧拉序](https://en.wikipedia.org/wiki/Euler_tour_technique)
+对有根树做一次深度优先搜索，记录每个顶点进入的时间 tin 和离开时的时间 tout（只在进入时计时）。
+一棵子树在 DFS 中是连续访问的，所以 v 的子树恰好对应区间 [tin[v], tout[v])，
+u 是 v 的祖先当且仅当 v 的区间嵌套在 u 的区间里。
+把顶点的值按 tin 排列存入树状数组，子树求和就变成了区间求和，单点修改也是 O(log n)
+*/
+
+use super::adjacency_list::Graph;
+use crate::data_structures::fenwick_tree::FenwickTree;
+
+/// 有根树的欧拉序
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EulerTour {
+    pub root: usize,
+    /// 进入顶点的时间，从 0 开始
+    pub tin: Vec<usize>,
+    /// 离开顶点时已经进入过的顶点数，子树对应区间 [tin, tout)
+    pub tout: Vec<usize>,
+    /// 按 tin 排列的顶点，`order[tin[v]] == v`
+    pub order: Vec<usize>,
+}
+
+impl EulerTour {
+    /// 把无向图 `tree` 当作以 `root` 为根的树，从 `root` 不可达的顶点不在树中，时间为 usize::MAX
+    pub fn new(tree: &Graph, root: usize) -> Self {
+        let nodes = tree.node_count();
+        let mut tin = vec![usize::MAX; nodes];
+        let mut tout = vec![usize::MAX; nodes];
+        let mut order = vec![];
+
+        // 显式栈，深度很大的树（比如一条链）也不会栈溢出
+        tin[root] = 0;
+        order.push(root);
+        let mut stack = vec![(root, tree.neighbors(root))];
+        while let Some((node, neighbors)) = stack.last_mut() {
+            let node = *node;
+            match neighbors.find(|&next| tin[next] == usize::MAX) {
+                Some(next) => {
+                    tin[next] = order.len();
+                    order.push(next);
+                    stack.push((next, tree.neighbors(next)));
+                }
+                None => {
+                    tout[node] = order.len();
+                    stack.pop();
+                }
+            }
+        }
+
+        EulerTour {
+            root,
+            tin,
+            tout,
+            order,
+        }
+    }
+
+    /// `ancestor` 是否是 `node` 的祖先（包括自身）
+    pub fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+        self.tin[ancestor] <= self.tin[node] && self.tout[node] <= self.tout[ancestor]
+    }
+
+    /// `node` 的子树大小
+    pub fn subtree_size(&self, node: usize) -> usize {
+        self.tout[node] - self.tin[node]
+    }
+}
+
+/// 子树求和、单点修改
+#[derive(Debug, Clone)]
+pub struct SubtreeQuery {
+    tour: EulerTour,
+    sums: FenwickTree,
+}
+
+impl SubtreeQuery {
+    /// 所有顶点的值为 0
+    pub fn new(tree: &Graph, root: usize) -> Self {
+        let tour = EulerTour::new(tree, root);
+        let sums = FenwickTree::new(tour.order.len());
+        SubtreeQuery { tour, sums }
+    }
+
+    /// 以 `values[v]` 作为顶点 v 的初始值
+    pub fn with_values(tree: &Graph, root: usize, values: &[i64]) -> Self {
+        let tour = EulerTour::new(tree, root);
+        let flattened: Vec<i64> = tour.order.iter().map(|&v| values[v]).collect();
+        let sums = FenwickTree::from_slice(&flattened);
+        SubtreeQuery { tour, sums }
+    }
+
+    pub fn tour(&self) -> &EulerTour {
+        &self.tour
+    }
+
+    pub fn add(&mut self, node: usize, delta: i64) {
+        self.sums.add(self.tour.tin[node], delta);
+    }
+
+    pub fn set(&mut self, node: usize, value: i64) {
+        self.sums.set(self.tour.tin[node], value);
+    }
+
+    pub fn value(&self, node: usize) -> i64 {
+        self.sums.get(self.tour.tin[node])
+    }
+
+    /// `node` 的子树中所有顶点的值之和
+    pub fn subtree_sum(&self, node: usize) -> i64 {
+        self.sums
+            .range_sum(self.tour.tin[node]..self.tour.tout[node])
+    }
+
+    pub fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+        self.tour.is_ancestor(ancestor, node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EulerTour, SubtreeQuery};
+    use crate::graph::Graph;
+    use crate::utils::random::Rng;
+
+    ///        0
+    ///      / | \
+    ///     1  2  3
+    ///    / \     \
+    ///   4   5     6
+    fn sample_tree() -> Graph {
+        let mut tree = Graph::new_undirected(7);
+        for (a, b) in [(0, 1), (0, 2), (0, 3), (1, 4), (1, 5), (3, 6)] {
+            tree.add_edge(a, b);
+        }
+        tree
+    }
+
+    #[test]
+    fn flattens_tree() {
+        let tour = EulerTour::new(&sample_tree(), 0);
+        assert_eq!(tour.order, vec![0, 1, 4, 5, 2, 3, 6]);
+        assert_eq!(tour.tin, vec![0, 1, 4, 5, 2, 3, 6]);
+        assert_eq!(tour.tout, vec![7, 4, 5, 7, 3, 4, 7]);
+        assert_eq!(tour.subtree_size(1), 3);
+        assert!(tour.is_ancestor(0, 6));
+        assert!(tour.is_ancestor(1, 5));
+        assert!(!tour.is_ancestor(1, 6));
+        assert!(!tour.is_ancestor(4, 1));
+    }
+
+    #[test]
+    fn subtree_sums_and_updates() {
+        let values = [1, 2, 3, 4, 5, 6, 7];
+        let mut query = SubtreeQuery::with_values(&sample_tree(), 0, &values);
+        assert_eq!(query.subtree_sum(0), 28);
+        assert_eq!(query.subtree_sum(1), 13);
+        assert_eq!(query.subtree_sum(3), 11);
+        query.add(5, 10);
+        query.set(0, 0);
+        assert_eq!(query.subtree_sum(1), 23);
+        assert_eq!(query.subtree_sum(0), 37);
+        assert_eq!(query.value(5), 16);
+        assert!(query.is_ancestor(3, 6));
+    }
+
+    #[test]
+    fn matches_naive_subtree_walk() {
+        let mut rng = Rng::new(1413);
+        let n = 200;
+        let mut tree = Graph::new_undirected(n);
+        let mut parent = vec![None; n];
+        for (v, slot) in parent.iter_mut().enumerate().skip(1) {
+            let p = rng.gen_index(v);
+            tree.add_edge(p, v);
+            *slot = Some(p);
+        }
+        let mut values: Vec<i64> = (0..n).map(|_| rng.gen_range(-100, 100)).collect();
+        let mut query = SubtreeQuery::new(&tree, 0);
+        for (v, &value) in values.iter().enumerate() {
+            query.set(v, value);
+        }
+
+        let is_ancestor = |a: usize, mut v: usize| loop {
+            if v == a {
+                return true;
+            }
+            match parent[v] {
+                Some(p) => v = p,
+                None => return false,
+            }
+        };
+        for _ in 0..200 {
+            let v = rng.gen_index(n);
+            let delta = rng.gen_range(-50, 50);
+            query.add(v, delta);
+            values[v] += delta;
+
+            let u = rng.gen_index(n);
+            let expected: i64 = (0..n)
+                .filter(|&w| is_ancestor(u, w))
+                .map(|w| values[w])
+                .sum();
+            assert_eq!(query.subtree_sum(u), expected);
+            assert_eq!(query.is_ancestor(u, v), is_ancestor(u, v));
+        }
+    }
+}
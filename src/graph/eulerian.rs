@@ -0,0 +1,219 @@
+/*!
+定义：
+[欧拉路径的定义](https://zh.wikipedia.org/wiki/%E4%B8%80%E7%AC%94%E7%94%BB%E9%97%AE%E9%A2%98)
+经过每条边恰好一次的路径，起点和终点相同时称为欧拉回路。存在条件（所有的边连通）：
+- 无向图：奇度顶点个数为 0（回路）或 2（路径，从一个奇度顶点出发）
+- 有向图：每个顶点入度等于出度（回路），或者恰好一个顶点出度比入度大 1 作为起点、
+  一个顶点入度比出度大 1 作为终点（路径）
+
+[Hierholzer 算法](https://en.wikipedia.org/wiki/Eulerian_path#Hierholzer's_algorithm)
+从起点出发一直走没用过的边，走不下去时把当前顶点加入结果并回退，最后把结果反转。复杂度 O(V + E)
+*/
+
+use super::adjacency_list::{EdgeRef, Graph};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EulerianPath {
+    /// 依次经过的顶点，比边数多一个
+    pub nodes: Vec<usize>,
+    /// 依次经过的边的 id
+    pub edges: Vec<usize>,
+}
+
+impl EulerianPath {
+    /// 起点和终点相同
+    pub fn is_circuit(&self) -> bool {
+        self.nodes.first() == self.nodes.last()
+    }
+}
+
+/// 不存在欧拉路径时返回 None；没有边的图返回空路径
+pub fn eulerian_path(graph: &Graph) -> Option<EulerianPath> {
+    if graph.edge_count() == 0 {
+        return Some(EulerianPath {
+            nodes: vec![],
+            edges: vec![],
+        });
+    }
+    let start = find_start(graph)?;
+
+    // Hierholzer：栈中保存 (顶点, 到达它经过的边)
+    let adjacency: Vec<Vec<EdgeRef>> = (0..graph.node_count())
+        .map(|node| graph.edges_from(node).collect())
+        .collect();
+    let mut used = vec![false; graph.edge_count()];
+    // 每个顶点下一条要检查的边，用过的边不会再回头检查
+    let mut next_edge = vec![0; graph.node_count()];
+    let mut stack: Vec<(usize, Option<usize>)> = vec![(start, None)];
+    let mut nodes = vec![];
+    let mut edges = vec![];
+    while let Some(&(node, arrived_by)) = stack.last() {
+        while next_edge[node] < adjacency[node].len() && used[adjacency[node][next_edge[node]].id] {
+            next_edge[node] += 1;
+        }
+        match adjacency[node].get(next_edge[node]) {
+            Some(edge) => {
+                used[edge.id] = true;
+                stack.push((edge.to, Some(edge.id)));
+            }
+            None => {
+                stack.pop();
+                nodes.push(node);
+                edges.extend(arrived_by);
+            }
+        }
+    }
+
+    // 有边和起点不连通
+    if edges.len() < graph.edge_count() {
+        return None;
+    }
+    nodes.reverse();
+    edges.reverse();
+    Some(EulerianPath { nodes, edges })
+}
+
+/// 根据度数条件选出起点，条件不满足时返回 None
+fn find_start(graph: &Graph) -> Option<usize> {
+    let nodes = graph.node_count();
+    // 有向图中 balance = 出度 - 入度；无向图中记录度数（自环算两次）
+    let mut balance = vec![0i64; nodes];
+    for edge in graph.edges() {
+        balance[edge.from] += 1;
+        if graph.is_directed() {
+            balance[edge.to] -= 1;
+        } else {
+            balance[edge.to] += 1;
+        }
+    }
+    let has_edges = |node: usize| graph.degree(node) > 0;
+
+    if graph.is_directed() {
+        let starts: Vec<usize> = (0..nodes).filter(|&n| balance[n] == 1).collect();
+        let ends = (0..nodes).filter(|&n| balance[n] == -1).count();
+        if balance.iter().any(|b| b.abs() > 1) {
+            return None;
+        }
+        match (starts.as_slice(), ends) {
+            ([], 0) => (0..nodes).find(|&n| has_edges(n)),
+            (&[start], 1) => Some(start),
+            _ => None,
+        }
+    } else {
+        let odd: Vec<usize> = (0..nodes).filter(|&n| balance[n] % 2 == 1).collect();
+        match odd.as_slice() {
+            [] => (0..nodes).find(|&n| has_edges(n)),
+            &[start, _] => Some(start),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eulerian_path;
+    use crate::graph::Graph;
+
+    /// 检查路径恰好使用每条边一次，且相邻顶点和边一致
+    fn check(graph: &Graph, expect_circuit: bool) {
+        let path = eulerian_path(graph).expect("path should exist");
+        assert_eq!(path.edges.len(), graph.edge_count());
+        assert_eq!(path.nodes.len(), graph.edge_count() + 1);
+        let mut edges = path.edges.clone();
+        edges.sort();
+        assert_eq!(edges, (0..graph.edge_count()).collect::<Vec<_>>());
+        for (i, &id) in path.edges.iter().enumerate() {
+            let edge = graph.edge(id);
+            let (a, b) = (path.nodes[i], path.nodes[i + 1]);
+            if graph.is_directed() {
+                assert_eq!((edge.from, edge.to), (a, b));
+            } else {
+                assert_eq!(edge.other(a), b);
+            }
+        }
+        assert_eq!(path.is_circuit(), expect_circuit);
+    }
+
+    #[test]
+    fn konigsberg_bridges_have_no_eulerian_path() {
+        // 四块陆地、七座桥，四个顶点的度数都是奇数
+        let mut graph = Graph::new_undirected(4);
+        for (a, b) in [(0, 1), (0, 1), (0, 2), (0, 2), (0, 3), (1, 3), (2, 3)] {
+            graph.add_edge(a, b);
+        }
+        assert_eq!(eulerian_path(&graph), None);
+
+        // 拆掉一座桥后只剩两个奇度顶点
+        let mut graph = Graph::new_undirected(4);
+        for (a, b) in [(0, 1), (0, 1), (0, 2), (0, 2), (0, 3), (1, 3)] {
+            graph.add_edge(a, b);
+        }
+        check(&graph, false);
+        let path = eulerian_path(&graph).unwrap();
+        let mut ends = [path.nodes[0], *path.nodes.last().unwrap()];
+        ends.sort();
+        assert_eq!(ends, [0, 1]);
+    }
+
+    #[test]
+    fn de_bruijn_sequence_from_circuit() {
+        // B(2, 4)：顶点是 3 位二进制串，边 v -> (v << 1 | b) 表示 4 位串
+        let (k, n) = (2, 4);
+        let nodes = k * k * k;
+        let mut graph = Graph::new_directed(nodes);
+        for v in 0..nodes {
+            for b in 0..k {
+                graph.add_edge(v, (v * k + b) % nodes);
+            }
+        }
+        check(&graph, true);
+
+        // 沿回路每条边贡献一位，得到的循环序列包含所有 4 位二进制串恰好一次
+        let path = eulerian_path(&graph).unwrap();
+        let bits: Vec<usize> = path.nodes[1..].iter().map(|v| v % k).collect();
+        let mut seen = vec![false; 1 << n];
+        for i in 0..bits.len() {
+            let word = (0..n).fold(0, |acc, j| acc * 2 + bits[(i + j) % bits.len()]);
+            assert!(!seen[word]);
+            seen[word] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn directed_paths_and_failures() {
+        let mut graph = Graph::new_directed(4);
+        for (a, b) in [(0, 1), (1, 2), (2, 0), (0, 3), (3, 3)] {
+            graph.add_edge(a, b);
+        }
+        check(&graph, false);
+        assert_eq!(eulerian_path(&graph).unwrap().nodes[0], 0);
+
+        // 度数满足条件但边不连通
+        let mut split = Graph::new_directed(4);
+        for (a, b) in [(0, 1), (1, 0), (2, 3), (3, 2)] {
+            split.add_edge(a, b);
+        }
+        assert_eq!(eulerian_path(&split), None);
+
+        // 有两个顶点出度比入度大
+        let mut fork = Graph::new_directed(3);
+        fork.add_edge(0, 2);
+        fork.add_edge(1, 2);
+        assert_eq!(eulerian_path(&fork), None);
+
+        assert_eq!(
+            eulerian_path(&Graph::new_directed(3)).unwrap().edges,
+            vec![]
+        );
+    }
+
+    #[test]
+    fn undirected_circuit_with_self_loop() {
+        let mut graph = Graph::new_undirected(3);
+        for (a, b) in [(0, 1), (1, 2), (2, 0), (1, 1)] {
+            graph.add_edge(a, b);
+        }
+        check(&graph, true);
+    }
+}
@@ -0,0 +1,142 @@
+/*!
+定义：
+[Dinic 算法](https://en.wikipedia.org/wiki/Dinic%27s_algorithm)
+每个阶段先用 BFS 按到源点的距离给顶点分层，只保留从第 i 层指向第 i + 1 层的残量弧，
+再用 DFS 在这个分层图上反复增广直到阻塞。每个顶点记录下一条要尝试的弧（当前弧优化），
+已经走不通的弧不会再试。阶段数不超过 V，总复杂度 O(V²E)，单位容量网络上是 O(E√V)
+*/
+
+use super::network::FlowNetwork;
+use std::collections::VecDeque;
+
+/// 从 `source` 到 `sink` 的最大流，流量保存在网络中
+pub fn dinic(network: &mut FlowNetwork, source: usize, sink: usize) -> i64 {
+    if source == sink {
+        return 0;
+    }
+    let mut total = 0;
+    while let Some(level) = build_levels(network, source, sink) {
+        let mut next_arc = vec![0; network.node_count()];
+        loop {
+            let pushed = blocking_flow(network, &level, &mut next_arc, source, sink, i64::MAX);
+            if pushed == 0 {
+                break;
+            }
+            total += pushed;
+        }
+    }
+    total
+}
+
+/// 残量网络中每个顶点到源点的距离，汇点不可达时返回 None
+fn build_levels(network: &FlowNetwork, source: usize, sink: usize) -> Option<Vec<usize>> {
+    let mut level = vec![usize::MAX; network.node_count()];
+    level[source] = 0;
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        for &arc in &network.adjacency[node] {
+            let to = network.arcs[arc].to;
+            if network.arcs[arc].residual() > 0 && level[to] == usize::MAX {
+                level[to] = level[node] + 1;
+                queue.push_back(to);
+            }
+        }
+    }
+    (level[sink] != usize::MAX).then_some(level)
+}
+
+/// 从 `node` 出发沿分层图推送不超过 `limit` 的流量，返回实际推送的流量
+fn blocking_flow(
+    network: &mut FlowNetwork,
+    level: &[usize],
+    next_arc: &mut [usize],
+    node: usize,
+    sink: usize,
+    limit: i64,
+) -> i64 {
+    if node == sink {
+        return limit;
+    }
+    while next_arc[node] < network.adjacency[node].len() {
+        let arc = network.adjacency[node][next_arc[node]];
+        let to = network.arcs[arc].to;
+        let residual = network.arcs[arc].residual();
+        if residual > 0 && level[to] == level[node] + 1 {
+            let pushed = blocking_flow(network, level, next_arc, to, sink, limit.min(residual));
+            if pushed > 0 {
+                network.push(arc, pushed);
+                return pushed;
+            }
+        }
+        // 这条弧已经饱和或走不到汇点，本阶段不再尝试
+        next_arc[node] += 1;
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dinic;
+    use crate::graph::flow::{edmonds_karp, min_cut, FlowNetwork};
+    use crate::utils::random::Rng;
+
+    /// 检查容量限制和流量守恒，返回源点的净流出
+    fn check_feasible(network: &FlowNetwork, source: usize, sink: usize) -> i64 {
+        let mut excess = vec![0; network.node_count()];
+        for id in 0..network.edge_count() {
+            let (from, to) = network.endpoints(id);
+            let flow = network.flow(id);
+            assert!(0 <= flow && flow <= network.capacity(id));
+            excess[from] -= flow;
+            excess[to] += flow;
+        }
+        for (node, &e) in excess.iter().enumerate() {
+            if node != source && node != sink {
+                assert_eq!(e, 0);
+            }
+        }
+        -excess[source]
+    }
+
+    #[test]
+    fn agrees_with_edmonds_karp_on_random_networks() {
+        let mut rng = Rng::new(1406);
+        for _ in 0..100 {
+            let nodes = 2 + rng.gen_index(15);
+            let mut network = FlowNetwork::new(nodes);
+            for _ in 0..rng.gen_index(5 * nodes) {
+                let (from, to) = (rng.gen_index(nodes), rng.gen_index(nodes));
+                network.add_edge(from, to, rng.gen_range(0, 20));
+            }
+            let (source, sink) = (0, nodes - 1);
+
+            let fast = dinic(&mut network, source, sink);
+            assert_eq!(check_feasible(&network, source, sink), fast);
+            // 最大流最小割定理
+            let cut = min_cut(&network, source);
+            assert_eq!(cut.capacity, fast);
+            assert!(!cut.source_side[sink]);
+
+            network.reset();
+            let slow = edmonds_karp(&mut network, source, sink);
+            assert_eq!(fast, slow);
+            assert_eq!(check_feasible(&network, source, sink), slow);
+        }
+    }
+
+    #[test]
+    fn bipartite_matching_as_unit_flow() {
+        // 源点 0，左部 1..=3，右部 4..=6，汇点 7
+        let mut network = FlowNetwork::new(8);
+        for l in 1..=3 {
+            network.add_edge(0, l, 1);
+        }
+        for r in 4..=6 {
+            network.add_edge(r, 7, 1);
+        }
+        for (l, r) in [(1, 4), (1, 5), (2, 4), (3, 4)] {
+            network.add_edge(l, r, 1);
+        }
+        assert_eq!(dinic(&mut network, 0, 7), 2);
+    }
+}
@@ -0,0 +1,93 @@
+/*!
+定义：
+[Edmonds-Karp 算法的定义](https://zh.wikipedia.org/wiki/Edmonds-Karp%E7%AE%97%E6%B3%95)
+Ford-Fulkerson 方法的一种实现：每次用广度优先搜索在残量网络中找一条边数最少的增广路，
+沿它推送瓶颈容量的流量。增广次数不超过 O(VE)，总复杂度 O(VE²)
+*/
+
+use super::network::FlowNetwork;
+use std::collections::VecDeque;
+
+/// 从 `source` 到 `sink` 的最大流，流量保存在网络中
+pub fn edmonds_karp(network: &mut FlowNetwork, source: usize, sink: usize) -> i64 {
+    let mut total = 0;
+    while let Some(path) = augmenting_path(network, source, sink) {
+        let bottleneck = path
+            .iter()
+            .map(|&arc| network.arcs[arc].residual())
+            .min()
+            .unwrap_or(0);
+        for &arc in &path {
+            network.push(arc, bottleneck);
+        }
+        total += bottleneck;
+    }
+    total
+}
+
+/// 残量网络中从源点到汇点边数最少的路径，按顺序返回经过的弧
+fn augmenting_path(network: &FlowNetwork, source: usize, sink: usize) -> Option<Vec<usize>> {
+    if source == sink {
+        return None;
+    }
+    let mut parent_arc = vec![None; network.node_count()];
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        for &arc in &network.adjacency[node] {
+            let to = network.arcs[arc].to;
+            if network.arcs[arc].residual() > 0 && to != source && parent_arc[to].is_none() {
+                parent_arc[to] = Some(arc);
+                if to == sink {
+                    // 沿父弧回溯到源点，反向弧 arc ^ 1 指向弧的起点
+                    let mut path = vec![];
+                    let mut node = sink;
+                    while let Some(arc) = parent_arc[node] {
+                        path.push(arc);
+                        node = network.arcs[arc ^ 1].to;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                queue.push_back(to);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::edmonds_karp;
+    use crate::graph::flow::{min_cut, FlowNetwork};
+
+    #[test]
+    fn computes_classic_max_flow() {
+        // CLRS 中的例子，最大流为 23
+        let mut network = FlowNetwork::new(6);
+        for (from, to, capacity) in [
+            (0, 1, 16),
+            (0, 2, 13),
+            (1, 3, 12),
+            (2, 1, 4),
+            (2, 4, 14),
+            (3, 2, 9),
+            (3, 5, 20),
+            (4, 3, 7),
+            (4, 5, 4),
+        ] {
+            network.add_edge(from, to, capacity);
+        }
+        assert_eq!(edmonds_karp(&mut network, 0, 5), 23);
+        let cut = min_cut(&network, 0);
+        assert_eq!(cut.capacity, 23);
+        assert_eq!(cut.source_side, vec![true, true, true, false, true, false]);
+    }
+
+    #[test]
+    fn disconnected_sink_has_zero_flow() {
+        let mut network = FlowNetwork::new(3);
+        network.add_edge(0, 1, 10);
+        assert_eq!(edmonds_karp(&mut network, 0, 2), 0);
+        assert_eq!(edmonds_karp(&mut network, 0, 0), 0);
+    }
+}
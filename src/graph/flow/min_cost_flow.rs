@@ -0,0 +1,235 @@
+/*!
+定义：
+[最小费用流](https://zh.wikipedia.org/wiki/%E6%9C%80%E5%B0%8F%E8%B4%B9%E7%94%A8%E6%B5%81%E9%97%AE%E9%A2%98)
+在流量最大的前提下让总费用 Σ flow × cost 最小。连续最短路算法每次沿残量网络中费用最小的路径增广。
+残量网络中有负费用的反向弧，所以先用 Bellman-Ford（SPFA）求出初始势能 h，
+之后按约翰逊重赋权的思路用约化费用 `cost + h[u] - h[v]`（总是非负）跑 Dijkstra，
+每轮结束把最短距离累加到势能上，约化费用在新的残量网络上依然非负
+*/
+
+use super::network::FlowNetwork;
+use crate::data_structures::indexed_priority_queue::IndexedPriorityQueue;
+use crate::graph::error::GraphError;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCostFlow {
+    pub flow: i64,
+    pub cost: i64,
+    /// 每条边上的流量，以边的 id 为索引
+    pub edge_flows: Vec<i64>,
+}
+
+/// 从 `source` 到 `sink` 的最小费用最大流，流量保存在网络中
+///
+/// 从源点可达的部分存在负费用环时返回 [`GraphError::NegativeCycle`]
+pub fn min_cost_max_flow(
+    network: &mut FlowNetwork,
+    source: usize,
+    sink: usize,
+) -> Result<MinCostFlow, GraphError> {
+    let mut potential = initial_potential(network, source)?;
+    let (mut flow, mut cost) = (0, 0);
+    loop {
+        let (distance, parent_arc) = reduced_dijkstra(network, &potential, source);
+        if source == sink || distance[sink].is_none() {
+            break;
+        }
+        for (h, d) in potential.iter_mut().zip(&distance) {
+            // 不可达的顶点之后也不会再可达，势能保持不变即可
+            if let Some(d) = d {
+                *h += d;
+            }
+        }
+
+        let mut path = vec![];
+        let mut node = sink;
+        while let Some(arc) = parent_arc[node] {
+            path.push(arc);
+            node = network.arcs[arc ^ 1].to;
+        }
+        let bottleneck = path
+            .iter()
+            .map(|&arc| network.arcs[arc].residual())
+            .min()
+            .unwrap();
+        for &arc in &path {
+            network.push(arc, bottleneck);
+            cost += bottleneck * network.arcs[arc].cost;
+        }
+        flow += bottleneck;
+    }
+
+    Ok(MinCostFlow {
+        flow,
+        cost,
+        edge_flows: (0..network.edge_count())
+            .map(|id| network.flow(id))
+            .collect(),
+    })
+}
+
+/// SPFA 求残量网络中源点到各顶点的最短费用作为初始势能，不可达的顶点势能为 0
+fn initial_potential(network: &FlowNetwork, source: usize) -> Result<Vec<i64>, GraphError> {
+    let nodes = network.node_count();
+    let mut distance = vec![None; nodes];
+    let mut in_queue = vec![false; nodes];
+    // 入队次数达到 V 说明存在负环
+    let mut enqueued = vec![0; nodes];
+    let mut queue = VecDeque::from([source]);
+    distance[source] = Some(0);
+    while let Some(node) = queue.pop_front() {
+        in_queue[node] = false;
+        let d: i64 = distance[node].unwrap();
+        for &arc in &network.adjacency[node] {
+            let arc = network.arcs[arc];
+            if arc.residual() <= 0 {
+                continue;
+            }
+            let candidate = d + arc.cost;
+            if distance[arc.to].is_none_or(|current| candidate < current) {
+                distance[arc.to] = Some(candidate);
+                if !in_queue[arc.to] {
+                    enqueued[arc.to] += 1;
+                    if enqueued[arc.to] >= nodes {
+                        return Err(GraphError::NegativeCycle { node: arc.to });
+                    }
+                    in_queue[arc.to] = true;
+                    queue.push_back(arc.to);
+                }
+            }
+        }
+    }
+    Ok(distance.into_iter().map(|d| d.unwrap_or(0)).collect())
+}
+
+/// 按约化费用做 Dijkstra，返回约化距离和每个顶点的父弧
+fn reduced_dijkstra(
+    network: &FlowNetwork,
+    potential: &[i64],
+    source: usize,
+) -> (Vec<Option<i64>>, Vec<Option<usize>>) {
+    let nodes = network.node_count();
+    let mut distance = vec![None; nodes];
+    let mut parent_arc = vec![None; nodes];
+    let mut settled = vec![false; nodes];
+    let mut queue = IndexedPriorityQueue::new(nodes);
+    distance[source] = Some(0);
+    queue.push(source, 0);
+    while let Some((node, d)) = queue.pop() {
+        settled[node] = true;
+        for &id in &network.adjacency[node] {
+            let arc = network.arcs[id];
+            if arc.residual() <= 0 || settled[arc.to] {
+                continue;
+            }
+            let reduced = arc.cost + potential[node] - potential[arc.to];
+            debug_assert!(reduced >= 0);
+            if queue.push_or_decrease(arc.to, d + reduced) {
+                distance[arc.to] = Some(d + reduced);
+                parent_arc[arc.to] = Some(id);
+            }
+        }
+    }
+    (distance, parent_arc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::min_cost_max_flow;
+    use crate::graph::flow::{dinic, FlowNetwork};
+    use crate::graph::GraphError;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn solves_transportation_problem() {
+        // 两个仓库（供应 20、30）运往三个商店（需求 10、25、15），单位运费如下
+        let supply = [20, 30];
+        let demand = [10, 25, 15];
+        let costs = [[8, 6, 10], [9, 12, 13]];
+        let (source, sink) = (0, 6);
+        let mut network = FlowNetwork::new(7);
+        for (w, &s) in supply.iter().enumerate() {
+            network.add_edge(source, 1 + w, s);
+        }
+        for (s, &d) in demand.iter().enumerate() {
+            network.add_edge(3 + s, sink, d);
+        }
+        let mut routes = vec![];
+        for (w, row) in costs.iter().enumerate() {
+            for (s, &cost) in row.iter().enumerate() {
+                routes.push(network.add_edge_with_cost(1 + w, 3 + s, i64::MAX / 4, cost));
+            }
+        }
+
+        let result = min_cost_max_flow(&mut network, source, sink).unwrap();
+        assert_eq!(result.flow, 50);
+        // 最优方案：仓库 0 运 20 到商店 1；仓库 1 运 10、5、15 到商店 0、1、2
+        assert_eq!(result.cost, 20 * 6 + 10 * 9 + 5 * 12 + 15 * 13);
+        let shipped: Vec<i64> = routes.iter().map(|&id| result.edge_flows[id]).collect();
+        assert_eq!(shipped, vec![0, 20, 0, 10, 5, 15]);
+    }
+
+    /// 残量网络中没有负费用环时当前流的费用最小，用 Bellman-Ford 检查
+    fn has_negative_residual_cycle(network: &FlowNetwork) -> bool {
+        let nodes = network.node_count();
+        let mut distance = vec![0; nodes];
+        for round in 0..=nodes {
+            let mut changed = false;
+            for from in 0..nodes {
+                for &id in &network.adjacency[from] {
+                    let arc = network.arcs[id];
+                    if arc.residual() > 0 && distance[from] + arc.cost < distance[arc.to] {
+                        distance[arc.to] = distance[from] + arc.cost;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                return false;
+            }
+            if round == nodes {
+                return true;
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn random_networks_are_max_and_min_cost() {
+        let mut rng = Rng::new(1407);
+        for _ in 0..100 {
+            let nodes = 2 + rng.gen_index(10);
+            let mut network = FlowNetwork::new(nodes);
+            // 只加从小编号指向大编号的边，保证没有负费用环
+            for _ in 0..rng.gen_index(4 * nodes) {
+                let (a, b) = (rng.gen_index(nodes), rng.gen_index(nodes));
+                if a < b {
+                    let cost = rng.gen_range(-5, 10);
+                    network.add_edge_with_cost(a, b, rng.gen_range(1, 10), cost);
+                }
+            }
+            let (source, sink) = (0, nodes - 1);
+            let mut copy = network.clone();
+            let result = min_cost_max_flow(&mut network, source, sink).unwrap();
+            assert_eq!(result.flow, dinic(&mut copy, source, sink));
+            assert!(!has_negative_residual_cycle(&network));
+            let cost: i64 = (0..network.edge_count())
+                .map(|id| network.flow(id) * network.cost(id))
+                .sum();
+            assert_eq!(cost, result.cost);
+        }
+    }
+
+    #[test]
+    fn rejects_negative_cost_cycles() {
+        let mut network = FlowNetwork::new(3);
+        network.add_edge_with_cost(0, 1, 1, 1);
+        network.add_edge_with_cost(1, 2, 1, -3);
+        network.add_edge_with_cost(2, 1, 1, 1);
+        assert!(matches!(
+            min_cost_max_flow(&mut network, 0, 2),
+            Err(GraphError::NegativeCycle { .. })
+        ));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod dinic;
+pub mod edmonds_karp;
+pub mod min_cost_flow;
+pub mod network;
+
+pub use self::dinic::dinic;
+pub use self::edmonds_karp::edmonds_karp;
+pub use self::min_cost_flow::{min_cost_max_flow, MinCostFlow};
+pub use self::network::{min_cut, FlowNetwork, MinCut};
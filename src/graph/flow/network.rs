@@ -0,0 +1,182 @@
+/*!
+定义：
+[流网络](https://zh.wikipedia.org/wiki/%E6%B5%81%E7%BD%91%E7%BB%9C)
+每条边有容量，流量不能超过容量，除源点和汇点外每个顶点流入等于流出。
+每条边在内部存成一对弧：下标 2i 是正向弧，2i + 1 是容量为 0 的反向弧，两者的流量互为相反数，
+因此 `arc ^ 1` 就能找到配对的弧。残量网络就是所有 `capacity - flow > 0` 的弧
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Arc {
+    pub(super) to: usize,
+    pub(super) capacity: i64,
+    pub(super) flow: i64,
+    /// 单位流量的费用，反向弧的费用是正向弧的相反数
+    pub(super) cost: i64,
+}
+
+impl Arc {
+    pub(super) fn residual(&self) -> i64 {
+        self.capacity - self.flow
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowNetwork {
+    pub(super) arcs: Vec<Arc>,
+    pub(super) adjacency: Vec<Vec<usize>>,
+}
+
+impl FlowNetwork {
+    pub fn new(nodes: usize) -> Self {
+        FlowNetwork {
+            arcs: vec![],
+            adjacency: vec![vec![]; nodes],
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.arcs.len() / 2
+    }
+
+    /// 新增一条有向边，返回边的 id
+    pub fn add_edge(&mut self, from: usize, to: usize, capacity: i64) -> usize {
+        self.add_edge_with_cost(from, to, capacity, 0)
+    }
+
+    /// 新增一条带单位流量费用的有向边，返回边的 id
+    pub fn add_edge_with_cost(
+        &mut self,
+        from: usize,
+        to: usize,
+        capacity: i64,
+        cost: i64,
+    ) -> usize {
+        let nodes = self.node_count();
+        assert!(
+            from < nodes && to < nodes,
+            "Edge ({from}, {to}) out of bounds for {nodes} nodes."
+        );
+        assert!(capacity >= 0, "Capacity must be non-negative.");
+        let id = self.edge_count();
+        self.adjacency[from].push(self.arcs.len());
+        self.arcs.push(Arc {
+            to,
+            capacity,
+            flow: 0,
+            cost,
+        });
+        self.adjacency[to].push(self.arcs.len());
+        self.arcs.push(Arc {
+            to: from,
+            capacity: 0,
+            flow: 0,
+            cost: -cost,
+        });
+        id
+    }
+
+    /// 边 `id` 的 (起点, 终点)
+    pub fn endpoints(&self, id: usize) -> (usize, usize) {
+        (self.arcs[2 * id + 1].to, self.arcs[2 * id].to)
+    }
+
+    pub fn capacity(&self, id: usize) -> i64 {
+        self.arcs[2 * id].capacity
+    }
+
+    pub fn cost(&self, id: usize) -> i64 {
+        self.arcs[2 * id].cost
+    }
+
+    /// 边 `id` 上当前的流量
+    pub fn flow(&self, id: usize) -> i64 {
+        self.arcs[2 * id].flow
+    }
+
+    /// 清空所有流量，可以在同一个网络上重新计算
+    pub fn reset(&mut self) {
+        for arc in &mut self.arcs {
+            arc.flow = 0;
+        }
+    }
+
+    /// 沿弧 `arc` 推送 `amount` 的流量
+    pub(super) fn push(&mut self, arc: usize, amount: i64) {
+        self.arcs[arc].flow += amount;
+        self.arcs[arc ^ 1].flow -= amount;
+    }
+
+    /// 在残量网络中从 `source` 可达的顶点
+    pub(super) fn residual_reachable(&self, source: usize) -> Vec<bool> {
+        let mut reachable = vec![false; self.node_count()];
+        reachable[source] = true;
+        let mut stack = vec![source];
+        while let Some(node) = stack.pop() {
+            for &arc in &self.adjacency[node] {
+                let Arc { to, .. } = self.arcs[arc];
+                if self.arcs[arc].residual() > 0 && !reachable[to] {
+                    reachable[to] = true;
+                    stack.push(to);
+                }
+            }
+        }
+        reachable
+    }
+}
+
+/// 一个 s-t 割
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut {
+    /// 和源点在同一侧的顶点
+    pub source_side: Vec<bool>,
+    /// 从源点一侧指向汇点一侧的边的 id
+    pub edges: Vec<usize>,
+    /// 割的容量
+    pub capacity: i64,
+}
+
+/// 在已经求出最大流的网络上提取最小割：残量网络中从源点可达的顶点构成源点一侧
+pub fn min_cut(network: &FlowNetwork, source: usize) -> MinCut {
+    let source_side = network.residual_reachable(source);
+    let edges: Vec<usize> = (0..network.edge_count())
+        .filter(|&id| {
+            let (from, to) = network.endpoints(id);
+            source_side[from] && !source_side[to]
+        })
+        .collect();
+    let capacity = edges.iter().map(|&id| network.capacity(id)).sum();
+    MinCut {
+        source_side,
+        edges,
+        capacity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlowNetwork;
+
+    #[test]
+    fn stores_paired_arcs() {
+        let mut network = FlowNetwork::new(3);
+        let a = network.add_edge(0, 1, 5);
+        let b = network.add_edge_with_cost(1, 2, 3, 7);
+        assert_eq!((a, b), (0, 1));
+        assert_eq!(network.edge_count(), 2);
+        assert_eq!(network.endpoints(b), (1, 2));
+        assert_eq!(network.capacity(a), 5);
+        assert_eq!((network.cost(a), network.cost(b)), (0, 7));
+        assert_eq!(network.arcs[3].cost, -7);
+
+        network.push(2, 2);
+        assert_eq!(network.flow(b), 2);
+        assert_eq!(network.arcs[3].residual(), 2);
+        network.reset();
+        assert_eq!(network.flow(b), 0);
+    }
+}
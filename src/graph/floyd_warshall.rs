@@ -0,0 +1,195 @@
+/*!
+定义：
+[Floyd-Warshall 算法的定义](https://zh.wikipedia.org/wiki/Floyd-Warshall%E7%AE%97%E6%B3%95)
+动态规划求所有点对之间的最短路径：依次允许顶点 k 作为中间点，
+`dist[i][j] = min(dist[i][j], dist[i][k] + dist[k][j])`，复杂度 O(V³)。
+允许负权边；某个 `dist[i][i] < 0` 时说明 i 在一个负权环上，这时立即停止，
+否则沿着负权环绕圈会让距离不断变小直到溢出。
+没有负权环时中间结果都是简单路径的长度，绝对值不超过 V · 2⁶³，用 i128 计算不会溢出，
+某条更长的路径超出 i64 也不影响结果；最后只检查最短距离能否用 i64 表示
+*/
+
+use super::adjacency_matrix::AdjacencyMatrix;
+use super::error::GraphError;
+
+/// 所有点对之间的最短路径，不可达为 None
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllPairsShortestPaths {
+    pub distance: Vec<Vec<Option<i64>>>,
+    /// `next[i][j]` 是从 i 到 j 的最短路径上 i 之后的下一个顶点
+    pub next: Vec<Vec<Option<usize>>>,
+}
+
+impl AllPairsShortestPaths {
+    /// 从 `from` 到 `to` 的一条最短路径（包含两端），不可达时返回 None
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.distance[from][to]?;
+        let mut path = vec![from];
+        let mut node = from;
+        while node != to {
+            node = self.next[node][to]?;
+            path.push(node);
+        }
+        Some(path)
+    }
+}
+
+/// 存在负权环时返回 [`GraphError::NegativeCycle`]，某对顶点的最短距离超出 i64 时返回
+/// [`GraphError::DistanceOverflow`]
+pub fn floyd_warshall(matrix: &AdjacencyMatrix) -> Result<AllPairsShortestPaths, GraphError> {
+    let nodes = matrix.node_count();
+    let mut distance: Vec<Vec<Option<i128>>> = vec![vec![None; nodes]; nodes];
+    let mut next = vec![vec![None; nodes]; nodes];
+    for i in 0..nodes {
+        for (j, weight) in matrix.neighbors(i) {
+            distance[i][j] = Some(i128::from(weight));
+            next[i][j] = Some(j);
+        }
+        // 负权自环本身就是负权环，保留它以便从对角线检测
+        if distance[i][i].is_none_or(|w| w > 0) {
+            distance[i][i] = Some(0);
+            next[i][i] = Some(i);
+        }
+    }
+
+    if let Some(node) = (0..nodes).find(|&i| distance[i][i] < Some(0)) {
+        return Err(GraphError::NegativeCycle { node });
+    }
+    for k in 0..nodes {
+        for i in 0..nodes {
+            let Some(ik) = distance[i][k] else { continue };
+            for j in 0..nodes {
+                let Some(kj) = distance[k][j] else { continue };
+                let candidate = ik + kj;
+                if distance[i][j].is_none_or(|ij| candidate < ij) {
+                    distance[i][j] = Some(candidate);
+                    next[i][j] = next[i][k];
+                    if i == j && candidate < 0 {
+                        return Err(GraphError::NegativeCycle { node: i });
+                    }
+                }
+            }
+        }
+    }
+
+    let distance = distance
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|d| d.map(i64::try_from).transpose())
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<_, _>>()
+        .map_err(|_| GraphError::DistanceOverflow)?;
+    Ok(AllPairsShortestPaths { distance, next })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::floyd_warshall;
+    use crate::graph::dijkstra;
+    use crate::graph::{AdjacencyMatrix, Graph, GraphError};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn handles_negative_edges() {
+        let mut matrix = AdjacencyMatrix::new_directed(4);
+        matrix.set_edge(0, 2, -2);
+        matrix.set_edge(2, 3, 2);
+        matrix.set_edge(3, 1, -1);
+        matrix.set_edge(1, 0, 4);
+        matrix.set_edge(1, 2, 3);
+        let paths = floyd_warshall(&matrix).unwrap();
+        assert_eq!(
+            paths.distance[0],
+            vec![Some(0), Some(-1), Some(-2), Some(0)]
+        );
+        assert_eq!(paths.distance[1][3], Some(4));
+        assert_eq!(paths.path(1, 3), Some(vec![1, 0, 2, 3]));
+        assert_eq!(paths.path(2, 2), Some(vec![2]));
+    }
+
+    #[test]
+    fn unreachable_pairs_are_none() {
+        let mut matrix = AdjacencyMatrix::new_directed(3);
+        matrix.set_edge(0, 1, 1);
+        let paths = floyd_warshall(&matrix).unwrap();
+        assert_eq!(paths.distance[1][0], None);
+        assert_eq!(paths.path(1, 0), None);
+        assert_eq!(paths.path(0, 2), None);
+    }
+
+    #[test]
+    fn detects_negative_cycles() {
+        let mut matrix = AdjacencyMatrix::new_directed(3);
+        matrix.set_edge(0, 1, 1);
+        matrix.set_edge(1, 2, -3);
+        matrix.set_edge(2, 1, 2);
+        assert!(matches!(
+            floyd_warshall(&matrix),
+            Err(GraphError::NegativeCycle { node: 1 | 2 })
+        ));
+
+        let mut self_loop = AdjacencyMatrix::new_directed(2);
+        self_loop.set_edge(1, 1, -1);
+        assert_eq!(
+            floyd_warshall(&self_loop),
+            Err(GraphError::NegativeCycle { node: 1 })
+        );
+    }
+
+    #[test]
+    fn reports_cycles_and_overflow_without_panicking() {
+        // 继续绕这个负权环一圈就会溢出，必须在第一次发现时停止
+        let mut matrix = AdjacencyMatrix::new_directed(2);
+        matrix.set_edge(0, 1, -4_000_000_000_000_000_000);
+        matrix.set_edge(1, 0, -4_000_000_000_000_000_000);
+        assert_eq!(
+            floyd_warshall(&matrix),
+            Err(GraphError::NegativeCycle { node: 1 })
+        );
+
+        // 0 到 2 的最短距离超出 i64
+        let mut matrix = AdjacencyMatrix::new_directed(3);
+        matrix.set_edge(0, 1, i64::MAX);
+        matrix.set_edge(1, 2, 1);
+        assert_eq!(floyd_warshall(&matrix), Err(GraphError::DistanceOverflow));
+        let mut matrix = AdjacencyMatrix::new_directed(3);
+        matrix.set_edge(0, 1, i64::MIN);
+        matrix.set_edge(1, 2, -1);
+        assert_eq!(floyd_warshall(&matrix), Err(GraphError::DistanceOverflow));
+
+        // 0 -> 1 -> 2 超出 i64，但最短距离 0 -> 2 仍在范围内
+        let mut matrix = AdjacencyMatrix::new_directed(3);
+        matrix.set_edge(0, 1, i64::MAX);
+        matrix.set_edge(0, 2, i64::MAX);
+        matrix.set_edge(1, 2, 1);
+        let paths = floyd_warshall(&matrix).unwrap();
+        assert_eq!(
+            paths.distance[0],
+            vec![Some(0), Some(i64::MAX), Some(i64::MAX)]
+        );
+    }
+
+    #[test]
+    fn agrees_with_dijkstra_on_non_negative_graphs() {
+        let mut rng = Rng::new(1395);
+        for _ in 0..20 {
+            let nodes = 1 + rng.gen_index(20);
+            let mut graph = Graph::new_directed(nodes);
+            for _ in 0..rng.gen_index(4 * nodes) {
+                let (from, to) = (rng.gen_index(nodes), rng.gen_index(nodes));
+                graph.add_weighted_edge(from, to, rng.gen_range(0, 50));
+            }
+            let all_pairs = floyd_warshall(&AdjacencyMatrix::from(&graph)).unwrap();
+            for source in 0..nodes {
+                let single = dijkstra(&graph, source).unwrap();
+                assert_eq!(all_pairs.distance[source], single.distance);
+                for target in 0..nodes {
+                    let path = all_pairs.path(source, target);
+                    assert_eq!(path.is_some(), single.distance[target].is_some());
+                }
+            }
+        }
+    }
+}
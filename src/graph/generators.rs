@@ -0,0 +1,173 @@
+/*!
+随机图和常见结构图的生成器，给测试和基准提供可复现的输入：随机部分都使用传入的 [`Rng`]，
+相同的种子总是生成相同的图。生成的边权都是 1，需要带权图时再调用 [`with_random_weights`]
+*/
+
+use super::adjacency_list::Graph;
+use crate::utils::random::Rng;
+
+/// [Erdős–Rényi 模型](https://zh.wikipedia.org/wiki/ER%E9%9A%8F%E6%9C%BA%E5%9B%BE) G(n, p)：
+/// 每一对顶点（有向图中每个有序对）之间独立地以概率 p 连边，不含自环
+pub fn erdos_renyi(n: usize, p: f64, directed: bool, rng: &mut Rng) -> Graph {
+    let mut graph = empty(n, directed);
+    for from in 0..n {
+        let targets = if directed { 0..n } else { from + 1..n };
+        for to in targets {
+            if from != to && rng.gen_bool(p) {
+                graph.add_edge(from, to);
+            }
+        }
+    }
+    graph
+}
+
+/// 在所有 n 个顶点的带标号树中均匀随机地选一棵，通过随机生成
+/// [Prüfer 序列](https://zh.wikipedia.org/wiki/Pr%C3%BCfer%E5%BA%8F%E5%88%97) 再解码得到
+pub fn random_tree(n: usize, rng: &mut Rng) -> Graph {
+    let mut tree = Graph::new_undirected(n);
+    if n < 2 {
+        return tree;
+    }
+    let sequence: Vec<usize> = (0..n - 2).map(|_| rng.gen_index(n)).collect();
+    let mut degree = vec![1; n];
+    for &v in &sequence {
+        degree[v] += 1;
+    }
+    // 每次把编号最小的叶子连到序列中的下一个顶点上
+    for &v in &sequence {
+        let leaf = (0..n).find(|&u| degree[u] == 1).unwrap();
+        tree.add_edge(leaf, v);
+        degree[leaf] -= 1;
+        degree[v] -= 1;
+    }
+    let mut last = (0..n).filter(|&u| degree[u] == 1);
+    let (a, b) = (last.next().unwrap(), last.next().unwrap());
+    tree.add_edge(a, b);
+    tree
+}
+
+/// 随机有向无环图：先随机打乱顶点得到一个拓扑序，再对每一对顶点以概率 p 从前往后连边
+pub fn random_dag(n: usize, p: f64, rng: &mut Rng) -> Graph {
+    let mut order: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut order);
+    let mut dag = Graph::new_directed(n);
+    for i in 0..n {
+        for j in i + 1..n {
+            if rng.gen_bool(p) {
+                dag.add_edge(order[i], order[j]);
+            }
+        }
+    }
+    dag
+}
+
+/// `width × height` 的网格，顶点 `y * width + x` 和上下左右的顶点相连
+pub fn grid(width: usize, height: usize) -> Graph {
+    let mut graph = Graph::new_undirected(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let node = y * width + x;
+            if x + 1 < width {
+                graph.add_edge(node, node + 1);
+            }
+            if y + 1 < height {
+                graph.add_edge(node, node + width);
+            }
+        }
+    }
+    graph
+}
+
+/// n 个顶点的完全图，有向时每个有序对都有一条边
+pub fn complete(n: usize, directed: bool) -> Graph {
+    let mut graph = empty(n, directed);
+    for from in 0..n {
+        for to in 0..n {
+            if from != to && (directed || from < to) {
+                graph.add_edge(from, to);
+            }
+        }
+    }
+    graph
+}
+
+/// 结构相同，每条边的权在 [low, high) 中随机选取
+pub fn with_random_weights(graph: &Graph, low: i64, high: i64, rng: &mut Rng) -> Graph {
+    let mut weighted = empty(graph.node_count(), graph.is_directed());
+    for edge in graph.edges() {
+        weighted.add_weighted_edge(edge.from, edge.to, rng.gen_range(low, high));
+    }
+    weighted
+}
+
+fn empty(n: usize, directed: bool) -> Graph {
+    if directed {
+        Graph::new_directed(n)
+    } else {
+        Graph::new_undirected(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{bfs, find_cycle};
+
+    #[test]
+    fn erdos_renyi_density() {
+        let mut rng = Rng::new(1419);
+        let graph = erdos_renyi(100, 0.1, false, &mut rng);
+        // 期望边数是 C(100, 2) * 0.1 = 495
+        assert!((400..600).contains(&graph.edge_count()));
+        assert!(graph.edges().iter().all(|e| e.from != e.to));
+        assert_eq!(erdos_renyi(10, 1.0, true, &mut rng).edge_count(), 90);
+        assert_eq!(erdos_renyi(10, 0.0, false, &mut rng).edge_count(), 0);
+    }
+
+    #[test]
+    fn same_seed_same_graph() {
+        let a = erdos_renyi(30, 0.2, true, &mut Rng::new(7));
+        let b = erdos_renyi(30, 0.2, true, &mut Rng::new(7));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn random_trees_are_spanning_trees() {
+        let mut rng = Rng::new(1419);
+        for n in 0..30 {
+            let tree = random_tree(n, &mut rng);
+            assert_eq!(tree.edge_count(), n.saturating_sub(1));
+            assert_eq!(find_cycle(&tree), None);
+            if n > 0 {
+                assert_eq!(bfs(&tree, 0).order.len(), n);
+            }
+        }
+    }
+
+    #[test]
+    fn random_dags_are_acyclic() {
+        let mut rng = Rng::new(1419);
+        for _ in 0..20 {
+            let dag = random_dag(25, 0.3, &mut rng);
+            assert!(dag.is_directed());
+            assert_eq!(find_cycle(&dag), None);
+        }
+    }
+
+    #[test]
+    fn structured_graphs() {
+        let g = grid(4, 3);
+        assert_eq!(g.node_count(), 12);
+        // 横向 3 × 3 条，纵向 4 × 2 条
+        assert_eq!(g.edge_count(), 17);
+        assert_eq!(bfs(&g, 0).distance[11], Some(5));
+
+        assert_eq!(complete(5, false).edge_count(), 10);
+        assert_eq!(complete(5, true).edge_count(), 20);
+
+        let mut rng = Rng::new(1);
+        let weighted = with_random_weights(&complete(6, false), -5, 5, &mut rng);
+        assert_eq!(weighted.edge_count(), 15);
+        assert!(weighted.edges().iter().all(|e| (-5..5).contains(&e.weight)));
+    }
+}
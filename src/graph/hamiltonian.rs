@@ -0,0 +1,420 @@
+/*!
+定义：
+[哈密顿路径的定义](https://zh.wikipedia.org/wiki/%E5%93%88%E5%AF%86%E9%A1%BF%E5%9B%BE)
+经过每个顶点恰好一次的路径。判定问题是 NP 完全的，这里提供两种指数级算法：
+- [Held-Karp](https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm) 状态压缩 DP：
+  `reach[mask]` 是所有满足“存在恰好经过 mask 中的顶点、以 v 结尾的路径”的 v 的集合，
+  时间 O(2ⁿ·n)，空间 O(2ⁿ)，适合 n 不超过 20 左右的图
+- 带剪枝的回溯：优先走剩余出边少的顶点，发现某个未访问的顶点已经无路可达就回退，
+  没有空间限制，在稀疏图或存在路径的图上通常很快
+
+旅行商问题（TSP）用同样的状态压缩求最短的哈密顿回路
+*/
+
+use super::adjacency_list::Graph;
+use super::adjacency_matrix::AdjacencyMatrix;
+
+/// 顶点数不超过这个值时使用状态压缩 DP。DP 需要 2ⁿ 个状态，再大就会耗尽内存，
+/// [`hamiltonian_path_dp`] 和 [`tsp`] 在顶点数超过它时 panic
+pub const HELD_KARP_LIMIT: usize = 20;
+
+/// 经过每个顶点恰好一次的路径，不存在时返回 None；有向图遵守边的方向
+pub fn hamiltonian_path(graph: &Graph) -> Option<Vec<usize>> {
+    if graph.node_count() <= HELD_KARP_LIMIT {
+        hamiltonian_path_dp(graph)
+    } else {
+        hamiltonian_path_backtracking(graph)
+    }
+}
+
+/// Held-Karp 状态压缩 DP，顶点数超过 [`HELD_KARP_LIMIT`] 时 panic
+pub fn hamiltonian_path_dp(graph: &Graph) -> Option<Vec<usize>> {
+    let n = graph.node_count();
+    assert!(
+        n <= HELD_KARP_LIMIT,
+        "Held-Karp supports at most {HELD_KARP_LIMIT} nodes, got {n}."
+    );
+    if n == 0 {
+        return Some(vec![]);
+    }
+    // into[v] 是有边指向 v 的顶点集合
+    let mut into = vec![0u64; n];
+    for node in 0..n {
+        for next in graph.neighbors(node) {
+            into[next] |= 1 << node;
+        }
+    }
+
+    let full = (1usize << n) - 1;
+    let mut reach = vec![0u64; full + 1];
+    for v in 0..n {
+        reach[1 << v] = 1 << v;
+    }
+    for mask in 1..=full {
+        for v in 0..n {
+            // v 不在 mask 中，且 mask 中存在能走到 v 的结尾顶点
+            if mask & (1 << v) == 0 && reach[mask] & into[v] != 0 {
+                reach[mask | (1 << v)] |= 1 << v;
+            }
+        }
+    }
+    if reach[full] == 0 {
+        return None;
+    }
+
+    // 从终点倒推：每次找一个能走到当前顶点的前驱
+    let mut path = vec![];
+    let mut mask = full;
+    let mut end = reach[full].trailing_zeros() as usize;
+    loop {
+        path.push(end);
+        mask ^= 1 << end;
+        if mask == 0 {
+            break;
+        }
+        end = (reach[mask] & into[end]).trailing_zeros() as usize;
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// 带剪枝的回溯搜索
+pub fn hamiltonian_path_backtracking(graph: &Graph) -> Option<Vec<usize>> {
+    let n = graph.node_count();
+    if n == 0 {
+        return Some(vec![]);
+    }
+    let adjacency: Vec<Vec<usize>> = (0..n)
+        .map(|node| {
+            let mut next: Vec<usize> = graph.neighbors(node).filter(|&v| v != node).collect();
+            next.sort();
+            next.dedup();
+            next
+        })
+        .collect();
+    // 来自未访问顶点的入边数
+    let mut in_degree = vec![0usize; n];
+    for next in adjacency.iter().flatten() {
+        in_degree[*next] += 1;
+    }
+
+    // 没有入边的顶点只能作为起点
+    let sources: Vec<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+    let starts: Vec<usize> = match sources.len() {
+        0 => (0..n).collect(),
+        1 => sources,
+        _ => return None,
+    };
+    let mut search = Backtracking {
+        adjacency,
+        in_degree,
+        visited: vec![false; n],
+        path: Vec::with_capacity(n),
+    };
+    for start in starts {
+        if search.extend(start) {
+            return Some(search.path);
+        }
+    }
+    None
+}
+
+struct Backtracking {
+    adjacency: Vec<Vec<usize>>,
+    /// 每个顶点来自未访问顶点的入边数
+    in_degree: Vec<usize>,
+    visited: Vec<bool>,
+    path: Vec<usize>,
+}
+
+impl Backtracking {
+    /// 把 `node` 加入路径末尾并继续搜索，找到完整路径时返回 true
+    fn extend(&mut self, node: usize) -> bool {
+        self.visited[node] = true;
+        self.path.push(node);
+        let n = self.adjacency.len();
+        if self.path.len() == n {
+            return true;
+        }
+        for &next in &self.adjacency[node] {
+            self.in_degree[next] -= 1;
+        }
+
+        // 未访问的顶点如果已经没有来自未访问顶点的入边，就只能紧接在 node 之后
+        let stranded: Vec<usize> = (0..n)
+            .filter(|&v| !self.visited[v] && self.in_degree[v] == 0)
+            .collect();
+        let candidates = match stranded.as_slice() {
+            [] => {
+                let mut candidates: Vec<usize> = self.adjacency[node]
+                    .iter()
+                    .copied()
+                    .filter(|&v| !self.visited[v])
+                    .collect();
+                // 优先走剩余出路少的顶点（Warnsdorff 规则）
+                candidates.sort_by_key(|&v| {
+                    self.adjacency[v]
+                        .iter()
+                        .filter(|&&w| !self.visited[w])
+                        .count()
+                });
+                candidates
+            }
+            &[only] if self.adjacency[node].contains(&only) => vec![only],
+            _ => vec![],
+        };
+        for next in candidates {
+            if self.extend(next) {
+                return true;
+            }
+        }
+
+        for &next in &self.adjacency[node] {
+            self.in_degree[next] += 1;
+        }
+        self.visited[node] = false;
+        self.path.pop();
+        false
+    }
+}
+
+/// 旅行商问题的最优回路
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tour {
+    pub cost: i64,
+    /// 从顶点 0 出发依次访问的顶点，最后回到顶点 0
+    pub order: Vec<usize>,
+}
+
+/// Held-Karp 求最短哈密顿回路，不存在回路时返回 None。时间 O(2ⁿ·n²)，空间 O(2ⁿ·n)，
+/// 顶点数超过 [`HELD_KARP_LIMIT`] 时 panic
+pub fn tsp(matrix: &AdjacencyMatrix) -> Option<Tour> {
+    let n = matrix.node_count();
+    assert!(
+        n <= HELD_KARP_LIMIT,
+        "Held-Karp supports at most {HELD_KARP_LIMIT} nodes, got {n}."
+    );
+    if n == 0 {
+        return Some(Tour {
+            cost: 0,
+            order: vec![],
+        });
+    }
+
+    // cost[mask][v]：从 0 出发，恰好经过 mask 中的顶点，停在 v 的最小费用
+    let full = (1usize << n) - 1;
+    let mut cost: Vec<Vec<Option<i64>>> = vec![vec![None; n]; full + 1];
+    cost[1][0] = Some(0);
+    for mask in (1..=full).filter(|mask| mask & 1 == 1) {
+        for v in 0..n {
+            let Some(current) = cost[mask][v] else {
+                continue;
+            };
+            for (next, weight) in matrix.neighbors(v) {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+                let slot = &mut cost[mask | (1 << next)][next];
+                if slot.is_none_or(|best| current + weight < best) {
+                    *slot = Some(current + weight);
+                }
+            }
+        }
+    }
+
+    // 只有一个顶点时回路就是它自己
+    if n == 1 {
+        return Some(Tour {
+            cost: 0,
+            order: vec![0],
+        });
+    }
+    let (last, best) = (1..n)
+        .filter_map(|v| Some((v, cost[full][v]? + matrix.weight(v, 0)?)))
+        .min_by_key(|&(_, c)| c)?;
+
+    // 倒推：找一个前驱 u 满足 cost[mask - v][u] + w(u, v) == cost[mask][v]
+    let mut order = vec![];
+    let mut mask = full;
+    let mut node = last;
+    while node != 0 {
+        order.push(node);
+        let target = cost[mask][node].unwrap();
+        let previous_mask = mask ^ (1 << node);
+        node = (0..n)
+            .find(|&u| {
+                cost[previous_mask][u]
+                    .zip(matrix.weight(u, node))
+                    .is_some_and(|(c, w)| c + w == target)
+            })
+            .unwrap();
+        mask = previous_mask;
+    }
+    order.push(0);
+    order.reverse();
+    Some(Tour { cost: best, order })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::Rng;
+
+    fn is_hamiltonian(graph: &Graph, path: &[usize]) -> bool {
+        let mut seen = vec![false; graph.node_count()];
+        for &v in path {
+            if seen[v] {
+                return false;
+            }
+            seen[v] = true;
+        }
+        path.len() == graph.node_count()
+            && path
+                .windows(2)
+                .all(|w| graph.neighbors(w[0]).any(|v| v == w[1]))
+    }
+
+    #[test]
+    fn finds_paths_in_small_graphs() {
+        let mut graph = Graph::new_directed(4);
+        for (a, b) in [(2, 0), (0, 3), (3, 1), (0, 1)] {
+            graph.add_edge(a, b);
+        }
+        assert_eq!(hamiltonian_path_dp(&graph), Some(vec![2, 0, 3, 1]));
+        assert_eq!(
+            hamiltonian_path_backtracking(&graph),
+            Some(vec![2, 0, 3, 1])
+        );
+
+        // 星形图没有哈密顿路径
+        let mut star = Graph::new_undirected(4);
+        for leaf in 1..4 {
+            star.add_edge(0, leaf);
+        }
+        assert_eq!(hamiltonian_path_dp(&star), None);
+        assert_eq!(hamiltonian_path_backtracking(&star), None);
+        assert_eq!(hamiltonian_path(&Graph::new_undirected(1)), Some(vec![0]));
+    }
+
+    #[test]
+    fn dp_and_backtracking_agree() {
+        let mut rng = Rng::new(1409);
+        for directed in [true, false] {
+            for _ in 0..200 {
+                let n = 1 + rng.gen_index(9);
+                let mut graph = if directed {
+                    Graph::new_directed(n)
+                } else {
+                    Graph::new_undirected(n)
+                };
+                for _ in 0..rng.gen_index(n * n) {
+                    graph.add_edge(rng.gen_index(n), rng.gen_index(n));
+                }
+                let dp = hamiltonian_path_dp(&graph);
+                let backtracking = hamiltonian_path_backtracking(&graph);
+                assert_eq!(dp.is_some(), backtracking.is_some());
+                for path in dp.iter().chain(backtracking.iter()) {
+                    assert!(is_hamiltonian(&graph, path));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 20 nodes")]
+    fn dp_rejects_graphs_above_the_limit() {
+        hamiltonian_path_dp(&Graph::new_undirected(HELD_KARP_LIMIT + 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 20 nodes")]
+    fn tsp_rejects_graphs_above_the_limit() {
+        tsp(&AdjacencyMatrix::new_undirected(HELD_KARP_LIMIT + 1));
+    }
+
+    #[test]
+    fn backtracking_handles_large_sparse_graphs() {
+        // 40 个顶点的环加上一些随机弦，DP 无法处理这么多顶点
+        let n = 40;
+        let mut rng = Rng::new(40);
+        let mut order: Vec<usize> = (0..n).collect();
+        rng.shuffle(&mut order);
+        let mut graph = Graph::new_undirected(n);
+        for i in 0..n {
+            graph.add_edge(order[i], order[(i + 1) % n]);
+        }
+        for _ in 0..20 {
+            graph.add_edge(rng.gen_index(n), rng.gen_index(n));
+        }
+        let path = hamiltonian_path(&graph).unwrap();
+        assert!(is_hamiltonian(&graph, &path));
+    }
+
+    /// 枚举从 0 出发的所有排列
+    fn brute_force_tsp(matrix: &AdjacencyMatrix) -> Option<i64> {
+        fn permute(
+            matrix: &AdjacencyMatrix,
+            order: &mut Vec<usize>,
+            rest: &mut Vec<usize>,
+            best: &mut Option<i64>,
+        ) {
+            if rest.is_empty() {
+                let mut cost = 0;
+                for i in 0..order.len() {
+                    match matrix.weight(order[i], order[(i + 1) % order.len()]) {
+                        Some(w) => cost += w,
+                        None => return,
+                    }
+                }
+                if best.is_none_or(|b| cost < b) {
+                    *best = Some(cost);
+                }
+                return;
+            }
+            for i in 0..rest.len() {
+                let v = rest.remove(i);
+                order.push(v);
+                permute(matrix, order, rest, best);
+                order.pop();
+                rest.insert(i, v);
+            }
+        }
+        let mut best = None;
+        permute(
+            matrix,
+            &mut vec![0],
+            &mut (1..matrix.node_count()).collect(),
+            &mut best,
+        );
+        best
+    }
+
+    #[test]
+    fn tsp_matches_brute_force() {
+        let mut rng = Rng::new(1409);
+        for _ in 0..50 {
+            let n = 2 + rng.gen_index(6);
+            let mut matrix = AdjacencyMatrix::new_directed(n);
+            for a in 0..n {
+                for b in 0..n {
+                    if a != b && rng.gen_bool(0.7) {
+                        matrix.set_edge(a, b, rng.gen_range(1, 100));
+                    }
+                }
+            }
+            let tour = tsp(&matrix);
+            assert_eq!(tour.as_ref().map(|t| t.cost), brute_force_tsp(&matrix));
+            if let Some(tour) = tour {
+                assert_eq!(tour.order[0], 0);
+                let cost: i64 = (0..n)
+                    .map(|i| {
+                        matrix
+                            .weight(tour.order[i], tour.order[(i + 1) % n])
+                            .unwrap()
+                    })
+                    .sum();
+                assert_eq!(cost, tour.cost);
+            }
+        }
+    }
+}
@@ -0,0 +1,303 @@
+/*!
+定义：
+[Hopcroft-Karp 算法](https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm)
+求二分图的最大匹配。每个阶段先从所有未匹配的左部顶点出发做 BFS 得到分层图，
+再用 DFS 沿分层图找出一组互不相交的最短增广路同时增广。只需要 O(√V) 个阶段，总复杂度 O(E√V)。
+
+由 [König 定理](https://zh.wikipedia.org/wiki/%E6%9F%AF%E5%B0%BC%E5%B8%8C%E5%AE%9A%E7%90%86_(%E5%9B%BE%E8%AE%BA))，
+二分图的最小顶点覆盖和最大匹配一样大：从未匹配的左部顶点出发沿交错路可达的顶点集合记为 Z，
+则 (左部 \ Z) ∪ (右部 ∩ Z) 就是一个最小顶点覆盖
+*/
+
+use super::adjacency_list::Graph;
+use super::bipartite::{is_bipartite, Bipartiteness};
+use super::error::GraphError;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matching {
+    /// 匹配的边 (左部顶点, 右部顶点)，左部是二染色中颜色为 0 的顶点
+    pub pairs: Vec<(usize, usize)>,
+    /// 每个顶点匹配到的顶点
+    pub mate: Vec<Option<usize>>,
+    /// 最小顶点覆盖，按顶点下标排序，大小等于匹配数
+    pub vertex_cover: Vec<usize>,
+}
+
+/// 边的方向会被忽略；图不是二分图时返回 [`GraphError::NotBipartite`]
+pub fn hopcroft_karp(graph: &Graph) -> Result<Matching, GraphError> {
+    let colors = match is_bipartite(graph) {
+        Bipartiteness::Bipartite { colors } => colors,
+        Bipartiteness::OddCycle(odd_cycle) => return Err(GraphError::NotBipartite { odd_cycle }),
+    };
+
+    let nodes = graph.node_count();
+    // 只保存从左部指向右部的边
+    let mut adjacency = vec![vec![]; nodes];
+    for edge in graph.edges() {
+        if colors[edge.from] == 0 {
+            adjacency[edge.from].push(edge.to);
+        } else {
+            adjacency[edge.to].push(edge.from);
+        }
+    }
+    let left: Vec<usize> = (0..nodes).filter(|&n| colors[n] == 0).collect();
+
+    let mut mate = vec![None; nodes];
+    let mut layer = vec![usize::MAX; nodes];
+    let mut next_edge = vec![0; nodes];
+    while let Some(limit) = build_layers(&adjacency, &left, &mate, &mut layer) {
+        next_edge.fill(0);
+        for &node in &left {
+            if mate[node].is_none() {
+                augment(
+                    &adjacency,
+                    &mut mate,
+                    &mut layer,
+                    &mut next_edge,
+                    limit,
+                    node,
+                );
+            }
+        }
+    }
+
+    let pairs = left
+        .iter()
+        .filter_map(|&l| mate[l].map(|r| (l, r)))
+        .collect();
+    let vertex_cover = konig_cover(&adjacency, &left, &colors, &mate);
+    Ok(Matching {
+        pairs,
+        mate,
+        vertex_cover,
+    })
+}
+
+/// 从未匹配的左部顶点出发 BFS，给左部顶点分层。返回最短增广路最后一个左部顶点所在的层，
+/// 没有增广路时返回 None；超过这一层的顶点不再展开，DFS 只会找到最短的增广路
+fn build_layers(
+    adjacency: &[Vec<usize>],
+    left: &[usize],
+    mate: &[Option<usize>],
+    layer: &mut [usize],
+) -> Option<usize> {
+    let mut queue = VecDeque::new();
+    for &node in left {
+        if mate[node].is_none() {
+            layer[node] = 0;
+            queue.push_back(node);
+        } else {
+            layer[node] = usize::MAX;
+        }
+    }
+
+    let mut limit = None;
+    while let Some(node) = queue.pop_front() {
+        // 按层出队，之后的顶点都在更深的层
+        if limit.is_some_and(|limit| layer[node] > limit) {
+            break;
+        }
+        for &right in &adjacency[node] {
+            match mate[right] {
+                None => limit = Some(layer[node]),
+                Some(next) if layer[next] == usize::MAX && limit.is_none() => {
+                    layer[next] = layer[node] + 1;
+                    queue.push_back(next);
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    limit
+}
+
+/// 沿分层图从未匹配的左部顶点 `root` 找一条增广路并增广。用显式栈代替递归，
+/// `next_edge[node]` 是 `node` 下一条要尝试的边，同一阶段中不会重复尝试失败过的边
+fn augment(
+    adjacency: &[Vec<usize>],
+    mate: &mut [Option<usize>],
+    layer: &mut [usize],
+    next_edge: &mut [usize],
+    limit: usize,
+    root: usize,
+) {
+    // 增广路上的左部顶点，每个顶点当前尝试的边指向路径上的下一个右部顶点
+    let mut path = vec![root];
+    while let Some(&node) = path.last() {
+        let Some(&right) = adjacency[node].get(next_edge[node]) else {
+            // 从这个顶点找不到增广路，本阶段不再访问它
+            layer[node] = usize::MAX;
+            path.pop();
+            if let Some(&parent) = path.last() {
+                next_edge[parent] += 1;
+            }
+            continue;
+        };
+        match mate[right] {
+            None if layer[node] == limit => {
+                // 沿路径翻转匹配边和非匹配边，路径上的顶点本阶段不再使用
+                for &left in &path {
+                    let right = adjacency[left][next_edge[left]];
+                    mate[left] = Some(right);
+                    mate[right] = Some(left);
+                    layer[left] = usize::MAX;
+                }
+                return;
+            }
+            Some(next) if layer[next] != usize::MAX && layer[next] == layer[node] + 1 => {
+                path.push(next);
+            }
+            _ => next_edge[node] += 1,
+        }
+    }
+}
+
+fn konig_cover(
+    adjacency: &[Vec<usize>],
+    left: &[usize],
+    colors: &[usize],
+    mate: &[Option<usize>],
+) -> Vec<usize> {
+    let mut visited = vec![false; colors.len()];
+    let mut queue: VecDeque<usize> = left
+        .iter()
+        .copied()
+        .filter(|&l| mate[l].is_none())
+        .collect();
+    for &node in &queue {
+        visited[node] = true;
+    }
+    // 左部到右部走非匹配边，右部回到左部走匹配边
+    while let Some(node) = queue.pop_front() {
+        for &right in &adjacency[node] {
+            if visited[right] || mate[node] == Some(right) {
+                continue;
+            }
+            visited[right] = true;
+            if let Some(next) = mate[right] {
+                if !visited[next] {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    // 最大匹配下 Z 之外的左部顶点和 Z 中的右部顶点都已匹配，且每条匹配边恰好贡献一个顶点
+    (0..colors.len())
+        .filter(|&n| (colors[n] == 0) != visited[n])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hopcroft_karp;
+    use crate::graph::{Graph, GraphError};
+    use crate::utils::random::Rng;
+
+    /// Kuhn 算法：逐个左部顶点找增广路，O(VE)
+    fn kuhn(adjacency: &[Vec<usize>], left: usize, right: usize) -> usize {
+        fn try_kuhn(
+            adjacency: &[Vec<usize>],
+            node: usize,
+            seen: &mut [bool],
+            mate: &mut [Option<usize>],
+        ) -> bool {
+            for &r in &adjacency[node] {
+                if seen[r] {
+                    continue;
+                }
+                seen[r] = true;
+                if mate[r].is_none_or(|l| try_kuhn(adjacency, l, seen, mate)) {
+                    mate[r] = Some(node);
+                    return true;
+                }
+            }
+            false
+        }
+        let mut mate = vec![None; right];
+        (0..left)
+            .filter(|&l| try_kuhn(adjacency, l, &mut vec![false; right], &mut mate))
+            .count()
+    }
+
+    #[test]
+    fn matches_small_example() {
+        // 左部 0..3，右部 3..6
+        let mut graph = Graph::new_undirected(6);
+        for (l, r) in [(0, 3), (0, 4), (1, 3), (2, 4), (2, 5)] {
+            graph.add_edge(l, r);
+        }
+        let matching = hopcroft_karp(&graph).unwrap();
+        assert_eq!(matching.pairs.len(), 3);
+        assert_eq!(matching.vertex_cover.len(), 3);
+        for &(l, r) in &matching.pairs {
+            assert_eq!(matching.mate[l], Some(r));
+            assert_eq!(matching.mate[r], Some(l));
+        }
+    }
+
+    #[test]
+    fn rejects_non_bipartite_graphs() {
+        let mut triangle = Graph::new_undirected(3);
+        for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+            triangle.add_edge(a, b);
+        }
+        assert!(matches!(
+            hopcroft_karp(&triangle),
+            Err(GraphError::NotBipartite { odd_cycle }) if odd_cycle.len() == 3
+        ));
+    }
+
+    #[test]
+    fn random_bipartite_graphs() {
+        let mut rng = Rng::new(1404);
+        for _ in 0..100 {
+            let (left, right) = (1 + rng.gen_index(12), 1 + rng.gen_index(12));
+            let mut graph = Graph::new_undirected(left + right);
+            let mut adjacency = vec![vec![]; left];
+            for _ in 0..rng.gen_index(left * right + 1) {
+                let (l, r) = (rng.gen_index(left), rng.gen_index(right));
+                graph.add_edge(l, left + r);
+                adjacency[l].push(r);
+            }
+            let matching = hopcroft_karp(&graph).unwrap();
+            assert_eq!(matching.pairs.len(), kuhn(&adjacency, left, right));
+
+            // 匹配的边互不相交
+            let mut used = vec![false; left + right];
+            for &(l, r) in &matching.pairs {
+                assert!(!used[l] && !used[r]);
+                used[l] = true;
+                used[r] = true;
+            }
+            // König 定理：覆盖所有边且大小等于匹配数
+            assert_eq!(matching.vertex_cover.len(), matching.pairs.len());
+            for edge in graph.edges() {
+                assert!(
+                    matching.vertex_cover.contains(&edge.from)
+                        || matching.vertex_cover.contains(&edge.to)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn long_augmenting_path_does_not_overflow_the_stack() {
+        // L_i 连接 R_(i-1) 和 R_i。第一阶段 L_i 都先匹配 R_(i-1)，L_0 剩下，
+        // 第二阶段的增广路 L_0 R_0 L_1 R_1 ... L_(n-1) R_(n-1) 经过所有顶点
+        let n = 200_000;
+        let (l, r) = (|i: usize| n - 1 - i, |i: usize| n + i);
+        let mut graph = Graph::new_undirected(2 * n);
+        for i in 1..n {
+            graph.add_edge(l(i), r(i - 1));
+        }
+        for i in 0..n {
+            graph.add_edge(l(i), r(i));
+        }
+        let matching = hopcroft_karp(&graph).unwrap();
+        assert_eq!(matching.pairs.len(), n);
+        assert!((0..n).all(|i| matching.mate[l(i)] == Some(r(i))));
+    }
+}
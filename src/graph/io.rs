@@ -0,0 +1,246 @@
+/*!
+图的导入和导出：
+- [DOT](https://graphviz.org/doc/info/lang.html) 是 Graphviz 的描述语言，导出后可以用 `dot -Tsvg` 画出来
+- 边列表每行一条边 `from to [weight]`，用空白分隔，`#` 开头的行是注释，很多公开的图数据集都是这种格式
+- CSV 每行 `from,to[,weight]`，第一行不是数字时当作表头跳过
+
+顶点编号直接作为下标，顶点数是最大编号加一。输入可能来自不可信的文件，
+编号不能超过 [`MAX_NODES`]，否则一行很大的编号就会让图分配海量的邻接表
+*/
+
+use super::adjacency_list::Graph;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+
+/// 导入时允许的最多顶点数，顶点编号必须小于它
+pub const MAX_NODES: usize = 1 << 24;
+
+/// 解析边列表失败，`line` 从 1 开始
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGraphError {
+    pub line: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for ParseGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl Error for ParseGraphError {}
+
+impl Graph {
+    /// 导出为 DOT，边权不为 1 时作为标签显示
+    pub fn to_dot(&self) -> String {
+        self.to_dot_highlighted(&[])
+    }
+
+    /// 导出为 DOT，`highlighted` 中的边（比如最小生成树、最短路径上的边）用红色加粗显示
+    pub fn to_dot_highlighted(&self, highlighted: &[usize]) -> String {
+        let highlighted: HashSet<usize> = highlighted.iter().copied().collect();
+        let (keyword, arrow) = if self.is_directed() {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        let mut dot = format!("{keyword} {{\n");
+        for node in 0..self.node_count() {
+            writeln!(dot, "    {node};").unwrap();
+        }
+        for (id, edge) in self.edges().iter().enumerate() {
+            let mut attributes = vec![];
+            if edge.weight != 1 {
+                attributes.push(format!("label=\"{}\"", edge.weight));
+            }
+            if highlighted.contains(&id) {
+                attributes.push("color=red, penwidth=2".to_string());
+            }
+            write!(dot, "    {} {arrow} {}", edge.from, edge.to).unwrap();
+            if !attributes.is_empty() {
+                write!(dot, " [{}]", attributes.join(", ")).unwrap();
+            }
+            dot.push_str(";\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// 从空白分隔的边列表解析，顶点数是出现过的最大编号加一，编号不能超过 [`MAX_NODES`]
+    pub fn from_edge_list(input: &str, directed: bool) -> Result<Graph, ParseGraphError> {
+        let edges = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| {
+                let line = line.trim();
+                !line.is_empty() && !line.starts_with('#')
+            })
+            .map(|(i, line)| parse_edge(i + 1, line.split_whitespace()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(build(edges, directed))
+    }
+
+    /// 从逗号分隔的 CSV 解析，可以有表头
+    pub fn from_csv(input: &str, directed: bool) -> Result<Graph, ParseGraphError> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .peekable();
+        // 第一列不是数字的首行是表头
+        if let Some((_, header)) = lines.peek() {
+            let first = header.split(',').next().unwrap_or("").trim();
+            if first.parse::<usize>().is_err() {
+                lines.next();
+            }
+        }
+        let edges = lines
+            .map(|(i, line)| parse_edge(i + 1, line.split(',').map(str::trim)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(build(edges, directed))
+    }
+}
+
+fn parse_edge<'a>(
+    line: usize,
+    mut fields: impl Iterator<Item = &'a str>,
+) -> Result<(usize, usize, i64), ParseGraphError> {
+    let error = |reason: String| ParseGraphError { line, reason };
+    let mut node = |name: &str| -> Result<usize, ParseGraphError> {
+        let field = fields
+            .next()
+            .ok_or_else(|| error(format!("missing {name} node")))?;
+        let node: usize = field
+            .parse()
+            .map_err(|_| error(format!("invalid {name} node {field:?}")))?;
+        if node >= MAX_NODES {
+            return Err(error(format!(
+                "{name} node {node} exceeds the limit of {MAX_NODES} nodes"
+            )));
+        }
+        Ok(node)
+    };
+    let from = node("source")?;
+    let to = node("target")?;
+    let weight = match fields.next() {
+        Some(field) => field
+            .parse()
+            .map_err(|_| error(format!("invalid weight {field:?}")))?,
+        None => 1,
+    };
+    if let Some(extra) = fields.next() {
+        return Err(error(format!("unexpected field {extra:?}")));
+    }
+    Ok((from, to, weight))
+}
+
+fn build(edges: Vec<(usize, usize, i64)>, directed: bool) -> Graph {
+    // 编号都小于 MAX_NODES，加一不会溢出
+    let nodes = edges
+        .iter()
+        .map(|&(a, b, _)| a.max(b) + 1)
+        .max()
+        .unwrap_or(0);
+    let mut graph = if directed {
+        Graph::new_directed(nodes)
+    } else {
+        Graph::new_undirected(nodes)
+    };
+    for (from, to, weight) in edges {
+        graph.add_weighted_edge(from, to, weight);
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseGraphError, MAX_NODES};
+    use crate::graph::{kruskal_mst, Graph};
+
+    #[test]
+    fn parses_edge_list() {
+        let input = "# a small weighted graph\n0 1 4\n1 2\n\n  2 0 -3  \n";
+        let graph = Graph::from_edge_list(input, true).unwrap();
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.edge(0).weight, 4);
+        assert_eq!(graph.edge(1).weight, 1);
+        assert_eq!(graph.edge(2).weight, -3);
+        assert!(graph.is_directed());
+    }
+
+    #[test]
+    fn parses_csv_with_header() {
+        let input = "source,target,weight\n0, 3, 2\n3,1,5\n";
+        let graph = Graph::from_csv(input, false).unwrap();
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.neighbors(3).collect::<Vec<_>>(), vec![0, 1]);
+        let without_header = Graph::from_csv("0,3,2\n3,1,5", false).unwrap();
+        assert_eq!(without_header, graph);
+    }
+
+    #[test]
+    fn reports_line_numbers() {
+        assert_eq!(
+            Graph::from_edge_list("0 1\n# ok\n1 x\n", true),
+            Err(ParseGraphError {
+                line: 3,
+                reason: "invalid target node \"x\"".to_string()
+            })
+        );
+        let error = Graph::from_csv("a,b\n0,1,2,3\n", true).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.to_string(), "line 2: unexpected field \"3\"");
+        assert_eq!(
+            Graph::from_edge_list("5", true).unwrap_err().reason,
+            "missing target node"
+        );
+    }
+
+    #[test]
+    fn rejects_huge_node_ids() {
+        let input = format!(
+            "0 1
+1 {}
+",
+            usize::MAX
+        );
+        assert_eq!(
+            Graph::from_edge_list(&input, true),
+            Err(ParseGraphError {
+                line: 2,
+                reason: format!(
+                    "target node {} exceeds the limit of {MAX_NODES} nodes",
+                    usize::MAX
+                )
+            })
+        );
+        let error = Graph::from_csv(
+            "from,to
+1000000000000,0
+",
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn exports_dot() {
+        let graph = Graph::from_edge_list("0 1\n1 2 7\n", true).unwrap();
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    0;\n    1;\n    2;\n    0 -> 1;\n    1 -> 2 [label=\"7\"];\n}\n"
+        );
+
+        let triangle = Graph::from_edge_list("0 1 1\n1 2 2\n0 2 3\n", false).unwrap();
+        let mst = kruskal_mst(&triangle);
+        let dot = triangle.to_dot_highlighted(&mst.edges);
+        assert!(dot.starts_with("graph {"));
+        assert!(dot.contains("0 -- 1 [color=red, penwidth=2];"));
+        assert!(dot.contains("0 -- 2 [label=\"3\"];"));
+    }
+}
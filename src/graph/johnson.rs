@@ -0,0 +1,194 @@
+/*!
+定义：
+[约翰逊算法](https://zh.wikipedia.org/wiki/%E7%BA%A6%E7%BF%B0%E9%80%8A%E7%AE%97%E6%B3%95)
+稀疏图上的全源最短路径，允许负权边但不能有负权环：
+1. 新增一个虚拟顶点 q，向所有顶点连一条权为 0 的边，用 Bellman-Ford 求出 q 到各顶点的距离 h
+2. 把边权改成 `w(u, v) + h(u) - h(v)`，由三角不等式它们都非负，且任意两点间的最短路径不变
+3. 从每个顶点出发跑一次 Dijkstra，再把距离还原为 `d'(u, v) - h(u) + h(v)`
+
+复杂度 O(VE log V)，在稀疏图上比 Floyd-Warshall 的 O(V³) 快。
+重赋权和还原时 `-h(u)` 可能超出 i64，这两步在 i128 中计算，只有结果超出 i64 时才报告溢出
+*/
+
+use super::adjacency_list::Graph;
+use super::dijkstra::dijkstra;
+use super::error::GraphError;
+use std::collections::HashMap;
+
+/// 所有可达点对之间的最短距离，以 (起点, 终点) 为键，不可达的点对不在表中。
+/// 存在负权环时返回 [`GraphError::NegativeCycle`]，最短距离或重赋权后的边权、距离超出 i64 时返回
+/// [`GraphError::DistanceOverflow`]
+pub fn johnson(graph: &Graph) -> Result<HashMap<(usize, usize), i64>, GraphError> {
+    let nodes = graph.node_count();
+    let potential = potentials(graph)?;
+
+    // 无向边在两个方向上的重赋权结果不同，拆成两条有向边
+    let mut reweighted = Graph::new_directed(nodes);
+    for edge in graph.edges() {
+        let mut add = |from: usize, to: usize| {
+            let weight = shift(edge.weight, potential[from], potential[to])?;
+            reweighted.add_weighted_edge(from, to, weight);
+            Ok::<_, GraphError>(())
+        };
+        add(edge.from, edge.to)?;
+        if !graph.is_directed() {
+            add(edge.to, edge.from)?;
+        }
+    }
+
+    let mut distances = HashMap::new();
+    for source in 0..nodes {
+        let paths = dijkstra(&reweighted, source)?;
+        for (target, distance) in paths.distance.into_iter().enumerate() {
+            if let Some(d) = distance {
+                let d = shift(d, potential[target], potential[source])?;
+                distances.insert((source, target), d);
+            }
+        }
+    }
+    Ok(distances)
+}
+
+/// `value + add - sub`，结果超出 i64 时返回 [`GraphError::DistanceOverflow`]
+fn shift(value: i64, add: i64, sub: i64) -> Result<i64, GraphError> {
+    let shifted = i128::from(value) + i128::from(add) - i128::from(sub);
+    i64::try_from(shifted).map_err(|_| GraphError::DistanceOverflow)
+}
+
+/// Bellman-Ford 求虚拟源点到各顶点的距离。虚拟源点到每个顶点都有权为 0 的边，
+/// 所以初始距离全部为 0；第 V 轮仍能松弛说明存在负权环。距离不会大于 0，
+/// 相加时只可能向下溢出，说明有负权环或者长度低于 i64 范围的路径
+fn potentials(graph: &Graph) -> Result<Vec<i64>, GraphError> {
+    let nodes = graph.node_count();
+    let mut distance = vec![0i64; nodes];
+    for round in 0..=nodes {
+        let mut relaxed = None;
+        for edge in graph.edges() {
+            let mut relax = |from: usize, to: usize| {
+                let candidate = distance[from]
+                    .checked_add(edge.weight)
+                    .ok_or(GraphError::DistanceOverflow)?;
+                if candidate < distance[to] {
+                    distance[to] = candidate;
+                    relaxed = Some(to);
+                }
+                Ok::<_, GraphError>(())
+            };
+            relax(edge.from, edge.to)?;
+            if !graph.is_directed() {
+                relax(edge.to, edge.from)?;
+            }
+        }
+        match relaxed {
+            None => return Ok(distance),
+            // 加上虚拟顶点一共 V + 1 个顶点，V 轮之后还能松弛就有负权环
+            Some(node) if round == nodes => return Err(GraphError::NegativeCycle { node }),
+            Some(_) => {}
+        }
+    }
+    unreachable!("the last round either converges or reports a cycle")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::johnson;
+    use crate::graph::{floyd_warshall, AdjacencyMatrix, Graph, GraphError};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn handles_negative_edges() {
+        let mut graph = Graph::new_directed(5);
+        for (from, to, weight) in [
+            (0, 1, 3),
+            (0, 2, 8),
+            (0, 4, -4),
+            (1, 3, 1),
+            (1, 4, 7),
+            (2, 1, 4),
+            (3, 0, 2),
+            (3, 2, -5),
+            (4, 3, 6),
+        ] {
+            graph.add_weighted_edge(from, to, weight);
+        }
+        let distances = johnson(&graph).unwrap();
+        assert_eq!(distances[&(0, 1)], 1);
+        assert_eq!(distances[&(0, 2)], -3);
+        assert_eq!(distances[&(0, 3)], 2);
+        assert_eq!(distances[&(4, 2)], 1);
+        assert_eq!(distances[&(2, 2)], 0);
+        assert_eq!(distances.len(), 25);
+    }
+
+    #[test]
+    fn detects_negative_cycles() {
+        let mut graph = Graph::new_directed(3);
+        graph.add_weighted_edge(0, 1, 1);
+        graph.add_weighted_edge(1, 2, -2);
+        graph.add_weighted_edge(2, 1, 1);
+        assert!(matches!(
+            johnson(&graph),
+            Err(GraphError::NegativeCycle { .. })
+        ));
+
+        // 无向图中的负权边本身就是负权环
+        let mut undirected = Graph::new_undirected(2);
+        undirected.add_weighted_edge(0, 1, -1);
+        assert!(johnson(&undirected).is_err());
+    }
+
+    #[test]
+    fn handles_extreme_weights() {
+        // 还原 1 -> 1 的距离时要减去 h(1) = i64::MIN
+        let mut graph = Graph::new_directed(2);
+        graph.add_weighted_edge(0, 1, i64::MIN);
+        let distances = johnson(&graph).unwrap();
+        assert_eq!(distances[&(0, 1)], i64::MIN);
+        assert_eq!(distances[&(1, 1)], 0);
+        assert_eq!(distances.len(), 3);
+
+        // 0 -> 1 -> 2 的长度低于 i64::MIN
+        graph = Graph::new_directed(3);
+        graph.add_weighted_edge(0, 1, i64::MIN);
+        graph.add_weighted_edge(1, 2, -1);
+        assert_eq!(johnson(&graph), Err(GraphError::DistanceOverflow));
+
+        // 更长的路径溢出时不影响结果
+        graph = Graph::new_directed(3);
+        graph.add_weighted_edge(0, 1, i64::MAX);
+        graph.add_weighted_edge(0, 2, i64::MAX);
+        graph.add_weighted_edge(1, 2, 1);
+        assert_eq!(johnson(&graph).unwrap()[&(0, 2)], i64::MAX);
+    }
+
+    #[test]
+    fn agrees_with_floyd_warshall() {
+        let mut rng = Rng::new(1414);
+        let mut compared = 0;
+        while compared < 50 {
+            let nodes = 1 + rng.gen_index(15);
+            let mut graph = Graph::new_directed(nodes);
+            for _ in 0..rng.gen_index(3 * nodes) {
+                let (from, to) = (rng.gen_index(nodes), rng.gen_index(nodes));
+                graph.add_weighted_edge(from, to, rng.gen_range(-3, 15));
+            }
+            let expected = floyd_warshall(&AdjacencyMatrix::from(&graph));
+            let actual = johnson(&graph);
+            let (expected, actual) = match (expected, actual) {
+                (Ok(expected), Ok(actual)) => (expected, actual),
+                // 两者必须同时报告负权环
+                (Err(_), Err(_)) => continue,
+                (expected, actual) => panic!("disagree: {expected:?} vs {actual:?}"),
+            };
+            for from in 0..nodes {
+                for to in 0..nodes {
+                    assert_eq!(
+                        actual.get(&(from, to)).copied(),
+                        expected.distance[from][to]
+                    );
+                }
+            }
+            compared += 1;
+        }
+    }
+}
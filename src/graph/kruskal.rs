@@ -0,0 +1,88 @@
+/*!
+定义：
+[克鲁斯克尔算法的定义](https://zh.wikipedia.org/wiki/%E5%85%8B%E9%B2%81%E6%96%AF%E5%85%8B%E5%B0%94%E6%BC%94%E7%AE%97%E6%B3%95)
+按边权从小到大考虑每条边，如果它连接的两个顶点还不连通（用并查集判断）就选中它。
+复杂度 O(E log E)，主要花在排序上。不连通的图得到的是最小生成森林，每个连通分量一棵树
+*/
+
+use super::adjacency_list::Graph;
+use crate::data_structures::union_find::UnionFind;
+use crate::sorting::tim_sort_by;
+
+/// 最小生成树（森林）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningForest {
+    /// 选中的边的 id
+    pub edges: Vec<usize>,
+    pub total_weight: i64,
+}
+
+/// 边的方向会被忽略，有向图按无向图处理
+pub fn kruskal_mst(graph: &Graph) -> SpanningForest {
+    let mut order: Vec<usize> = (0..graph.edge_count()).collect();
+    // 稳定排序，权相同时按 id 顺序选边，结果是确定的
+    tim_sort_by(&mut order, |&a, &b| {
+        graph.edge(a).weight.cmp(&graph.edge(b).weight)
+    });
+
+    let mut sets = UnionFind::new(graph.node_count());
+    let mut forest = SpanningForest {
+        edges: vec![],
+        total_weight: 0,
+    };
+    for id in order {
+        let edge = graph.edge(id);
+        if sets.union(edge.from, edge.to) {
+            forest.edges.push(id);
+            forest.total_weight += edge.weight;
+            // 只剩一个连通分量时已经选够了 V - 1 条边
+            if sets.component_count() == 1 {
+                break;
+            }
+        }
+    }
+    forest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::kruskal_mst;
+    use crate::graph::Graph;
+
+    #[test]
+    fn finds_minimum_spanning_tree() {
+        let mut graph = Graph::new_undirected(5);
+        for (from, to, weight) in [
+            (0, 1, 2),
+            (0, 3, 6),
+            (1, 2, 3),
+            (1, 3, 8),
+            (1, 4, 5),
+            (2, 4, 7),
+            (3, 4, 9),
+        ] {
+            graph.add_weighted_edge(from, to, weight);
+        }
+        let mst = kruskal_mst(&graph);
+        assert_eq!(mst.total_weight, 16);
+        let mut edges = mst.edges;
+        edges.sort();
+        assert_eq!(edges, vec![0, 1, 2, 4]);
+    }
+
+    #[test]
+    fn returns_forest_for_disconnected_graph() {
+        let mut graph = Graph::new_undirected(6);
+        graph.add_weighted_edge(0, 1, 4);
+        graph.add_weighted_edge(1, 2, -1);
+        graph.add_weighted_edge(0, 2, 3);
+        graph.add_weighted_edge(3, 4, 10);
+        graph.add_weighted_edge(3, 3, -5);
+        let mst = kruskal_mst(&graph);
+        // 三个连通分量：{0, 1, 2}、{3, 4}、{5}，自环不会被选中
+        assert_eq!(mst.edges.len(), 3);
+        assert_eq!(mst.total_weight, -1 + 3 + 10);
+
+        assert_eq!(kruskal_mst(&Graph::new_undirected(0)).edges, vec![]);
+    }
+}
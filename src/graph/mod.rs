@@ -0,0 +1,43 @@
+pub mod adjacency_list;
+pub mod adjacency_matrix;
+pub mod bfs;
+pub mod bipartite;
+pub mod boruvka;
+pub mod coloring;
+pub mod cycle;
+pub mod dfs;
+pub mod dijkstra;
+pub mod error;
+pub mod euler_tour;
+pub mod eulerian;
+pub mod flow;
+pub mod floyd_warshall;
+pub mod generators;
+pub mod hamiltonian;
+pub mod hopcroft_karp;
+pub mod io;
+pub mod johnson;
+pub mod kruskal;
+pub mod pagerank;
+
+pub use self::adjacency_list::{Edge, EdgeRef, Graph};
+pub use self::adjacency_matrix::AdjacencyMatrix;
+pub use self::bfs::{bfs, bfs_observed, shortest_path, BfsResult};
+pub use self::bipartite::{is_bipartite, Bipartiteness};
+pub use self::boruvka::boruvka_mst;
+pub use self::coloring::{greedy_coloring, is_proper_coloring, Coloring, ColoringStrategy};
+pub use self::cycle::{find_cycle, has_cycle};
+pub use self::dfs::{dfs, dfs_recursive, DfsResult, EdgeKind};
+pub use self::dijkstra::{dijkstra, dijkstra_observed, ShortestPaths};
+pub use self::error::GraphError;
+pub use self::euler_tour::{EulerTour, SubtreeQuery};
+pub use self::eulerian::{eulerian_path, EulerianPath};
+pub use self::floyd_warshall::{floyd_warshall, AllPairsShortestPaths};
+pub use self::hamiltonian::{
+    hamiltonian_path, hamiltonian_path_backtracking, hamiltonian_path_dp, tsp, Tour,
+};
+pub use self::hopcroft_karp::{hopcroft_karp, Matching};
+pub use self::io::ParseGraphError;
+pub use self::johnson::johnson;
+pub use self::kruskal::{kruskal_mst, SpanningForest};
+pub use self::pagerank::pagerank;
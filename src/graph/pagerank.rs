@@ -0,0 +1,122 @@
+/*!
+定义：
+[PageRank](https://zh.wikipedia.org/wiki/PageRank)
+随机游走的冲浪者以概率 d（阻尼系数）沿当前页面的一条出边随机前进，以概率 1 - d 跳到任意页面，
+PageRank 就是这个马尔可夫链的平稳分布。用幂迭代求解：
+`rank'[v] = (1 - d) / n + d · (Σ rank[u] / outdeg(u) + dangling / n)`，
+没有出边的顶点（悬挂节点）把自己的分数平均分给所有顶点，保证分数之和始终为 1
+*/
+
+use super::adjacency_list::Graph;
+use crate::numerical::Tolerance;
+
+/// 返回每个顶点的分数，总和为 1。两轮之间分数变化的 L1 距离小于 `tolerance.epsilon`，
+/// 或者迭代了 `tolerance.max_iterations` 轮时停止；阻尼系数为 1 时幂迭代可能不收敛
+pub fn pagerank(graph: &Graph, damping: f64, tolerance: Tolerance) -> Vec<f64> {
+    assert!(
+        (0.0..=1.0).contains(&damping),
+        "Damping factor {damping} must be in [0, 1]."
+    );
+    let n = graph.node_count();
+    if n == 0 {
+        return vec![];
+    }
+    let mut rank = vec![1.0 / n as f64; n];
+
+    for _ in 0..tolerance.max_iterations {
+        let mut next = vec![0.0; n];
+        let mut dangling = 0.0;
+        for (node, &score) in rank.iter().enumerate() {
+            let degree = graph.degree(node);
+            if degree == 0 {
+                dangling += score;
+                continue;
+            }
+            let share = score / degree as f64;
+            for target in graph.neighbors(node) {
+                next[target] += share;
+            }
+        }
+        let base = (1.0 - damping) / n as f64 + damping * dangling / n as f64;
+        for score in &mut next {
+            *score = base + damping * *score;
+        }
+
+        let change: f64 = rank.iter().zip(&next).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < tolerance.epsilon {
+            break;
+        }
+    }
+    rank
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pagerank;
+    use crate::graph::Graph;
+    use crate::numerical::Tolerance;
+
+    fn assert_close(actual: &[f64], expected: &[f64], tolerance: f64) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < tolerance, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn symmetric_cycle_is_uniform() {
+        let mut cycle = Graph::new_directed(4);
+        for v in 0..4 {
+            cycle.add_edge(v, (v + 1) % 4);
+        }
+        let rank = pagerank(&cycle, 0.85, Tolerance::new(f64::MIN_POSITIVE, 50));
+        assert_close(&rank, &[0.25; 4], 1e-12);
+    }
+
+    #[test]
+    fn matches_known_example() {
+        // A -> B, A -> C, B -> C, C -> A, D -> C
+        let mut graph = Graph::new_directed(4);
+        for (a, b) in [(0, 1), (0, 2), (1, 2), (2, 0), (3, 2)] {
+            graph.add_edge(a, b);
+        }
+        let rank = pagerank(&graph, 0.85, Tolerance::new(1e-12, 1000));
+        assert!((rank.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        // 没有入边的 D 只得到随机跳转的部分
+        assert!((rank[3] - 0.15 / 4.0).abs() < 1e-9);
+        assert_close(&rank, &[0.372526, 0.195824, 0.394149, 0.0375], 1e-5);
+        // C 有最多的入边
+        assert!(rank[2] > rank[0] && rank[0] > rank[1]);
+    }
+
+    #[test]
+    fn dangling_nodes_keep_total_mass() {
+        // 1 和 2 没有出边
+        let mut graph = Graph::new_directed(3);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        let rank = pagerank(&graph, 0.85, Tolerance::new(1e-12, 1000));
+        assert!((rank.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!((rank[1] - rank[2]).abs() < 1e-12);
+        assert!(rank[1] > rank[0]);
+
+        // 阻尼系数为 0 时是均匀分布
+        let rank = pagerank(&graph, 0.0, Tolerance::new(f64::MIN_POSITIVE, 1));
+        assert_close(&rank, &[1.0 / 3.0; 3], 1e-12);
+        assert_eq!(
+            pagerank(&Graph::new_directed(0), 0.85, Tolerance::default()),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn periodic_graph_stops_after_max_iterations() {
+        // 没有阻尼时，路径 0 - 1 - 2 上的分数在均匀分布和 (1/6, 2/3, 1/6) 之间来回振荡
+        let mut graph = Graph::new_directed(3);
+        for (a, b) in [(0, 1), (1, 0), (1, 2), (2, 1)] {
+            graph.add_edge(a, b);
+        }
+        let rank = pagerank(&graph, 1.0, Tolerance::new(1e-12, 3));
+        assert_close(&rank, &[1.0 / 6.0, 2.0 / 3.0, 1.0 / 6.0], 1e-12);
+    }
+}
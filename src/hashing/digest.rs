@@ -0,0 +1,106 @@
+/*!
+定义：
+[密码散列函数](https://zh.wikipedia.org/wiki/%E5%AF%86%E7%A2%BC%E9%9B%9C%E6%B9%8A%E5%87%BD%E6%95%B8)
+MD5 和 SHA-256 都是 [Merkle–Damgård 结构](https://en.wikipedia.org/wiki/Merkle%E2%80%93Damg%C3%A5rd_construction)：
+把消息切成 64 字节的块，依次用压缩函数把每一块混入内部状态。
+最后一块先补一个 0x80，再补 0 直到余下 8 字节，最后 8 字节写入消息的比特数，
+MD5 用小端序，SHA-256 用大端序
+*/
+
+use crate::conversions::hex_encode;
+
+/// 可以分多次输入数据的散列函数
+pub trait Digest: Sized {
+    /// 摘要，MD5 是 16 字节，SHA-256 是 32 字节
+    type Output: AsRef<[u8]>;
+
+    fn new() -> Self;
+
+    /// 追加一段数据
+    fn update(&mut self, data: &[u8]);
+
+    /// 补齐最后一块并返回摘要
+    fn finalize(self) -> Self::Output;
+
+    /// 一次性计算整段数据的摘要
+    fn digest(data: &[u8]) -> Self::Output {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// 摘要的小写十六进制表示
+    fn hex_digest(data: &[u8]) -> String {
+        to_hex(Self::digest(data).as_ref())
+    }
+}
+
+/// 每个字节两位小写十六进制
+pub fn to_hex(bytes: &[u8]) -> String {
+    hex_encode(bytes)
+}
+
+pub(super) const BLOCK_LEN: usize = 64;
+
+/// 攒满一块再交给压缩函数，并记录消息的总长度
+#[derive(Debug, Clone)]
+pub(super) struct BlockBuffer {
+    block: [u8; BLOCK_LEN],
+    len: usize,
+    /// 已经输入的字节数
+    total: u64,
+}
+
+impl BlockBuffer {
+    pub(super) fn new() -> Self {
+        BlockBuffer {
+            block: [0; BLOCK_LEN],
+            len: 0,
+            total: 0,
+        }
+    }
+
+    pub(super) fn update(&mut self, mut data: &[u8], mut compress: impl FnMut(&[u8; BLOCK_LEN])) {
+        self.total = self.total.wrapping_add(data.len() as u64);
+        while !data.is_empty() {
+            let take = (BLOCK_LEN - self.len).min(data.len());
+            self.block[self.len..self.len + take].copy_from_slice(&data[..take]);
+            self.len += take;
+            data = &data[take..];
+            if self.len == BLOCK_LEN {
+                compress(&self.block);
+                self.len = 0;
+            }
+        }
+    }
+
+    /// 补齐最后一块，`big_endian` 决定消息长度的字节序
+    pub(super) fn finish(mut self, big_endian: bool, mut compress: impl FnMut(&[u8; BLOCK_LEN])) {
+        let bits = self.total.wrapping_mul(8);
+        self.block[self.len] = 0x80;
+        self.block[self.len + 1..].fill(0);
+        // 放不下 8 字节的长度时要多补一整块
+        if self.len + 1 > BLOCK_LEN - 8 {
+            compress(&self.block);
+            self.block.fill(0);
+        }
+        let length = if big_endian {
+            bits.to_be_bytes()
+        } else {
+            bits.to_le_bytes()
+        };
+        self.block[BLOCK_LEN - 8..].copy_from_slice(&length);
+        compress(&self.block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_hex;
+
+    #[test]
+    fn hex_encoding() {
+        assert_eq!(to_hex(&[]), "");
+        assert_eq!(to_hex(&[0x00, 0x0f, 0xa5, 0xff]), "000fa5ff");
+    }
+}
@@ -0,0 +1,273 @@
+/*!
+定义：
+[非密码散列函数](https://en.wikipedia.org/wiki/Non-cryptographic_hash_function)
+速度快、分布均匀，但不抗碰撞攻击，适合散列表、布隆过滤器、Count-Min sketch 之类的场合。
+- [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)：
+  每个字节先异或进状态再乘以 FNV 素数
+- [djb2](http://www.cse.yorku.ca/~oz/hash.html)：`h = h * 33 + c`，非常简单，低位分布较差
+- [MurmurHash3](https://en.wikipedia.org/wiki/MurmurHash)：这里是 32 位的 x86 版本，
+  每 4 字节一组乘法、循环移位后混入状态，最后用 fmix32 打散所有位
+
+三者都实现了 [`std::hash::Hasher`]，可以配合 [`std::hash::BuildHasherDefault`] 用在标准库的 `HashMap` 里；
+[`SeededHasher`] 用不同的种子得到一族互相独立的散列函数
+*/
+
+use std::hash::{Hash, Hasher};
+
+/// 可以指定种子的散列函数，种子为 0 时和 `Default` 相同
+pub trait SeededHasher: Hasher + Default {
+    fn with_seed(seed: u64) -> Self;
+}
+
+/// 用种子为 `seed` 的 `H` 计算 `value` 的散列值
+pub fn hash_with<H: SeededHasher, T: Hash + ?Sized>(value: &T, seed: u64) -> u64 {
+    let mut hasher = H::with_seed(seed);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// 64 位 FNV-1a
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fnv1a {
+    state: u64,
+}
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a::with_seed(0)
+    }
+}
+
+impl SeededHasher for Fnv1a {
+    fn with_seed(seed: u64) -> Self {
+        Fnv1a {
+            state: FNV_OFFSET_BASIS ^ seed,
+        }
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state ^= byte as u64;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+/// 64 位 djb2
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Djb2 {
+    state: u64,
+}
+
+impl Default for Djb2 {
+    fn default() -> Self {
+        Djb2::with_seed(0)
+    }
+}
+
+impl SeededHasher for Djb2 {
+    fn with_seed(seed: u64) -> Self {
+        Djb2 { state: 5381 ^ seed }
+    }
+}
+
+impl Hasher for Djb2 {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state = self.state.wrapping_mul(33).wrapping_add(byte as u64);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+const MURMUR_C1: u32 = 0xcc9e2d51;
+const MURMUR_C2: u32 = 0x1b873593;
+
+/// 32 位 MurmurHash3，可以分多次输入，结果和一次性输入相同
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Murmur3 {
+    state: u32,
+    /// 还不满 4 字节的尾部
+    tail: [u8; 4],
+    tail_len: usize,
+    len: u64,
+}
+
+impl SeededHasher for Murmur3 {
+    /// 只使用种子的低 32 位
+    fn with_seed(seed: u64) -> Self {
+        Murmur3 {
+            state: seed as u32,
+            ..Default::default()
+        }
+    }
+}
+
+impl Hasher for Murmur3 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.len += bytes.len() as u64;
+        while !bytes.is_empty() {
+            let take = (4 - self.tail_len).min(bytes.len());
+            self.tail[self.tail_len..self.tail_len + take].copy_from_slice(&bytes[..take]);
+            self.tail_len += take;
+            bytes = &bytes[take..];
+            if self.tail_len == 4 {
+                self.state ^= murmur_scramble(u32::from_le_bytes(self.tail));
+                self.state = self
+                    .state
+                    .rotate_left(13)
+                    .wrapping_mul(5)
+                    .wrapping_add(0xe6546b64);
+                self.tail_len = 0;
+            }
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        let mut h = self.state;
+        if self.tail_len > 0 {
+            let mut tail = [0; 4];
+            tail[..self.tail_len].copy_from_slice(&self.tail[..self.tail_len]);
+            h ^= murmur_scramble(u32::from_le_bytes(tail));
+        }
+        // 长度按 32 位参与运算
+        h ^= self.len as u32;
+        h ^= h >> 16;
+        h = h.wrapping_mul(0x85ebca6b);
+        h ^= h >> 13;
+        h = h.wrapping_mul(0xc2b2ae35);
+        h ^= h >> 16;
+        h as u64
+    }
+}
+
+fn murmur_scramble(k: u32) -> u32 {
+    k.wrapping_mul(MURMUR_C1)
+        .rotate_left(15)
+        .wrapping_mul(MURMUR_C2)
+}
+
+/// 一次性计算 32 位 MurmurHash3
+pub fn murmur3_32(data: &[u8], seed: u32) -> u32 {
+    let mut hasher = Murmur3::with_seed(seed as u64);
+    hasher.write(data);
+    hasher.finish() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_with, murmur3_32, Djb2, Fnv1a, Murmur3, SeededHasher};
+    use crate::utils::random::Rng;
+    use std::collections::HashMap;
+    use std::hash::{BuildHasherDefault, Hasher};
+
+    fn raw<H: SeededHasher>(data: &[u8]) -> u64 {
+        let mut hasher = H::default();
+        hasher.write(data);
+        hasher.finish()
+    }
+
+    #[test]
+    fn known_values() {
+        assert_eq!(raw::<Fnv1a>(b""), 0xcbf29ce484222325);
+        assert_eq!(raw::<Fnv1a>(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(raw::<Fnv1a>(b"foobar"), 0x85944171f73967e8);
+
+        assert_eq!(raw::<Djb2>(b""), 5381);
+        assert_eq!(raw::<Djb2>(b"a"), 5381 * 33 + 97);
+
+        assert_eq!(murmur3_32(b"", 0), 0);
+        assert_eq!(murmur3_32(b"", 1), 0x514e28b7);
+        assert_eq!(murmur3_32(b"hello", 0), 0x248bfa47);
+        assert_eq!(murmur3_32(b"hello", 42), 0xe2dbd2e1);
+        assert_eq!(
+            murmur3_32(b"The quick brown fox jumps over the lazy dog", 0),
+            0x2e4ff723
+        );
+    }
+
+    #[test]
+    fn murmur_streaming_matches_one_shot() {
+        let mut rng = Rng::new(1481);
+        for len in 0..50 {
+            let data: Vec<u8> = (0..len).map(|_| rng.gen_index(256) as u8).collect();
+            let mut hasher = Murmur3::with_seed(7);
+            let mut rest = &data[..];
+            while !rest.is_empty() {
+                let take = rng.gen_index(rest.len()) + 1;
+                hasher.write(&rest[..take]);
+                rest = &rest[take..];
+            }
+            assert_eq!(hasher.finish() as u32, murmur3_32(&data, 7));
+        }
+    }
+
+    #[test]
+    fn seeds_give_different_functions() {
+        assert_eq!(
+            hash_with::<Fnv1a, _>("key", 0),
+            hash_with::<Fnv1a, _>("key", 0)
+        );
+        assert_ne!(
+            hash_with::<Fnv1a, _>("key", 0),
+            hash_with::<Fnv1a, _>("key", 1)
+        );
+        assert_ne!(
+            hash_with::<Djb2, _>("key", 0),
+            hash_with::<Djb2, _>("key", 1)
+        );
+        assert_ne!(
+            hash_with::<Murmur3, _>("key", 0),
+            hash_with::<Murmur3, _>("key", 1)
+        );
+    }
+
+    #[test]
+    fn works_with_std_hash_map() {
+        let mut map: HashMap<String, usize, BuildHasherDefault<Murmur3>> = HashMap::default();
+        for i in 0..1000 {
+            map.insert(format!("key{i}"), i);
+        }
+        assert_eq!(map.len(), 1000);
+        assert_eq!(map["key123"], 123);
+    }
+
+    /// 把连续的整数键放进 64 个桶，返回卡方统计量
+    fn chi_square<H: SeededHasher>(keys: usize) -> f64 {
+        const BUCKETS: usize = 64;
+        let mut counts = [0usize; BUCKETS];
+        for key in 0..keys {
+            let bucket = hash_with::<H, _>(&format!("item-{key}"), 0) as usize % BUCKETS;
+            counts[bucket] += 1;
+        }
+        let expected = keys as f64 / BUCKETS as f64;
+        counts
+            .iter()
+            .map(|&c| (c as f64 - expected).powi(2) / expected)
+            .sum()
+    }
+
+    #[test]
+    fn distribution_quality() {
+        // 自由度为 63，卡方统计量的期望是 63，超过 120 说明分布明显不均匀
+        let fnv = chi_square::<Fnv1a>(20_000);
+        let murmur = chi_square::<Murmur3>(20_000);
+        assert!(fnv < 120.0, "FNV-1a: {fnv}");
+        assert!(murmur < 120.0, "Murmur3: {murmur}");
+        // djb2 对只有结尾不同的键，低 6 位几乎完全由最后几个字节决定
+        let djb2 = chi_square::<Djb2>(20_000);
+        assert!(djb2 > murmur, "djb2: {djb2}, Murmur3: {murmur}");
+    }
+}
@@ -0,0 +1,141 @@
+/*!
+定义：
+[MD5](https://zh.wikipedia.org/wiki/MD5)
+按 RFC 1321 实现。每个 64 字节的块按小端序读成 16 个 32 位字，对 4 个 32 位的状态字做 4 组、每组 16 轮运算，
+每组使用不同的非线性函数和字的读取顺序。轮常数是 `floor(|sin(i + 1)| · 2^32)`。
+MD5 已经可以被构造碰撞，不应再用于安全相关的场合，这里只用作校验和
+*/
+
+use super::digest::{BlockBuffer, Digest, BLOCK_LEN};
+
+const INITIAL_STATE: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// 每组 4 个循环左移位数，组内轮流使用
+const SHIFTS: [[u32; 4]; 4] = [
+    [7, 12, 17, 22],
+    [5, 9, 14, 20],
+    [4, 11, 16, 23],
+    [6, 10, 15, 21],
+];
+
+#[derive(Debug, Clone)]
+pub struct Md5 {
+    state: [u32; 4],
+    buffer: BlockBuffer,
+}
+
+impl Digest for Md5 {
+    type Output = [u8; 16];
+
+    fn new() -> Self {
+        Md5 {
+            state: INITIAL_STATE,
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let state = &mut self.state;
+        self.buffer.update(data, |block| compress(state, block));
+    }
+
+    fn finalize(mut self) -> [u8; 16] {
+        let state = &mut self.state;
+        self.buffer.finish(false, |block| compress(state, block));
+        let mut output = [0; 16];
+        for (chunk, word) in output.chunks_exact_mut(4).zip(self.state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        output
+    }
+}
+
+impl Default for Md5 {
+    fn default() -> Self {
+        Md5::new()
+    }
+}
+
+fn compress(state: &mut [u32; 4], block: &[u8; BLOCK_LEN]) {
+    let mut words = [0u32; 16];
+    for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes(bytes.try_into().unwrap());
+    }
+
+    let [mut a, mut b, mut c, mut d] = *state;
+    for (i, &k) in ROUND_CONSTANTS.iter().enumerate() {
+        let (mixed, index) = match i / 16 {
+            0 => ((b & c) | (!b & d), i),
+            1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+            2 => (b ^ c ^ d, (3 * i + 5) % 16),
+            _ => (c ^ (b | !d), 7 * i % 16),
+        };
+        let sum = a
+            .wrapping_add(mixed)
+            .wrapping_add(k)
+            .wrapping_add(words[index]);
+        a = d;
+        d = c;
+        c = b;
+        b = b.wrapping_add(sum.rotate_left(SHIFTS[i / 16][i % 4]));
+    }
+    for (word, value) in state.iter_mut().zip([a, b, c, d]) {
+        *word = word.wrapping_add(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Md5;
+    use crate::hashing::digest::Digest;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn rfc_1321_test_suite() {
+        let vectors: [(&[u8], &str); 7] = [
+            (b"", "d41d8cd98f00b204e9800998ecf8427e"),
+            (b"a", "0cc175b9c0f1b6a831c399e269772661"),
+            (b"abc", "900150983cd24fb0d6963f7d28e17f72"),
+            (b"message digest", "f96b697d7cb7938d525a2f31aaf161d0"),
+            (
+                b"abcdefghijklmnopqrstuvwxyz",
+                "c3fcd3d76192e4007dfb496cca67e13b",
+            ),
+            (
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+                "d174ab98d277d9f5a5611c2c9f419d9f",
+            ),
+            (
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                "57edf4a22be3c955ac49da2e2107b67a",
+            ),
+        ];
+        for (message, expected) in vectors {
+            assert_eq!(Md5::hex_digest(message), expected);
+        }
+    }
+
+    #[test]
+    fn streaming_matches_one_shot() {
+        let mut rng = Rng::new(1480);
+        for len in (0..200).chain([447, 448, 511, 512]) {
+            let data: Vec<u8> = (0..len).map(|_| rng.gen_index(256) as u8).collect();
+            let mut hasher = Md5::new();
+            for chunk in data.chunks(rng.gen_index(70) + 1) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), Md5::digest(&data), "len = {len}");
+        }
+    }
+}
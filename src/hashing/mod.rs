@@ -0,0 +1,9 @@
+pub mod digest;
+pub mod hashers;
+pub mod md5;
+pub mod sha256;
+
+pub use self::digest::{to_hex, Digest};
+pub use self::hashers::{hash_with, murmur3_32, Djb2, Fnv1a, Murmur3, SeededHasher};
+pub use self::md5::Md5;
+pub use self::sha256::Sha256;
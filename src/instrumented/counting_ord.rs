@@ -0,0 +1,312 @@
+/*!
+定义：
+统计算法的基本操作次数，用实测的数字验证复杂度分析。
+`CountingOrd<T>` 包装任意可比较的值，每次比较都记到共享的 `Counters` 中，
+所以不用修改算法本身就能数出比较次数；复制一个元素（`Clone`）记作一次分配。
+交换在 Rust 中无法从外部观察，需要算法自己调用 `Counters::swap` 或 `record_swap` 报告，
+或者把 `Counters` 作为 [`Observer`] 传给支持观察的算法
+*/
+
+use crate::visualization::Observer;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+
+/// 某一时刻各项操作的次数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Counts {
+    pub comparisons: u64,
+    pub swaps: u64,
+    pub allocations: u64,
+}
+
+#[derive(Debug, Default)]
+struct Cells {
+    comparisons: Cell<u64>,
+    swaps: Cell<u64>,
+    allocations: Cell<u64>,
+}
+
+/// 共享的计数器，克隆得到的是同一组计数
+#[derive(Debug, Clone, Default)]
+pub struct Counters {
+    cells: Rc<Cells>,
+}
+
+impl Counters {
+    pub fn new() -> Self {
+        Counters::default()
+    }
+
+    pub fn comparisons(&self) -> u64 {
+        self.cells.comparisons.get()
+    }
+
+    pub fn swaps(&self) -> u64 {
+        self.cells.swaps.get()
+    }
+
+    pub fn allocations(&self) -> u64 {
+        self.cells.allocations.get()
+    }
+
+    pub fn counts(&self) -> Counts {
+        Counts {
+            comparisons: self.comparisons(),
+            swaps: self.swaps(),
+            allocations: self.allocations(),
+        }
+    }
+
+    pub fn record_comparison(&self) {
+        add(&self.cells.comparisons, 1);
+    }
+
+    pub fn record_swap(&self) {
+        add(&self.cells.swaps, 1);
+    }
+
+    pub fn record_allocation(&self) {
+        add(&self.cells.allocations, 1);
+    }
+
+    /// 交换 `arr[a]` 和 `arr[b]` 并记一次交换
+    pub fn swap<T>(&self, arr: &mut [T], a: usize, b: usize) {
+        arr.swap(a, b);
+        self.record_swap();
+    }
+
+    /// 所有计数清零
+    pub fn reset(&self) {
+        self.cells.comparisons.set(0);
+        self.cells.swaps.set(0);
+        self.cells.allocations.set(0);
+    }
+
+    /// 用这组计数器包装一个值
+    pub fn wrap<T>(&self, value: T) -> CountingOrd<T> {
+        CountingOrd::new(value, self)
+    }
+
+    pub fn wrap_all<T, I: IntoIterator<Item = T>>(&self, values: I) -> Vec<CountingOrd<T>> {
+        values.into_iter().map(|v| self.wrap(v)).collect()
+    }
+}
+
+/// 观察者接口只统计比较和交换，元素在原数组中移动，不产生分配
+impl Observer for Counters {
+    fn on_compare(&mut self, _i: usize, _j: usize) {
+        self.record_comparison();
+    }
+
+    fn on_swap(&mut self, _i: usize, _j: usize) {
+        self.record_swap();
+    }
+}
+
+fn add(cell: &Cell<u64>, amount: u64) {
+    cell.set(cell.get() + amount);
+}
+
+/// 比较时计数的包装类型，比较结果与 `T` 本身相同
+pub struct CountingOrd<T> {
+    value: T,
+    counters: Counters,
+}
+
+impl<T> CountingOrd<T> {
+    pub fn new(value: T, counters: &Counters) -> Self {
+        CountingOrd {
+            value,
+            counters: counters.clone(),
+        }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Clone> Clone for CountingOrd<T> {
+    fn clone(&self) -> Self {
+        self.counters.record_allocation();
+        CountingOrd::new(self.value.clone(), &self.counters)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CountingOrd<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for CountingOrd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counters.record_comparison();
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for CountingOrd<T> {}
+
+impl<T: Ord> PartialOrd for CountingOrd<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for CountingOrd<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.counters.record_comparison();
+        self.value.cmp(&other.value)
+    }
+}
+
+#[cfg(all(test, feature = "sorting"))]
+mod tests {
+    use super::{Counters, Counts};
+    use crate::data_structures::binary_search_tree::BinarySearchTree;
+    use crate::sorting::{all, merge_sort, Sorter};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn counts_operations() {
+        let counters = Counters::new();
+        let (a, b) = (counters.wrap(1), counters.wrap(2));
+        assert!(a < b);
+        assert!(a != b);
+        assert_eq!(a.clone().into_inner(), 1);
+        let mut arr = [1, 2, 3];
+        counters.swap(&mut arr, 0, 2);
+        assert_eq!(arr, [3, 2, 1]);
+        assert_eq!(
+            counters.counts(),
+            Counts {
+                comparisons: 2,
+                swaps: 1,
+                allocations: 1,
+            }
+        );
+        // 克隆的计数器共享计数
+        counters.clone().record_comparison();
+        assert_eq!(counters.comparisons(), 3);
+        counters.reset();
+        assert_eq!(counters.counts(), Counts::default());
+        assert_eq!(format!("{:?}", counters.wrap("x")), "\"x\"");
+    }
+
+    #[test]
+    fn merge_sort_is_n_log_n() {
+        let mut rng = Rng::new(1490);
+        for n in [1usize, 2, 10, 100, 1000, 4096, 5000] {
+            let counters = Counters::new();
+            let mut arr = counters.wrap_all((0..n).map(|_| rng.gen_range(0, 1_000_000)));
+            merge_sort(&mut arr);
+            assert!(arr.windows(2).all(|w| w[0].get() <= w[1].get()));
+            let log = (n as f64).log2().ceil() as u64;
+            assert!(counters.comparisons() <= n as u64 * log + n as u64);
+            // 合并只移动元素，不复制
+            assert_eq!(counters.allocations(), 0);
+        }
+    }
+
+    #[test]
+    fn insertion_sort_swaps_equal_inversions() {
+        let mut rng = Rng::new(1490);
+        let arr: Vec<i64> = (0..300).map(|_| rng.gen_range(0, 100)).collect();
+        let inversions = crate::sorting::count_inversions(&arr);
+        let sorters = all();
+        let insertion = sorters
+            .iter()
+            .find(|s| s.name() == "insertion_sort")
+            .unwrap();
+        let counters = Counters::new();
+        let mut sorted = arr.clone();
+        insertion.sort_counted(&mut sorted, &counters);
+        assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(counters.swaps(), inversions);
+        assert!(counters.comparisons() <= inversions + 299);
+    }
+
+    #[test]
+    fn observed_sorters_count_swaps() {
+        let mut rng = Rng::new(1498);
+        let n = 1000u64;
+        let arr: Vec<i64> = (0..n).map(|_| rng.gen_range(0, 1_000_000)).collect();
+        let log = (n as f64).log2().ceil() as u64;
+        for name in ["heap_sort", "shell_sort"] {
+            for sorter in all().iter().filter(|s| s.name() == name) {
+                let counters = Counters::new();
+                let mut sorted = arr.clone();
+                sorter.sort_counted(&mut sorted, &counters);
+                assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+                assert!(counters.swaps() > 0, "{name}");
+                assert!(counters.swaps() <= counters.comparisons());
+                // 堆排序每次下沉最多 log n 次交换
+                if name == "heap_sort" {
+                    assert!(counters.swaps() <= 2 * n * log);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn quadratic_and_linearithmic_sorters() {
+        let mut rng = Rng::new(1490);
+        let n = 2000u64;
+        let arr: Vec<i64> = (0..n).map(|_| rng.gen_range(0, 1_000_000)).collect();
+        for sorter in all() {
+            let counters = Counters::new();
+            let mut sorted = arr.clone();
+            sorter.sort_counted(&mut sorted, &counters);
+            assert!(sorted.windows(2).all(|w| w[0] <= w[1]), "{}", sorter.name());
+            let comparisons = counters.comparisons();
+            match sorter.name() {
+                // 随机输入上的 O(n²) 算法至少要 n²/8 次比较
+                "insertion_sort"
+                | "gnome_sort"
+                | "cocktail_shaker_sort"
+                | "cycle_sort"
+                | "pancake_sort" => assert!(comparisons > n * n / 8, "{}", sorter.name()),
+                // O(n log n) 的算法不超过 4·n·log₂n 次比较
+                "merge_sort" | "heap_sort" | "intro_sort" | "tim_sort" => {
+                    assert!(comparisons < 4 * n * 11, "{}", sorter.name())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn bst_depth_depends_on_insertion_order() {
+        let n = 500;
+        let counters = Counters::new();
+        let mut sorted = BinarySearchTree::new();
+        for value in counters.wrap_all(0..n) {
+            sorted.insert(value);
+        }
+        // 按顺序插入退化成链表，共比较 0 + 1 + ... + (n - 1) 次
+        assert_eq!(counters.comparisons(), (n * (n - 1) / 2) as u64);
+        assert_eq!(sorted.height(), n as usize);
+
+        counters.reset();
+        let mut values: Vec<i64> = (0..n).collect();
+        Rng::new(1490).shuffle(&mut values);
+        let mut random = BinarySearchTree::new();
+        for value in counters.wrap_all(values) {
+            random.insert(value);
+        }
+        // 随机顺序插入的期望比较次数约为 1.39·n·log₂n
+        assert!(counters.comparisons() < 3 * n as u64 * 9);
+        assert!(random.height() < 40);
+
+        counters.reset();
+        assert!(random.search(&counters.wrap(250)));
+        assert!(counters.comparisons() <= random.height() as u64);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod counting_ord;
+
+pub use self::counting_ord::{Counters, CountingOrd, Counts};
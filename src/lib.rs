@@ -1,4 +1,69 @@
+/*!
+算法与数据结构的 Rust 实现。常用的条目可以通过 `use rust_algo::prelude::*;` 一次导入。
+
+每个较大的领域都有同名的特性，默认全部开启，只需要其中一部分时可以关掉默认特性按需选择，减少编译时间：
+- `sorting`、`searching`、`strings`、`math`
+- `graph`：依赖 `sorting` 和 `searching`
+- `dp`：依赖 `graph`、`searching` 和 `strings`
+- `wasm`：通过 wasm-bindgen 导出排序、二叉搜索树和迷宫寻路的逐步记录，供浏览器中的可视化使用
+
+依赖某个领域的模块随之开启，例如 `backtracking` 需要 `dp`，`ciphers`、`conversions`、`hashing`、
+`machine_learning` 需要 `math`，`maze` 需要 `graph`，其余模块只需要 `std`。
+
+关闭所有特性（`default-features = false`）时 crate 是 `#![no_std]` 的，只依赖 `alloc`，
+提供 `data_structures` 中不需要浮点数学函数的结构：链表、二叉搜索树、队列、堆、并查集等，
+可以在嵌入式环境中使用
+*/
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "dp")]
+pub mod backtracking;
+#[cfg(feature = "math")]
+pub mod ciphers;
+#[cfg(feature = "std")]
+pub mod combinatorics;
+#[cfg(feature = "std")]
+pub mod compression;
+#[cfg(feature = "math")]
+pub mod conversions;
 pub mod data_structures;
+#[cfg(feature = "dp")]
+pub mod dp;
+pub mod error;
+#[cfg(feature = "arbitrary")]
+pub mod fuzzing;
+#[cfg(feature = "std")]
+pub mod geometry;
+#[cfg(feature = "graph")]
+pub mod graph;
+#[cfg(feature = "math")]
+pub mod hashing;
+#[cfg(feature = "std")]
+pub mod instrumented;
+#[cfg(feature = "math")]
+pub mod machine_learning;
+#[cfg(feature = "math")]
+pub mod math;
+#[cfg(feature = "graph")]
+pub mod maze;
+#[cfg(feature = "std")]
+pub mod numerical;
+#[cfg(feature = "std")]
+pub mod optimization;
+pub mod prelude;
+#[cfg(feature = "std")]
+pub mod recursion;
+#[cfg(feature = "searching")]
+pub mod searching;
+#[cfg(feature = "sorting")]
+pub mod sorting;
+#[cfg(feature = "strings")]
+pub mod strings;
+pub mod utils;
+pub mod visualization;
 
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
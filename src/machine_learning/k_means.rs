@@ -0,0 +1,241 @@
+/*!
+定义：
+[k-平均算法](https://zh.wikipedia.org/wiki/K-%E5%B9%B3%E5%9D%87%E7%AE%97%E6%B3%95)
+把点分成 k 簇，使每个点到所属簇中心的距离平方和（inertia）尽量小。
+Lloyd 迭代交替进行两步：把每个点分给最近的中心，再把中心移到簇内所有点的平均位置，inertia 单调不增。
+结果依赖初始中心，[k-means++](https://en.wikipedia.org/wiki/K-means%2B%2B) 初始化先随机选一个点，
+之后每次以正比于“到已选中心最近距离的平方”的概率选下一个点，期望上离最优解不超过 O(log k) 倍
+*/
+
+use crate::numerical::Tolerance;
+use crate::utils::random::Rng;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clustering<const N: usize> {
+    pub centroids: Vec<[f64; N]>,
+    /// 每个点所属的簇
+    pub assignments: Vec<usize>,
+    /// 每个点到所属中心的距离平方之和
+    pub inertia: f64,
+    pub iterations: usize,
+    /// 中心的移动距离是否已经小于 `tolerance.epsilon`
+    pub converged: bool,
+}
+
+/// 用 k-means++ 初始化后做 Lloyd 迭代，直到所有中心的移动距离都小于 `tolerance.epsilon`，
+/// 或者达到最大迭代次数。`k` 必须在 1 和点数之间
+pub fn k_means<const N: usize>(
+    points: &[[f64; N]],
+    k: usize,
+    tolerance: &Tolerance,
+    rng: &mut Rng,
+) -> Clustering<N> {
+    assert!(
+        (1..=points.len()).contains(&k),
+        "Cannot split {} points into {k} clusters.",
+        points.len()
+    );
+    let mut centroids = k_means_plus_plus(points, k, rng);
+    let mut assignments = vec![0; points.len()];
+    let mut iterations = 0;
+    let mut converged = false;
+    while iterations < tolerance.max_iterations && !converged {
+        iterations += 1;
+        for (assignment, point) in assignments.iter_mut().zip(points) {
+            *assignment = nearest(&centroids, point).0;
+        }
+
+        let mut sums = vec![[0.0; N]; k];
+        let mut counts = vec![0usize; k];
+        for (&cluster, point) in assignments.iter().zip(points) {
+            counts[cluster] += 1;
+            for (sum, &x) in sums[cluster].iter_mut().zip(point) {
+                *sum += x;
+            }
+        }
+        let mut max_shift: f64 = 0.0;
+        for cluster in 0..k {
+            // 空簇保留原来的中心
+            if counts[cluster] == 0 {
+                continue;
+            }
+            let mean = sums[cluster].map(|sum| sum / counts[cluster] as f64);
+            max_shift = max_shift.max(squared_distance(&mean, &centroids[cluster]).sqrt());
+            centroids[cluster] = mean;
+        }
+        converged = max_shift < tolerance.epsilon;
+    }
+
+    // 最后一次移动中心之后重新分配，保证结果和中心一致
+    let mut inertia = 0.0;
+    for (assignment, point) in assignments.iter_mut().zip(points) {
+        let (cluster, distance) = nearest(&centroids, point);
+        *assignment = cluster;
+        inertia += distance;
+    }
+    Clustering {
+        centroids,
+        assignments,
+        inertia,
+        iterations,
+        converged,
+    }
+}
+
+/// k-means++ 选出的 `k` 个初始中心
+pub fn k_means_plus_plus<const N: usize>(
+    points: &[[f64; N]],
+    k: usize,
+    rng: &mut Rng,
+) -> Vec<[f64; N]> {
+    let mut centroids = vec![points[rng.gen_index(points.len())]];
+    // 每个点到已选中心的最近距离的平方
+    let mut distances: Vec<f64> = points
+        .iter()
+        .map(|p| squared_distance(p, &centroids[0]))
+        .collect();
+    while centroids.len() < k {
+        let total: f64 = distances.iter().sum();
+        let chosen = if total > 0.0 {
+            let mut target = rng.next_f64() * total;
+            // 浮点误差可能让 target 略大于所有距离之和，这时取最后一个距离不为 0 的点
+            distances
+                .iter()
+                .position(|&d| {
+                    target -= d;
+                    target < 0.0
+                })
+                .unwrap_or_else(|| distances.iter().rposition(|&d| d > 0.0).unwrap())
+        } else {
+            // 所有点都和已选的中心重合
+            rng.gen_index(points.len())
+        };
+        centroids.push(points[chosen]);
+        for (distance, point) in distances.iter_mut().zip(points) {
+            *distance = distance.min(squared_distance(point, &points[chosen]));
+        }
+    }
+    centroids
+}
+
+/// 最近的中心的下标和距离的平方
+fn nearest<const N: usize>(centroids: &[[f64; N]], point: &[f64; N]) -> (usize, f64) {
+    centroids
+        .iter()
+        .map(|c| squared_distance(c, point))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap()
+}
+
+fn squared_distance<const N: usize>(a: &[f64; N], b: &[f64; N]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{k_means, k_means_plus_plus};
+    use crate::numerical::Tolerance;
+    use crate::utils::random::Rng;
+
+    /// 以 `centers` 为中心、标准差为 `spread` 的正态分布点，每个中心 `per_blob` 个
+    fn blobs<const N: usize>(
+        centers: &[[f64; N]],
+        per_blob: usize,
+        spread: f64,
+        rng: &mut Rng,
+    ) -> Vec<[f64; N]> {
+        let mut points = vec![];
+        for center in centers {
+            for _ in 0..per_blob {
+                // Box–Muller 变换
+                points.push(center.map(|c| {
+                    let (u, v) = (1.0 - rng.next_f64(), rng.next_f64());
+                    c + spread * (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+                }));
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn separates_well_separated_blobs() {
+        let mut rng = Rng::new(1485);
+        let centers = [[0.0, 0.0], [10.0, 10.0], [-10.0, 10.0]];
+        let points = blobs(&centers, 100, 1.0, &mut rng);
+        let result = k_means(&points, 3, &Tolerance::default(), &mut rng);
+        assert!(result.converged);
+        for blob in result.assignments.chunks(100) {
+            assert!(blob.iter().all(|&c| c == blob[0]));
+        }
+        for center in centers {
+            let closest = result
+                .centroids
+                .iter()
+                .map(|c| ((c[0] - center[0]).powi(2) + (c[1] - center[1]).powi(2)).sqrt())
+                .fold(f64::INFINITY, f64::min);
+            assert!(closest < 0.5, "{closest}");
+        }
+        // 每个点大约贡献 2 倍方差
+        assert!(result.inertia < 300.0 * 2.0 * 1.5);
+    }
+
+    #[test]
+    fn reproducible_with_same_seed() {
+        let points = blobs(&[[0.0; 3], [5.0; 3]], 50, 2.0, &mut Rng::new(1485));
+        let run = |seed| k_means(&points, 4, &Tolerance::default(), &mut Rng::new(seed));
+        assert_eq!(run(1), run(1));
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        let mut rng = Rng::new(1485);
+        let points = [[1.0], [2.0], [10.0], [11.0]];
+        let result = k_means(&points, 4, &Tolerance::default(), &mut rng);
+        assert_eq!(result.inertia, 0.0);
+
+        let single = k_means(&points, 1, &Tolerance::default(), &mut rng);
+        assert_eq!(single.centroids, vec![[6.0]]);
+        assert_eq!(single.assignments, vec![0; 4]);
+
+        let result = k_means(&points, 2, &Tolerance::default(), &mut rng);
+        assert_eq!(result.assignments[0], result.assignments[1]);
+        assert_ne!(result.assignments[1], result.assignments[2]);
+        assert_eq!(result.inertia, 1.0);
+
+        // 重复的点：k-means++ 仍然能选出 k 个中心
+        let same = [[3.0, 3.0]; 5];
+        assert_eq!(k_means_plus_plus(&same, 3, &mut rng).len(), 3);
+        let result = k_means(&same, 2, &Tolerance::default(), &mut rng);
+        assert_eq!(result.inertia, 0.0);
+    }
+
+    #[test]
+    fn stops_at_max_iterations() {
+        let mut rng = Rng::new(1485);
+        let points = blobs(&[[0.0, 0.0], [3.0, 0.0]], 200, 1.5, &mut rng);
+        let result = k_means(&points, 5, &Tolerance::new(1e-12, 1), &mut rng);
+        assert_eq!(result.iterations, 1);
+    }
+
+    #[test]
+    fn plus_plus_prefers_distant_points() {
+        let mut rng = Rng::new(1485);
+        // 离群点到其它点的距离平方远大于其它点之间的，所以它几乎总会被选为中心
+        let mut points = vec![[0.0, 0.0]; 99];
+        points.push([1000.0, 1000.0]);
+        points[0] = [0.001, 0.0];
+        let mut hits = 0;
+        for _ in 0..100 {
+            let centroids = k_means_plus_plus(&points, 2, &mut rng);
+            hits += centroids.contains(&[1000.0, 1000.0]) as usize;
+        }
+        assert!(hits >= 99, "{hits}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_too_many_clusters() {
+        k_means(&[[0.0]], 2, &Tolerance::default(), &mut Rng::new(1485));
+    }
+}
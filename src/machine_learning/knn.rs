@@ -0,0 +1,225 @@
+/*!
+定义：
+[k-近邻算法](https://zh.wikipedia.org/wiki/K-%E8%BF%91%E9%82%BB%E7%AE%97%E6%B3%95)
+不做训练，预测时找出离查询点最近的 k 个样本，由它们的标签投票决定结果。
+投票可以一人一票，也可以按距离的倒数加权，让更近的邻居说话更有分量。
+欧氏距离和曼哈顿距离都不小于任意一个坐标之差，可以用 k-d 树剪枝查找；
+余弦距离不满足这一点，只能逐个比较
+*/
+
+use crate::data_structures::kd_tree::KdTree;
+
+/// 点之间的距离
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Euclidean,
+    Manhattan,
+    /// 1 - 余弦相似度，范围 [0, 2]。零向量与任何点的距离都视为 1
+    Cosine,
+}
+
+impl Metric {
+    pub fn distance<const N: usize>(&self, a: &[f64; N], b: &[f64; N]) -> f64 {
+        match self {
+            Metric::Euclidean => a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum::<f64>()
+                .sqrt(),
+            Metric::Manhattan => a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum(),
+            Metric::Cosine => {
+                let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+                let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+                let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+                if norm_a == 0.0 || norm_b == 0.0 {
+                    1.0
+                } else {
+                    1.0 - dot / (norm_a * norm_b)
+                }
+            }
+        }
+    }
+}
+
+/// 邻居的投票方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Voting {
+    /// 每个邻居一票
+    Majority,
+    /// 每个邻居的票数是距离的倒数，距离为 0 的邻居票数无穷大
+    DistanceWeighted,
+}
+
+#[derive(Debug, Clone)]
+pub struct KnnClassifier<const N: usize, L> {
+    tree: KdTree<N>,
+    labels: Vec<L>,
+    k: usize,
+    metric: Metric,
+    voting: Voting,
+}
+
+impl<const N: usize, L: Clone + PartialEq> KnnClassifier<N, L> {
+    /// `points` 和 `labels` 必须等长且不为空，`k` 至少为 1
+    pub fn new(
+        points: &[[f64; N]],
+        labels: &[L],
+        k: usize,
+        metric: Metric,
+        voting: Voting,
+    ) -> Self {
+        assert_eq!(
+            points.len(),
+            labels.len(),
+            "Every point needs exactly one label."
+        );
+        assert!(!points.is_empty(), "Cannot classify without samples.");
+        assert!(k > 0, "k must be at least 1.");
+        KnnClassifier {
+            tree: KdTree::new(points),
+            labels: labels.to_vec(),
+            k,
+            metric,
+            voting,
+        }
+    }
+
+    /// 最近的 k 个样本的下标和距离，按距离从近到远排列，距离相同时下标小的在前
+    pub fn neighbors(&self, query: &[f64; N]) -> Vec<(usize, f64)> {
+        match self.metric {
+            Metric::Euclidean => self.tree.k_nearest(query, self.k),
+            Metric::Manhattan => self
+                .tree
+                .k_nearest_by(query, self.k, |a, b| self.metric.distance(a, b)),
+            Metric::Cosine => {
+                let mut all: Vec<(usize, f64)> = (0..self.tree.len())
+                    .map(|i| (i, self.metric.distance(query, self.tree.point(i))))
+                    .collect();
+                all.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+                all.truncate(self.k);
+                all
+            }
+        }
+    }
+
+    /// 得票最多的标签，平票时取其中离查询点最近的邻居的标签
+    pub fn predict(&self, query: &[f64; N]) -> L {
+        // 按邻居出现的顺序记录标签，先出现的标签离查询点更近
+        let mut votes: Vec<(&L, f64)> = vec![];
+        for (index, distance) in self.neighbors(query) {
+            let weight = match self.voting {
+                Voting::Majority => 1.0,
+                Voting::DistanceWeighted => 1.0 / distance,
+            };
+            let label = &self.labels[index];
+            match votes.iter_mut().find(|(l, _)| *l == label) {
+                Some((_, total)) => *total += weight,
+                None => votes.push((label, weight)),
+            }
+        }
+        // 严格大于才替换，平票时保留先出现的标签
+        let mut best = votes[0];
+        for &vote in &votes[1..] {
+            if vote.1 > best.1 {
+                best = vote;
+            }
+        }
+        best.0.clone()
+    }
+
+    pub fn predict_all(&self, queries: &[[f64; N]]) -> Vec<L> {
+        queries.iter().map(|q| self.predict(q)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KnnClassifier, Metric, Voting};
+    use crate::machine_learning::metrics::accuracy;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn metrics() {
+        let (a, b) = ([1.0, 2.0], [4.0, 6.0]);
+        assert_eq!(Metric::Euclidean.distance(&a, &b), 5.0);
+        assert_eq!(Metric::Manhattan.distance(&a, &b), 7.0);
+        assert!(Metric::Cosine.distance(&[1.0, 0.0], &[3.0, 0.0]).abs() < 1e-12);
+        assert!((Metric::Cosine.distance(&[1.0, 0.0], &[0.0, 2.0]) - 1.0).abs() < 1e-12);
+        assert!((Metric::Cosine.distance(&[1.0, 1.0], &[-1.0, -1.0]) - 2.0).abs() < 1e-12);
+        assert_eq!(Metric::Cosine.distance(&[0.0, 0.0], &a), 1.0);
+    }
+
+    #[test]
+    fn voting() {
+        let points = [[0.0], [1.0], [5.0], [6.0], [7.0]];
+        let labels = ["a", "a", "b", "b", "b"];
+        // 最近的 5 个邻居中 b 占多数
+        let majority = KnnClassifier::new(&points, &labels, 5, Metric::Euclidean, Voting::Majority);
+        assert_eq!(majority.predict(&[0.5]), "b");
+        // 加权后近处的 a 胜出
+        let weighted = KnnClassifier::new(
+            &points,
+            &labels,
+            5,
+            Metric::Euclidean,
+            Voting::DistanceWeighted,
+        );
+        assert_eq!(weighted.predict(&[0.5]), "a");
+        // 与样本重合时直接取该样本的标签
+        assert_eq!(weighted.predict(&[5.0]), "b");
+        // 平票时取最近的邻居的标签
+        let two = KnnClassifier::new(&points, &labels, 2, Metric::Manhattan, Voting::Majority);
+        assert_eq!(two.predict(&[3.2]), "b");
+        assert_eq!(two.predict(&[2.8]), "a");
+        let neighbors: Vec<usize> = two.neighbors(&[3.2]).iter().map(|n| n.0).collect();
+        assert_eq!(neighbors, vec![2, 1]);
+    }
+
+    #[test]
+    fn cosine_compares_directions() {
+        let points = [[1.0, 0.1], [10.0, 0.0], [0.0, 1.0], [0.1, 10.0]];
+        let labels = [0, 0, 1, 1];
+        let knn = KnnClassifier::new(&points, &labels, 2, Metric::Cosine, Voting::Majority);
+        // 离 [1, 0.1] 很近，但方向更接近第二类
+        assert_eq!(knn.predict(&[0.9, 1.0]), 1);
+        assert_eq!(knn.predict_all(&[[100.0, 1.0], [1.0, 100.0]]), vec![0, 1]);
+    }
+
+    #[test]
+    fn matches_brute_force_and_separates_blobs() {
+        let mut rng = Rng::new(1487);
+        let sample =
+            |center: f64, rng: &mut Rng| [0; 3].map(|_: i32| center + rng.next_f64() * 4.0 - 2.0);
+        let mut points = vec![];
+        let mut labels = vec![];
+        for _ in 0..150 {
+            let label = rng.gen_index(3);
+            points.push(sample(label as f64 * 3.0, &mut rng));
+            labels.push(label);
+        }
+        let queries: Vec<[f64; 3]> = (0..60)
+            .map(|i| sample((i % 3) as f64 * 3.0, &mut rng))
+            .collect();
+        let expected: Vec<usize> = (0..60).map(|i| i % 3).collect();
+
+        for metric in [Metric::Euclidean, Metric::Manhattan, Metric::Cosine] {
+            for voting in [Voting::Majority, Voting::DistanceWeighted] {
+                let knn = KnnClassifier::new(&points, &labels, 7, metric, voting);
+                for query in &queries {
+                    let mut all: Vec<(usize, f64)> = points
+                        .iter()
+                        .enumerate()
+                        .map(|(i, p)| (i, metric.distance(query, p)))
+                        .collect();
+                    all.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+                    assert_eq!(knn.neighbors(query), all[..7]);
+                }
+                if metric != Metric::Cosine {
+                    let predicted = knn.predict_all(&queries);
+                    assert!(accuracy(&predicted, &expected) > 0.8);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+/*!
+定义：
+[线性回归](https://zh.wikipedia.org/wiki/%E7%B7%9A%E6%80%A7%E5%9B%9E%E6%AD%B8)
+用 ŷ = w·x + b 拟合数据，使均方误差最小。
+- 只有一个特征时有闭式解：w = cov(x, y) / var(x)，b = ȳ - w·x̄
+- 多个特征时用[梯度下降](https://zh.wikipedia.org/wiki/%E6%A2%AF%E5%BA%A6%E4%B8%8B%E9%99%8D%E6%B3%95)：
+  误差 e = Xw + b - y，均方误差对 w 的梯度是 (2/n)·Xᵀe，对 b 的梯度是 (2/n)·Σe，
+  每一轮沿负梯度方向走 `learning_rate` 倍的步长。特征的量级相差很大时需要先标准化，否则要很小的学习率才不会发散
+*/
+
+use crate::math::matrix::Matrix;
+
+/// 梯度下降的参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientDescent {
+    pub learning_rate: f64,
+    /// 遍历全部样本的轮数
+    pub epochs: usize,
+}
+
+impl GradientDescent {
+    pub fn new(learning_rate: f64, epochs: usize) -> Self {
+        assert!(learning_rate > 0.0, "Learning rate must be positive.");
+        GradientDescent {
+            learning_rate,
+            epochs,
+        }
+    }
+}
+
+impl Default for GradientDescent {
+    fn default() -> Self {
+        GradientDescent::new(0.01, 1000)
+    }
+}
+
+/// ŷ = weights · x + bias
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearModel {
+    pub weights: Vec<f64>,
+    pub bias: f64,
+}
+
+impl LinearModel {
+    /// 一个样本的预测值
+    pub fn predict_one(&self, features: &[f64]) -> f64 {
+        assert_eq!(features.len(), self.weights.len(), "Dimension mismatch.");
+        dot(&self.weights, features) + self.bias
+    }
+
+    /// 每一行是一个样本
+    pub fn predict(&self, features: &Matrix) -> Vec<f64> {
+        features
+            .mul_vector(&self.weights)
+            .into_iter()
+            .map(|y| y + self.bias)
+            .collect()
+    }
+}
+
+/// 单个特征的最小二乘闭式解。少于两个点或者 x 全部相同时无法确定斜率，返回 None
+pub fn simple_linear_regression(x: &[f64], y: &[f64]) -> Option<LinearModel> {
+    assert_eq!(x.len(), y.len(), "Expected as many targets as samples.");
+    if x.len() < 2 {
+        return None;
+    }
+    let n = x.len() as f64;
+    let (mean_x, mean_y) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+    let covariance: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| (a - mean_x) * (b - mean_y))
+        .sum();
+    let variance: f64 = x.iter().map(|a| (a - mean_x) * (a - mean_x)).sum();
+    if variance == 0.0 {
+        return None;
+    }
+    let slope = covariance / variance;
+    Some(LinearModel {
+        weights: vec![slope],
+        bias: mean_y - slope * mean_x,
+    })
+}
+
+/// 用批量梯度下降拟合，`features` 的每一行是一个样本，权重和偏置都从 0 开始
+pub fn linear_regression(
+    features: &Matrix,
+    targets: &[f64],
+    options: &GradientDescent,
+) -> LinearModel {
+    assert_eq!(
+        features.rows(),
+        targets.len(),
+        "Expected as many targets as samples."
+    );
+    assert!(!targets.is_empty(), "Cannot fit an empty data set.");
+    let transposed = features.transpose();
+    let n = targets.len() as f64;
+    let mut model = LinearModel {
+        weights: vec![0.0; features.cols()],
+        bias: 0.0,
+    };
+    for _ in 0..options.epochs {
+        let errors: Vec<f64> = model
+            .predict(features)
+            .iter()
+            .zip(targets)
+            .map(|(p, y)| p - y)
+            .collect();
+        let gradient = transposed.mul_vector(&errors);
+        for (w, g) in model.weights.iter_mut().zip(gradient) {
+            *w -= options.learning_rate * 2.0 * g / n;
+        }
+        model.bias -= options.learning_rate * 2.0 * errors.iter().sum::<f64>() / n;
+    }
+    model
+}
+
+pub(super) fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{linear_regression, simple_linear_regression, GradientDescent};
+    use crate::machine_learning::metrics::{mean_squared_error, r_squared};
+    use crate::math::matrix::Matrix;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn closed_form_single_feature() {
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y: Vec<f64> = x.iter().map(|x| 2.0 * x + 1.0).collect();
+        let model = simple_linear_regression(&x, &y).unwrap();
+        assert!((model.weights[0] - 2.0).abs() < 1e-12);
+        assert!((model.bias - 1.0).abs() < 1e-12);
+        assert!((model.predict_one(&[10.0]) - 21.0).abs() < 1e-12);
+
+        // 教科书上的例子
+        let model =
+            simple_linear_regression(&[1.0, 2.0, 3.0, 4.0], &[6.0, 5.0, 7.0, 10.0]).unwrap();
+        assert!((model.weights[0] - 1.4).abs() < 1e-12);
+        assert!((model.bias - 3.5).abs() < 1e-12);
+
+        assert_eq!(simple_linear_regression(&[1.0], &[1.0]), None);
+        assert_eq!(simple_linear_regression(&[2.0, 2.0], &[1.0, 3.0]), None);
+    }
+
+    #[test]
+    fn gradient_descent_matches_closed_form() {
+        let mut rng = Rng::new(1486);
+        let x: Vec<f64> = (0..50).map(|_| rng.next_f64() * 4.0 - 2.0).collect();
+        let y: Vec<f64> = x
+            .iter()
+            .map(|x| -1.5 * x + 0.7 + rng.next_f64() - 0.5)
+            .collect();
+        let exact = simple_linear_regression(&x, &y).unwrap();
+        let fitted = linear_regression(&Matrix::column(&x), &y, &GradientDescent::new(0.1, 2000));
+        assert!((fitted.weights[0] - exact.weights[0]).abs() < 1e-9);
+        assert!((fitted.bias - exact.bias).abs() < 1e-9);
+    }
+
+    #[test]
+    fn recovers_multiple_features() {
+        let mut rng = Rng::new(1486);
+        let true_weights = [3.0, -2.0, 0.5];
+        let rows: Vec<Vec<f64>> = (0..200)
+            .map(|_| (0..3).map(|_| rng.next_f64() * 2.0 - 1.0).collect())
+            .collect();
+        let targets: Vec<f64> = rows
+            .iter()
+            .map(|row| super::dot(row, &true_weights) + 5.0 + 0.01 * (rng.next_f64() - 0.5))
+            .collect();
+        let features = Matrix::from_rows(&rows);
+        let model = linear_regression(&features, &targets, &GradientDescent::new(0.2, 3000));
+        for (w, expected) in model.weights.iter().zip(true_weights) {
+            assert!((w - expected).abs() < 0.01, "{w} vs {expected}");
+        }
+        assert!((model.bias - 5.0).abs() < 0.01);
+        let predicted = model.predict(&features);
+        assert!(mean_squared_error(&predicted, &targets) < 1e-4);
+        assert!(r_squared(&predicted, &targets).unwrap() > 0.999);
+
+        // 学习率太小、轮数太少时还远没有收敛
+        let early = linear_regression(&features, &targets, &GradientDescent::new(0.001, 10));
+        assert!(mean_squared_error(&early.predict(&features), &targets) > 1.0);
+    }
+}
@@ -0,0 +1,146 @@
+/*!
+定义：
+[逻辑回归](https://zh.wikipedia.org/wiki/%E9%82%8F%E8%BC%AF%E8%BF%B4%E6%AD%B8)
+二分类模型，P(y = 1 | x) = σ(w·x + b)，σ(z) = 1 / (1 + e⁻ᶻ)。
+最小化交叉熵损失 -Σ[y·ln p + (1 - y)·ln(1 - p)] / n，它对 w 的梯度恰好是 Xᵀ(p - y) / n，
+形式和线性回归一样，用同样的批量梯度下降求解
+*/
+
+use super::linear_regression::{dot, GradientDescent};
+use crate::math::matrix::Matrix;
+
+/// P(y = 1 | x) = σ(weights · x + bias)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogisticModel {
+    pub weights: Vec<f64>,
+    pub bias: f64,
+}
+
+impl LogisticModel {
+    pub fn predict_probability_one(&self, features: &[f64]) -> f64 {
+        assert_eq!(features.len(), self.weights.len(), "Dimension mismatch.");
+        sigmoid(dot(&self.weights, features) + self.bias)
+    }
+
+    /// 每一行样本属于正类的概率
+    pub fn predict_probability(&self, features: &Matrix) -> Vec<f64> {
+        features
+            .mul_vector(&self.weights)
+            .into_iter()
+            .map(|z| sigmoid(z + self.bias))
+            .collect()
+    }
+
+    /// 概率不小于 0.5 时预测为正类
+    pub fn predict(&self, features: &Matrix) -> Vec<bool> {
+        self.predict_probability(features)
+            .into_iter()
+            .map(|p| p >= 0.5)
+            .collect()
+    }
+}
+
+pub fn sigmoid(z: f64) -> f64 {
+    1.0 / (1.0 + (-z).exp())
+}
+
+/// 平均交叉熵损失，概率会被限制在 [1e-15, 1 - 1e-15] 内以免取对数得到无穷大
+pub fn log_loss(probabilities: &[f64], labels: &[bool]) -> f64 {
+    assert_eq!(probabilities.len(), labels.len(), "Dimension mismatch.");
+    let total: f64 = probabilities
+        .iter()
+        .zip(labels)
+        .map(|(&p, &y)| {
+            let p = p.clamp(1e-15, 1.0 - 1e-15);
+            if y {
+                -p.ln()
+            } else {
+                -(1.0 - p).ln()
+            }
+        })
+        .sum();
+    total / labels.len() as f64
+}
+
+/// 用批量梯度下降拟合，`features` 的每一行是一个样本，权重和偏置都从 0 开始
+pub fn logistic_regression(
+    features: &Matrix,
+    labels: &[bool],
+    options: &GradientDescent,
+) -> LogisticModel {
+    assert_eq!(
+        features.rows(),
+        labels.len(),
+        "Expected as many labels as samples."
+    );
+    assert!(!labels.is_empty(), "Cannot fit an empty data set.");
+    let transposed = features.transpose();
+    let n = labels.len() as f64;
+    let mut model = LogisticModel {
+        weights: vec![0.0; features.cols()],
+        bias: 0.0,
+    };
+    for _ in 0..options.epochs {
+        let errors: Vec<f64> = model
+            .predict_probability(features)
+            .iter()
+            .zip(labels)
+            .map(|(p, &y)| p - if y { 1.0 } else { 0.0 })
+            .collect();
+        let gradient = transposed.mul_vector(&errors);
+        for (w, g) in model.weights.iter_mut().zip(gradient) {
+            *w -= options.learning_rate * g / n;
+        }
+        model.bias -= options.learning_rate * errors.iter().sum::<f64>() / n;
+    }
+    model
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{log_loss, logistic_regression, sigmoid};
+    use crate::machine_learning::linear_regression::GradientDescent;
+    use crate::machine_learning::metrics::accuracy;
+    use crate::math::matrix::Matrix;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn sigmoid_properties() {
+        assert_eq!(sigmoid(0.0), 0.5);
+        assert!((sigmoid(2.0) + sigmoid(-2.0) - 1.0).abs() < 1e-15);
+        assert_eq!(sigmoid(1000.0), 1.0);
+        assert_eq!(sigmoid(-1000.0), 0.0);
+        assert!((log_loss(&[0.5, 0.5], &[true, false]) - 2f64.ln()).abs() < 1e-12);
+        assert!(log_loss(&[1.0, 0.0], &[false, true]).is_finite());
+    }
+
+    #[test]
+    fn separates_linearly_separable_data() {
+        let mut rng = Rng::new(1486);
+        // 直线 x + 2y = 1 两侧的点，离直线太近的去掉
+        let mut rows = vec![];
+        let mut labels = vec![];
+        while rows.len() < 200 {
+            let (x, y) = (rng.next_f64() * 4.0 - 2.0, rng.next_f64() * 4.0 - 2.0);
+            let side = x + 2.0 * y - 1.0;
+            if side.abs() > 0.2 {
+                rows.push(vec![x, y]);
+                labels.push(side > 0.0);
+            }
+        }
+        let features = Matrix::from_rows(&rows);
+        let options = GradientDescent::new(0.5, 2000);
+        let model = logistic_regression(&features, &labels, &options);
+        assert_eq!(accuracy(&model.predict(&features), &labels), 1.0);
+        // 决策边界的方向和真实的直线一致
+        let ratio = model.weights[1] / model.weights[0];
+        assert!((ratio - 2.0).abs() < 0.2, "{ratio}");
+        assert!(model.predict_probability_one(&[2.0, 2.0]) > 0.99);
+        assert!(model.predict_probability_one(&[-2.0, -2.0]) < 0.01);
+
+        // 训练越久损失越小
+        let short = logistic_regression(&features, &labels, &GradientDescent::new(0.5, 10));
+        let loss = |m: &super::LogisticModel| log_loss(&m.predict_probability(&features), &labels);
+        assert!(loss(&model) < loss(&short));
+    }
+}
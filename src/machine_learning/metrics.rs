@@ -0,0 +1,77 @@
+/*!
+定义：
+评估模型的常用指标。
+- [均方误差](https://zh.wikipedia.org/wiki/%E5%9D%87%E6%96%B9%E8%AF%AF%E5%B7%AE)：预测值与真实值之差的平方的平均
+- [决定系数](https://zh.wikipedia.org/wiki/%E5%86%B3%E5%AE%9A%E7%B3%BB%E6%95%B0) R²：
+  1 - 残差平方和 / 总平方和，1 表示完全拟合，0 相当于总是预测平均值
+- 准确率：预测正确的样本所占的比例
+*/
+
+/// 均方误差，两个切片必须等长且不为空
+pub fn mean_squared_error(predicted: &[f64], actual: &[f64]) -> f64 {
+    check_lengths(predicted.len(), actual.len());
+    let total: f64 = predicted
+        .iter()
+        .zip(actual)
+        .map(|(p, a)| (p - a) * (p - a))
+        .sum();
+    total / actual.len() as f64
+}
+
+/// 决定系数 R²。真实值全部相同时总平方和为 0，返回 None
+pub fn r_squared(predicted: &[f64], actual: &[f64]) -> Option<f64> {
+    check_lengths(predicted.len(), actual.len());
+    let mean = actual.iter().sum::<f64>() / actual.len() as f64;
+    let total: f64 = actual.iter().map(|a| (a - mean) * (a - mean)).sum();
+    let residual: f64 = predicted
+        .iter()
+        .zip(actual)
+        .map(|(p, a)| (p - a) * (p - a))
+        .sum();
+    (total > 0.0).then(|| 1.0 - residual / total)
+}
+
+/// 准确率，两个切片必须等长且不为空
+pub fn accuracy<T: PartialEq>(predicted: &[T], actual: &[T]) -> f64 {
+    check_lengths(predicted.len(), actual.len());
+    let correct = predicted.iter().zip(actual).filter(|(p, a)| p == a).count();
+    correct as f64 / actual.len() as f64
+}
+
+fn check_lengths(predicted: usize, actual: usize) {
+    assert!(
+        predicted == actual && actual > 0,
+        "Expected two non-empty slices of equal length, got {predicted} and {actual}."
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{accuracy, mean_squared_error, r_squared};
+
+    #[test]
+    fn regression_metrics() {
+        let actual = [3.0, -0.5, 2.0, 7.0];
+        let predicted = [2.5, 0.0, 2.0, 8.0];
+        assert_eq!(mean_squared_error(&predicted, &actual), 0.375);
+        let r2 = r_squared(&predicted, &actual).unwrap();
+        assert!((r2 - 0.948_608_137_044_967_9).abs() < 1e-12);
+        assert_eq!(r_squared(&actual, &actual), Some(1.0));
+        assert_eq!(r_squared(&[1.0, 2.0], &[5.0, 5.0]), None);
+    }
+
+    #[test]
+    fn classification_accuracy() {
+        assert_eq!(
+            accuracy(&[true, false, true, true], &[true, true, true, false]),
+            0.5
+        );
+        assert_eq!(accuracy(&["a", "b"], &["a", "b"]), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_mismatched_lengths() {
+        mean_squared_error(&[1.0], &[1.0, 2.0]);
+    }
+}
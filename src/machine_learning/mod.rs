@@ -0,0 +1,13 @@
+pub mod k_means;
+pub mod knn;
+pub mod linear_regression;
+pub mod logistic_regression;
+pub mod metrics;
+
+pub use self::k_means::{k_means, k_means_plus_plus, Clustering};
+pub use self::knn::{KnnClassifier, Metric, Voting};
+pub use self::linear_regression::{
+    linear_regression, simple_linear_regression, GradientDescent, LinearModel,
+};
+pub use self::logistic_regression::{log_loss, logistic_regression, sigmoid, LogisticModel};
+pub use self::metrics::{accuracy, mean_squared_error, r_squared};
@@ -0,0 +1,172 @@
+/*!
+定义：
+[算术函数](https://zh.wikipedia.org/wiki/%E7%AE%97%E6%9C%AF%E5%87%BD%E6%95%B0)
+- [欧拉函数](https://zh.wikipedia.org/wiki/%E6%AC%A7%E6%8B%89%E5%87%BD%E6%95%B0) φ(n)：1..=n 中与 n 互素的数的个数，
+  φ(n) = n·∏(1 - 1/p)，p 取遍 n 的素因子
+- [除数函数](https://zh.wikipedia.org/wiki/%E9%99%A4%E6%95%B0%E5%87%BD%E6%95%B0) σ(n)：所有正因子的和，
+  n = ∏pᵏ 时 σ(n) = ∏(1 + p + ... + pᵏ)
+- [素数计数函数](https://zh.wikipedia.org/wiki/%E8%B4%A8%E6%95%B0%E8%AE%A1%E6%95%B0%E5%87%BD%E6%95%B0) π(n)：
+  不超过 n 的素数个数。用 Meissel–Lehmer 思路的简化版（Lucy 的动态规划）：
+  S(v, p) 是 2..=v 中没有被不超过 p 的素数筛掉的数的个数，
+  S(v, p) = S(v, p-1) - (S(v/p, p-1) - S(p-1, p-1))。
+  只有 v = n/i 形式的 O(√n) 个值需要维护，复杂度 O(n^(3/4))
+*/
+
+use super::factorization::factorize;
+
+/// 欧拉函数 φ(n)，φ(0) 定义为 0
+pub fn euler_phi(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .fold(n, |phi, (p, _)| phi / p * (p - 1))
+}
+
+/// 0..=limit 的欧拉函数表，筛法 O(n log log n)
+pub fn euler_phi_sieve(limit: usize) -> Vec<u64> {
+    let mut phi: Vec<u64> = (0..=limit as u64).collect();
+    for p in 2..=limit {
+        // phi[p] 没被改过说明 p 是素数
+        if phi[p] == p as u64 {
+            for multiple in (p..=limit).step_by(p) {
+                phi[multiple] -= phi[multiple] / p as u64;
+            }
+        }
+    }
+    phi
+}
+
+/// n 的所有正因子，从小到大；0 没有因子，返回空
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return vec![];
+    }
+    let mut result = vec![1];
+    for (p, exponent) in factorize(n) {
+        // 已有的每个因子分别乘上 p, p², ..., pᵏ
+        let existing = result.len();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= p;
+            for i in 0..existing {
+                result.push(result[i] * power);
+            }
+        }
+    }
+    result.sort_unstable();
+    result
+}
+
+/// 所有正因子的和 σ(n)，可能超过 u64
+pub fn sum_of_divisors(n: u64) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n)
+        .into_iter()
+        .map(|(p, exponent)| {
+            // 1 + p + ... + pᵏ
+            let p = p as u128;
+            (0..exponent).fold(1u128, |sum, _| sum * p + 1)
+        })
+        .product()
+}
+
+/// 不超过 n 的素数个数 π(n)，n = 10^10 时也只需要不到一秒
+pub fn prime_count(n: u64) -> u64 {
+    if n < 2 {
+        return 0;
+    }
+    let root = n.isqrt() as usize;
+    // small[v] = S(v)，v ≤ √n；large[i] = S(n / i)，i ≤ √n。初始时 2..=v 都还在
+    let mut small: Vec<u64> = (0..=root as u64).map(|v| v.saturating_sub(1)).collect();
+    let mut large: Vec<u64> = (0..=root as u64)
+        .map(|i| n.checked_div(i).map_or(0, |v| v - 1))
+        .collect();
+    for p in 2..=root {
+        if small[p] == small[p - 1] {
+            // p 已经被更小的素数筛掉了
+            continue;
+        }
+        let below = small[p - 1];
+        let square = (p * p) as u64;
+        // 只有 v ≥ p² 的 S(v) 会变化，large 从小的 i（大的 v）开始更新
+        let end = root.min((n / square) as usize);
+        for i in 1..=end {
+            let d = i * p;
+            let quotient = if d <= root {
+                large[d]
+            } else {
+                small[(n / d as u64) as usize]
+            };
+            large[i] -= quotient - below;
+        }
+        // small 从大往小更新，small[v / p] 仍然是上一轮的值
+        for v in (square as usize..=root).rev() {
+            small[v] -= small[v / p] - below;
+        }
+    }
+    large[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{divisors, euler_phi, euler_phi_sieve, prime_count, sum_of_divisors};
+    use crate::math::{gcd, sieve};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn totient() {
+        let phi = euler_phi_sieve(1000);
+        assert_eq!(&phi[..11], &[0, 1, 1, 2, 2, 4, 2, 6, 4, 6, 4]);
+        for n in 1..=1000u64 {
+            assert_eq!(euler_phi(n), phi[n as usize], "φ({n})");
+        }
+        for n in 1..=60u64 {
+            let coprime = (1..=n).filter(|&k| gcd(k, n) == 1).count() as u64;
+            assert_eq!(euler_phi(n), coprime);
+        }
+        assert_eq!(euler_phi(0), 0);
+        assert_eq!(euler_phi(1_000_000_007), 1_000_000_006);
+        assert_eq!(euler_phi(1 << 63), 1 << 62);
+    }
+
+    #[test]
+    fn divisor_functions() {
+        assert_eq!(divisors(0), Vec::<u64>::new());
+        assert_eq!(divisors(1), vec![1]);
+        assert_eq!(divisors(36), vec![1, 2, 3, 4, 6, 9, 12, 18, 36]);
+        assert_eq!(sum_of_divisors(28), 56);
+        assert_eq!(sum_of_divisors(0), 0);
+        // 720720 有 240 个因子
+        assert_eq!(divisors(720_720).len(), 240);
+
+        let mut rng = Rng::new(1465);
+        for _ in 0..200 {
+            let n = 1 + rng.gen_index(5000) as u64;
+            let expected: Vec<u64> = (1..=n).filter(|d| n.is_multiple_of(*d)).collect();
+            assert_eq!(divisors(n), expected);
+            assert_eq!(
+                sum_of_divisors(n),
+                expected.iter().map(|&d| d as u128).sum()
+            );
+        }
+        // σ(n) > u64::MAX
+        let n = 18_401_055_938_125_660_800;
+        assert!(sum_of_divisors(n) > u64::MAX as u128);
+    }
+
+    #[test]
+    fn counts_primes() {
+        let is_prime = sieve(100_000);
+        let mut count = 0;
+        for (n, &prime) in is_prime.iter().enumerate() {
+            count += prime as u64;
+            if n % 997 == 0 || n < 200 {
+                assert_eq!(prime_count(n as u64), count, "π({n})");
+            }
+        }
+        assert_eq!(prime_count(100_000), 9592);
+        assert_eq!(prime_count(1_000_000_000), 50_847_534);
+        assert_eq!(prime_count(10_000_000_000), 455_052_511);
+    }
+}
@@ -0,0 +1,1020 @@
+/*!
+定义：
+[高精度计算](https://zh.wikipedia.org/wiki/%E9%AB%98%E7%B2%BE%E5%BA%A6%E8%AE%A1%E7%AE%97)
+用符号位加上 2³² 进制的“数字”（limb）数组表示任意大的整数，低位在前。
+- 加减法逐位进位、借位，O(n)
+- 乘法：短的用竖式乘法 O(n·m)；都足够长时用
+  [Karatsuba 算法](https://zh.wikipedia.org/wiki/Karatsuba%E7%AE%97%E6%B3%95)，
+  把 (a₁B + a₀)(b₁B + b₀) 的四次乘法减少到三次：中间项 = (a₀+a₁)(b₀+b₁) - a₀b₀ - a₁b₁，复杂度 O(n^1.585)
+- 除法：Knuth 的 Algorithm D，先把除数左移到最高位为 1，再逐位试商，每一位的试商最多修正两次
+
+除法和 Rust 的内置整数一致：商向零取整，余数和被除数同号
+*/
+
+use crate::utils::random::Rng;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
+
+/// 两个数都至少有这么多个 limb 时才用 Karatsuba，更短时竖式乘法更快
+const KARATSUBA_THRESHOLD: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    /// 绝对值，低位在前，最高位不为 0。零是空数组，并且不是负数
+    magnitude: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    /// 没有任何数字
+    Empty,
+    /// 第 `index` 个字节不是合法的数字
+    InvalidDigit { index: usize, digit: char },
+}
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseBigIntError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseBigIntError::InvalidDigit { index, digit } => {
+                write!(f, "invalid digit {digit:?} at byte {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    pub fn one() -> Self {
+        BigInt::from(1u64)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// -1、0 或 1
+    pub fn signum(&self) -> i32 {
+        match (self.is_zero(), self.negative) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        }
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from_parts(false, self.magnitude.clone())
+    }
+
+    /// 二进制位数，零是 0
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            None => 0,
+            Some(&top) => self.magnitude.len() as u64 * 32 - top.leading_zeros() as u64,
+        }
+    }
+
+    /// self^exp，平方求幂
+    pub fn pow(&self, mut exp: u32) -> BigInt {
+        let mut base = self.clone();
+        let mut result = BigInt::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// 同时返回商和余数，除数为 0 时 panic
+    pub fn div_rem(&self, other: &BigInt) -> (BigInt, BigInt) {
+        assert!(!other.is_zero(), "Attempt to divide by zero.");
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &other.magnitude);
+        (
+            BigInt::from_parts(self.negative != other.negative, quotient),
+            BigInt::from_parts(self.negative, remainder),
+        )
+    }
+
+    /// 非负的余数，结果在 [0, |modulus|) 中
+    pub fn rem_euclid(&self, modulus: &BigInt) -> BigInt {
+        let remainder = self % modulus;
+        if remainder.is_negative() {
+            &remainder + &modulus.abs()
+        } else {
+            remainder
+        }
+    }
+
+    /// 绝对值的第 `index` 个二进制位
+    pub fn bit(&self, index: u64) -> bool {
+        let limb = (index / 32) as usize;
+        limb < self.magnitude.len() && self.magnitude[limb] >> (index % 32) & 1 == 1
+    }
+
+    /// self^exp mod modulus，从高位到低位平方求幂。`exp` 不能是负数，`modulus` 必须是正数
+    pub fn mod_pow(&self, exp: &BigInt, modulus: &BigInt) -> BigInt {
+        assert!(!exp.is_negative(), "Exponent must be non-negative.");
+        assert!(modulus.signum() > 0, "Modulus must be positive.");
+        let base = self.rem_euclid(modulus);
+        let mut result = BigInt::one().rem_euclid(modulus);
+        for i in (0..exp.bits()).rev() {
+            result = (&result * &result).rem_euclid(modulus);
+            if exp.bit(i) {
+                result = (&result * &base).rem_euclid(modulus);
+            }
+        }
+        result
+    }
+
+    /// 模 `modulus` 的乘法逆元，结果在 [0, modulus) 中，不互素时返回 None
+    pub fn mod_inverse(&self, modulus: &BigInt) -> Option<BigInt> {
+        assert!(modulus.signum() > 0, "Modulus must be positive.");
+        // 扩展欧几里得算法，只维护 self 的系数
+        let (mut old_r, mut r) = (self.rem_euclid(modulus), modulus.clone());
+        let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
+        while !r.is_zero() {
+            let (quotient, remainder) = old_r.div_rem(&r);
+            (old_r, r) = (r, remainder);
+            let next = &old_s - &(&quotient * &s);
+            (old_s, s) = (s, next);
+        }
+        (old_r == BigInt::one()).then(|| old_s.rem_euclid(modulus))
+    }
+
+    /// [0, 2^bits) 中均匀随机的整数
+    pub fn random(bits: u64, rng: &mut Rng) -> BigInt {
+        let mut bytes: Vec<u8> = (0..bits.div_ceil(8))
+            .map(|_| rng.next_u64() as u8)
+            .collect();
+        if !bits.is_multiple_of(8) {
+            bytes[0] &= (1 << (bits % 8)) - 1;
+        }
+        BigInt::from_bytes_be(&bytes)
+    }
+
+    /// 把字节串按大端序解释成非负整数
+    pub fn from_bytes_be(bytes: &[u8]) -> BigInt {
+        let magnitude = bytes
+            .rchunks(4)
+            .map(|chunk| chunk.iter().fold(0u32, |acc, &byte| acc << 8 | byte as u32))
+            .collect();
+        BigInt::from_parts(false, magnitude)
+    }
+
+    /// 绝对值的大端序字节串，没有前导 0，零是空数组
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let bytes: Vec<u8> = self
+            .magnitude
+            .iter()
+            .rev()
+            .flat_map(|limb| limb.to_be_bytes())
+            .collect();
+        let leading_zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+        bytes[leading_zeros..].to_vec()
+    }
+
+    /// 能放进 i128 时返回它的值
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &limb| acc << 32 | limb as u128);
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// 按 `radix` 进制（2..=36）解析，可以带一个 `+` 或 `-`，字母不区分大小写
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<BigInt, ParseBigIntError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "Radix {radix} out of range 2..=36."
+        );
+        let (negative, offset) = match text.as_bytes().first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let digits = &text[offset..];
+        if digits.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+        let (chunk_len, chunk_base) = chunk_for_radix(radix);
+        let mut magnitude = vec![];
+        let (mut chunk, mut chunk_digits) = (0u32, 0);
+        for (i, c) in digits.char_indices() {
+            let digit = c.to_digit(radix).ok_or(ParseBigIntError::InvalidDigit {
+                index: offset + i,
+                digit: c,
+            })?;
+            chunk = chunk * radix + digit;
+            chunk_digits += 1;
+            // 攒够一块再乘进去，减少大数运算的次数
+            if chunk_digits == chunk_len {
+                mul_small_add(&mut magnitude, chunk_base, chunk);
+                (chunk, chunk_digits) = (0, 0);
+            }
+        }
+        if chunk_digits > 0 {
+            mul_small_add(&mut magnitude, radix.pow(chunk_digits), chunk);
+        }
+        Ok(BigInt::from_parts(negative, magnitude))
+    }
+
+    /// 转成 `radix` 进制（2..=36）的字符串，字母用小写
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let digits = magnitude_to_radix(&self.magnitude, radix);
+        if self.negative {
+            format!("-{digits}")
+        } else {
+            digits
+        }
+    }
+
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        trim(&mut magnitude);
+        BigInt {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        BigInt::from(value as u128)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        BigInt::from(value as i128)
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(mut value: u128) -> Self {
+        let mut magnitude = vec![];
+        while value > 0 {
+            magnitude.push(value as u32);
+            value >>= 32;
+        }
+        BigInt::from_parts(false, magnitude)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mut result = BigInt::from(value.unsigned_abs());
+        result.negative = value < 0;
+        result
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    /// 十进制；以 `0x` 开头（符号之后）时按十六进制解析
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (sign, rest) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text.strip_prefix('+').unwrap_or(text)),
+        };
+        match rest.strip_prefix("0x").or_else(|| rest.strip_prefix("0X")) {
+            Some(hex) => {
+                // 错误里的下标仍然对应原字符串
+                let prefix = text.len() - hex.len();
+                // 前缀之后不能再有符号，比如 `0x-5`
+                if let Some(sign @ ('+' | '-')) = hex.chars().next() {
+                    return Err(ParseBigIntError::InvalidDigit {
+                        index: prefix,
+                        digit: sign,
+                    });
+                }
+                BigInt::from_str_radix(&format!("{sign}{hex}"), 16).map_err(|error| match error {
+                    ParseBigIntError::InvalidDigit { index, digit } => {
+                        ParseBigIntError::InvalidDigit {
+                            index: index - sign.len() + prefix,
+                            digit,
+                        }
+                    }
+                    error => error,
+                })
+            }
+            None => BigInt::from_str_radix(text, 10),
+        }
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &magnitude_to_radix(&self.magnitude, 10))
+    }
+}
+
+impl fmt::LowerHex for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(
+            !self.negative,
+            "0x",
+            &magnitude_to_radix(&self.magnitude, 16),
+        )
+    }
+}
+
+impl fmt::UpperHex for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = magnitude_to_radix(&self.magnitude, 16).to_uppercase();
+        f.pad_integral(!self.negative, "0x", &digits)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitude(&self.magnitude, &other.magnitude),
+            );
+        }
+        // 异号相加：大的绝对值减小的，符号跟绝对值大的一方
+        match compare_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::from_parts(
+                other.negative,
+                sub_magnitude(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::from_parts(
+                self.negative,
+                sub_magnitude(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != other.negative,
+            mul_magnitude(&self.magnitude, &other.magnitude),
+        )
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+/// 按值传参的版本都转发给引用版本
+macro_rules! forward_binary {
+    ($($trait:ident $method:ident),*) => {$(
+        impl $trait for BigInt {
+            type Output = BigInt;
+
+            fn $method(self, other: BigInt) -> BigInt {
+                (&self).$method(&other)
+            }
+        }
+
+        impl $trait<&BigInt> for BigInt {
+            type Output = BigInt;
+
+            fn $method(self, other: &BigInt) -> BigInt {
+                (&self).$method(other)
+            }
+        }
+
+        impl $trait<BigInt> for &BigInt {
+            type Output = BigInt;
+
+            fn $method(self, other: BigInt) -> BigInt {
+                self.$method(&other)
+            }
+        }
+    )*};
+}
+
+forward_binary!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+macro_rules! forward_assign {
+    ($($trait:ident $method:ident $op:tt),*) => {$(
+        impl $trait<&BigInt> for BigInt {
+            fn $method(&mut self, other: &BigInt) {
+                *self = &*self $op other;
+            }
+        }
+
+        impl $trait for BigInt {
+            fn $method(&mut self, other: BigInt) {
+                *self = &*self $op &other;
+            }
+        }
+    )*};
+}
+
+forward_assign!(AddAssign add_assign +, SubAssign sub_assign -, MulAssign mul_assign *);
+
+/// 去掉高位的 0
+fn trim(magnitude: &mut Vec<u32>) {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+}
+
+fn compare_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in long.iter().enumerate() {
+        let sum = limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// a - b，要求 |a| ≥ |b|
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let diff = limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        result.push(diff as u32);
+        borrow = (diff < 0) as i64;
+    }
+    debug_assert_eq!(borrow, 0);
+    trim(&mut result);
+    result
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        mul_schoolbook(a, b)
+    } else {
+        mul_karatsuba(a, b)
+    }
+}
+
+/// 竖式乘法
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            // (2³²-1)² + 2·(2³²-1) 恰好不超过 u64
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    trim(&mut result);
+    result
+}
+
+fn mul_karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    // 按较短一方的一半切分，保证两边的高位部分都不为空
+    let half = a.len().min(b.len()) / 2;
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half);
+    let (a0, b0) = (trimmed(a0), trimmed(b0));
+
+    let low = mul_magnitude(a0, b0);
+    let high = mul_magnitude(a1, b1);
+    let cross = mul_magnitude(&add_magnitude(a0, a1), &add_magnitude(b0, b1));
+    let middle = sub_magnitude(&sub_magnitude(&cross, &low), &high);
+
+    let mut result = vec![0u32; a.len() + b.len() + 1];
+    add_shifted(&mut result, &low, 0);
+    add_shifted(&mut result, &middle, half);
+    add_shifted(&mut result, &high, 2 * half);
+    trim(&mut result);
+    result
+}
+
+fn trimmed(mut limbs: &[u32]) -> &[u32] {
+    while let [rest @ .., 0] = limbs {
+        limbs = rest;
+    }
+    limbs
+}
+
+/// result += value · B^shift，调用方保证 result 足够长
+fn add_shifted(result: &mut [u32], value: &[u32], shift: usize) {
+    let mut carry = 0u64;
+    let mut i = shift;
+    for &limb in value {
+        let sum = result[i] as u64 + limb as u64 + carry;
+        result[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+    while carry > 0 {
+        let sum = result[i] as u64 + carry;
+        result[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+}
+
+/// magnitude = magnitude · factor + addend
+fn mul_small_add(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+    for limb in magnitude.iter_mut() {
+        let product = *limb as u64 * factor as u64 + carry;
+        *limb = product as u32;
+        carry = product >> 32;
+    }
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+/// 除以一个 limb，原地得到商，返回余数
+fn div_rem_small(magnitude: &mut [u32], divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+    for limb in magnitude.iter_mut().rev() {
+        let current = remainder << 32 | *limb as u64;
+        *limb = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    remainder as u32
+}
+
+/// Knuth Algorithm D，返回 (商, 余数)
+fn div_rem_magnitude(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitude(u, v) == Ordering::Less {
+        return (vec![], u.to_vec());
+    }
+    if v.len() == 1 {
+        let mut quotient = u.to_vec();
+        let remainder = div_rem_small(&mut quotient, v[0]);
+        trim(&mut quotient);
+        return (quotient, vec![remainder]);
+    }
+
+    // 规格化：左移使除数最高位为 1，这样试商最多比真实的商大 2
+    let shift = v[v.len() - 1].leading_zeros();
+    let v = shift_left(v, shift);
+    let mut u = shift_left(u, shift);
+    u.resize(u.len().max(v.len()) + 1, 0);
+    let n = v.len();
+    let (top, second) = (v[n - 1] as u64, v[n - 2] as u64);
+    let mut quotient = vec![0u32; u.len() - n];
+
+    for j in (0..quotient.len()).rev() {
+        // 用被除数最高两位除以除数最高一位试商，再用次高位修正
+        let numerator = (u[j + n] as u64) << 32 | u[j + n - 1] as u64;
+        let mut q_hat = numerator / top;
+        let mut r_hat = numerator % top;
+        while q_hat >> 32 != 0 || q_hat * second > (r_hat << 32 | u[j + n - 2] as u64) {
+            q_hat -= 1;
+            r_hat += top;
+            if r_hat >> 32 != 0 {
+                break;
+            }
+        }
+
+        // u[j..=j+n] -= q_hat · v
+        let mut borrow = 0i64;
+        for i in 0..n {
+            let product = q_hat * v[i] as u64;
+            let diff = u[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+            u[i + j] = diff as u32;
+            borrow = (product >> 32) as i64 - (diff >> 32);
+        }
+        let diff = u[j + n] as i64 - borrow;
+        u[j + n] = diff as u32;
+
+        if diff < 0 {
+            // 试商大了 1，加回一个除数
+            q_hat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = q_hat as u32;
+    }
+
+    trim(&mut quotient);
+    let mut remainder = shift_right(&u[..n], shift);
+    trim(&mut remainder);
+    (quotient, remainder)
+}
+
+/// 左移不到一个 limb 的位数，可能多出一个 limb
+fn shift_left(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    let mut result = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0;
+    for &limb in limbs {
+        result.push(limb << shift | carry);
+        carry = limb >> (32 - shift);
+    }
+    if carry > 0 {
+        result.push(carry);
+    }
+    result
+}
+
+fn shift_right(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    let mut result = vec![0; limbs.len()];
+    for i in 0..limbs.len() {
+        let high = limbs.get(i + 1).map_or(0, |&next| next << (32 - shift));
+        result[i] = limbs[i] >> shift | high;
+    }
+    result
+}
+
+/// 一块能放进 u32 的最多位数，以及 radix 的这个次方
+fn chunk_for_radix(radix: u32) -> (u32, u32) {
+    let (mut len, mut base) = (1, radix);
+    while let Some(next) = base.checked_mul(radix) {
+        len += 1;
+        base = next;
+    }
+    (len, base)
+}
+
+fn magnitude_to_radix(magnitude: &[u32], radix: u32) -> String {
+    assert!(
+        (2..=36).contains(&radix),
+        "Radix {radix} out of range 2..=36."
+    );
+    if magnitude.is_empty() {
+        return "0".to_string();
+    }
+    let (chunk_len, chunk_base) = chunk_for_radix(radix);
+    let mut rest = magnitude.to_vec();
+    // 每次除以 radix^chunk_len，得到的余数是低位的一块
+    let mut chunks = vec![];
+    while !rest.is_empty() {
+        chunks.push(div_rem_small(&mut rest, chunk_base));
+        trim(&mut rest);
+    }
+    let mut digits = String::new();
+    for (i, &chunk) in chunks.iter().rev().enumerate() {
+        let chunk_digits = chunk_to_radix(chunk, radix);
+        // 除了最高的一块，其它块要补足前导 0
+        if i > 0 {
+            digits.extend(std::iter::repeat_n(
+                '0',
+                chunk_len as usize - chunk_digits.len(),
+            ));
+        }
+        digits.push_str(&chunk_digits);
+    }
+    digits
+}
+
+fn chunk_to_radix(mut chunk: u32, radix: u32) -> String {
+    let mut digits = vec![];
+    loop {
+        digits.push(std::char::from_digit(chunk % radix, radix).unwrap());
+        chunk /= radix;
+        if chunk == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mul_karatsuba, mul_schoolbook, BigInt, ParseBigIntError};
+    use crate::utils::random::Rng;
+
+    fn random_big(rng: &mut Rng, max_limbs: usize) -> BigInt {
+        let limbs = rng.gen_index(max_limbs + 1);
+        let magnitude = (0..limbs).map(|_| rng.next_u64() as u32).collect();
+        BigInt::from_parts(rng.gen_bool(0.5), magnitude)
+    }
+
+    fn random_i128(rng: &mut Rng) -> i128 {
+        // 用 i64 范围内的值，保证乘积不会溢出 i128
+        let value = rng.next_u64() >> rng.gen_index(64);
+        if rng.gen_bool(0.5) {
+            -(value as i128)
+        } else {
+            value as i128
+        }
+    }
+
+    #[test]
+    fn matches_i128_arithmetic() {
+        let mut rng = Rng::new(1458);
+        for _ in 0..2000 {
+            let (a, b) = (random_i128(&mut rng), random_i128(&mut rng));
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!((&x + &y).to_i128(), Some(a + b));
+            assert_eq!((&x - &y).to_i128(), Some(a - b));
+            assert_eq!((&x * &y).to_i128(), Some(a * b));
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            if b != 0 {
+                assert_eq!((&x / &y).to_i128(), Some(a / b), "{a} / {b}");
+                assert_eq!((&x % &y).to_i128(), Some(a % b), "{a} % {b}");
+            }
+            assert_eq!(x.to_string(), a.to_string());
+            assert_eq!(
+                x.to_str_radix(16),
+                format!("{}{:x}", if a < 0 { "-" } else { "" }, a.unsigned_abs())
+            );
+        }
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook() {
+        let mut rng = Rng::new(1458);
+        for _ in 0..20 {
+            let a: Vec<u32> = (0..32 + rng.gen_index(200))
+                .map(|_| rng.next_u64() as u32)
+                .collect();
+            let b: Vec<u32> = (0..32 + rng.gen_index(200))
+                .map(|_| rng.next_u64() as u32)
+                .collect();
+            assert_eq!(mul_karatsuba(&a, &b), mul_schoolbook(&a, &b));
+        }
+        // 全是最大值的 limb，进位最多
+        let ones = vec![u32::MAX; 100];
+        assert_eq!(mul_karatsuba(&ones, &ones), mul_schoolbook(&ones, &ones));
+    }
+
+    #[test]
+    fn division_identity_on_large_values() {
+        let mut rng = Rng::new(1458);
+        for _ in 0..300 {
+            let a = random_big(&mut rng, 40);
+            let b = random_big(&mut rng, 20);
+            if b.is_zero() {
+                continue;
+            }
+            let (q, r) = a.div_rem(&b);
+            assert_eq!(&(&q * &b) + &r, a);
+            assert!(r.abs() < b.abs());
+            assert!(r.is_zero() || r.is_negative() == a.is_negative());
+        }
+        // Hacker's Delight 中试商大了 1、需要加回除数的例子
+        let a = BigInt::from_parts(false, vec![0, 0, 0x8000_0000, 0x7fff_ffff]);
+        let b = BigInt::from_parts(false, vec![1, 0, 0x8000_0000]);
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(q, BigInt::from(0xffff_fffeu64));
+        assert_eq!(&(&q * &b) + &r, a);
+        assert!(r < b);
+        // 被除数的高位和除数非常接近
+        let b = BigInt::from_str_radix("ffffffff00000000ffffffff", 16).unwrap();
+        let a = &(&b * &b) - &BigInt::one();
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(q, &b - &BigInt::one());
+        assert_eq!(r, &b - &BigInt::one());
+    }
+
+    #[test]
+    fn parses_and_prints() {
+        let factorial_30: BigInt = (1..=30u64).fold(BigInt::one(), |acc, i| acc * BigInt::from(i));
+        assert_eq!(
+            factorial_30.to_string(),
+            "265252859812191058636308480000000"
+        );
+        assert_eq!(
+            BigInt::from(2u64).pow(200).to_string(),
+            "1606938044258990275541962092341162602522202993782792835301376"
+        );
+        assert_eq!(
+            format!("{:x}", BigInt::from(2u64).pow(100)),
+            format!("1{}", "0".repeat(25))
+        );
+        assert_eq!(format!("{:#X}", BigInt::from(-255i64)), "-0xFF");
+        assert_eq!(format!("{:>6}", BigInt::from(-42i64)), "   -42");
+        assert_eq!(format!("{:+}", BigInt::zero()), "+0");
+
+        let mut rng = Rng::new(1458);
+        for _ in 0..100 {
+            let value = random_big(&mut rng, 30);
+            assert_eq!(value.to_string().parse::<BigInt>(), Ok(value.clone()));
+            let hex = format!("{:#x}", value);
+            assert_eq!(hex.parse::<BigInt>(), Ok(value.clone()));
+            for radix in [2, 7, 36] {
+                let text = value.to_str_radix(radix);
+                assert_eq!(BigInt::from_str_radix(&text, radix), Ok(value.clone()));
+            }
+        }
+
+        assert_eq!("-0".parse::<BigInt>(), Ok(BigInt::zero()));
+        assert_eq!("+0x1F".parse::<BigInt>(), Ok(BigInt::from(31u64)));
+        assert_eq!("".parse::<BigInt>(), Err(ParseBigIntError::Empty));
+        assert_eq!("-".parse::<BigInt>(), Err(ParseBigIntError::Empty));
+        assert_eq!(
+            "12a4".parse::<BigInt>(),
+            Err(ParseBigIntError::InvalidDigit {
+                index: 2,
+                digit: 'a'
+            })
+        );
+        assert_eq!(
+            "-0xfg".parse::<BigInt>(),
+            Err(ParseBigIntError::InvalidDigit {
+                index: 4,
+                digit: 'g'
+            })
+        );
+        assert_eq!(
+            "0x-5".parse::<BigInt>(),
+            Err(ParseBigIntError::InvalidDigit {
+                index: 2,
+                digit: '-'
+            })
+        );
+        assert_eq!(
+            "-0x+5".parse::<BigInt>(),
+            Err(ParseBigIntError::InvalidDigit {
+                index: 3,
+                digit: '+'
+            })
+        );
+    }
+
+    #[test]
+    fn sign_helpers() {
+        let mut x = BigInt::from(-5i64);
+        assert_eq!((x.signum(), x.abs()), (-1, BigInt::from(5u64)));
+        assert_eq!(BigInt::zero().signum(), 0);
+        assert_eq!(-BigInt::zero(), BigInt::zero());
+        assert!(!(-BigInt::zero()).is_negative());
+        x += BigInt::from(5u64);
+        assert!(x.is_zero() && !x.is_negative());
+        x -= &BigInt::from(3u64);
+        x *= BigInt::from(-7i64);
+        assert_eq!(x, BigInt::from(21u64));
+        assert_eq!(BigInt::from(u128::MAX).bits(), 128);
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(BigInt::from(u128::MAX).to_i128(), None);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        use crate::math::modular::{mod_inverse, pow_mod};
+
+        let mut rng = Rng::new(1482);
+        for _ in 0..500 {
+            let (a, e) = (rng.next_u64(), rng.next_u64() >> rng.gen_index(64));
+            let m = (rng.next_u64() >> rng.gen_index(63)).max(1);
+            let (big_a, big_m) = (BigInt::from(a), BigInt::from(m));
+            assert_eq!(
+                big_a.mod_pow(&BigInt::from(e), &big_m),
+                BigInt::from(pow_mod(a, e, m))
+            );
+            assert_eq!(
+                big_a.mod_inverse(&big_m),
+                mod_inverse(a % m, m).map(BigInt::from)
+            );
+        }
+        let minus_seven = BigInt::from(-7i64);
+        let five = BigInt::from(5u64);
+        assert_eq!(minus_seven.rem_euclid(&five), BigInt::from(3u64));
+        assert_eq!(
+            minus_seven.mod_pow(&BigInt::one(), &five),
+            BigInt::from(3u64)
+        );
+        assert_eq!(minus_seven.mod_inverse(&five), Some(BigInt::from(2u64)));
+        assert_eq!(
+            five.mod_pow(&BigInt::zero(), &BigInt::one()),
+            BigInt::zero()
+        );
+
+        // 费马小定理：2^(p-1) ≡ 1 (mod p)，p = 2^127 - 1 是梅森素数
+        let p = &BigInt::from(2u64).pow(127) - &BigInt::one();
+        let two = BigInt::from(2u64);
+        assert_eq!(two.mod_pow(&(&p - &BigInt::one()), &p), BigInt::one());
+        let inverse = two.mod_inverse(&p).unwrap();
+        assert_eq!((&two * &inverse).rem_euclid(&p), BigInt::one());
+    }
+
+    #[test]
+    fn byte_conversions() {
+        assert_eq!(BigInt::from_bytes_be(&[]), BigInt::zero());
+        assert_eq!(BigInt::zero().to_bytes_be(), Vec::<u8>::new());
+        assert_eq!(
+            BigInt::from_bytes_be(&[0, 0, 1, 2, 3, 4, 5]),
+            BigInt::from(0x0102030405u64)
+        );
+        assert_eq!(BigInt::from(-0x0102i64).to_bytes_be(), vec![1, 2]);
+        assert!(BigInt::from(0x80u64).bit(7) && !BigInt::from(0x80u64).bit(200));
+
+        let mut rng = Rng::new(1482);
+        for _ in 0..100 {
+            let mut bytes: Vec<u8> = (0..rng.gen_index(40))
+                .map(|_| rng.next_u64() as u8)
+                .collect();
+            let value = BigInt::from_bytes_be(&bytes);
+            let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+            bytes.drain(..leading_zeros);
+            assert_eq!(value.to_bytes_be(), bytes);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero_panics() {
+        let _ = BigInt::one() / BigInt::zero();
+    }
+}
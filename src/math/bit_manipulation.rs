@@ -0,0 +1,242 @@
+/*!
+定义：
+[位运算](https://zh.wikipedia.org/wiki/%E4%BD%8D%E6%93%8D%E4%BD%9C)的常用技巧，都以 u64 为例。
+- `x & (x - 1)` 清掉最低位的 1，`x & -x` 只保留最低位的 1
+- 异或满足交换律、结合律，且 a ^ a = 0、a ^ 0 = a，成对出现的数异或后全部抵消
+- [格雷码](https://zh.wikipedia.org/wiki/%E6%A0%BC%E9%9B%B7%E7%A0%81)：相邻的两个数只有一位不同，g = x ^ (x >> 1)
+*/
+
+/// 逐位检查，O(位数)
+pub fn popcount_naive(mut x: u64) -> u32 {
+    let mut count = 0;
+    while x != 0 {
+        count += (x & 1) as u32;
+        x >>= 1;
+    }
+    count
+}
+
+/// Kernighan 方法：每次清掉最低位的 1，循环次数等于 1 的个数
+pub fn popcount_kernighan(mut x: u64) -> u32 {
+    let mut count = 0;
+    while x != 0 {
+        x &= x - 1;
+        count += 1;
+    }
+    count
+}
+
+/// SWAR：先算每 2 位中 1 的个数，再合并成每 4 位、每 8 位，最后用乘法把 8 个字节加到最高字节
+pub fn popcount_swar(x: u64) -> u32 {
+    let x = x - ((x >> 1) & 0x5555_5555_5555_5555);
+    let x = (x & 0x3333_3333_3333_3333) + ((x >> 2) & 0x3333_3333_3333_3333);
+    let x = (x + (x >> 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    (x.wrapping_mul(0x0101_0101_0101_0101) >> 56) as u32
+}
+
+/// 每个字节中 1 的个数，编译期生成
+const BYTE_POPCOUNT: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 1;
+    while i < 256 {
+        table[i] = table[i / 2] + (i & 1) as u8;
+        i += 1;
+    }
+    table
+};
+
+/// 查表：每个字节查一次
+pub fn popcount_table(x: u64) -> u32 {
+    x.to_le_bytes()
+        .iter()
+        .map(|&byte| BYTE_POPCOUNT[byte as usize] as u32)
+        .sum()
+}
+
+pub fn is_power_of_two(x: u64) -> bool {
+    x != 0 && x & (x - 1) == 0
+}
+
+/// 不小于 x 的最小的 2 的幂，超出 u64 时返回 None。
+/// 把 x - 1 最高位的 1 向右“涂满”所有低位，再加一
+pub fn next_power_of_two(x: u64) -> Option<u64> {
+    if x <= 1 {
+        return Some(1);
+    }
+    let mut smeared = x - 1;
+    for shift in [1, 2, 4, 8, 16, 32] {
+        smeared |= smeared >> shift;
+    }
+    smeared.checked_add(1)
+}
+
+/// 不用临时变量交换两个数：a ^= b 后 a 记录了两者的差异，再分别异或回去
+#[allow(clippy::manual_swap)] // 演示异或交换本身，实际代码应使用 std::mem::swap
+pub fn xor_swap(a: &mut u64, b: &mut u64) {
+    *a ^= *b;
+    *b ^= *a;
+    *a ^= *b;
+}
+
+/// 只保留最低位的 1，x = 0 时为 0
+pub fn lowest_set_bit(x: u64) -> u64 {
+    // 补码中 -x = !x + 1，只有最低位的 1 在两者中都是 1
+    x & x.wrapping_neg()
+}
+
+/// 清掉最低位的 1，x = 0 时为 0
+pub fn clear_lowest_set_bit(x: u64) -> u64 {
+    x & x.wrapping_sub(1)
+}
+
+/// 二进制转格雷码
+pub fn to_gray(x: u64) -> u64 {
+    x ^ (x >> 1)
+}
+
+/// 格雷码转二进制：x 的每一位是 g 中这一位及更高位的异或，用前缀异或在 6 步内算完
+pub fn from_gray(mut g: u64) -> u64 {
+    for shift in [1, 2, 4, 8, 16, 32] {
+        g ^= g >> shift;
+    }
+    g
+}
+
+/// 翻转位的顺序：依次交换相邻的 1 位、2 位、4 位……32 位
+pub fn reverse_bits(x: u64) -> u64 {
+    let x = (x >> 1) & 0x5555_5555_5555_5555 | (x & 0x5555_5555_5555_5555) << 1;
+    let x = (x >> 2) & 0x3333_3333_3333_3333 | (x & 0x3333_3333_3333_3333) << 2;
+    let x = (x >> 4) & 0x0f0f_0f0f_0f0f_0f0f | (x & 0x0f0f_0f0f_0f0f_0f0f) << 4;
+    let x = (x >> 8) & 0x00ff_00ff_00ff_00ff | (x & 0x00ff_00ff_00ff_00ff) << 8;
+    let x = (x >> 16) & 0x0000_ffff_0000_ffff | (x & 0x0000_ffff_0000_ffff) << 16;
+    x.rotate_left(32)
+}
+
+/// 其它数都恰好出现两次，找出只出现一次的数
+pub fn single_number(values: &[u64]) -> u64 {
+    values.iter().fold(0, |acc, &x| acc ^ x)
+}
+
+/// 其它数都恰好出现三次，找出只出现一次的数。
+/// 对每一位做模 3 计数：ones、twos 分别记录出现次数模 3 余 1、余 2 的位
+pub fn single_number_among_triples(values: &[u64]) -> u64 {
+    let (mut ones, mut twos) = (0u64, 0u64);
+    for &x in values {
+        ones = (ones ^ x) & !twos;
+        twos = (twos ^ x) & !ones;
+    }
+    ones
+}
+
+/// 其它数都恰好出现两次，找出只出现一次的两个数，从小到大返回。
+/// 两者的异或非零，按它最低位的 1 把所有数分成两组，每组各含其中一个
+pub fn two_single_numbers(values: &[u64]) -> (u64, u64) {
+    let difference = lowest_set_bit(single_number(values));
+    let (mut a, mut b) = (0, 0);
+    for &x in values {
+        if x & difference == 0 {
+            a ^= x;
+        } else {
+            b ^= x;
+        }
+    }
+    (a.min(b), a.max(b))
+}
+
+/// `values` 是 0..=n 中缺了一个数的排列，返回缺的数
+pub fn missing_number(values: &[u64]) -> u64 {
+    let all = (0..=values.len() as u64).fold(0, |acc, x| acc ^ x);
+    all ^ single_number(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn popcount_variants_agree() {
+        let mut rng = Rng::new(1466);
+        let mut cases = vec![0, 1, u64::MAX, 1 << 63, 0x5555_5555_5555_5555];
+        cases.extend((0..1000).map(|_| rng.next_u64() >> rng.gen_index(64)));
+        for x in cases {
+            let expected = x.count_ones();
+            assert_eq!(popcount_naive(x), expected);
+            assert_eq!(popcount_kernighan(x), expected);
+            assert_eq!(popcount_swar(x), expected);
+            assert_eq!(popcount_table(x), expected);
+        }
+    }
+
+    #[test]
+    fn powers_and_lowest_bits() {
+        assert_eq!(next_power_of_two(0), Some(1));
+        assert_eq!(next_power_of_two(5), Some(8));
+        assert_eq!(next_power_of_two(64), Some(64));
+        assert_eq!(next_power_of_two(1 << 63), Some(1 << 63));
+        assert_eq!(next_power_of_two((1 << 63) + 1), None);
+        assert!(is_power_of_two(1) && is_power_of_two(1 << 40));
+        assert!(!is_power_of_two(0) && !is_power_of_two(6));
+        assert_eq!(lowest_set_bit(0b1011_0100), 0b100);
+        assert_eq!(clear_lowest_set_bit(0b1011_0100), 0b1011_0000);
+        assert_eq!((lowest_set_bit(0), clear_lowest_set_bit(0)), (0, 0));
+
+        let mut rng = Rng::new(1466);
+        for _ in 0..1000 {
+            let x = rng.next_u64() >> rng.gen_index(64);
+            assert_eq!(next_power_of_two(x), x.checked_next_power_of_two());
+            assert_eq!(is_power_of_two(x), x.is_power_of_two());
+            assert_eq!(lowest_set_bit(x) | clear_lowest_set_bit(x), x);
+            assert_eq!(reverse_bits(x), x.reverse_bits());
+        }
+    }
+
+    #[test]
+    fn swaps_and_gray_codes() {
+        let (mut a, mut b) = (12, 34);
+        xor_swap(&mut a, &mut b);
+        assert_eq!((a, b), (34, 12));
+
+        let codes: Vec<u64> = (0..8).map(to_gray).collect();
+        assert_eq!(codes, vec![0, 1, 3, 2, 6, 7, 5, 4]);
+        for x in 0..10_000u64 {
+            // 相邻格雷码只差一位
+            assert_eq!((to_gray(x) ^ to_gray(x + 1)).count_ones(), 1);
+            assert_eq!(from_gray(to_gray(x)), x);
+        }
+        assert_eq!(from_gray(to_gray(u64::MAX)), u64::MAX);
+        assert_eq!(reverse_bits(1), 1 << 63);
+    }
+
+    #[test]
+    fn xor_tricks() {
+        let mut rng = Rng::new(1466);
+        for _ in 0..50 {
+            let distinct: Vec<u64> = (0..20)
+                .map(|i| i * 1000 + rng.gen_index(1000) as u64)
+                .collect();
+            let (single, rest) = distinct.split_first().unwrap();
+
+            let mut pairs: Vec<u64> = rest.iter().flat_map(|&x| [x, x]).collect();
+            pairs.push(*single);
+            rng.shuffle(&mut pairs);
+            assert_eq!(single_number(&pairs), *single);
+
+            let mut triples: Vec<u64> = rest.iter().flat_map(|&x| [x, x, x]).collect();
+            triples.push(*single);
+            rng.shuffle(&mut triples);
+            assert_eq!(single_number_among_triples(&triples), *single);
+
+            let mut two: Vec<u64> = rest[1..].iter().flat_map(|&x| [x, x]).collect();
+            two.extend([*single, rest[0]]);
+            rng.shuffle(&mut two);
+            assert_eq!(two_single_numbers(&two), (*single, rest[0]));
+
+            let n = rng.gen_index(100) as u64 + 1;
+            let missing = rng.gen_index(n as usize + 1) as u64;
+            let mut values: Vec<u64> = (0..=n).filter(|&x| x != missing).collect();
+            rng.shuffle(&mut values);
+            assert_eq!(missing_number(&values), missing);
+        }
+    }
+}
@@ -0,0 +1,343 @@
+/*!
+定义：
+[组合数学](https://zh.wikipedia.org/wiki/%E7%BB%84%E5%90%88%E6%95%B0%E5%AD%A6)
+- 组合数 C(n, k) = n! / (k!(n-k)!)，排列数 P(n, k) = n! / (n-k)!
+- [卡特兰数](https://zh.wikipedia.org/wiki/%E5%8D%A1%E5%A1%94%E5%85%B0%E6%95%B0) Cₙ = C(2n, n) / (n + 1)
+- [斯特林数](https://zh.wikipedia.org/wiki/%E6%96%AF%E7%89%B9%E6%9E%97%E6%95%B0)：
+  第一类 c(n, k) 是 n 个元素划分成 k 个环排列的方案数，第二类 S(n, k) 是划分成 k 个非空集合的方案数
+- [卢卡斯定理](https://zh.wikipedia.org/wiki/%E7%9B%A7%E5%8D%A1%E6%96%AF%E5%AE%9A%E7%90%86)：
+  把 n、k 写成 p 进制，C(n, k) ≡ ∏ C(nᵢ, kᵢ) (mod p)，n 远大于 p 时也能计算
+
+模素数 p 时预处理阶乘和阶乘的逆元，之后每次查询 O(1)；结果不取模时用 u128 精确计算，溢出返回 None，
+`*_big` 版本用 [`BigInt`] 计算，没有大小限制
+*/
+
+use super::big_int::BigInt;
+use super::gcd::gcd;
+use super::modular::{mod_inverse_prime, mul_mod};
+use super::primality::is_prime_u64;
+
+/// 模素数 `p` 下预处理好的阶乘表
+#[derive(Debug, Clone)]
+pub struct Combinatorics {
+    modulus: u64,
+    factorial: Vec<u64>,
+    inverse_factorial: Vec<u64>,
+}
+
+impl Combinatorics {
+    /// 预处理 0..=max_n 的阶乘。n ≥ p 时 n! ≡ 0 没有逆元，所以表最多到 p - 1
+    pub fn new(max_n: usize, modulus: u64) -> Self {
+        assert!(is_prime_u64(modulus), "Modulus {modulus} must be prime.");
+        let len = (max_n as u64).min(modulus - 1) as usize + 1;
+        let mut factorial = vec![1; len];
+        for i in 1..len {
+            factorial[i] = mul_mod(factorial[i - 1], i as u64, modulus);
+        }
+        let mut inverse_factorial = vec![1; len];
+        inverse_factorial[len - 1] = mod_inverse_prime(factorial[len - 1], modulus).unwrap();
+        // (i-1)!⁻¹ = i!⁻¹ · i，只需要一次求逆
+        for i in (1..len).rev() {
+            inverse_factorial[i - 1] = mul_mod(inverse_factorial[i], i as u64, modulus);
+        }
+        Combinatorics {
+            modulus,
+            factorial,
+            inverse_factorial,
+        }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// 表能覆盖的最大 n
+    pub fn max_n(&self) -> usize {
+        self.factorial.len() - 1
+    }
+
+    /// n! mod p
+    pub fn factorial(&self, n: usize) -> u64 {
+        self.check(n);
+        self.factorial[n]
+    }
+
+    /// C(n, k) mod p，k > n 时为 0
+    pub fn ncr(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+        self.check(n);
+        let denominator = mul_mod(
+            self.inverse_factorial[k],
+            self.inverse_factorial[n - k],
+            self.modulus,
+        );
+        mul_mod(self.factorial[n], denominator, self.modulus)
+    }
+
+    /// P(n, k) mod p，k > n 时为 0
+    pub fn npr(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+        self.check(n);
+        mul_mod(
+            self.factorial[n],
+            self.inverse_factorial[n - k],
+            self.modulus,
+        )
+    }
+
+    /// 第 n 个卡特兰数 mod p，用 C(2n, n) - C(2n, n+1) 避免除以 n + 1
+    pub fn catalan(&self, n: usize) -> u64 {
+        let (all, bad) = (self.ncr(2 * n, n), self.ncr(2 * n, n + 1));
+        (all + self.modulus - bad) % self.modulus
+    }
+
+    /// 卢卡斯定理计算 C(n, k) mod p，n、k 可以是任意 u64。要求表覆盖到 p - 1
+    pub fn lucas(&self, mut n: u64, mut k: u64) -> u64 {
+        assert!(
+            self.max_n() as u64 == self.modulus - 1,
+            "Lucas' theorem needs factorials up to p - 1."
+        );
+        let p = self.modulus;
+        let mut result = 1;
+        while k > 0 {
+            let (ni, ki) = ((n % p) as usize, (k % p) as usize);
+            if ki > ni {
+                return 0;
+            }
+            result = mul_mod(result, self.ncr(ni, ki), p);
+            n /= p;
+            k /= p;
+        }
+        result
+    }
+
+    fn check(&self, n: usize) {
+        assert!(
+            n <= self.max_n(),
+            "n = {n} exceeds the precomputed range 0..={}.",
+            self.max_n()
+        );
+    }
+}
+
+/// 精确的 C(n, k)，超出 u128 时返回 None
+pub fn binomial(n: u64, k: u64) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k as u128 {
+        // C(n, i+1) = C(n, i) · (n-i) / (i+1)。先约掉公因子，中间结果不会比答案大太多
+        let (numerator, denominator) = (n as u128 - i, i + 1);
+        let g = gcd(result, denominator);
+        result = (result / g).checked_mul(numerator / (denominator / g))?;
+    }
+    Some(result)
+}
+
+/// 精确的 P(n, k)，超出 u128 时返回 None
+pub fn permutations(n: u64, k: u64) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    (n - k + 1..=n).try_fold(1u128, |acc, x| acc.checked_mul(x as u128))
+}
+
+/// 精确的第 n 个卡特兰数，超出 u128 时返回 None
+pub fn catalan(n: u64) -> Option<u128> {
+    // Cₙ₊₁ = Cₙ · 2(2n+1) / (n+2)，同样先约分
+    let mut result: u128 = 1;
+    for i in 0..n as u128 {
+        let (numerator, denominator) = (2 * (2 * i + 1), i + 2);
+        let g = gcd(result, denominator);
+        result = (result / g).checked_mul(numerator / (denominator / g))?;
+    }
+    Some(result)
+}
+
+/// 任意大的 C(n, k)
+pub fn binomial_big(n: u64, k: u64) -> BigInt {
+    if k > n {
+        return BigInt::zero();
+    }
+    let k = k.min(n - k);
+    let mut result = BigInt::one();
+    for i in 0..k {
+        // C(n, i) · (n-i) = C(n, i+1) · (i+1)，除法总是整除
+        result = &(&result * &BigInt::from(n - i)) / &BigInt::from(i + 1);
+    }
+    result
+}
+
+/// 任意大的 P(n, k)
+pub fn permutations_big(n: u64, k: u64) -> BigInt {
+    if k > n {
+        return BigInt::zero();
+    }
+    (n - k + 1..=n).fold(BigInt::one(), |acc, x| &acc * &BigInt::from(x))
+}
+
+/// 任意大的第 n 个卡特兰数
+pub fn catalan_big(n: u64) -> BigInt {
+    let mut result = BigInt::one();
+    for i in 0..n as u128 {
+        // Cᵢ · 2(2i+1) = Cᵢ₊₁ · (i+2)，除法总是整除
+        result = &(&result * &BigInt::from(2 * (2 * i + 1))) / &BigInt::from(i + 2);
+    }
+    result
+}
+
+/// 无符号第一类斯特林数 c(n, k)，超出 u128 时返回 None。
+/// 递推 c(n, k) = c(n-1, k-1) + (n-1)·c(n-1, k)
+pub fn stirling_first(n: usize, k: usize) -> Option<u128> {
+    stirling(n, k, |i, _| i as u128 - 1)
+}
+
+/// 第二类斯特林数 S(n, k)，超出 u128 时返回 None。
+/// 递推 S(n, k) = S(n-1, k-1) + k·S(n-1, k)
+pub fn stirling_second(n: usize, k: usize) -> Option<u128> {
+    stirling(n, k, |_, j| j as u128)
+}
+
+/// 两类斯特林数共用的递推：s(i, j) = s(i-1, j-1) + factor(i, j)·s(i-1, j)，只保留一行
+fn stirling(n: usize, k: usize, factor: impl Fn(usize, usize) -> u128) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    // row[j] 是 s(i, j)，只需要 j ≤ k
+    let mut row = vec![0u128; k + 1];
+    row[0] = 1;
+    for i in 1..=n {
+        // 从右往左更新，row[j - 1] 还是上一行的值。之后每行 j 最多加 1，
+        // j < k - (n - i) 的格子不会影响 s(n, k)，跳过它们，避免其中的溢出让结果变成 None
+        for j in (k.saturating_sub(n - i).max(1)..=k.min(i)).rev() {
+            row[j] = factor(i, j).checked_mul(row[j])?.checked_add(row[j - 1])?;
+        }
+        row[0] = 0;
+    }
+    Some(row[k])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        binomial, binomial_big, catalan, catalan_big, permutations, permutations_big,
+        stirling_first, stirling_second, Combinatorics,
+    };
+    use crate::math::BigInt;
+    use crate::utils::random::Rng;
+
+    const P: u64 = 1_000_000_007;
+
+    #[test]
+    fn modular_tables() {
+        let table = Combinatorics::new(1000, P);
+        assert_eq!(table.max_n(), 1000);
+        assert_eq!(table.factorial(10), 3_628_800);
+        assert_eq!(table.ncr(5, 2), 10);
+        assert_eq!(table.ncr(2, 5), 0);
+        assert_eq!(table.npr(5, 2), 20);
+        assert_eq!(table.catalan(0), 1);
+        assert_eq!(table.catalan(10), 16_796);
+        // C(1000, 500) mod 1e9+7
+        assert_eq!(table.ncr(1000, 500), 159_835_829);
+
+        let mut rng = Rng::new(1456);
+        for _ in 0..200 {
+            let n = rng.gen_index(120) as u64;
+            let k = rng.gen_index(130) as u64;
+            let expected = binomial(n, k).unwrap() % P as u128;
+            assert_eq!(table.ncr(n as usize, k as usize) as u128, expected);
+            let expected = permutations(n, k).map_or(0, |v| v % P as u128);
+            if n <= 30 {
+                assert_eq!(table.npr(n as usize, k as usize) as u128, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn lucas_matches_direct_computation() {
+        let table = Combinatorics::new(usize::MAX, 13);
+        assert_eq!(table.max_n(), 12);
+        let mut rng = Rng::new(1456);
+        for _ in 0..300 {
+            let n = rng.gen_index(120) as u64;
+            let k = rng.gen_index(120) as u64;
+            assert_eq!(table.lucas(n, k) as u128, binomial(n, k).unwrap() % 13);
+        }
+        // C(p^a, 1) ≡ 0，C(n, 0) ≡ 1
+        assert_eq!(table.lucas(13u64.pow(15), 1), 0);
+        assert_eq!(table.lucas(u64::MAX, 0), 1);
+    }
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(binomial(0, 0), Some(1));
+        assert_eq!(binomial(52, 5), Some(2_598_960));
+        assert_eq!(binomial(3, 4), Some(0));
+        // C(128, 64) 约 2.4e37，仍然放得下
+        assert_eq!(
+            binomial(128, 64),
+            Some(23_951_146_041_928_082_866_135_587_776_380_551_750)
+        );
+        assert_eq!(binomial(200, 100), None);
+        assert_eq!(permutations(10, 3), Some(720));
+        assert_eq!(permutations(40, 40), None);
+        let first: Vec<u128> = (0..10).map(|n| catalan(n).unwrap()).collect();
+        assert_eq!(first, vec![1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862]);
+        assert!(catalan(60).is_some());
+        assert_eq!(catalan(80), None);
+
+        assert_eq!(stirling_second(0, 0), Some(1));
+        assert_eq!(stirling_second(5, 0), Some(0));
+        assert_eq!(stirling_second(5, 2), Some(15));
+        assert_eq!(stirling_second(10, 3), Some(9330));
+        // S(n, n-1) = s(n, n-1) = C(n, 2)，中间的大数不影响结果
+        for n in [100, 1000] {
+            let expected = binomial(n as u64, 2);
+            assert_eq!(stirling_second(n, n - 1), expected);
+            assert_eq!(stirling_first(n, n - 1), expected);
+        }
+        assert_eq!(stirling_first(4, 2), Some(11));
+        assert_eq!(stirling_first(6, 3), Some(225));
+        // 第一类斯特林数按 k 求和是 n!
+        let total: u128 = (0..=8).map(|k| stirling_first(8, k).unwrap()).sum();
+        assert_eq!(total, 40320);
+        assert_eq!(stirling_second(3, 5), Some(0));
+    }
+
+    #[test]
+    fn big_values() {
+        assert_eq!(binomial_big(3, 4), BigInt::zero());
+        assert_eq!(permutations_big(3, 4), BigInt::zero());
+        assert_eq!(
+            binomial_big(200, 100).to_string(),
+            "90548514656103281165404177077484163874504589675413336841320"
+        );
+        assert_eq!(
+            permutations_big(40, 40).to_string(),
+            "815915283247897734345611269596115894272000000000"
+        );
+        assert_eq!(
+            catalan_big(200).to_string(),
+            "512201493211017079467541693136328292324432464582475861864920694407578768023144072628540276213813397768975366156750120"
+        );
+        // 在 u128 范围内和精确版本一致
+        for n in 0..70 {
+            for k in 0..=n + 1 {
+                if let Some(expected) = binomial(n, k) {
+                    assert_eq!(binomial_big(n, k), BigInt::from(expected));
+                }
+                if let Some(expected) = permutations(n, k) {
+                    assert_eq!(permutations_big(n, k), BigInt::from(expected));
+                }
+            }
+            assert_eq!(catalan_big(n), BigInt::from(catalan(n).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,173 @@
+/*!
+定义：
+[整数分解](https://zh.wikipedia.org/wiki/%E6%95%B4%E6%95%B8%E5%88%86%E8%A7%A3)
+先用小素数试除去掉小因子，剩下的数用 Miller–Rabin 判断是否是素数，不是素数就用
+[Pollard ρ 算法](https://zh.wikipedia.org/wiki/Pollard%27s_rho%E7%AE%97%E6%B3%95)找到一个非平凡因子后递归分解。
+ρ 算法迭代 x ← x² + c (mod n)，序列模 n 的某个素因子 p 时大约 √p 步进入循环，此时 gcd(|x - y|, n) 就含有 p。
+Brent 的改进用倍增的步长检测循环，并把多次的 |x - y| 连乘后才求一次 gcd，减少 gcd 的次数
+*/
+
+use super::gcd::gcd;
+use super::modular::mul_mod;
+use super::primality::is_prime_u64;
+
+/// 试除用的素数上界
+const TRIAL_DIVISION_LIMIT: u64 = 1000;
+/// 每累乘这么多个差值求一次 gcd
+const BATCH: u64 = 128;
+
+/// 质因数分解，返回按素数从小到大排列的 (素数, 指数)；0 和 1 返回空
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    if n < 2 {
+        return factors;
+    }
+    let mut n = n;
+    let mut p = 2;
+    while p < TRIAL_DIVISION_LIMIT && p * p <= n {
+        let mut exponent = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((p, exponent));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+
+    let mut large = vec![];
+    if n > 1 {
+        split(n, &mut large);
+    }
+    large.sort_unstable();
+    for prime in large {
+        match factors.last_mut() {
+            Some((last, exponent)) if *last == prime => *exponent += 1,
+            _ => factors.push((prime, 1)),
+        }
+    }
+    factors
+}
+
+/// 把 `n` 分解成素数（可重复）放进 `primes`，`n` 没有小于试除上界的因子
+fn split(n: u64, primes: &mut Vec<u64>) {
+    if is_prime_u64(n) {
+        primes.push(n);
+        return;
+    }
+    // 完全平方数在 ρ 算法中很慢，直接处理
+    let root = n.isqrt();
+    if root * root == n {
+        split(root, primes);
+        split(root, primes);
+        return;
+    }
+    let divisor = (1..)
+        .find_map(|c| pollard_brent(n, c))
+        .expect("some constant finds a factor");
+    split(divisor, primes);
+    split(n / divisor, primes);
+}
+
+/// Brent 版本的 Pollard ρ，用 x² + c 作为迭代函数；失败（只得到 n 本身）时返回 None
+fn pollard_brent(n: u64, c: u64) -> Option<u64> {
+    let step = |x: u64| (mul_mod(x, x, n) + c) % n;
+    let (mut y, mut g, mut r, mut q) = (2, 1, 1, 1);
+    let (mut x, mut saved) = (y, y);
+    while g == 1 {
+        x = y;
+        for _ in 0..r {
+            y = step(y);
+        }
+        let mut k = 0;
+        while k < r && g == 1 {
+            saved = y;
+            for _ in 0..BATCH.min(r - k) {
+                y = step(y);
+                q = mul_mod(q, x.abs_diff(y), n);
+            }
+            g = gcd(q, n);
+            k += BATCH;
+        }
+        r *= 2;
+    }
+    if g == n {
+        // 批量相乘跳过了因子，从保存的位置逐步重来
+        loop {
+            saved = step(saved);
+            g = gcd(x.abs_diff(saved), n);
+            if g > 1 {
+                break;
+            }
+        }
+    }
+    (g != n).then_some(g)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::factorize;
+    use crate::math::primality::is_prime_u64;
+    use crate::utils::random::Rng;
+
+    fn product(factors: &[(u64, u32)]) -> u64 {
+        factors.iter().map(|&(p, e)| p.pow(e)).product()
+    }
+
+    #[test]
+    fn small_numbers() {
+        assert_eq!(factorize(0), vec![]);
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(2), vec![(2, 1)]);
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(997 * 997), vec![(997, 2)]);
+        for n in 2..5000u64 {
+            let factors = factorize(n);
+            assert_eq!(product(&factors), n);
+            assert!(factors.iter().all(|&(p, _)| is_prime_u64(p)));
+        }
+    }
+
+    #[test]
+    fn large_semiprimes_and_powers() {
+        let p = 1_000_000_007u64;
+        let q = 998_244_353u64;
+        assert_eq!(factorize(p * q), vec![(q, 1), (p, 1)]);
+        assert_eq!(
+            factorize(4_294_967_291 * 4_294_967_279),
+            vec![(4_294_967_279, 1), (4_294_967_291, 1)]
+        );
+        assert_eq!(factorize(1 << 63), vec![(2, 63)]);
+        assert_eq!(factorize(1_000_003u64.pow(3)), vec![(1_000_003, 3)]);
+        assert_eq!(
+            factorize(18_446_744_073_709_551_557),
+            vec![(18_446_744_073_709_551_557, 1)]
+        );
+        // 2^64 - 1 = 3 · 5 · 17 · 257 · 641 · 65537 · 6700417
+        assert_eq!(
+            factorize(u64::MAX),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6_700_417, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn random_numbers() {
+        let mut rng = Rng::new(1453);
+        for _ in 0..200 {
+            let n = rng.next_u64() >> rng.gen_index(40);
+            let factors = factorize(n);
+            assert_eq!(product(&factors), n.max(1));
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+            assert!(factors.iter().all(|&(p, _)| is_prime_u64(p)));
+        }
+    }
+}
@@ -0,0 +1,235 @@
+/*!
+定义：
+[斐波那契数](https://zh.wikipedia.org/wiki/%E6%96%90%E6%B3%A2%E9%82%A3%E5%A5%91%E6%95%B0)
+F(0) = 0，F(1) = 1，F(n) = F(n-1) + F(n-2)。快速倍增法利用
+F(2k) = F(k)·(2F(k+1) - F(k))，F(2k+1) = F(k)² + F(k+1)²，
+从 n 的最高位开始每次把下标翻倍（再按需要加一），O(log n) 次乘法就能求出 F(n)。
+- [卢卡斯数](https://zh.wikipedia.org/wiki/%E5%8D%A2%E5%8D%A1%E6%96%AF%E6%95%B0) L(n) = F(n-1) + F(n+1)，L(0) = 2
+- [佩尔数](https://en.wikipedia.org/wiki/Pell_number) P(n) = 2P(n-1) + P(n-2)，用 2×2 矩阵快速幂计算
+- [皮萨诺周期](https://en.wikipedia.org/wiki/Pisano_period) π(m) 是斐波那契数列模 m 的最小正周期
+*/
+
+use super::big_int::BigInt;
+use super::factorization::factorize;
+use super::gcd::lcm;
+use super::modular::mul_mod;
+
+/// 第 n 个斐波那契数，超出 u128（n > 186）时返回 None
+pub fn fibonacci_fast_doubling(n: u64) -> Option<u128> {
+    // 最后一步只算 F(n)，不需要可能已经溢出的 F(n+1)
+    let (a, b) = fibonacci_pair(n / 2)?;
+    if n.is_multiple_of(2) {
+        a.checked_mul((2 * b).checked_sub(a)?)
+    } else {
+        a.checked_mul(a)?.checked_add(b.checked_mul(b)?)
+    }
+}
+
+/// (F(n), F(n+1))，溢出时返回 None
+fn fibonacci_pair(n: u64) -> Option<(u128, u128)> {
+    if n == 0 {
+        return Some((0, 1));
+    }
+    let (a, b) = fibonacci_pair(n / 2)?;
+    let even = a.checked_mul(b.checked_mul(2)?.checked_sub(a)?)?;
+    let odd = a.checked_mul(a)?.checked_add(b.checked_mul(b)?)?;
+    if n.is_multiple_of(2) {
+        Some((even, odd))
+    } else {
+        Some((odd, even.checked_add(odd)?))
+    }
+}
+
+/// 任意大的第 n 个斐波那契数
+pub fn fibonacci_big(n: u64) -> BigInt {
+    // 从最高位往低位扫描，(a, b) 始终是 (F(k), F(k+1))，k 是已经扫过的前缀
+    let (mut a, mut b) = (BigInt::zero(), BigInt::one());
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        let even = &a * &(&(&b + &b) - &a);
+        let odd = &(&a * &a) + &(&b * &b);
+        (a, b) = if n >> bit & 1 == 0 {
+            (even, odd)
+        } else {
+            let next = &even + &odd;
+            (odd, next)
+        };
+    }
+    a
+}
+
+/// F(n) mod m
+pub fn fibonacci_mod(n: u64, m: u64) -> u64 {
+    assert!(m > 0, "Modulus must be positive.");
+    fibonacci_pair_mod(n, m).0
+}
+
+/// (F(n), F(n+1)) mod m
+fn fibonacci_pair_mod(n: u64, m: u64) -> (u64, u64) {
+    let (mut a, mut b) = (0, 1 % m);
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        let twice_b_minus_a = ((2 * b as u128 + m as u128 - a as u128) % m as u128) as u64;
+        let even = mul_mod(a, twice_b_minus_a, m);
+        let odd = ((mul_mod(a, a, m) as u128 + mul_mod(b, b, m) as u128) % m as u128) as u64;
+        (a, b) = if n >> bit & 1 == 0 {
+            (even, odd)
+        } else {
+            (odd, ((even as u128 + odd as u128) % m as u128) as u64)
+        };
+    }
+    (a, b)
+}
+
+/// 第 n 个卢卡斯数，超出 u128 时返回 None
+pub fn lucas_number(n: u64) -> Option<u128> {
+    if n == 0 {
+        return Some(2);
+    }
+    // L(n) = F(n-1) + F(n+1) = 2F(n-1) + F(n)
+    let (previous, current) = fibonacci_pair(n - 1)?;
+    previous.checked_mul(2)?.checked_add(current)
+}
+
+/// 第 n 个佩尔数，超出 u128 时返回 None
+pub fn pell_number(n: u64) -> Option<u128> {
+    // [[2, 1], [1, 0]]^n = [[P(n+1), P(n)], [P(n), P(n-1)]]
+    type Matrix = [[u128; 2]; 2];
+    fn multiply(x: &Matrix, y: &Matrix) -> Option<Matrix> {
+        let mut result = [[0; 2]; 2];
+        for i in 0..2 {
+            for j in 0..2 {
+                result[i][j] = x[i][0]
+                    .checked_mul(y[0][j])?
+                    .checked_add(x[i][1].checked_mul(y[1][j])?)?;
+            }
+        }
+        Some(result)
+    }
+
+    if n == 0 {
+        return Some(0);
+    }
+    // 只算到 n-1 次幂，取左上角的 P(n)，避免 P(n+1) 溢出
+    let exp = n - 1;
+    let mut result: Matrix = [[1, 0], [0, 1]];
+    for bit in (0..u64::BITS - exp.leading_zeros()).rev() {
+        result = multiply(&result, &result)?;
+        if exp >> bit & 1 == 1 {
+            result = multiply(&result, &[[2, 1], [1, 0]])?;
+        }
+    }
+    Some(result[0][0])
+}
+
+/// 斐波那契数列模 m 的最小正周期 π(m)，要求 m < 2^60
+pub fn pisano_period(m: u64) -> u64 {
+    assert!(m > 0 && m < 1 << 60, "Modulus {m} out of range 1..2^60.");
+    // π(m) 是各素数幂因子的周期的最小公倍数
+    factorize(m)
+        .into_iter()
+        .map(|(p, k)| {
+            let modulus = p.pow(k);
+            // π(p) 整除 p - 1（p ≡ ±1 mod 5）或 2(p + 1)（p ≡ ±2 mod 5），π(2) = 3，π(5) = 20；
+            // π(p^k) 整除 p^(k-1)·π(p)。先取这个已知的周期的倍数，再去掉多余的素因子
+            let multiple = match p {
+                2 => 3,
+                5 => 20,
+                _ if matches!(p % 5, 1 | 4) => p - 1,
+                _ => 2 * (p + 1),
+            } * (modulus / p);
+            smallest_period(multiple, modulus)
+        })
+        .fold(1, lcm)
+}
+
+/// 已知 `multiple` 是模 `m` 的一个周期，求最小正周期
+fn smallest_period(multiple: u64, m: u64) -> u64 {
+    let is_period = |d: u64| fibonacci_pair_mod(d, m) == (0, 1 % m);
+    // 周期构成 π 的所有倍数，能去掉某个素因子时就一直去掉
+    let mut period = multiple;
+    for (p, _) in factorize(multiple) {
+        while period.is_multiple_of(p) && is_period(period / p) {
+            period /= p;
+        }
+    }
+    period
+}
+
+#[cfg(all(test, feature = "dp"))]
+mod tests {
+    use super::{
+        fibonacci_big, fibonacci_fast_doubling, fibonacci_mod, lucas_number, pell_number,
+        pisano_period,
+    };
+    use crate::dp::fibonacci_memo;
+    use crate::math::BigInt;
+
+    #[test]
+    fn fibonacci_matches_iteration() {
+        let (mut a, mut b) = (0u128, 1u128);
+        for n in 0..=186 {
+            assert_eq!(fibonacci_fast_doubling(n), Some(a), "F({n})");
+            assert_eq!(fibonacci_big(n), BigInt::from(a));
+            assert_eq!(fibonacci_memo(n as u32), a);
+            assert_eq!(fibonacci_mod(n, 1_000_000_007) as u128, a % 1_000_000_007);
+            (a, b) = (b, a.wrapping_add(b));
+        }
+        assert_eq!(fibonacci_fast_doubling(187), None);
+        assert_eq!(fibonacci_fast_doubling(u64::MAX), None);
+    }
+
+    #[test]
+    fn large_fibonacci() {
+        assert_eq!(
+            fibonacci_big(300).to_string(),
+            "222232244629420445529739893461909967206666939096499764990979600"
+        );
+        // F(10^18) mod 10^9+7
+        assert_eq!(
+            fibonacci_mod(1_000_000_000_000_000_000, 1_000_000_007),
+            209_783_453
+        );
+        assert_eq!(fibonacci_mod(12345, 1), 0);
+        // 卡西尼恒等式 F(n-1)F(n+1) - F(n)² = (-1)^n
+        let n = 1001;
+        let lhs = &(&fibonacci_big(n - 1) * &fibonacci_big(n + 1)) - &fibonacci_big(n).pow(2);
+        assert_eq!(lhs, BigInt::from(-1i64));
+    }
+
+    #[test]
+    fn lucas_and_pell() {
+        let lucas: Vec<u128> = (0..10).map(|n| lucas_number(n).unwrap()).collect();
+        assert_eq!(lucas, vec![2, 1, 3, 4, 7, 11, 18, 29, 47, 76]);
+        let pell: Vec<u128> = (0..10).map(|n| pell_number(n).unwrap()).collect();
+        assert_eq!(pell, vec![0, 1, 2, 5, 12, 29, 70, 169, 408, 985]);
+
+        let (mut l, mut p) = ((2u128, 1u128), (0u128, 1u128));
+        for n in 0..100 {
+            assert_eq!(lucas_number(n), Some(l.0));
+            assert_eq!(pell_number(n), Some(p.0));
+            l = (l.1, l.0 + l.1);
+            p = (p.1, 2 * p.1 + p.0);
+        }
+        assert!(lucas_number(184).is_some());
+        assert_eq!(lucas_number(185), None);
+        assert!(pell_number(101).is_some());
+        assert_eq!(pell_number(102), None);
+    }
+
+    #[test]
+    fn pisano_matches_brute_force() {
+        for m in 1..=500u64 {
+            let mut period = 0;
+            let (mut a, mut b) = (0, 1 % m);
+            loop {
+                (a, b) = (b, (a + b) % m);
+                period += 1;
+                if (a, b) == (0, 1 % m) {
+                    break;
+                }
+            }
+            assert_eq!(pisano_period(m), period, "m = {m}");
+        }
+        assert_eq!(pisano_period(1_000_000_007), 2_000_000_016);
+        assert_eq!(pisano_period(1_000_000_000), 1_500_000_000);
+    }
+}
@@ -0,0 +1,91 @@
+/*!
+定义：
+[最大公约数的定义](https://zh.wikipedia.org/wiki/%E6%9C%80%E5%A4%A7%E5%85%AC%E5%9B%A0%E6%95%B8)
+[辗转相除法](https://zh.wikipedia.org/wiki/%E8%BC%BE%E8%BD%89%E7%9B%B8%E9%99%A4%E6%B3%95)：gcd(a, b) = gcd(b, a mod b)，
+每两步至少让较大的数减半，O(log min(a, b))。
+扩展欧几里得算法在辗转相除的同时维护系数，求出满足 ax + by = gcd(a, b) 的一组 x、y（裴蜀等式）
+*/
+
+use super::integer::{Integer, SignedInteger};
+
+/// 最大公约数，结果非负；gcd(0, 0) = 0
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs_value(), b.abs_value());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// 最小公倍数，结果非负；任一参数为 0 时结果为 0
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    // 先除后乘，减少溢出的可能
+    (a / gcd(a, b) * b).abs_value()
+}
+
+/// 返回 (g, x, y)，满足 a·x + b·y = g = gcd(a, b)，g 非负
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    // 不变式：old_r = a·old_x + b·old_y，r = a·x + b·y
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// 一组数的最大公约数，空切片返回 0
+pub fn gcd_of_slice<T: Integer>(values: &[T]) -> T {
+    values.iter().fold(T::ZERO, |g, &v| gcd(g, v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extended_gcd, gcd, gcd_of_slice, lcm};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn works_for_all_widths() {
+        assert_eq!(gcd(48u8, 18), 6);
+        assert_eq!(gcd(-48i16, 18), 6);
+        assert_eq!(gcd(0u32, 7), 7);
+        assert_eq!(gcd(0i64, 0), 0);
+        assert_eq!(gcd(1u128 << 100, 1 << 64), 1 << 64);
+        assert_eq!(gcd(12usize, 18), 6);
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(-4i32, 6), 12);
+        assert_eq!(lcm(0i8, 5), 0);
+        assert_eq!(gcd_of_slice(&[12i32, -18, 30]), 6);
+        assert_eq!(gcd_of_slice::<u16>(&[]), 0);
+    }
+
+    #[test]
+    fn bezout_coefficients() {
+        assert_eq!(extended_gcd(240i32, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(0i64, -5), (5, 0, -1));
+        assert_eq!(extended_gcd(0i8, 0), (0, 1, 0));
+
+        let mut rng = Rng::new(1450);
+        for _ in 0..1000 {
+            let a = rng.gen_range(-1_000_000, 1_000_000);
+            let b = rng.gen_range(-1_000_000, 1_000_000);
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+            if g != 0 {
+                assert_eq!(lcm(a, b) * g, (a * b).abs());
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+/*!
+整数类型的公共接口，让数论函数可以同时用于 i8..i128、u8..u128 以及 isize、usize
+*/
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// 绝对值，无符号类型返回自身。有符号类型的最小值没有对应的正数，会溢出
+    fn abs_value(self) -> Self;
+}
+
+/// 有符号整数
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs_value(self) -> Self {
+                self
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs_value(self) -> Self {
+                self.abs()
+            }
+        }
+
+        impl SignedInteger for $t {}
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,231 @@
+/*!
+定义：
+[矩阵](https://zh.wikipedia.org/wiki/%E7%9F%A9%E9%98%B5)
+按行优先顺序存放在一个连续数组中的 f64 矩阵，第 (i, j) 个元素在 `data[i * cols + j]`。
+乘法是直接的三重循环，把最内层放在 k 上改成按行遍历，访问内存是连续的
+*/
+
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// 全为 0 的矩阵
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![0.0; rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Matrix::zeros(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = 1.0;
+        }
+        matrix
+    }
+
+    /// 从按行排列的数据构造，`data` 的长度必须是 `rows * cols`
+    pub fn new(rows: usize, cols: usize, data: Vec<f64>) -> Self {
+        assert_eq!(
+            data.len(),
+            rows * cols,
+            "Expected {rows}x{cols} = {} elements.",
+            rows * cols
+        );
+        Matrix { rows, cols, data }
+    }
+
+    /// 每一行长度必须相同
+    pub fn from_rows(rows: &[Vec<f64>]) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "All rows must have {cols} columns."
+        );
+        Matrix::new(rows.len(), cols, rows.concat())
+    }
+
+    /// 只有一列的矩阵
+    pub fn column(values: &[f64]) -> Self {
+        Matrix::new(values.len(), 1, values.to_vec())
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[f64] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut result = Matrix::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(j, i)] = self[(i, j)];
+            }
+        }
+        result
+    }
+
+    /// 矩阵乘以列向量
+    pub fn mul_vector(&self, vector: &[f64]) -> Vec<f64> {
+        assert_eq!(vector.len(), self.cols, "Dimension mismatch.");
+        (0..self.rows)
+            .map(|i| self.row(i).iter().zip(vector).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+
+    /// 每个元素乘以 `factor`
+    pub fn scale(&self, factor: f64) -> Matrix {
+        let data = self.data.iter().map(|x| x * factor).collect();
+        Matrix::new(self.rows, self.cols, data)
+    }
+
+    fn zip_with(&self, other: &Matrix, f: impl Fn(f64, f64) -> f64) -> Matrix {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "Dimension mismatch."
+        );
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(&a, &b)| f(a, b))
+            .collect();
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        assert!(
+            i < self.rows && j < self.cols,
+            "Index ({i}, {j}) out of bounds."
+        );
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        assert!(
+            i < self.rows && j < self.cols,
+            "Index ({i}, {j}) out of bounds."
+        );
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl Add for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, other: &Matrix) -> Matrix {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+impl Sub for &Matrix {
+    type Output = Matrix;
+
+    fn sub(self, other: &Matrix) -> Matrix {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+
+impl Mul for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: &Matrix) -> Matrix {
+        assert_eq!(self.cols, other.rows, "Dimension mismatch.");
+        let mut result = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                for j in 0..other.cols {
+                    result.data[i * other.cols + j] += a * other.data[k * other.cols + j];
+                }
+            }
+        }
+        result
+    }
+}
+
+impl fmt::Display for Matrix {
+    /// 每行一行，元素之间用空格分隔，格式参数作用于每个元素
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.rows {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for (j, value) in self.row(i).iter().enumerate() {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                fmt::Display::fmt(value, f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn basic_operations() {
+        let a = Matrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        assert_eq!((a.rows(), a.cols()), (2, 3));
+        assert_eq!(a[(1, 2)], 6.0);
+        assert_eq!(a.row(1), [4.0, 5.0, 6.0]);
+        let t = a.transpose();
+        assert_eq!(t, Matrix::new(3, 2, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]));
+        assert_eq!(
+            &a * &t,
+            Matrix::from_rows(&[vec![14.0, 32.0], vec![32.0, 77.0]])
+        );
+        assert_eq!(a.mul_vector(&[1.0, 0.0, -1.0]), vec![-2.0, -2.0]);
+        assert_eq!(&(&a + &a) - &a.scale(2.0), Matrix::zeros(2, 3));
+        assert_eq!(&Matrix::identity(2) * &a, a);
+        assert_eq!(Matrix::column(&[1.0, 2.0]).transpose().row(0), [1.0, 2.0]);
+        assert_eq!(format!("{:.1}", a), "1.0 2.0 3.0\n4.0 5.0 6.0");
+    }
+
+    #[test]
+    fn multiplication_is_associative() {
+        let mut rng = Rng::new(1486);
+        let mut random = |rows, cols| {
+            let data = (0..rows * cols)
+                .map(|_| rng.gen_range(-5, 5) as f64)
+                .collect();
+            Matrix::new(rows, cols, data)
+        };
+        let (a, b, c) = (random(3, 4), random(4, 5), random(5, 2));
+        assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+        assert_eq!((&a * &b).transpose(), &b.transpose() * &a.transpose());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_mismatched_dimensions() {
+        let _ = &Matrix::zeros(2, 3) * &Matrix::zeros(2, 3);
+    }
+}
@@ -0,0 +1,35 @@
+pub mod arithmetic_functions;
+pub mod big_int;
+pub mod bit_manipulation;
+pub mod combinatorics;
+pub mod factorization;
+pub mod fibonacci;
+pub mod gcd;
+pub mod integer;
+pub mod matrix;
+pub mod modular;
+pub mod primality;
+pub mod rational;
+pub mod sieve;
+pub mod statistics;
+
+pub use self::arithmetic_functions::{
+    divisors, euler_phi, euler_phi_sieve, prime_count, sum_of_divisors,
+};
+pub use self::big_int::{BigInt, ParseBigIntError};
+pub use self::combinatorics::{
+    binomial, binomial_big, catalan, catalan_big, permutations, permutations_big, stirling_first,
+    stirling_second, Combinatorics,
+};
+pub use self::factorization::factorize;
+pub use self::fibonacci::{
+    fibonacci_big, fibonacci_fast_doubling, fibonacci_mod, lucas_number, pell_number, pisano_period,
+};
+pub use self::gcd::{extended_gcd, gcd, gcd_of_slice, lcm};
+pub use self::integer::{Integer, SignedInteger};
+pub use self::matrix::Matrix;
+pub use self::modular::{mod_inverse, mod_inverse_prime, mul_mod, pow_mod, ModInt};
+pub use self::primality::{is_prime_u64, is_probable_prime, random_prime};
+pub use self::rational::Rational;
+pub use self::sieve::{primes_up_to, sieve};
+pub use self::statistics::{percentile, percentile_of_sorted, MedianOfStream, Statistics};
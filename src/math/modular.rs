@@ -0,0 +1,233 @@
+/*!
+定义：
+[模算术](https://zh.wikipedia.org/wiki/%E6%A8%A1%E7%AE%97%E6%95%B8)
+- 快速幂：把指数按二进制拆开，base^e 只需要 O(log e) 次乘法
+- 模逆元：a·x ≡ 1 (mod m) 的 x，当且仅当 gcd(a, m) = 1 时存在。
+  m 是素数时由费马小定理 x = a^(m-2)；一般情况用扩展欧几里得算法解 a·x + m·y = 1
+- [`ModInt`]：把模数放进类型参数，用普通的运算符在模意义下计算
+*/
+
+use super::gcd::extended_gcd;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// (a · b) mod m，中间结果用 u128 不会溢出
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// base^exp mod m，快速幂
+pub fn pow_mod(base: u64, mut exp: u64, m: u64) -> u64 {
+    assert!(m > 0, "Modulus must be positive.");
+    if m == 1 {
+        return 0;
+    }
+    let mut base = base % m;
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// 素数模 `p` 下的逆元（费马小定理），`a` 是 p 的倍数时返回 None
+pub fn mod_inverse_prime(a: u64, p: u64) -> Option<u64> {
+    (!a.is_multiple_of(p)).then(|| pow_mod(a, p - 2, p))
+}
+
+/// 任意模 `m` 下的逆元（扩展欧几里得），不互素时返回 None
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    assert!(m > 0, "Modulus must be positive.");
+    let (g, x, _) = extended_gcd((a % m) as i128, m as i128);
+    (g == 1).then(|| x.rem_euclid(m as i128) as u64)
+}
+
+/// 模 `M` 的剩余类，值始终在 [0, M) 内。除法要求除数与 M 互素，否则 panic
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    pub fn new(value: u64) -> Self {
+        ModInt { value: value % M }
+    }
+
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    pub fn pow(self, exp: u64) -> Self {
+        ModInt {
+            value: pow_mod(self.value, exp, M),
+        }
+    }
+
+    /// 逆元，不存在时返回 None
+    pub fn inverse(self) -> Option<Self> {
+        mod_inverse(self.value, M).map(|value| ModInt { value })
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> Self {
+        ModInt {
+            value: (value as i128).rem_euclid(M as i128) as u64,
+        }
+    }
+}
+
+impl<const M: u64> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const M: u64> fmt::Debug for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {M})", self.value)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        // 两个数都小于 M，和用 u128 不会溢出
+        ModInt {
+            value: ((self.value as u128 + other.value as u128) % M as u128) as u64,
+        }
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        ModInt {
+            value: (M - self.value) % M,
+        }
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        ModInt {
+            value: mul_mod(self.value, other.value, M),
+        }
+    }
+}
+
+impl<const M: u64> Div for ModInt<M> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        let inverse = other
+            .inverse()
+            .unwrap_or_else(|| panic!("{} has no inverse modulo {M}.", other.value));
+        self * inverse
+    }
+}
+
+macro_rules! impl_assign {
+    ($($trait:ident $method:ident $op:tt),*) => {$(
+        impl<const M: u64> $trait for ModInt<M> {
+            fn $method(&mut self, other: Self) {
+                *self = *self $op other;
+            }
+        }
+    )*};
+}
+
+impl_assign!(AddAssign add_assign +, SubAssign sub_assign -, MulAssign mul_assign *, DivAssign div_assign /);
+
+#[cfg(test)]
+mod tests {
+    use super::{mod_inverse, mod_inverse_prime, mul_mod, pow_mod, ModInt};
+    use crate::utils::random::Rng;
+
+    const P: u64 = 1_000_000_007;
+    type Mint = ModInt<P>;
+
+    #[test]
+    fn exponentiation_and_inverses() {
+        assert_eq!(mul_mod(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+        assert_eq!(pow_mod(2, 10, 1000), 24);
+        assert_eq!(pow_mod(5, 0, 1), 0);
+        // 费马小定理
+        let p = 18_446_744_073_709_551_557;
+        assert_eq!(pow_mod(123_456_789, p - 1, p), 1);
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse_prime(0, 7), None);
+
+        let mut rng = Rng::new(1454);
+        for _ in 0..500 {
+            let a = 1 + rng.next_u64() % (P - 1);
+            let inverse = mod_inverse_prime(a, P).unwrap();
+            assert_eq!(mul_mod(a, inverse, P), 1);
+            assert_eq!(mod_inverse(a, P), Some(inverse));
+            let m = 2 + rng.next_u64() % 1_000_000;
+            if let Some(x) = mod_inverse(a, m) {
+                assert_eq!(mul_mod(a, x, m), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn modint_operators() {
+        let a = Mint::new(P - 1);
+        let b = Mint::from(5u64);
+        assert_eq!((a + b).value(), 4);
+        assert_eq!((b - a).value(), 6);
+        assert_eq!(-Mint::new(0), Mint::new(0));
+        assert_eq!(Mint::from(-1i64), a);
+        assert_eq!((a * a).value(), 1);
+        assert_eq!(b / b, Mint::new(1));
+        assert_eq!(Mint::new(2).pow(P - 1).value(), 1);
+
+        let mut x = Mint::new(10);
+        x += Mint::new(5);
+        x -= Mint::new(3);
+        x *= Mint::new(4);
+        x /= Mint::new(2);
+        assert_eq!(x.value(), 24);
+        assert_eq!(format!("{x}"), "24");
+        assert_eq!(format!("{x:?}"), "24 (mod 1000000007)");
+
+        // 大模数也不会溢出
+        type Big = ModInt<18_446_744_073_709_551_557>;
+        let big = Big::new(u64::MAX);
+        assert_eq!((big + big).value(), 116);
+        assert_eq!((big / big).value(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_non_invertible_panics() {
+        let _ = ModInt::<12>::new(5) / ModInt::<12>::new(4);
+    }
+}
@@ -0,0 +1,177 @@
+/*!
+定义：
+[米勒-拉宾素性检验](https://zh.wikipedia.org/wiki/%E7%B1%B3%E5%8B%92-%E6%8B%89%E5%AE%BE%E6%A3%80%E9%AA%8C)
+把 n - 1 写成 d·2ˢ（d 为奇数）。若 n 是素数，对任意底数 a，序列 a^d, a^(2d), ..., a^(2ˢd) (mod n)
+要么第一项是 1，要么某一项是 n - 1。不满足的底数就证明了 n 是合数。
+对 64 位整数，取前 12 个素数作为底数就能确定地判断，不存在能骗过所有这些底数的合数。
+更大的整数用随机底数，每一轮至少以 3/4 的概率识破合数，k 轮之后误判的概率不超过 4^(-k)
+*/
+
+use super::big_int::BigInt;
+use super::modular::{mul_mod, pow_mod};
+use crate::utils::random::Rng;
+
+/// 对所有 u64 都足够的确定性底数
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// 确定性的 Miller–Rabin 素性检验
+pub fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    WITNESSES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// 概率性的 Miller–Rabin 素性检验，做 `rounds` 轮随机底数的测试。能放进 u64 时用确定性的版本
+pub fn is_probable_prime(n: &BigInt, rounds: usize, rng: &mut Rng) -> bool {
+    if n.is_negative() {
+        return false;
+    }
+    if n.bits() <= 64 {
+        let value = n.to_i128().unwrap() as u64;
+        return is_prime_u64(value);
+    }
+    // 先用小素数试除，排除大部分合数
+    for p in WITNESSES {
+        if (n % &BigInt::from(p)).is_zero() {
+            return false;
+        }
+    }
+    let one = BigInt::one();
+    let n_minus_one = n - &one;
+    let s = (0..).find(|&i| n_minus_one.bit(i)).unwrap();
+    let d = &n_minus_one / &BigInt::from(2u64).pow(s as u32);
+    // 底数在 [2, n - 2] 中
+    let range = n - &BigInt::from(3u64);
+    (0..rounds).all(|_| {
+        let a = &BigInt::random(n.bits() + 64, rng).rem_euclid(&range) + &BigInt::from(2u64);
+        let mut x = a.mod_pow(&d, n);
+        if x == one || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = (&x * &x).rem_euclid(n);
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// 随机生成一个恰好 `bits` 位的素数（最高位为 1），`bits` 至少为 2
+pub fn random_prime(bits: u64, rng: &mut Rng) -> BigInt {
+    assert!(bits >= 2, "A prime needs at least 2 bits.");
+    let top = BigInt::from(2u64).pow(bits as u32 - 1);
+    loop {
+        // 把最高位和最低位置为 1，得到一个 bits 位的奇数
+        let mut candidate = &BigInt::random(bits - 1, rng) + &top;
+        if !candidate.bit(0) && bits > 2 {
+            candidate += BigInt::one();
+        }
+        if is_probable_prime(&candidate, 40, rng) {
+            return candidate;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_prime_u64, is_probable_prime, random_prime};
+    use crate::math::big_int::BigInt;
+    use crate::math::sieve::sieve;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn matches_sieve() {
+        let is_prime = sieve(100_000);
+        for (n, &expected) in is_prime.iter().enumerate() {
+            assert_eq!(is_prime_u64(n as u64), expected, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn rejects_strong_pseudoprimes() {
+        // 依次是能骗过前 1、2、3、4、5、9 个素数底数的最小强伪素数
+        for n in [
+            2047u64,
+            1_373_653,
+            25_326_001,
+            3_215_031_751,
+            2_152_302_898_747,
+            3_825_123_056_546_413_051,
+        ] {
+            assert!(!is_prime_u64(n), "{n} is composite");
+        }
+        // 卡迈克尔数
+        for n in [561, 1105, 1729, 2465, 2821, 6601, 8911] {
+            assert!(!is_prime_u64(n));
+        }
+    }
+
+    #[test]
+    fn large_primes() {
+        assert!(is_prime_u64(18_446_744_073_709_551_557));
+        assert!(is_prime_u64(1_000_000_007));
+        assert!(is_prime_u64((1 << 61) - 1));
+        assert!(!is_prime_u64(u64::MAX));
+        assert!(!is_prime_u64(1_000_000_007 * 998_244_353));
+    }
+
+    #[test]
+    fn big_probable_primes() {
+        let mut rng = Rng::new(1482);
+        let two = BigInt::from(2u64);
+        let mersenne = |p: u32| &two.pow(p) - &BigInt::one();
+        // 2^p - 1 是素数当且仅当 p 属于这个列表（p <= 521）
+        let exponents = [61, 89, 107, 127, 521];
+        for p in 60..=130 {
+            assert_eq!(
+                is_probable_prime(&mersenne(p), 20, &mut rng),
+                exponents.contains(&p),
+                "2^{p} - 1"
+            );
+        }
+        assert!(is_probable_prime(&mersenne(521), 10, &mut rng));
+        // 两个大素数的乘积
+        let product = &mersenne(89) * &mersenne(107);
+        assert!(!is_probable_prime(&product, 20, &mut rng));
+        assert!(is_probable_prime(
+            &BigInt::from(1_000_000_007u64),
+            1,
+            &mut rng
+        ));
+        assert!(!is_probable_prime(&BigInt::from(-7i64), 1, &mut rng));
+    }
+
+    #[test]
+    fn random_primes_have_requested_size() {
+        let mut rng = Rng::new(1482);
+        for bits in [2, 3, 8, 64, 65, 128] {
+            let p = random_prime(bits, &mut rng);
+            assert_eq!(p.bits(), bits);
+            assert!(is_probable_prime(&p, 20, &mut rng));
+        }
+        let small = random_prime(20, &mut rng).to_i128().unwrap() as u64;
+        assert!(is_prime_u64(small));
+    }
+}
@@ -0,0 +1,397 @@
+/*!
+定义：
+[有理数](https://zh.wikipedia.org/wiki/%E6%9C%89%E7%90%86%E6%95%B0)
+表示成既约分数 p/q：q > 0，gcd(|p|, q) = 1，零表示成 0/1。每次运算后都约分，所以相等的有理数表示唯一。
+运算的中间结果用 i128 计算，约分后放不进 i64 时 `checked_*` 返回 None，运算符 panic。
+
+浮点数转有理数用[连分数](https://zh.wikipedia.org/wiki/%E8%BF%9E%E5%88%86%E6%95%B0)：
+在分母不超过上限的分数中，离 x 最近的一定是某个渐近分数或者中间分数
+*/
+
+use super::gcd::gcd;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+    pub const ONE: Rational = Rational {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// numerator / denominator，分母为 0 或约分后溢出时 panic
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        assert!(denominator != 0, "Denominator must be non-zero.");
+        Self::checked_new(numerator, denominator).expect("Rational overflow.")
+    }
+
+    /// 分母为 0 或约分后溢出时返回 None
+    pub fn checked_new(numerator: i64, denominator: i64) -> Option<Self> {
+        Self::reduce(numerator as i128, denominator as i128)
+    }
+
+    pub fn numerator(self) -> i64 {
+        self.numerator
+    }
+
+    pub fn denominator(self) -> i64 {
+        self.denominator
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn signum(self) -> i64 {
+        self.numerator.signum()
+    }
+
+    pub fn abs(self) -> Self {
+        if self.numerator < 0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// 倒数，自身为 0 时返回 None
+    pub fn checked_recip(self) -> Option<Self> {
+        Self::reduce(self.denominator as i128, self.numerator as i128)
+    }
+
+    pub fn recip(self) -> Self {
+        assert!(!self.is_zero(), "Zero has no reciprocal.");
+        self.checked_recip().expect("Rational overflow.")
+    }
+
+    /// 不大于自身的最大整数
+    pub fn floor(self) -> i64 {
+        self.numerator.div_euclid(self.denominator)
+    }
+
+    /// 不小于自身的最小整数
+    pub fn ceil(self) -> i64 {
+        -(-self).floor()
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), other.wide());
+        Self::reduce(a.0 * b.1 + b.0 * a.1, a.1 * b.1)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), other.wide());
+        Self::reduce(a.0 * b.1 - b.0 * a.1, a.1 * b.1)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), other.wide());
+        Self::reduce(a.0 * b.0, a.1 * b.1)
+    }
+
+    /// 除数为 0 或溢出时返回 None
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.wide(), other.wide());
+        Self::reduce(a.0 * b.1, a.1 * b.0)
+    }
+
+    /// 整数次幂，负指数取倒数，平方求幂；0 的负数次幂或溢出时返回 None
+    pub fn checked_pow(self, exp: i32) -> Option<Self> {
+        let mut base = if exp < 0 { self.checked_recip()? } else { self };
+        let mut exp = exp.unsigned_abs();
+        let mut result = Rational::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            // 还有更高的位时结果至少是 base 平方后的大小，平方溢出说明结果也会溢出
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// 分母不超过 `max_denominator` 的分数中最接近 x 的一个，x 不是有限数或超出 i64 时返回 None
+    pub fn from_f64(x: f64, max_denominator: i64) -> Option<Self> {
+        assert!(max_denominator > 0, "Maximum denominator must be positive.");
+        if !x.is_finite() || x.abs() >= i64::MAX as f64 {
+            return None;
+        }
+        // (h, k) 是渐近分数 h/k，previous 是上一个
+        let (mut previous, mut current) = ((0i128, 1i128), (1i128, 0i128));
+        let mut rest = x;
+        loop {
+            let a = rest.floor();
+            let next = (
+                a as i128 * current.0 + previous.0,
+                a as i128 * current.1 + previous.1,
+            );
+            if next.1 > max_denominator as i128 {
+                // 分母超限：比较最后一个渐近分数和分母最大的中间分数
+                let m = (max_denominator as i128 - previous.1) / current.1;
+                let middle = (m * current.0 + previous.0, m * current.1 + previous.1);
+                let distance = |(h, k): (i128, i128)| (h as f64 / k as f64 - x).abs();
+                let best = if m > 0 && distance(middle) < distance(current) {
+                    middle
+                } else {
+                    current
+                };
+                return Self::reduce(best.0, best.1);
+            }
+            (previous, current) = (current, next);
+            let fraction = rest - a;
+            // 已经精确表示，或者剩余部分小到倒数会超出范围
+            if fraction < 1.0 / max_denominator as f64 / 4.0 || fraction == 0.0 {
+                return Self::reduce(current.0, current.1);
+            }
+            rest = 1.0 / fraction;
+        }
+    }
+
+    /// 保留 `places` 位小数（截断）的十进制表示
+    pub fn to_decimal_string(self, places: usize) -> String {
+        let sign = if self.numerator < 0 { "-" } else { "" };
+        let (numerator, denominator) = (self.numerator.unsigned_abs(), self.denominator as u64);
+        let mut text = format!("{sign}{}", numerator / denominator);
+        if places > 0 {
+            text.push('.');
+            let mut remainder = numerator % denominator;
+            // 竖式除法，每次得到一位小数
+            for _ in 0..places {
+                let digit = remainder as u128 * 10 / denominator as u128;
+                remainder = (remainder as u128 * 10 % denominator as u128) as u64;
+                text.push(char::from(b'0' + digit as u8));
+            }
+        }
+        text
+    }
+
+    fn wide(self) -> (i128, i128) {
+        (self.numerator as i128, self.denominator as i128)
+    }
+
+    /// 约分并把符号放到分子上
+    fn reduce(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let g = gcd(numerator.abs(), denominator.abs());
+        let sign = denominator.signum();
+        Some(Rational {
+            numerator: i64::try_from(numerator / g * sign).ok()?,
+            denominator: i64::try_from(denominator / g * sign).ok()?,
+        })
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // 分母都是正数，交叉相乘不改变大小关系
+        let (a, b) = (self.wide(), other.wide());
+        (a.0 * b.1).cmp(&(b.0 * a.1))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::reduce(-(self.numerator as i128), self.denominator as i128)
+            .expect("Rational overflow.")
+    }
+}
+
+macro_rules! impl_operator {
+    ($($trait:ident $method:ident $checked:ident $assign:ident $assign_method:ident),*) => {$(
+        impl $trait for Rational {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                self.$checked(other).expect("Rational overflow or division by zero.")
+            }
+        }
+
+        impl $assign for Rational {
+            fn $assign_method(&mut self, other: Self) {
+                *self = $trait::$method(*self, other);
+            }
+        }
+    )*};
+}
+
+impl_operator!(
+    Add add checked_add AddAssign add_assign,
+    Sub sub checked_sub SubAssign sub_assign,
+    Mul mul checked_mul MulAssign mul_assign,
+    Div div checked_div DivAssign div_assign
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Rational;
+    use crate::utils::random::Rng;
+
+    fn r(numerator: i64, denominator: i64) -> Rational {
+        Rational::new(numerator, denominator)
+    }
+
+    #[test]
+    fn normalizes_on_construction() {
+        assert_eq!(r(6, -8), r(-3, 4));
+        assert_eq!((r(-6, -8).numerator(), r(-6, -8).denominator()), (3, 4));
+        assert_eq!(r(0, -5), Rational::ZERO);
+        assert_eq!(Rational::checked_new(1, 0), None);
+        assert_eq!(Rational::checked_new(i64::MIN, -1), None);
+        assert_eq!(r(i64::MIN, -2), r(1 << 62, 1));
+        assert_eq!(r(7, 1).to_string(), "7");
+        assert_eq!(r(-7, 3).to_string(), "-7/3");
+        assert_eq!(format!("{:?}", Rational::from(2)), "2/1");
+    }
+
+    #[test]
+    fn arithmetic_and_ordering() {
+        let mut x = r(1, 2) + r(1, 3);
+        assert_eq!(x, r(5, 6));
+        x -= r(1, 6);
+        assert_eq!(x, r(2, 3));
+        x *= r(9, 4);
+        assert_eq!(x, r(3, 2));
+        x /= r(-3, 5);
+        assert_eq!(x, r(-5, 2));
+        assert_eq!((x.floor(), x.ceil()), (-3, -2));
+        assert_eq!(x.abs().recip(), r(2, 5));
+        assert_eq!(x.checked_pow(-2), Some(r(4, 25)));
+        assert_eq!(Rational::ZERO.checked_pow(-1), None);
+        assert_eq!(r(1, 2).checked_div(Rational::ZERO), None);
+        assert!(r(1, 3) < r(1, 2) && r(-1, 2) < r(-1, 3));
+        assert!(r(i64::MAX, 2) > r(i64::MAX - 1, 2));
+
+        // 随机运算结果和 f64 一致，且 (a + b) - b == a
+        let mut rng = Rng::new(1463);
+        for _ in 0..500 {
+            let a = r(rng.gen_range(-1000, 1000), rng.gen_range(1, 1000));
+            let b = r(rng.gen_range(-1000, 1000), rng.gen_range(1, 1000));
+            assert_eq!(a + b - b, a);
+            assert!(((a * b).to_f64() - a.to_f64() * b.to_f64()).abs() < 1e-9);
+            assert_eq!(a < b, a.to_f64() < b.to_f64());
+            if !b.is_zero() {
+                assert_eq!(a / b * b, a);
+            }
+        }
+    }
+
+    #[test]
+    fn overflow_is_detected() {
+        let big = r(i64::MAX, 1);
+        assert_eq!(big.checked_add(Rational::ONE), None);
+        assert_eq!(big.checked_mul(r(2, 1)), None);
+        // 中间结果溢出 i64 但约分后放得下
+        assert_eq!(big.checked_mul(r(1, i64::MAX)), Some(Rational::ONE));
+        assert_eq!(r(1, i64::MAX).checked_add(r(1, i64::MAX - 1)), None);
+        assert_eq!(r(2, 1).checked_pow(62), Some(r(1 << 62, 1)));
+        assert_eq!(r(2, 1).checked_pow(63), None);
+        // 平方求幂，指数很大时也不会循环 2³¹ 次
+        assert_eq!(Rational::ONE.checked_pow(i32::MAX), Some(Rational::ONE));
+        assert_eq!(r(-1, 1).checked_pow(i32::MIN), Some(Rational::ONE));
+        assert_eq!(r(-1, 1).checked_pow(i32::MAX), Some(r(-1, 1)));
+        assert_eq!(r(3, 2).checked_pow(i32::MAX), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn operator_panics_on_overflow() {
+        let _ = r(i64::MAX, 1) + Rational::ONE;
+    }
+
+    #[test]
+    fn float_conversions() {
+        assert_eq!(Rational::from_f64(0.75, 100), Some(r(3, 4)));
+        assert_eq!(Rational::from_f64(-2.5, 10), Some(r(-5, 2)));
+        assert_eq!(Rational::from_f64(0.0, 10), Some(Rational::ZERO));
+        // π 的经典近似
+        let pi = std::f64::consts::PI;
+        assert_eq!(Rational::from_f64(pi, 10), Some(r(22, 7)));
+        assert_eq!(Rational::from_f64(pi, 200), Some(r(355, 113)));
+        assert_eq!(Rational::from_f64(pi, 100), Some(r(311, 99)));
+        assert_eq!(Rational::from_f64(f64::NAN, 10), None);
+        assert_eq!(Rational::from_f64(1e300, 10), None);
+
+        // 和暴力枚举所有分母的最优结果一样近
+        let mut rng = Rng::new(1463);
+        for _ in 0..200 {
+            let x = rng.next_f64() * 20.0 - 10.0;
+            let max_denominator = rng.gen_range(1, 300);
+            let found = Rational::from_f64(x, max_denominator).unwrap();
+            assert!(found.denominator() <= max_denominator);
+            let best = (1..=max_denominator)
+                .map(|d| ((x * d as f64).round() / d as f64 - x).abs())
+                .fold(f64::INFINITY, f64::min);
+            assert!(
+                (found.to_f64() - x).abs() <= best + 1e-12,
+                "{x} {max_denominator}"
+            );
+        }
+
+        assert_eq!(r(1, 3).to_decimal_string(5), "0.33333");
+        assert_eq!(r(-22, 7).to_decimal_string(3), "-3.142");
+        assert_eq!(r(-1, 8).to_decimal_string(2), "-0.12");
+        assert_eq!(r(5, 1).to_decimal_string(0), "5");
+    }
+}
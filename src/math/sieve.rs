@@ -0,0 +1,48 @@
+/*!
+定义：
+[埃拉托斯特尼筛法](https://zh.wikipedia.org/wiki/%E5%9F%83%E6%8B%89%E6%89%98%E6%96%AF%E7%89%B9%E5%B0%BC%E7%AD%9B%E6%B3%95)
+从小到大枚举素数 p，把 p² 开始的所有 p 的倍数标记为合数（更小的倍数已经被更小的素因子标记过），
+O(n log log n)
+*/
+
+/// `is_prime[i]` 表示 i 是否是素数，i 的范围是 0..=limit
+pub fn sieve(limit: usize) -> Vec<bool> {
+    let mut is_prime = vec![true; limit + 1];
+    is_prime[0] = false;
+    if limit >= 1 {
+        is_prime[1] = false;
+    }
+    let mut p = 2;
+    while p * p <= limit {
+        if is_prime[p] {
+            for multiple in (p * p..=limit).step_by(p) {
+                is_prime[multiple] = false;
+            }
+        }
+        p += 1;
+    }
+    is_prime
+}
+
+/// 不超过 `limit` 的所有素数，从小到大
+pub fn primes_up_to(limit: usize) -> Vec<usize> {
+    sieve(limit)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(n, is_prime)| is_prime.then_some(n))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{primes_up_to, sieve};
+
+    #[test]
+    fn small_primes() {
+        assert_eq!(primes_up_to(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes_up_to(1), Vec::<usize>::new());
+        assert_eq!(sieve(0), vec![false]);
+        // π(10⁶) = 78498
+        assert_eq!(primes_up_to(1_000_000).len(), 78498);
+    }
+}
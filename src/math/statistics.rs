@@ -0,0 +1,298 @@
+/*!
+定义：
+[描述统计](https://zh.wikipedia.org/wiki/%E6%8F%8F%E8%BF%B0%E7%BB%9F%E8%AE%A1%E5%AD%A6)
+- [Welford 在线算法](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm)：
+  每来一个数就更新均值和离差平方和 M₂，避免先求 Σx² 再减去 n·mean² 时的大数相减误差
+- 数据流的中位数：大顶堆存较小的一半，小顶堆存较大的一半，两个堆的大小最多差 1，
+  中位数就在堆顶，插入 O(log n)、查询 O(1)
+- [百分位数](https://zh.wikipedia.org/wiki/%E7%99%BE%E5%88%86%E4%BD%8D%E6%95%B0)：
+  排序后按位置 (n-1)·p 在相邻两个样本之间线性插值
+*/
+
+use crate::data_structures::heap::Heap;
+
+/// 一遍扫描的统计量，不保存样本
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Statistics {
+    count: u64,
+    mean: f64,
+    /// 与均值之差的平方和
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Statistics {
+    pub fn new() -> Self {
+        Statistics {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        // 用更新前后的两个差相乘
+        self.m2 += delta * (x - self.mean);
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+    }
+
+    /// 合并另一组数据的统计量（Chan 的并行合并公式），结果和依次 push 所有数据相同
+    pub fn merge(&mut self, other: &Statistics) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 +=
+            other.m2 + delta * delta * (self.count as f64 * other.count as f64) / count as f64;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// 总体方差 M₂ / n
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// 样本方差 M₂ / (n - 1)，至少需要两个数
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// 总体标准差
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Statistics::new()
+    }
+}
+
+impl Extend<f64> for Statistics {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl FromIterator<f64> for Statistics {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut statistics = Statistics::new();
+        statistics.extend(iter);
+        statistics
+    }
+}
+
+/// 数据流的中位数
+pub struct MedianOfStream {
+    /// 较小的一半，大顶堆；元素个数为奇数时多存一个
+    lower: Heap<f64>,
+    /// 较大的一半，小顶堆
+    upper: Heap<f64>,
+}
+
+impl MedianOfStream {
+    pub fn new() -> Self {
+        MedianOfStream {
+            lower: Heap::new(|a, b| a > b),
+            upper: Heap::new(|a, b| a < b),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+
+    /// 加入一个数，NaN 会 panic
+    pub fn push(&mut self, x: f64) {
+        assert!(!x.is_nan(), "Cannot take the median of NaN.");
+        match self.lower.peek() {
+            Some(&top) if x > top => self.upper.push(x),
+            _ => self.lower.push(x),
+        }
+        // 重新平衡，保持 lower.len() == upper.len() 或 upper.len() + 1
+        if self.lower.len() > self.upper.len() + 1 {
+            let moved = self.lower.pop().unwrap();
+            self.upper.push(moved);
+        } else if self.upper.len() > self.lower.len() {
+            let moved = self.upper.pop().unwrap();
+            self.lower.push(moved);
+        }
+    }
+
+    /// 个数为偶数时取中间两个数的平均值
+    pub fn median(&self) -> Option<f64> {
+        let &low = self.lower.peek()?;
+        if self.lower.len() > self.upper.len() {
+            Some(low)
+        } else {
+            self.upper.peek().map(|&high| low + (high - low) / 2.0)
+        }
+    }
+}
+
+impl Default for MedianOfStream {
+    fn default() -> Self {
+        MedianOfStream::new()
+    }
+}
+
+/// 第 `p` 百分位数（p ∈ [0, 100]），样本为空时返回 None。会对样本的副本排序
+pub fn percentile(samples: &[f64], p: f64) -> Option<f64> {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    percentile_of_sorted(&sorted, p)
+}
+
+/// 对已经排好序的样本求第 `p` 百分位数，需要求多个百分位数时只排序一次
+pub fn percentile_of_sorted(sorted: &[f64], p: f64) -> Option<f64> {
+    assert!(
+        (0.0..=100.0).contains(&p),
+        "Percentile {p} out of range 0..=100."
+    );
+    if sorted.is_empty() {
+        return None;
+    }
+    let position = (sorted.len() - 1) as f64 * p / 100.0;
+    let (index, fraction) = (position.floor() as usize, position.fract());
+    let low = sorted[index];
+    Some(match sorted.get(index + 1) {
+        Some(&high) if fraction > 0.0 => low + (high - low) * fraction,
+        _ => low,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{percentile, percentile_of_sorted, MedianOfStream, Statistics};
+    use crate::utils::random::Rng;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn welford_matches_two_pass() {
+        let empty = Statistics::new();
+        assert_eq!(
+            (empty.mean(), empty.variance(), empty.min()),
+            (None, None, None)
+        );
+
+        let statistics: Statistics = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .into_iter()
+            .collect();
+        assert_eq!(statistics.count(), 8);
+        assert_eq!(statistics.mean(), Some(5.0));
+        assert_eq!(statistics.variance(), Some(4.0));
+        assert_eq!(statistics.std_dev(), Some(2.0));
+        assert!(close(statistics.sample_variance().unwrap(), 32.0 / 7.0));
+        assert_eq!((statistics.min(), statistics.max()), (Some(2.0), Some(9.0)));
+
+        let mut rng = Rng::new(1467);
+        let samples: Vec<f64> = (0..1000).map(|_| rng.next_f64() * 100.0 - 50.0).collect();
+        let statistics: Statistics = samples.iter().copied().collect();
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let variance =
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+        assert!(close(statistics.mean().unwrap(), mean));
+        assert!(close(statistics.variance().unwrap(), variance));
+
+        // 分成两段分别统计再合并，和整体统计一致
+        let (left, right) = samples.split_at(377);
+        let mut merged: Statistics = left.iter().copied().collect();
+        merged.merge(&right.iter().copied().collect());
+        merged.merge(&Statistics::new());
+        assert_eq!(merged.count(), 1000);
+        assert!(close(merged.mean().unwrap(), mean));
+        assert!(close(merged.variance().unwrap(), variance));
+        assert_eq!(merged.max(), statistics.max());
+    }
+
+    #[test]
+    fn numerically_stable_with_large_offset() {
+        // 朴素的 Σx²/n - mean² 在这里会完全丢失精度
+        let statistics: Statistics = [4.0, 7.0, 13.0, 16.0].iter().map(|x| x + 1e9).collect();
+        assert!(close(statistics.sample_variance().unwrap(), 30.0));
+    }
+
+    #[test]
+    fn streaming_median() {
+        let mut stream = MedianOfStream::new();
+        assert_eq!(stream.median(), None);
+        let mut rng = Rng::new(1467);
+        let mut seen = vec![];
+        for _ in 0..300 {
+            let x = rng.gen_range(-50, 50) as f64;
+            stream.push(x);
+            seen.push(x);
+            seen.sort_by(f64::total_cmp);
+            let n = seen.len();
+            let expected = if n % 2 == 1 {
+                seen[n / 2]
+            } else {
+                (seen[n / 2 - 1] + seen[n / 2]) / 2.0
+            };
+            assert_eq!(stream.median(), Some(expected));
+            assert_eq!(stream.len(), n);
+        }
+    }
+
+    #[test]
+    fn percentiles() {
+        let samples = [15.0, 20.0, 35.0, 40.0, 50.0];
+        assert_eq!(percentile(&samples, 0.0), Some(15.0));
+        assert_eq!(percentile(&samples, 50.0), Some(35.0));
+        assert_eq!(percentile(&samples, 100.0), Some(50.0));
+        assert_eq!(percentile(&samples, 40.0), Some(29.0));
+        assert_eq!(percentile(&[3.0, 1.0, 2.0, 4.0], 50.0), Some(2.5));
+        assert_eq!(percentile(&[], 50.0), None);
+        assert_eq!(percentile_of_sorted(&[7.0], 90.0), Some(7.0));
+
+        // 和流式中位数一致
+        let mut rng = Rng::new(1467);
+        let samples: Vec<f64> = (0..101).map(|_| rng.next_f64()).collect();
+        let mut stream = MedianOfStream::new();
+        samples.iter().for_each(|&x| stream.push(x));
+        assert_eq!(percentile(&samples, 50.0), stream.median());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_nan() {
+        MedianOfStream::new().push(f64::NAN);
+    }
+}
@@ -0,0 +1,97 @@
+/*!
+用[递归回溯](https://en.wikipedia.org/wiki/Maze_generation_algorithm#Randomized_depth-first_search)生成迷宫：
+从一个房间出发做随机的深度优先搜索，每走到一个没去过的相邻房间就打通中间的墙，无路可走时回溯。
+生成的迷宫是一棵生成树，任意两个房间之间恰好有一条路径
+*/
+
+use super::grid::Grid;
+use crate::utils::random::Rng;
+
+/// 生成 `columns × rows` 个房间的迷宫。房间位于网格的奇数行奇数列，
+/// 网格大小为 `(2 * rows + 1) × (2 * columns + 1)`，四周都是墙
+pub fn generate_maze(columns: usize, rows: usize, rng: &mut Rng) -> Grid {
+    let (width, height) = (2 * columns + 1, 2 * rows + 1);
+    let mut grid = Grid::new(width, height);
+    for row in 0..height {
+        for column in 0..width {
+            grid.set_wall((row, column));
+        }
+    }
+    if columns == 0 || rows == 0 {
+        return grid;
+    }
+
+    let room = |(r, c): (usize, usize)| (2 * r + 1, 2 * c + 1);
+    let mut visited = vec![vec![false; columns]; rows];
+    // 显式栈，大迷宫也不会栈溢出
+    let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+    visited[0][0] = true;
+    grid.set_cost(room((0, 0)), 1);
+    while let Some(&(r, c)) = stack.last() {
+        let mut unvisited: Vec<(usize, usize)> = [
+            r.checked_sub(1).map(|r| (r, c)),
+            (r + 1 < rows).then_some((r + 1, c)),
+            c.checked_sub(1).map(|c| (r, c)),
+            (c + 1 < columns).then_some((r, c + 1)),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|&(nr, nc)| !visited[nr][nc])
+        .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        rng.shuffle(&mut unvisited);
+        let (nr, nc) = unvisited[0];
+        visited[nr][nc] = true;
+        // 打通两个房间之间的墙
+        grid.set_cost((r + nr + 1, c + nc + 1), 1);
+        grid.set_cost(room((nr, nc)), 1);
+        stack.push((nr, nc));
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate_maze;
+    use crate::graph::{bfs, Graph};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn maze_is_a_spanning_tree_of_rooms() {
+        let mut rng = Rng::new(1420);
+        let (columns, rows) = (12, 7);
+        let grid = generate_maze(columns, rows, &mut rng);
+        assert_eq!((grid.width(), grid.height()), (25, 15));
+
+        let open: Vec<(usize, usize)> = (0..grid.height())
+            .flat_map(|r| (0..grid.width()).map(move |c| (r, c)))
+            .filter(|&p| grid.is_open(p))
+            .collect();
+        // 房间加上打通的墙：n 个房间之间有 n - 1 个通道
+        assert_eq!(open.len(), 2 * columns * rows - 1);
+        // 四周都是墙
+        assert!(open
+            .iter()
+            .all(|&(r, c)| r % (grid.height() - 1) != 0 && c % (grid.width() - 1) != 0));
+
+        // 所有空地连通，并且无向图没有环：边数 = 顶点数 - 1
+        let graph: Graph = grid.to_graph();
+        let reached = bfs(&graph, grid.node((1, 1))).order.len();
+        assert_eq!(reached, open.len());
+        assert_eq!(graph.edge_count() / 2, open.len() - 1);
+    }
+
+    #[test]
+    fn same_seed_same_maze() {
+        let a = generate_maze(10, 10, &mut Rng::new(3));
+        let b = generate_maze(10, 10, &mut Rng::new(3));
+        assert_eq!(a, b);
+        assert_eq!(
+            generate_maze(0, 5, &mut Rng::new(3)).render(&[]),
+            "#\n".repeat(11)
+        );
+    }
+}
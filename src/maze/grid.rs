@@ -0,0 +1,224 @@
+/*!
+二维网格迷宫。每个格子要么是墙，要么是可以通行的空地，空地带有进入它的代价（地形），
+只能向上下左右四个方向移动。坐标使用 (行, 列)
+*/
+
+use crate::graph::Graph;
+use std::fmt;
+
+pub type Position = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    /// 按行存储，None 表示墙，Some(cost) 表示进入这个格子的代价
+    cells: Vec<Option<u32>>,
+}
+
+/// 解析迷宫文本失败
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// 各行长度不一致
+    RaggedRow { row: usize },
+    /// 无法识别的字符
+    UnknownCell {
+        row: usize,
+        column: usize,
+        cell: char,
+    },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::RaggedRow { row } => write!(f, "row {row} has a different width"),
+            ParseGridError::UnknownCell { row, column, cell } => {
+                write!(f, "unknown cell {cell:?} at ({row}, {column})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+impl Grid {
+    /// 所有格子都是代价为 1 的空地
+    pub fn new(width: usize, height: usize) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![Some(1); width * height],
+        }
+    }
+
+    /// 从文本解析：`#` 是墙，`.` 是代价为 1 的空地，数字 `1`-`9` 是对应代价的空地
+    pub fn parse(text: &str) -> Result<Self, ParseGridError> {
+        let rows: Vec<&str> = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+        let width = rows.first().map_or(0, |row| row.trim().chars().count());
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for (row, line) in rows.iter().enumerate() {
+            let line = line.trim();
+            if line.chars().count() != width {
+                return Err(ParseGridError::RaggedRow { row });
+            }
+            for (column, cell) in line.chars().enumerate() {
+                cells.push(match cell {
+                    '#' => None,
+                    '.' => Some(1),
+                    '1'..='9' => cell.to_digit(10),
+                    _ => return Err(ParseGridError::UnknownCell { row, column, cell }),
+                });
+            }
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// 进入格子的代价，墙返回 None
+    pub fn cost(&self, (row, column): Position) -> Option<u32> {
+        self.cells[row * self.width + column]
+    }
+
+    pub fn is_open(&self, position: Position) -> bool {
+        self.cost(position).is_some()
+    }
+
+    pub fn set_wall(&mut self, (row, column): Position) {
+        self.cells[row * self.width + column] = None;
+    }
+
+    /// 把格子设为代价为 `cost` 的空地
+    pub fn set_cost(&mut self, (row, column): Position, cost: u32) {
+        self.cells[row * self.width + column] = Some(cost);
+    }
+
+    /// 上下左右可以进入的格子
+    pub fn neighbors(&self, (row, column): Position) -> impl Iterator<Item = Position> + '_ {
+        let candidates = [
+            row.checked_sub(1).map(|r| (r, column)),
+            (row + 1 < self.height).then_some((row + 1, column)),
+            column.checked_sub(1).map(|c| (row, c)),
+            (column + 1 < self.width).then_some((row, column + 1)),
+        ];
+        candidates
+            .into_iter()
+            .flatten()
+            .filter(|&position| self.is_open(position))
+    }
+
+    /// 格子在图中的顶点编号
+    pub fn node(&self, (row, column): Position) -> usize {
+        row * self.width + column
+    }
+
+    pub fn position(&self, node: usize) -> Position {
+        (node / self.width, node % self.width)
+    }
+
+    /// 转换成有向图：每个格子一个顶点，走进一个格子的边权是它的代价
+    pub fn to_graph(&self) -> Graph {
+        let mut graph = Graph::new_directed(self.cells.len());
+        for row in 0..self.height {
+            for column in 0..self.width {
+                if !self.is_open((row, column)) {
+                    continue;
+                }
+                for next in self.neighbors((row, column)) {
+                    let weight = self.cost(next).unwrap() as i64;
+                    graph.add_weighted_edge(self.node((row, column)), self.node(next), weight);
+                }
+            }
+        }
+        graph
+    }
+
+    /// 画出迷宫，路径上的格子用 `*` 表示
+    pub fn render(&self, path: &[Position]) -> String {
+        let mut canvas: Vec<Vec<char>> = (0..self.height)
+            .map(|row| {
+                (0..self.width)
+                    .map(|column| match self.cost((row, column)) {
+                        None => '#',
+                        Some(1) => '.',
+                        Some(cost) => char::from_digit(cost.min(9), 10).unwrap(),
+                    })
+                    .collect()
+            })
+            .collect();
+        for &(row, column) in path {
+            canvas[row][column] = '*';
+        }
+        canvas
+            .into_iter()
+            .map(|row| row.into_iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, ParseGridError};
+
+    #[test]
+    fn parses_and_renders() {
+        let text = "#.#\n.5.\n...\n";
+        let grid = Grid::parse(text).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.cost((1, 1)), Some(5));
+        assert!(!grid.is_open((0, 0)));
+        assert_eq!(grid.render(&[]), text);
+        assert_eq!(grid.render(&[(2, 0), (2, 1)]), "#.#\n.5.\n**.\n");
+        let mut neighbors: Vec<_> = grid.neighbors((1, 1)).collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+        assert_eq!(grid.neighbors((0, 1)).collect::<Vec<_>>(), vec![(1, 1)]);
+    }
+
+    #[test]
+    fn rejects_malformed_text() {
+        assert_eq!(
+            Grid::parse("..\n...\n"),
+            Err(ParseGridError::RaggedRow { row: 1 })
+        );
+        assert_eq!(
+            Grid::parse("..\n.x\n"),
+            Err(ParseGridError::UnknownCell {
+                row: 1,
+                column: 1,
+                cell: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn converts_to_graph() {
+        let grid = Grid::parse(".3\n#.\n").unwrap();
+        let graph = grid.to_graph();
+        assert_eq!(graph.node_count(), 4);
+        // (0,0) <-> (0,1)、(0,1) <-> (1,1)，每对两个方向
+        assert_eq!(graph.edge_count(), 4);
+        let into_costly: Vec<i64> = graph
+            .edges()
+            .iter()
+            .filter(|e| e.to == grid.node((0, 1)))
+            .map(|e| e.weight)
+            .collect();
+        assert_eq!(into_costly, vec![3, 3]);
+        assert_eq!(grid.position(3), (1, 1));
+    }
+}
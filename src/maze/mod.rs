@@ -0,0 +1,9 @@
+pub mod generator;
+pub mod grid;
+pub mod solver;
+
+pub use self::generator::generate_maze;
+pub use self::grid::{Grid, ParseGridError, Position};
+pub use self::solver::{
+    solve_astar, solve_astar_observed, solve_bfs, solve_dijkstra, solve_dijkstra_observed, GridPath,
+};
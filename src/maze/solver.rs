@@ -0,0 +1,223 @@
+/*!
+在网格上求起点到终点的最短路径：
+- BFS：忽略地形代价，求步数最少的路径
+- Dijkstra：把网格转成图后调用 [`crate::graph::dijkstra`]，求总代价最小的路径
+- [A*](https://zh.wikipedia.org/wiki/A*%E6%90%9C%E5%B0%8B%E6%BC%94%E7%AE%97%E6%B3%95)：
+  在 Dijkstra 的基础上按 `已走代价 + 估计剩余代价` 选点。曼哈顿距离乘以最小格子代价不会高估剩余代价，
+  所以结果依然最优，但通常只需要展开朝向终点的一小部分格子
+*/
+
+use super::grid::{Grid, Position};
+use crate::data_structures::indexed_priority_queue::IndexedPriorityQueue;
+use crate::graph::{bfs, dijkstra_observed};
+use crate::visualization::Observer;
+
+/// 一条路径和它的总代价（不含起点的代价）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridPath {
+    pub cost: u64,
+    /// 从起点到终点依次经过的格子
+    pub cells: Vec<Position>,
+}
+
+/// 步数最少的路径，代价按实际地形计算；起点或终点是墙、或者不可达时返回 None
+pub fn solve_bfs(grid: &Grid, start: Position, goal: Position) -> Option<GridPath> {
+    if !grid.is_open(start) || !grid.is_open(goal) {
+        return None;
+    }
+    let nodes = bfs(&grid.to_graph(), grid.node(start)).path_to(grid.node(goal))?;
+    Some(to_grid_path(grid, nodes))
+}
+
+/// 总代价最小的路径
+pub fn solve_dijkstra(grid: &Grid, start: Position, goal: Position) -> Option<GridPath> {
+    solve_dijkstra_observed(grid, start, goal, &mut ())
+}
+
+/// 同 [`solve_dijkstra`]，把搜索过程报告给 `observer`，顶点是 [`Grid::node`] 给出的格子编号
+pub fn solve_dijkstra_observed<O>(
+    grid: &Grid,
+    start: Position,
+    goal: Position,
+    observer: &mut O,
+) -> Option<GridPath>
+where
+    O: Observer + ?Sized,
+{
+    if !grid.is_open(start) || !grid.is_open(goal) {
+        return None;
+    }
+    let paths = dijkstra_observed(&grid.to_graph(), grid.node(start), observer)
+        .expect("terrain costs are small and non-negative");
+    let nodes = paths.shortest_path_to(grid.node(goal))?;
+    Some(to_grid_path(grid, nodes))
+}
+
+/// 总代价最小的路径，用曼哈顿距离作为启发函数
+pub fn solve_astar(grid: &Grid, start: Position, goal: Position) -> Option<GridPath> {
+    solve_astar_observed(grid, start, goal, &mut ())
+}
+
+/// 同 [`solve_astar`]，每展开一个格子调用一次 [`Observer::on_visit_node`]，
+/// 每次更新代价调用一次 [`Observer::on_relax_edge`]
+pub fn solve_astar_observed<O>(
+    grid: &Grid,
+    start: Position,
+    goal: Position,
+    observer: &mut O,
+) -> Option<GridPath>
+where
+    O: Observer + ?Sized,
+{
+    if !grid.is_open(start) || !grid.is_open(goal) {
+        return None;
+    }
+    let min_cost = (0..grid.height())
+        .flat_map(|row| (0..grid.width()).map(move |column| (row, column)))
+        .filter_map(|position| grid.cost(position))
+        .min()
+        .unwrap_or(1) as u64;
+    let heuristic = |(row, column): Position| {
+        (row.abs_diff(goal.0) + column.abs_diff(goal.1)) as u64 * min_cost
+    };
+
+    let cells = grid.width() * grid.height();
+    let mut cost = vec![None; cells];
+    let mut parent = vec![None; cells];
+    let mut closed = vec![false; cells];
+    let mut open = IndexedPriorityQueue::new(cells);
+    cost[grid.node(start)] = Some(0);
+    open.push(grid.node(start), heuristic(start));
+
+    while let Some((node, _)) = open.pop() {
+        observer.on_visit_node(node);
+        if node == grid.node(goal) {
+            let mut nodes = vec![node];
+            while let Some(previous) = parent[*nodes.last().unwrap()] {
+                nodes.push(previous);
+            }
+            nodes.reverse();
+            return Some(to_grid_path(grid, nodes));
+        }
+        closed[node] = true;
+        let position = grid.position(node);
+        let current: u64 = cost[node].unwrap();
+        for next in grid.neighbors(position) {
+            let next_node = grid.node(next);
+            if closed[next_node] {
+                continue;
+            }
+            let candidate = current + grid.cost(next).unwrap() as u64;
+            if cost[next_node].is_none_or(|c| candidate < c) {
+                cost[next_node] = Some(candidate);
+                parent[next_node] = Some(node);
+                open.push_or_decrease(next_node, candidate + heuristic(next));
+                observer.on_relax_edge(node, next_node, candidate as i64);
+            }
+        }
+    }
+    None
+}
+
+fn to_grid_path(grid: &Grid, nodes: Vec<usize>) -> GridPath {
+    let cells: Vec<Position> = nodes.into_iter().map(|n| grid.position(n)).collect();
+    let cost = cells[1..]
+        .iter()
+        .map(|&position| grid.cost(position).unwrap() as u64)
+        .sum();
+    GridPath { cost, cells }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::generate_maze;
+    use crate::utils::random::Rng;
+    use crate::visualization::Step;
+
+    fn check_path(grid: &Grid, path: &GridPath, start: Position, goal: Position) {
+        assert_eq!(path.cells.first(), Some(&start));
+        assert_eq!(path.cells.last(), Some(&goal));
+        for pair in path.cells.windows(2) {
+            assert!(grid.neighbors(pair[0]).any(|n| n == pair[1]));
+        }
+    }
+
+    #[test]
+    fn weighted_terrain_changes_the_route() {
+        // 直走要穿过代价为 9 的沼泽，绕路更便宜
+        let grid = Grid::parse(
+            "
+            .....
+            .999.
+            .....
+            ",
+        )
+        .unwrap();
+        let (start, goal) = ((1, 0), (1, 4));
+        let shortest = solve_bfs(&grid, start, goal).unwrap();
+        assert_eq!(shortest.cells.len(), 5);
+        assert_eq!(shortest.cost, 9 * 3 + 1);
+
+        let cheapest = solve_dijkstra(&grid, start, goal).unwrap();
+        assert_eq!(cheapest.cost, 6);
+        check_path(&grid, &cheapest, start, goal);
+        assert_eq!(solve_astar(&grid, start, goal).unwrap().cost, 6);
+    }
+
+    #[test]
+    fn unreachable_and_blocked_goals() {
+        let grid = Grid::parse(".#.\n.#.\n").unwrap();
+        assert_eq!(solve_bfs(&grid, (0, 0), (0, 2)), None);
+        assert_eq!(solve_dijkstra(&grid, (0, 0), (0, 2)), None);
+        assert_eq!(solve_astar(&grid, (0, 0), (0, 2)), None);
+        assert_eq!(solve_astar(&grid, (0, 0), (0, 1)), None);
+        let here = solve_astar(&grid, (1, 0), (1, 0)).unwrap();
+        assert_eq!((here.cost, here.cells), (0, vec![(1, 0)]));
+    }
+
+    #[test]
+    fn astar_expands_fewer_cells() {
+        let grid = Grid::new(20, 20);
+        let (start, goal) = ((10, 0), (10, 19));
+        let visits = |steps: &[Step]| {
+            steps
+                .iter()
+                .filter(|step| matches!(step, Step::Visit(_)))
+                .count()
+        };
+        let mut dijkstra_steps = vec![];
+        let cheapest = solve_dijkstra_observed(&grid, start, goal, &mut dijkstra_steps).unwrap();
+        let mut astar_steps = vec![];
+        let guided = solve_astar_observed(&grid, start, goal, &mut astar_steps).unwrap();
+        assert_eq!(cheapest.cost, guided.cost);
+        // Dijkstra 确定了全部格子，A* 只沿着起点和终点所在的那一行展开
+        assert_eq!(visits(&dijkstra_steps), 400);
+        assert_eq!(visits(&astar_steps), 20);
+        assert_eq!(astar_steps.last(), Some(&Step::Visit(grid.node(goal))));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra_on_random_terrain() {
+        let mut rng = Rng::new(1420);
+        for _ in 0..30 {
+            let mut grid = generate_maze(8, 6, &mut rng);
+            // 打通一些墙并随机设置地形，让迷宫有多条路可走
+            for row in 1..grid.height() - 1 {
+                for column in 1..grid.width() - 1 {
+                    if !grid.is_open((row, column)) && rng.gen_bool(0.3) {
+                        grid.set_cost((row, column), 1);
+                    }
+                    if grid.is_open((row, column)) {
+                        grid.set_cost((row, column), 1 + rng.gen_index(5) as u32);
+                    }
+                }
+            }
+            let (start, goal) = ((1, 1), (grid.height() - 2, grid.width() - 2));
+            let expected = solve_dijkstra(&grid, start, goal).unwrap();
+            let actual = solve_astar(&grid, start, goal).unwrap();
+            assert_eq!(actual.cost, expected.cost);
+            check_path(&grid, &actual, start, goal);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+/*!
+定义：
+[数值积分](https://zh.wikipedia.org/wiki/%E6%95%B0%E5%80%BC%E7%A7%AF%E5%88%86)
+把 [a, b] 等分成 n 段，在每段上用简单的多项式近似 f 再求面积。
+- [梯形法则](https://zh.wikipedia.org/wiki/%E6%A2%AF%E5%BD%A2%E6%B3%95%E5%88%99)：每段用直线近似，误差 O(h²)
+- [辛普森法则](https://zh.wikipedia.org/wiki/%E8%BE%9B%E6%99%AE%E6%A3%AE%E7%A7%AF%E5%88%86%E6%B3%95)：
+  每两段用一条抛物线近似，权重是 1, 4, 2, 4, ..., 2, 4, 1，误差 O(h⁴)
+*/
+
+/// 复合梯形法则，`intervals` 是分段数
+pub fn trapezoidal<F>(f: F, a: f64, b: f64, intervals: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    assert!(intervals > 0, "Need at least one interval.");
+    let h = (b - a) / intervals as f64;
+    let inner: f64 = (1..intervals).map(|i| f(a + i as f64 * h)).sum();
+    h * ((f(a) + f(b)) / 2.0 + inner)
+}
+
+/// 复合辛普森法则，`intervals` 是奇数时向上取成偶数
+pub fn simpson<F>(f: F, a: f64, b: f64, intervals: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    assert!(intervals > 0, "Need at least one interval.");
+    let intervals = intervals + intervals % 2;
+    let h = (b - a) / intervals as f64;
+    let inner: f64 = (1..intervals)
+        .map(|i| {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            weight * f(a + i as f64 * h)
+        })
+        .sum();
+    h / 3.0 * (f(a) + f(b) + inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{simpson, trapezoidal};
+    use std::f64::consts::PI;
+
+    type Rule = fn(fn(f64) -> f64, f64, f64, usize) -> f64;
+
+    #[test]
+    fn integrates_polynomials() {
+        // 梯形法则对一次函数精确，辛普森法则对三次函数精确
+        assert!((trapezoidal(|x| 3.0 * x + 1.0, 0.0, 2.0, 1) - 8.0).abs() < 1e-12);
+        let cubic = |x: f64| x * x * x - 2.0 * x + 5.0;
+        assert!((simpson(cubic, -1.0, 3.0, 2) - 32.0).abs() < 1e-12);
+        // 奇数段数会自动加一
+        assert!((simpson(cubic, -1.0, 3.0, 3) - 32.0).abs() < 1e-12);
+        // 积分上下限颠倒时结果取反
+        assert!((simpson(cubic, 3.0, -1.0, 4) + 32.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn error_orders() {
+        let exact = 2.0;
+        let error = |integrate: Rule, n| (integrate(f64::sin, 0.0, PI, n) - exact).abs();
+        // 段数翻倍：梯形法则误差约缩小到 1/4，辛普森法则约缩小到 1/16
+        let ratio = error(trapezoidal, 64) / error(trapezoidal, 128);
+        assert!((ratio - 4.0).abs() < 0.1, "{ratio}");
+        let ratio = error(simpson, 64) / error(simpson, 128);
+        assert!((ratio - 16.0).abs() < 0.5, "{ratio}");
+        assert!(error(simpson, 128) < 1e-8);
+        assert!(error(simpson, 16) < error(trapezoidal, 16));
+    }
+
+    #[test]
+    fn gaussian_integral() {
+        // ∫₋₆⁶ e^(-x²) dx ≈ √π，区间外的部分小于 1e-16
+        let value = simpson(|x| (-x * x).exp(), -6.0, 6.0, 200);
+        assert!((value - PI.sqrt()).abs() < 1e-10);
+    }
+}
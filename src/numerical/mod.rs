@@ -0,0 +1,5 @@
+pub mod integration;
+pub mod root_finding;
+
+pub use self::integration::{simpson, trapezoidal};
+pub use self::root_finding::{bisection, newton_raphson, secant, Root, RootError, Tolerance};
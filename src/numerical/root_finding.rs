@@ -0,0 +1,254 @@
+/*!
+定义：
+[求根算法](https://zh.wikipedia.org/wiki/%E6%B1%82%E6%A0%B9%E7%AE%97%E6%B3%95)
+求 f(x) = 0 的数值解。
+- [二分法](https://zh.wikipedia.org/wiki/%E4%BA%8C%E5%88%86%E6%B3%95_(%E6%95%B8%E5%AD%B8))：
+  区间两端异号时必有根，每次取中点保留异号的一半，线性收敛但一定收敛
+- [牛顿法](https://zh.wikipedia.org/wiki/%E7%89%9B%E9%A1%BF%E6%B3%95)：
+  x ← x - f(x)/f'(x)，在单根附近二次收敛，但需要导数，初值不好时可能发散
+- [割线法](https://zh.wikipedia.org/wiki/%E5%89%B2%E7%BA%BF%E6%B3%95)：
+  用最近两个点连线的斜率代替导数，收敛阶约 1.618
+*/
+
+use std::fmt;
+
+/// 迭代的终止条件
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// 区间宽度或步长小于它时认为已经收敛
+    pub epsilon: f64,
+    pub max_iterations: usize,
+}
+
+impl Tolerance {
+    pub fn new(epsilon: f64, max_iterations: usize) -> Self {
+        assert!(epsilon > 0.0, "Tolerance must be positive.");
+        Tolerance {
+            epsilon,
+            max_iterations,
+        }
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::new(1e-12, 100)
+    }
+}
+
+/// 找到的根，以及用了多少次迭代
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Root {
+    pub value: f64,
+    pub iterations: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootError {
+    /// 二分法的区间两端同号
+    NoSignChange { f_low: f64, f_high: f64 },
+    /// 导数（或割线的斜率）为 0，无法继续迭代
+    ZeroSlope { at: f64 },
+    /// 迭代出了 NaN 或无穷大
+    NotFinite { at: f64 },
+    /// 达到最大迭代次数仍未收敛，`last` 是最后一次的近似值
+    DidNotConverge { last: f64 },
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootError::NoSignChange { f_low, f_high } => {
+                write!(f, "f has the same sign at both ends ({f_low}, {f_high})")
+            }
+            RootError::ZeroSlope { at } => write!(f, "zero slope at x = {at}"),
+            RootError::NotFinite { at } => write!(f, "iteration diverged at x = {at}"),
+            RootError::DidNotConverge { last } => {
+                write!(f, "did not converge, last approximation {last}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RootError {}
+
+/// 二分法，要求 f(low) 和 f(high) 异号（或其中一个为 0）
+pub fn bisection<F>(f: F, low: f64, high: f64, tolerance: Tolerance) -> Result<Root, RootError>
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut low, mut high) = (low.min(high), low.max(high));
+    let (mut f_low, f_high) = (f(low), f(high));
+    if f_low == 0.0 {
+        return Ok(Root {
+            value: low,
+            iterations: 0,
+        });
+    }
+    if f_high == 0.0 {
+        return Ok(Root {
+            value: high,
+            iterations: 0,
+        });
+    }
+    if f_low.signum() == f_high.signum() {
+        return Err(RootError::NoSignChange { f_low, f_high });
+    }
+    for iterations in 1..=tolerance.max_iterations {
+        let mid = low + (high - low) / 2.0;
+        let f_mid = f(mid);
+        if f_mid == 0.0 || (high - low) / 2.0 < tolerance.epsilon {
+            return Ok(Root {
+                value: mid,
+                iterations,
+            });
+        }
+        if f_mid.signum() == f_low.signum() {
+            (low, f_low) = (mid, f_mid);
+        } else {
+            high = mid;
+        }
+    }
+    Err(RootError::DidNotConverge {
+        last: low + (high - low) / 2.0,
+    })
+}
+
+/// 牛顿法，`derivative` 是 f 的导数
+pub fn newton_raphson<F, D>(
+    f: F,
+    derivative: D,
+    initial: f64,
+    tolerance: Tolerance,
+) -> Result<Root, RootError>
+where
+    F: Fn(f64) -> f64,
+    D: Fn(f64) -> f64,
+{
+    let mut x = initial;
+    for iterations in 1..=tolerance.max_iterations {
+        let slope = derivative(x);
+        if slope == 0.0 {
+            return Err(RootError::ZeroSlope { at: x });
+        }
+        let step = f(x) / slope;
+        x -= step;
+        if !x.is_finite() {
+            return Err(RootError::NotFinite { at: x });
+        }
+        if step.abs() < tolerance.epsilon {
+            return Ok(Root {
+                value: x,
+                iterations,
+            });
+        }
+    }
+    Err(RootError::DidNotConverge { last: x })
+}
+
+/// 割线法，从 x0、x1 两个初始点开始
+pub fn secant<F>(f: F, x0: f64, x1: f64, tolerance: Tolerance) -> Result<Root, RootError>
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut previous, mut current) = (x0, x1);
+    let (mut f_previous, mut f_current) = (f(x0), f(x1));
+    for iterations in 1..=tolerance.max_iterations {
+        if f_current == 0.0 {
+            return Ok(Root {
+                value: current,
+                iterations,
+            });
+        }
+        let slope = (f_current - f_previous) / (current - previous);
+        if slope == 0.0 {
+            return Err(RootError::ZeroSlope { at: current });
+        }
+        let step = f_current / slope;
+        (previous, f_previous) = (current, f_current);
+        current -= step;
+        if !current.is_finite() {
+            return Err(RootError::NotFinite { at: current });
+        }
+        if step.abs() < tolerance.epsilon {
+            return Ok(Root {
+                value: current,
+                iterations,
+            });
+        }
+        f_current = f(current);
+    }
+    Err(RootError::DidNotConverge { last: current })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bisection, newton_raphson, secant, RootError, Tolerance};
+
+    const SQRT_2: f64 = std::f64::consts::SQRT_2;
+
+    #[test]
+    fn finds_square_root_of_two() {
+        let f = |x: f64| x * x - 2.0;
+        let tolerance = Tolerance::default();
+
+        let by_bisection = bisection(f, 0.0, 2.0, tolerance).unwrap();
+        let by_newton = newton_raphson(f, |x| 2.0 * x, 1.0, tolerance).unwrap();
+        let by_secant = secant(f, 1.0, 2.0, tolerance).unwrap();
+        for root in [by_bisection, by_newton, by_secant] {
+            assert!((root.value - SQRT_2).abs() < 1e-11, "{root:?}");
+        }
+        // 收敛速度：牛顿法 < 割线法 < 二分法
+        assert!(by_newton.iterations < by_secant.iterations);
+        assert!(by_secant.iterations < by_bisection.iterations);
+        assert!(by_newton.iterations <= 7);
+    }
+
+    #[test]
+    fn transcendental_equations() {
+        let tolerance = Tolerance::new(1e-10, 200);
+        // cos x = x 的唯一实根
+        let dottie = 0.739_085_133_215_160_6;
+        let f = |x: f64| x.cos() - x;
+        let root = bisection(f, 0.0, 1.0, tolerance).unwrap();
+        assert!((root.value - dottie).abs() < 1e-9);
+        let root = newton_raphson(f, |x| -x.sin() - 1.0, 0.0, tolerance).unwrap();
+        assert!((root.value - dottie).abs() < 1e-12);
+        // 区间端点就是根
+        let root = bisection(f64::sin, 0.0, 1.0, tolerance).unwrap();
+        assert_eq!((root.value, root.iterations), (0.0, 0));
+        // 端点顺序颠倒也可以
+        let root = bisection(|x| x.exp() - 3.0, 2.0, 0.0, tolerance).unwrap();
+        assert!((root.value - 3f64.ln()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reports_failures() {
+        let tolerance = Tolerance::default();
+        let f = |x: f64| x * x + 1.0;
+        assert!(matches!(
+            bisection(f, -1.0, 1.0, tolerance),
+            Err(RootError::NoSignChange { .. })
+        ));
+        assert_eq!(
+            newton_raphson(f, |x| 2.0 * x, 0.0, tolerance),
+            Err(RootError::ZeroSlope { at: 0.0 })
+        );
+        // x² + 1 没有实根，牛顿法会来回震荡
+        assert!(matches!(
+            newton_raphson(f, |x| 2.0 * x, 0.5, Tolerance::new(1e-12, 50)),
+            Err(RootError::DidNotConverge { .. })
+        ));
+        assert!(matches!(
+            secant(|_| 1.0, 0.0, 1.0, tolerance),
+            Err(RootError::ZeroSlope { .. })
+        ));
+        let error = bisection(f, 0.0, 1.0, Tolerance::new(1e-12, 5)).unwrap_err();
+        assert!(error.to_string().contains("same sign"));
+        // 迭代次数不够时二分法也会报告未收敛
+        assert!(matches!(
+            bisection(|x| x - 0.3, 0.0, 1.0, Tolerance::new(1e-12, 5)),
+            Err(RootError::DidNotConverge { .. })
+        ));
+    }
+}
@@ -0,0 +1,318 @@
+/*!
+定义：
+[遗传算法](https://zh.wikipedia.org/wiki/%E9%81%97%E4%BC%A0%E7%AE%97%E6%B3%95)
+模仿自然选择在一群候选解（染色体）中搜索适应度高的解。每一代按适应度挑选父代，
+交叉得到子代，再以一定概率变异，用子代替换整个种群。
+- 锦标赛选择：随机抽几个个体，取其中适应度最高的
+- 轮盘赌选择：被选中的概率正比于适应度
+- 精英保留：适应度最高的几个个体原样进入下一代，保证最优解不会丢失
+*/
+
+use crate::utils::random::Rng;
+
+/// 候选解，适应度越高越好
+pub trait Chromosome: Clone {
+    fn fitness(&self) -> f64;
+
+    /// 由两个父代产生一个子代
+    fn crossover(&self, other: &Self, rng: &mut Rng) -> Self;
+
+    /// 随机修改自身。每个基因的变异概率由实现自己决定
+    fn mutate(&mut self, rng: &mut Rng);
+}
+
+/// 挑选父代的方式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Selection {
+    /// 有放回地抽取给定数量的个体，取其中适应度最高的
+    Tournament(usize),
+    /// 概率正比于适应度。种群中有负的适应度时，先把所有适应度减去最小值
+    Roulette,
+}
+
+/// 遗传算法的参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeneticOptions {
+    pub generations: usize,
+    /// 每一代原样保留的最优个体数
+    pub elitism: usize,
+    /// 子代由交叉产生的概率，否则直接复制第一个父代
+    pub crossover_rate: f64,
+    /// 子代调用 `mutate` 的概率
+    pub mutation_rate: f64,
+    pub selection: Selection,
+}
+
+impl GeneticOptions {
+    pub fn new(
+        generations: usize,
+        elitism: usize,
+        crossover_rate: f64,
+        mutation_rate: f64,
+        selection: Selection,
+    ) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&crossover_rate) && (0.0..=1.0).contains(&mutation_rate),
+            "Rates must be probabilities."
+        );
+        if let Selection::Tournament(size) = selection {
+            assert!(size > 0, "Tournament size must be positive.");
+        }
+        GeneticOptions {
+            generations,
+            elitism,
+            crossover_rate,
+            mutation_rate,
+            selection,
+        }
+    }
+}
+
+impl Default for GeneticOptions {
+    fn default() -> Self {
+        GeneticOptions::new(100, 1, 0.9, 0.2, Selection::Tournament(3))
+    }
+}
+
+/// 一代种群的适应度统计
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GenerationStats {
+    pub generation: usize,
+    pub best: f64,
+    pub mean: f64,
+    pub worst: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Evolution<C> {
+    /// 所有代中适应度最高的个体
+    pub best: C,
+    pub best_fitness: f64,
+    /// 初始种群以及之后每一代的统计，共 `generations + 1` 项
+    pub history: Vec<GenerationStats>,
+    pub population: Vec<C>,
+}
+
+/// 从 `population` 开始进化 `options.generations` 代，种群大小保持不变
+pub fn evolve<C: Chromosome>(
+    population: Vec<C>,
+    options: &GeneticOptions,
+    rng: &mut Rng,
+) -> Evolution<C> {
+    assert!(!population.is_empty(), "Population must not be empty.");
+    assert!(
+        options.elitism <= population.len(),
+        "Cannot keep {} elites out of {} chromosomes.",
+        options.elitism,
+        population.len()
+    );
+    let size = population.len();
+    let mut population = population;
+    let mut fitness: Vec<f64> = population.iter().map(C::fitness).collect();
+    let mut history = vec![stats(0, &fitness)];
+    let mut best_index = argmax(&fitness);
+    let mut best = population[best_index].clone();
+    let mut best_fitness = fitness[best_index];
+
+    for generation in 1..=options.generations {
+        // 按适应度从高到低排序，前 elitism 个直接保留
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|&a, &b| fitness[b].total_cmp(&fitness[a]));
+        let mut next: Vec<C> = order[..options.elitism]
+            .iter()
+            .map(|&i| population[i].clone())
+            .collect();
+        while next.len() < size {
+            let first = &population[select(&fitness, options.selection, rng)];
+            let second = &population[select(&fitness, options.selection, rng)];
+            let mut child = if rng.gen_bool(options.crossover_rate) {
+                first.crossover(second, rng)
+            } else {
+                first.clone()
+            };
+            if rng.gen_bool(options.mutation_rate) {
+                child.mutate(rng);
+            }
+            next.push(child);
+        }
+
+        population = next;
+        fitness = population.iter().map(C::fitness).collect();
+        history.push(stats(generation, &fitness));
+        best_index = argmax(&fitness);
+        if fitness[best_index] > best_fitness {
+            best = population[best_index].clone();
+            best_fitness = fitness[best_index];
+        }
+    }
+    Evolution {
+        best,
+        best_fitness,
+        history,
+        population,
+    }
+}
+
+/// 被选中的个体的下标
+fn select(fitness: &[f64], selection: Selection, rng: &mut Rng) -> usize {
+    match selection {
+        Selection::Tournament(size) => (0..size)
+            .map(|_| rng.gen_index(fitness.len()))
+            .max_by(|&a, &b| fitness[a].total_cmp(&fitness[b]))
+            .unwrap(),
+        Selection::Roulette => {
+            let min = fitness.iter().copied().fold(f64::INFINITY, f64::min);
+            let shift = if min < 0.0 { -min } else { 0.0 };
+            let total: f64 = fitness.iter().map(|f| f + shift).sum();
+            if total <= 0.0 {
+                // 适应度全部相同（或全为 0）时等概率选择
+                return rng.gen_index(fitness.len());
+            }
+            let mut target = rng.next_f64() * total;
+            // 浮点误差可能让 target 略大于总和，这时取最后一个权重不为 0 的个体
+            fitness
+                .iter()
+                .position(|f| {
+                    target -= f + shift;
+                    target < 0.0
+                })
+                .unwrap_or_else(|| fitness.iter().rposition(|f| f + shift > 0.0).unwrap())
+        }
+    }
+}
+
+fn argmax(fitness: &[f64]) -> usize {
+    (0..fitness.len())
+        .max_by(|&a, &b| fitness[a].total_cmp(&fitness[b]))
+        .unwrap()
+}
+
+fn stats(generation: usize, fitness: &[f64]) -> GenerationStats {
+    GenerationStats {
+        generation,
+        best: fitness.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        mean: fitness.iter().sum::<f64>() / fitness.len() as f64,
+        worst: fitness.iter().copied().fold(f64::INFINITY, f64::min),
+    }
+}
+
+#[cfg(all(test, feature = "dp"))]
+mod tests {
+    use super::{evolve, Chromosome, GeneticOptions, Selection};
+    use crate::dp::knapsack::knapsack_01;
+    use crate::utils::random::Rng;
+
+    const WEIGHTS: [usize; 20] = [
+        23, 31, 29, 44, 53, 38, 63, 85, 89, 82, 12, 17, 41, 27, 35, 48, 9, 56, 71, 19,
+    ];
+    const VALUES: [u64; 20] = [
+        92, 57, 49, 68, 60, 43, 67, 84, 87, 72, 30, 41, 66, 45, 52, 70, 21, 63, 90, 33,
+    ];
+    const CAPACITY: usize = 300;
+
+    /// 第 i 位表示是否选第 i 件物品
+    #[derive(Debug, Clone)]
+    struct Knapsack(Vec<bool>);
+
+    impl Knapsack {
+        fn random(rng: &mut Rng) -> Self {
+            Knapsack((0..WEIGHTS.len()).map(|_| rng.gen_bool(0.2)).collect())
+        }
+
+        fn weight(&self) -> usize {
+            self.0
+                .iter()
+                .zip(WEIGHTS)
+                .filter(|(&b, _)| b)
+                .map(|(_, w)| w)
+                .sum()
+        }
+    }
+
+    impl Chromosome for Knapsack {
+        fn fitness(&self) -> f64 {
+            let weight = self.weight();
+            if weight > CAPACITY {
+                // 超重的解适应度为负，超得越多越差
+                return CAPACITY as f64 - weight as f64;
+            }
+            self.0
+                .iter()
+                .zip(VALUES)
+                .filter(|(&b, _)| b)
+                .map(|(_, v)| v)
+                .sum::<u64>() as f64
+        }
+
+        /// 单点交叉
+        fn crossover(&self, other: &Self, rng: &mut Rng) -> Self {
+            let cut = rng.gen_index(self.0.len() + 1);
+            Knapsack([&self.0[..cut], &other.0[cut..]].concat())
+        }
+
+        /// 随机翻转一位
+        fn mutate(&mut self, rng: &mut Rng) {
+            let i = rng.gen_index(self.0.len());
+            self.0[i] = !self.0[i];
+        }
+    }
+
+    #[test]
+    fn solves_knapsack() {
+        let optimum = knapsack_01(&WEIGHTS, &VALUES, CAPACITY).value as f64;
+        let mut rng = Rng::new(1488);
+        for selection in [Selection::Tournament(3), Selection::Roulette] {
+            let population = (0..100).map(|_| Knapsack::random(&mut rng)).collect();
+            let options = GeneticOptions::new(300, 2, 0.9, 0.5, selection);
+            let evolution = evolve(population, &options, &mut rng);
+            assert!(evolution.best.weight() <= CAPACITY);
+            assert_eq!(evolution.best.fitness(), evolution.best_fitness);
+            assert!(
+                evolution.best_fitness >= 0.95 * optimum,
+                "{selection:?}: {} vs {optimum}",
+                evolution.best_fitness
+            );
+            assert!(evolution.best_fitness <= optimum);
+        }
+    }
+
+    #[test]
+    fn elitism_keeps_best() {
+        let mut rng = Rng::new(1488);
+        let population: Vec<Knapsack> = (0..30).map(|_| Knapsack::random(&mut rng)).collect();
+        let options = GeneticOptions::new(50, 1, 0.9, 1.0, Selection::Tournament(2));
+        let evolution = evolve(population, &options, &mut rng);
+        assert_eq!(evolution.history.len(), 51);
+        assert_eq!(evolution.population.len(), 30);
+        for (i, stats) in evolution.history.iter().enumerate() {
+            assert_eq!(stats.generation, i);
+            assert!(stats.worst <= stats.mean && stats.mean <= stats.best);
+        }
+        // 有精英保留时每一代的最优适应度不会下降
+        assert!(evolution.history.windows(2).all(|w| w[0].best <= w[1].best));
+        assert_eq!(
+            evolution.best_fitness,
+            evolution.history.last().unwrap().best
+        );
+    }
+
+    #[test]
+    fn roulette_handles_negative_and_equal_fitness() {
+        let mut rng = Rng::new(1488);
+        // 全部超重，适应度都是负数
+        let heavy = vec![Knapsack(vec![true; WEIGHTS.len()]); 10];
+        let options = GeneticOptions::new(20, 0, 0.5, 0.5, Selection::Roulette);
+        let evolution = evolve(heavy, &options, &mut rng);
+        assert_eq!(evolution.history[0].best, evolution.history[0].worst);
+        assert!(evolution.best_fitness >= evolution.history[0].best);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_too_many_elites() {
+        let mut rng = Rng::new(1488);
+        let population = vec![Knapsack::random(&mut rng)];
+        let options = GeneticOptions::new(1, 2, 0.5, 0.5, Selection::Roulette);
+        evolve(population, &options, &mut rng);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod genetic;
+pub mod simulated_annealing;
+pub mod tsp;
+
+pub use self::genetic::{
+    evolve, Chromosome, Evolution, GenerationStats, GeneticOptions, Selection,
+};
+pub use self::simulated_annealing::{
+    hill_climb, simulated_annealing, Annealing, CoolingSchedule, Solution,
+};
+pub use self::tsp::{anneal_tour, hill_climb_tour, tour_length, two_opt_neighbor};
@@ -0,0 +1,227 @@
+/*!
+定义：
+[模拟退火](https://zh.wikipedia.org/wiki/%E6%A8%A1%E6%8B%9F%E9%80%80%E7%81%AB)
+在状态空间中随机游走，寻找能量（目标函数）尽量低的状态。每一步随机生成一个邻居，
+能量更低就接受，更高时以 exp(-ΔE / T) 的概率接受，温度 T 随步数按降温计划逐渐降低。
+高温时容易跳出局部最优，低温时退化为只接受更优解的
+[爬山算法](https://zh.wikipedia.org/wiki/%E7%88%AC%E5%B1%B1%E7%AE%97%E6%B3%95)
+*/
+
+use crate::utils::random::Rng;
+
+/// 第 k 步的温度如何由初始温度 T₀ 得到
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoolingSchedule {
+    /// T₀·αᵏ，α 在 (0, 1) 之间
+    Exponential(f64),
+    /// 从 T₀ 线性降到最后一步的 0
+    Linear,
+    /// T₀ / (1 + ln(1 + k))，降得很慢
+    Logarithmic,
+}
+
+impl CoolingSchedule {
+    /// 共 `steps` 步时第 `step` 步的温度
+    pub fn temperature(&self, initial: f64, step: usize, steps: usize) -> f64 {
+        match *self {
+            CoolingSchedule::Exponential(alpha) => initial * alpha.powf(step as f64),
+            CoolingSchedule::Linear => initial * (1.0 - step as f64 / steps.max(1) as f64),
+            CoolingSchedule::Logarithmic => initial / (1.0 + (step as f64).ln_1p()),
+        }
+    }
+}
+
+/// 模拟退火的参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Annealing {
+    pub initial_temperature: f64,
+    pub steps: usize,
+    pub schedule: CoolingSchedule,
+}
+
+impl Annealing {
+    pub fn new(initial_temperature: f64, steps: usize, schedule: CoolingSchedule) -> Self {
+        assert!(
+            initial_temperature > 0.0,
+            "Initial temperature must be positive."
+        );
+        if let CoolingSchedule::Exponential(alpha) = schedule {
+            assert!(
+                alpha > 0.0 && alpha < 1.0,
+                "Cooling factor must be in (0, 1)."
+            );
+        }
+        Annealing {
+            initial_temperature,
+            steps,
+            schedule,
+        }
+    }
+}
+
+impl Default for Annealing {
+    fn default() -> Self {
+        Annealing::new(100.0, 10_000, CoolingSchedule::Exponential(0.999))
+    }
+}
+
+/// 搜索过程中能量最低的状态
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution<S> {
+    pub state: S,
+    pub energy: f64,
+    /// 生成过的邻居数
+    pub iterations: usize,
+    /// 被接受的邻居数
+    pub accepted: usize,
+}
+
+/// 模拟退火。`neighbor` 随机生成当前状态的一个邻居，`energy` 越小越好
+pub fn simulated_annealing<S, N, E>(
+    initial: S,
+    mut neighbor: N,
+    mut energy: E,
+    options: &Annealing,
+    rng: &mut Rng,
+) -> Solution<S>
+where
+    S: Clone,
+    N: FnMut(&S, &mut Rng) -> S,
+    E: FnMut(&S) -> f64,
+{
+    let mut current_energy = energy(&initial);
+    let mut best = Solution {
+        state: initial.clone(),
+        energy: current_energy,
+        iterations: 0,
+        accepted: 0,
+    };
+    let mut current = initial;
+    for step in 0..options.steps {
+        let temperature =
+            options
+                .schedule
+                .temperature(options.initial_temperature, step, options.steps);
+        let candidate = neighbor(&current, rng);
+        let candidate_energy = energy(&candidate);
+        let delta = candidate_energy - current_energy;
+        best.iterations += 1;
+        if delta <= 0.0 || (temperature > 0.0 && rng.next_f64() < (-delta / temperature).exp()) {
+            best.accepted += 1;
+            current = candidate;
+            current_energy = candidate_energy;
+            if current_energy < best.energy {
+                best.state = current.clone();
+                best.energy = current_energy;
+            }
+        }
+    }
+    best
+}
+
+/// 随机爬山：每步生成一个邻居，能量严格更低才接受。
+/// 连续 `patience` 个邻居都没有改进，或者总共生成了 `max_iterations` 个邻居时停止
+pub fn hill_climb<S, N, E>(
+    initial: S,
+    mut neighbor: N,
+    mut energy: E,
+    max_iterations: usize,
+    patience: usize,
+    rng: &mut Rng,
+) -> Solution<S>
+where
+    N: FnMut(&S, &mut Rng) -> S,
+    E: FnMut(&S) -> f64,
+{
+    let mut best = Solution {
+        energy: energy(&initial),
+        state: initial,
+        iterations: 0,
+        accepted: 0,
+    };
+    let mut stale = 0;
+    while best.iterations < max_iterations && stale < patience {
+        let candidate = neighbor(&best.state, rng);
+        let candidate_energy = energy(&candidate);
+        best.iterations += 1;
+        if candidate_energy < best.energy {
+            best.state = candidate;
+            best.energy = candidate_energy;
+            best.accepted += 1;
+            stale = 0;
+        } else {
+            stale += 1;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hill_climb, simulated_annealing, Annealing, CoolingSchedule};
+    use crate::utils::random::Rng;
+
+    /// 有很多局部最小值的一维函数，全局最小值在 x = 0
+    fn rastrigin(x: &f64) -> f64 {
+        x * x + 10.0 - 10.0 * (2.0 * std::f64::consts::PI * x).cos()
+    }
+
+    fn step(x: &f64, rng: &mut Rng) -> f64 {
+        x + rng.next_f64() - 0.5
+    }
+
+    #[test]
+    fn schedules() {
+        let exponential = CoolingSchedule::Exponential(0.5);
+        assert_eq!(exponential.temperature(8.0, 3, 10), 1.0);
+        assert_eq!(CoolingSchedule::Linear.temperature(8.0, 0, 4), 8.0);
+        assert_eq!(CoolingSchedule::Linear.temperature(8.0, 3, 4), 2.0);
+        assert_eq!(CoolingSchedule::Logarithmic.temperature(8.0, 0, 4), 8.0);
+        for schedule in [
+            exponential,
+            CoolingSchedule::Linear,
+            CoolingSchedule::Logarithmic,
+        ] {
+            let temperatures: Vec<f64> = (0..100)
+                .map(|k| schedule.temperature(10.0, k, 100))
+                .collect();
+            assert!(temperatures.windows(2).all(|w| w[0] > w[1]));
+        }
+    }
+
+    #[test]
+    fn annealing_escapes_local_minima() {
+        let mut rng = Rng::new(1489);
+        // 从远处的局部最小值出发，爬山会卡住
+        let climbed = hill_climb(5.0, step, rastrigin, 10_000, 1000, &mut rng);
+        assert!(climbed.energy > 1.0);
+        assert!(climbed.iterations < 10_000);
+
+        for schedule in [
+            CoolingSchedule::Exponential(0.999),
+            CoolingSchedule::Linear,
+            CoolingSchedule::Logarithmic,
+        ] {
+            let options = Annealing::new(20.0, 20_000, schedule);
+            let annealed = simulated_annealing(5.0, step, rastrigin, &options, &mut rng);
+            assert!(
+                annealed.state.abs() < 0.5,
+                "{schedule:?}: {}",
+                annealed.state
+            );
+            assert_eq!(annealed.energy, rastrigin(&annealed.state));
+            assert_eq!(annealed.iterations, 20_000);
+            assert!(annealed.accepted > 0);
+        }
+    }
+
+    #[test]
+    fn hill_climb_finds_nearby_minimum() {
+        let mut rng = Rng::new(1489);
+        let parabola = |x: &f64| (x - 3.0) * (x - 3.0);
+        let solution = hill_climb(-10.0, step, parabola, 100_000, 200, &mut rng);
+        assert!((solution.state - 3.0).abs() < 0.01);
+        let untouched = hill_climb(-10.0, step, parabola, 0, 200, &mut rng);
+        assert_eq!((untouched.state, untouched.iterations), (-10.0, 0));
+    }
+}
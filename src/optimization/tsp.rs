@@ -0,0 +1,142 @@
+/*!
+定义：
+[旅行推销员问题](https://zh.wikipedia.org/wiki/%E6%97%85%E8%A1%8C%E6%8E%A8%E9%94%80%E5%91%98%E9%97%AE%E9%A2%98)
+用局部搜索改进平面上的回路。邻居由 [2-opt](https://en.wikipedia.org/wiki/2-opt) 产生：
+随机选一段路径把它反转，相当于删掉两条边再交叉连回去。
+点多时精确算法（见 `graph::tsp`）不可行，模拟退火通常能在很短时间内得到接近最优的回路
+*/
+
+use super::simulated_annealing::{hill_climb, simulated_annealing, Annealing, Solution};
+use crate::geometry::Point2D;
+use crate::utils::random::Rng;
+
+/// 按 `tour` 的顺序访问所有点再回到起点的总长度
+pub fn tour_length(points: &[Point2D], tour: &[usize]) -> f64 {
+    (0..tour.len())
+        .map(|i| points[tour[i]].distance(points[tour[(i + 1) % tour.len()]]))
+        .sum()
+}
+
+/// 随机反转回路中的一段
+pub fn two_opt_neighbor(tour: &[usize], rng: &mut Rng) -> Vec<usize> {
+    let mut next = tour.to_vec();
+    if tour.len() >= 2 {
+        let (a, b) = (rng.gen_index(tour.len()), rng.gen_index(tour.len()));
+        next[a.min(b)..=a.max(b)].reverse();
+    }
+    next
+}
+
+/// 从 `tour` 出发用模拟退火改进回路
+pub fn anneal_tour(
+    points: &[Point2D],
+    tour: Vec<usize>,
+    options: &Annealing,
+    rng: &mut Rng,
+) -> Solution<Vec<usize>> {
+    simulated_annealing(
+        tour,
+        |t: &Vec<usize>, rng: &mut Rng| two_opt_neighbor(t, rng),
+        |t: &Vec<usize>| tour_length(points, t),
+        options,
+        rng,
+    )
+}
+
+/// 从 `tour` 出发用爬山改进回路，连续 `patience` 次 2-opt 都没有改进时停止
+pub fn hill_climb_tour(
+    points: &[Point2D],
+    tour: Vec<usize>,
+    patience: usize,
+    rng: &mut Rng,
+) -> Solution<Vec<usize>> {
+    hill_climb(
+        tour,
+        |t: &Vec<usize>, rng: &mut Rng| two_opt_neighbor(t, rng),
+        |t: &Vec<usize>| tour_length(points, t),
+        usize::MAX,
+        patience,
+        rng,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{anneal_tour, hill_climb_tour, tour_length, two_opt_neighbor};
+    use crate::combinatorics::lexicographic_permutations;
+    use crate::geometry::Point2D;
+    use crate::optimization::{Annealing, CoolingSchedule};
+    use crate::utils::random::Rng;
+
+    fn random_points(n: usize, rng: &mut Rng) -> Vec<Point2D> {
+        (0..n)
+            .map(|_| Point2D::new(rng.next_f64() * 100.0, rng.next_f64() * 100.0))
+            .collect()
+    }
+
+    /// 固定起点 0，枚举其余点的所有排列
+    fn optimal_length(points: &[Point2D]) -> f64 {
+        let rest: Vec<usize> = (1..points.len()).collect();
+        lexicographic_permutations(&rest)
+            .map(|p| {
+                let tour: Vec<usize> = std::iter::once(0).chain(p).collect();
+                tour_length(points, &tour)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    fn is_permutation(tour: &[usize]) -> bool {
+        let mut sorted = tour.to_vec();
+        sorted.sort_unstable();
+        sorted.into_iter().eq(0..tour.len())
+    }
+
+    #[test]
+    fn square() {
+        let points =
+            [(0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 1.0)].map(|(x, y)| Point2D::new(x, y));
+        assert_eq!(tour_length(&points, &[0, 2, 1, 3]), 4.0);
+        assert_eq!(tour_length(&points, &[]), 0.0);
+        let mut rng = Rng::new(1489);
+        let solution = hill_climb_tour(&points, vec![0, 1, 2, 3], 100, &mut rng);
+        assert_eq!(solution.energy, 4.0);
+    }
+
+    #[test]
+    fn neighbors_are_permutations() {
+        let mut rng = Rng::new(1489);
+        let mut tour: Vec<usize> = (0..10).collect();
+        for _ in 0..100 {
+            tour = two_opt_neighbor(&tour, &mut rng);
+            assert!(is_permutation(&tour));
+        }
+        assert_eq!(two_opt_neighbor(&[0], &mut rng), vec![0]);
+    }
+
+    #[test]
+    fn annealing_finds_optimal_small_tours() {
+        let mut rng = Rng::new(1489);
+        for _ in 0..5 {
+            let points = random_points(8, &mut rng);
+            let optimum = optimal_length(&points);
+            let options = Annealing::new(50.0, 20_000, CoolingSchedule::Exponential(0.9995));
+            let solution = anneal_tour(&points, (0..8).collect(), &options, &mut rng);
+            assert!(is_permutation(&solution.state));
+            assert!((solution.energy - optimum).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn annealing_improves_large_tours() {
+        let mut rng = Rng::new(1489);
+        let points = random_points(60, &mut rng);
+        let initial: Vec<usize> = (0..60).collect();
+        let initial_length = tour_length(&points, &initial);
+        let climbed = hill_climb_tour(&points, initial.clone(), 2000, &mut rng);
+        let options = Annealing::new(100.0, 100_000, CoolingSchedule::Exponential(0.9999));
+        let annealed = anneal_tour(&points, initial, &options, &mut rng);
+        assert!(is_permutation(&annealed.state));
+        assert!(climbed.energy < initial_length / 2.0);
+        assert!(annealed.energy <= climbed.energy * 1.05);
+    }
+}
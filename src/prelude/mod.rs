@@ -0,0 +1,60 @@
+/*!
+常用的类型、trait 和函数，`use rust_algo::prelude::*;` 一次导入。
+每个领域的条目只在对应的特性开启时导出，名字容易冲突或不常用的条目请从各自的模块导入
+*/
+
+#[cfg(feature = "std")]
+pub use crate::data_structures::KdTree;
+pub use crate::data_structures::{
+    BinarySearchTree, BitSet, FenwickTree, Heap, IndexedPriorityQueue, LinkedList, Queue, Trie,
+    UnionFind,
+};
+#[cfg(feature = "dp")]
+pub use crate::dp::{
+    knapsack_01, longest_increasing_subsequence, memoize, subset_sum, word_break, Memo,
+};
+pub use crate::error::AlgoError;
+#[cfg(feature = "graph")]
+pub use crate::graph::{
+    bfs, dfs, dijkstra, floyd_warshall, kruskal_mst, AdjacencyMatrix, Graph, GraphError,
+};
+#[cfg(feature = "math")]
+pub use crate::math::{
+    gcd, is_prime_u64, lcm, mod_inverse, pow_mod, BigInt, Integer, Matrix, ModInt, Rational,
+    SignedInteger,
+};
+#[cfg(feature = "searching")]
+pub use crate::searching::{binary_search, lower_bound, upper_bound};
+#[cfg(feature = "sorting")]
+pub use crate::sorting::{
+    heap_sort, insertion_sort, intro_sort, merge_sort, quickselect, tim_sort, SortAlgorithm, Sorter,
+};
+#[cfg(feature = "strings")]
+pub use crate::strings::{RollingHash, StringHasher, SuffixAutomaton};
+pub use crate::utils::random::Rng;
+pub use crate::visualization::{Observer, Step};
+
+#[cfg(all(test, feature = "sorting", feature = "searching", feature = "math"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prelude_is_enough_for_common_tasks() {
+        let mut rng = Rng::new(1494);
+        let mut values: Vec<i64> = (0..100).map(|_| rng.gen_range(-50, 50)).collect();
+        merge_sort(&mut values);
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+        let index = lower_bound(&values, &0);
+        assert!(values[..index].iter().all(|&v| v < 0));
+
+        let mut heap = Heap::new_min();
+        let mut sets = UnionFind::new(4);
+        for value in [3, 1, 2] {
+            heap.push(value);
+        }
+        sets.union(0, 1);
+        assert_eq!(heap.pop(), Some(1));
+        assert!(sets.connected(1, 0));
+        assert_eq!(gcd(12u64, 18), 6);
+    }
+}
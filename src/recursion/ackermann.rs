@@ -0,0 +1,129 @@
+/*!
+定义：
+[阿克曼函数](https://zh.wikipedia.org/wiki/%E9%98%BF%E5%85%8B%E6%9B%BC%E5%87%BD%E6%95%B8)
+- A(0, n) = n + 1
+- A(m, 0) = A(m - 1, 1)
+- A(m, n) = A(m - 1, A(m, n - 1))
+
+它是可计算但不是原始递归的函数，增长极快：A(3, n) = 2^(n+3) - 3，A(4, 2) 已经有 19729 位。
+直接递归的深度和函数值同阶，这里用显式的栈代替调用栈，并记住算过的值。
+记忆表的大小有上限，超过上限就放弃，防止在算不出来的参数上耗尽内存
+*/
+
+use std::collections::HashMap;
+
+/// 默认最多记住的值的个数，足够算出 A(4, 1) = 65533
+pub const DEFAULT_MEMO_LIMIT: usize = 1 << 20;
+
+/// 带记忆表的阿克曼函数，可以重复使用已经算过的值
+#[derive(Debug, Clone)]
+pub struct Ackermann {
+    memo: HashMap<(u64, u64), u64>,
+    limit: usize,
+}
+
+/// 显式栈上的一帧
+enum Frame {
+    /// 计算 A(m, n)
+    Eval(u64, u64),
+    /// 已经算出 A(m, n - 1)，接下来计算 A(m - 1, A(m, n - 1))
+    Outer(u64, u64),
+    /// 刚算出的值就是 A(m, n)，记下来
+    Store(u64, u64),
+}
+
+impl Ackermann {
+    /// 最多记住 `limit` 个值
+    pub fn new(limit: usize) -> Self {
+        Ackermann {
+            memo: HashMap::new(),
+            limit,
+        }
+    }
+
+    /// 已经记住的值的个数
+    pub fn memo_len(&self) -> usize {
+        self.memo.len()
+    }
+
+    /// A(m, n)，记忆表超过上限或者结果超出 u64 时返回 None
+    pub fn compute(&mut self, m: u64, n: u64) -> Option<u64> {
+        let mut stack = vec![Frame::Eval(m, n)];
+        // 最近一次算出的值
+        let mut value = 0;
+        while let Some(frame) = stack.pop() {
+            match frame {
+                Frame::Eval(m, n) => {
+                    if let Some(&known) = self.memo.get(&(m, n)) {
+                        value = known;
+                    } else if m == 0 {
+                        value = n.checked_add(1)?;
+                    } else if n == 0 {
+                        stack.push(Frame::Store(m, 0));
+                        stack.push(Frame::Eval(m - 1, 1));
+                    } else {
+                        stack.push(Frame::Outer(m, n));
+                        stack.push(Frame::Eval(m, n - 1));
+                    }
+                }
+                Frame::Outer(m, n) => {
+                    stack.push(Frame::Store(m, n));
+                    stack.push(Frame::Eval(m - 1, value));
+                }
+                Frame::Store(m, n) => {
+                    if self.memo.len() >= self.limit {
+                        return None;
+                    }
+                    self.memo.insert((m, n), value);
+                }
+            }
+        }
+        Some(value)
+    }
+}
+
+impl Default for Ackermann {
+    fn default() -> Self {
+        Ackermann::new(DEFAULT_MEMO_LIMIT)
+    }
+}
+
+/// A(m, n)，使用默认的记忆表上限
+pub fn ackermann(m: u64, n: u64) -> Option<u64> {
+    Ackermann::default().compute(m, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ackermann, Ackermann};
+
+    #[test]
+    fn closed_forms_for_small_m() {
+        let mut memo = Ackermann::default();
+        for n in 0..10 {
+            assert_eq!(memo.compute(0, n), Some(n + 1));
+            assert_eq!(memo.compute(1, n), Some(n + 2));
+            assert_eq!(memo.compute(2, n), Some(2 * n + 3));
+            assert_eq!(memo.compute(3, n), Some((1 << (n + 3)) - 3));
+        }
+        assert_eq!(ackermann(4, 0), Some(13));
+        assert_eq!(ackermann(4, 1), Some(65533));
+        assert_eq!(ackermann(0, u64::MAX), None);
+    }
+
+    #[test]
+    fn reuses_memo_between_calls() {
+        let mut memo = Ackermann::default();
+        assert_eq!(memo.compute(3, 6), Some(509));
+        let size = memo.memo_len();
+        assert!(size > 0);
+        assert_eq!(memo.compute(3, 5), Some(253));
+        assert_eq!(memo.memo_len(), size);
+    }
+
+    #[test]
+    fn gives_up_when_memo_is_full() {
+        assert_eq!(Ackermann::new(100).compute(3, 6), None);
+        assert_eq!(Ackermann::new(100_000).compute(4, 2), None);
+    }
+}
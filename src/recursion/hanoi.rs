@@ -0,0 +1,115 @@
+/*!
+定义：
+[汉诺塔](https://zh.wikipedia.org/wiki/%E6%B1%89%E8%AF%BA%E5%A1%94)
+三根柱子，n 个大小不同的圆盘，每次移动一个，大盘不能压在小盘上，把整座塔从一根柱子移到另一根。
+递归解法：先把上面 n - 1 个移到中转柱，再移最大的，最后把 n - 1 个移回来，共 2^n - 1 步。
+第 m 步（从 1 开始）移动的是第 `trailing_zeros(m) + 1` 小的盘，从柱子 `(m & (m - 1)) % 3`
+移到 `((m | (m - 1)) + 1) % 3`，不需要递归就能直接算出每一步。
+按这个公式 n 为奇数时塔最终在 2 号柱、为偶数时在 1 号柱，再换一下柱子的编号即可
+*/
+
+/// 把第 `disk` 小的圆盘（从 1 开始）从柱子 `from` 移到 `to`，柱子编号为 0、1、2
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Move {
+    pub disk: u32,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// 递归求出把 `disks` 个圆盘从 `from` 移到 `to` 的所有步骤
+pub fn hanoi(disks: u32, from: usize, to: usize) -> Vec<Move> {
+    check_pegs(from, to);
+    let mut moves = vec![];
+    hanoi_recursive(disks, from, to, 3 - from - to, &mut moves);
+    moves
+}
+
+fn hanoi_recursive(disks: u32, from: usize, to: usize, via: usize, moves: &mut Vec<Move>) {
+    if disks == 0 {
+        return;
+    }
+    hanoi_recursive(disks - 1, from, via, to, moves);
+    moves.push(Move {
+        disk: disks,
+        from,
+        to,
+    });
+    hanoi_recursive(disks - 1, via, to, from, moves);
+}
+
+/// 用位运算逐步生成，和 [`hanoi`] 的结果相同。`disks` 不超过 63
+pub fn hanoi_iterative(disks: u32, from: usize, to: usize) -> impl Iterator<Item = Move> {
+    check_pegs(from, to);
+    assert!(disks < 64, "Too many disks: {disks}.");
+    let via = 3 - from - to;
+    // 公式里的 0、1、2 号柱分别对应哪根柱子
+    let pegs = if disks % 2 == 1 {
+        [from, via, to]
+    } else {
+        [from, to, via]
+    };
+    (1..1u64 << disks).map(move |m| Move {
+        disk: m.trailing_zeros() + 1,
+        from: pegs[((m & (m - 1)) % 3) as usize],
+        to: pegs[(((m | (m - 1)) + 1) % 3) as usize],
+    })
+}
+
+fn check_pegs(from: usize, to: usize) {
+    assert!(
+        from < 3 && to < 3 && from != to,
+        "Invalid pegs: from {from} to {to}."
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hanoi, hanoi_iterative, Move};
+
+    /// 在三根柱子上依次执行，检查每一步都合法，返回最后每根柱子上的圆盘
+    fn simulate(disks: u32, from: usize, moves: &[Move]) -> [Vec<u32>; 3] {
+        let mut pegs: [Vec<u32>; 3] = Default::default();
+        pegs[from] = (1..=disks).rev().collect();
+        for m in moves {
+            let disk = pegs[m.from].pop().expect("moved from an empty peg");
+            assert_eq!(disk, m.disk);
+            assert!(pegs[m.to].last().is_none_or(|&top| top > disk));
+            pegs[m.to].push(disk);
+        }
+        pegs
+    }
+
+    #[test]
+    fn small_towers() {
+        assert_eq!(hanoi(0, 0, 2), vec![]);
+        let moves = hanoi(2, 0, 2);
+        let pairs: Vec<(usize, usize)> = moves.iter().map(|m| (m.from, m.to)).collect();
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn both_variants_solve_the_puzzle() {
+        for disks in 0..=10 {
+            for (from, to) in [(0, 2), (0, 1), (2, 0), (1, 2)] {
+                let moves = hanoi(disks, from, to);
+                assert_eq!(moves.len(), (1 << disks) - 1);
+                let pegs = simulate(disks, from, &moves);
+                assert_eq!(pegs[to], (1..=disks).rev().collect::<Vec<_>>());
+                assert_eq!(hanoi_iterative(disks, from, to).collect::<Vec<_>>(), moves);
+            }
+        }
+    }
+
+    #[test]
+    fn iterative_is_lazy() {
+        // 前 7 步把最上面 3 个圆盘移走，3 和 63 奇偶性相同，所以目标柱也相同
+        let first: Vec<Move> = hanoi_iterative(63, 0, 2).take(7).collect();
+        assert_eq!(first, hanoi(3, 0, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_same_peg() {
+        hanoi(3, 1, 1);
+    }
+}
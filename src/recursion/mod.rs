@@ -0,0 +1,7 @@
+pub mod ackermann;
+pub mod hanoi;
+pub mod mutual;
+
+pub use self::ackermann::{ackermann, Ackermann};
+pub use self::hanoi::{hanoi, hanoi_iterative, Move};
+pub use self::mutual::{hofstadter_female, hofstadter_male, hofstadter_sequences, is_even, is_odd};
@@ -0,0 +1,92 @@
+/*!
+定义：
+[相互递归](https://en.wikipedia.org/wiki/Mutual_recursion)
+两个或多个函数互相调用对方。
+- 奇偶判断：n 是偶数当且仅当 n - 1 是奇数，n 是奇数当且仅当 n - 1 是偶数
+- [Hofstadter 雌雄序列](https://en.wikipedia.org/wiki/Hofstadter_sequence#Hofstadter_Female_and_Male_sequences)：
+  F(0) = 1，M(0) = 0，F(n) = n - M(F(n - 1))，M(n) = n - F(M(n - 1))
+
+递归深度都和 n 同阶，只适合较小的 n；[`hofstadter_sequences`] 按下标从小到大递推，可以算到很大的 n
+*/
+
+pub fn is_even(n: u32) -> bool {
+    n == 0 || is_odd(n - 1)
+}
+
+pub fn is_odd(n: u32) -> bool {
+    n != 0 && is_even(n - 1)
+}
+
+/// Hofstadter 雌序列 F(n)，直接相互递归，调用次数随 n 迅速增长
+pub fn hofstadter_female(n: u64) -> u64 {
+    if n == 0 {
+        1
+    } else {
+        n - hofstadter_male(hofstadter_female(n - 1))
+    }
+}
+
+/// Hofstadter 雄序列 M(n)
+pub fn hofstadter_male(n: u64) -> u64 {
+    if n == 0 {
+        0
+    } else {
+        n - hofstadter_female(hofstadter_male(n - 1))
+    }
+}
+
+/// F(0..len) 和 M(0..len)。M(n - 1) 小于 n，F(n - 1) 不超过 n，先算 M(n) 再算 F(n)，需要的值都已经算好了
+pub fn hofstadter_sequences(len: usize) -> (Vec<u64>, Vec<u64>) {
+    let mut female = Vec::with_capacity(len);
+    let mut male = Vec::with_capacity(len);
+    for n in 0..len {
+        if n == 0 {
+            female.push(1);
+            male.push(0);
+        } else {
+            male.push(n as u64 - female[male[n - 1] as usize]);
+            female.push(n as u64 - male[female[n - 1] as usize]);
+        }
+    }
+    (female, male)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hofstadter_female, hofstadter_male, hofstadter_sequences, is_even, is_odd};
+
+    const FEMALE: [u64; 21] = [
+        1, 1, 2, 2, 3, 3, 4, 5, 5, 6, 6, 7, 8, 8, 9, 9, 10, 11, 11, 12, 13,
+    ];
+    const MALE: [u64; 21] = [
+        0, 0, 1, 2, 2, 3, 4, 4, 5, 6, 6, 7, 7, 8, 9, 9, 10, 11, 11, 12, 12,
+    ];
+
+    #[test]
+    fn parity() {
+        for n in 0..1000 {
+            assert_eq!(is_even(n), n % 2 == 0);
+            assert_eq!(is_odd(n), n % 2 == 1);
+        }
+    }
+
+    #[test]
+    fn hofstadter_matches_known_terms() {
+        for n in 0..FEMALE.len() {
+            assert_eq!(hofstadter_female(n as u64), FEMALE[n]);
+            assert_eq!(hofstadter_male(n as u64), MALE[n]);
+        }
+        let (female, male) = hofstadter_sequences(10_000);
+        assert_eq!(female[..21], FEMALE);
+        assert_eq!(male[..21], MALE);
+        assert_eq!(hofstadter_sequences(0), (vec![], vec![]));
+        // 两个序列只在 n + 1 为斐波那契数时不相等
+        let fibonacci = [
+            1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610, 987, 1597,
+        ];
+        for n in 1..1000 {
+            let differs = female[n] != male[n];
+            assert_eq!(differs, fibonacci.contains(&(n + 1)), "n = {n}");
+        }
+    }
+}
@@ -0,0 +1,93 @@
+/*!
+二分答案：很多最优化问题可以转化为判定问题“答案为 x 时是否可行”。
+如果可行性关于 x 单调（x 可行则所有更大的值都可行），就能在答案的取值范围上二分，
+找到最小的可行值，只需要 O(log(hi - lo)) 次判定
+*/
+
+/// 在 `[low, high]` 中找到最小的满足 `predicate` 的值，没有满足的值时返回 None
+///
+/// `predicate` 必须是单调的：一旦某个值满足，比它大的值也都满足
+pub fn binary_search_answer<P>(low: i64, high: i64, mut predicate: P) -> Option<i64>
+where
+    P: FnMut(i64) -> bool,
+{
+    if low > high || !predicate(high) {
+        return None;
+    }
+    // 不变式：high 满足，low 之前的值都不满足
+    let (mut low, mut high) = (low, high);
+    while low < high {
+        // 用 i128 计算中点，避免 low + high 溢出
+        let mid = (low as i128 + (high as i128 - low as i128) / 2) as i64;
+        if predicate(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Some(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::binary_search_answer;
+
+    /// 按顺序装船，每天的载重不超过 capacity 时需要的天数
+    fn days_needed(weights: &[i64], capacity: i64) -> i64 {
+        let mut days = 1;
+        let mut load = 0;
+        for &weight in weights {
+            if load + weight > capacity {
+                days += 1;
+                load = 0;
+            }
+            load += weight;
+        }
+        days
+    }
+
+    /// 在 `days` 天内运完所有包裹的最小载重
+    fn ship_within_days(weights: &[i64], days: i64) -> i64 {
+        let low = *weights.iter().max().unwrap();
+        let high = weights.iter().sum();
+        binary_search_answer(low, high, |capacity| days_needed(weights, capacity) <= days).unwrap()
+    }
+
+    /// 向下取整的平方根：最小的平方大于 n 的数减一
+    fn floor_sqrt(n: i64) -> i64 {
+        binary_search_answer(0, 3_037_000_500, |x| {
+            x.checked_mul(x).is_none_or(|square| square > n)
+        })
+        .unwrap()
+            - 1
+    }
+
+    #[test]
+    fn minimum_shipping_capacity() {
+        let weights = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        assert_eq!(ship_within_days(&weights, 5), 15);
+        assert_eq!(ship_within_days(&[3, 2, 2, 4, 1, 4], 3), 6);
+        assert_eq!(ship_within_days(&[1, 2, 3, 1, 1], 4), 3);
+        assert_eq!(ship_within_days(&weights, 1), 55);
+    }
+
+    #[test]
+    fn square_root_flooring() {
+        for n in 0..2000 {
+            let root = floor_sqrt(n);
+            assert!(root * root <= n && (root + 1) * (root + 1) > n, "{n}");
+        }
+        assert_eq!(floor_sqrt(i64::MAX), 3_037_000_499);
+    }
+
+    #[test]
+    fn unsatisfiable_and_extreme_ranges() {
+        assert_eq!(binary_search_answer(0, 100, |_| false), None);
+        assert_eq!(binary_search_answer(5, 4, |_| true), None);
+        assert_eq!(binary_search_answer(-10, 10, |_| true), Some(-10));
+        assert_eq!(
+            binary_search_answer(i64::MIN, i64::MAX, |x| x >= 42),
+            Some(42)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+/*!
+定义：
+[二分查找的定义](https://zh.wikipedia.org/wiki/%E4%BA%8C%E5%88%86%E6%90%9C%E5%B0%8B%E6%BC%94%E7%AE%97%E6%B3%95)
+在有序数组中每次和中间元素比较，排除一半的区间，复杂度 O(log n)。
+所有函数都建立在 `partition_point` 之上：数组被谓词分成“满足”在前、“不满足”在后两段，返回分界点的下标
+*/
+
+use std::ops::Range;
+
+/// 查找 `target`，存在时返回它的下标（有重复元素时返回第一个），否则返回 None
+pub fn binary_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    let index = lower_bound(arr, target);
+    if index < arr.len() && arr[index] == *target {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+/// 第一个不小于 `target` 的元素的下标，都小于时返回 `arr.len()`
+pub fn lower_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    partition_point(arr, |x| x < target)
+}
+
+/// 第一个大于 `target` 的元素的下标，都不大于时返回 `arr.len()`
+pub fn upper_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    partition_point(arr, |x| x <= target)
+}
+
+/// 所有等于 `target` 的元素所在的区间，不存在时是一个空区间，起点为插入位置
+pub fn equal_range<T: Ord>(arr: &[T], target: &T) -> Range<usize> {
+    lower_bound(arr, target)..upper_bound(arr, target)
+}
+
+/// 要求数组中满足 `pred` 的元素都在不满足的元素之前，返回第一个不满足的元素的下标
+pub fn partition_point<T, P>(arr: &[T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    // 不变式：[0, low) 都满足，[high, len) 都不满足
+    let (mut low, mut high) = (0, arr.len());
+    while low < high {
+        // 写成 low + (high - low) / 2 而不是 (low + high) / 2，避免加法溢出
+        let mid = low + (high - low) / 2;
+        if pred(&arr[mid]) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 枚举长度 0..=7、元素取值 1..=4 的所有有序数组
+    fn all_sorted_arrays() -> Vec<Vec<u8>> {
+        let mut result = vec![vec![]];
+        let mut frontier = vec![vec![]];
+        for _ in 0..7 {
+            let mut next = vec![];
+            for arr in &frontier {
+                let start = arr.last().copied().unwrap_or(1);
+                for value in start..=4 {
+                    let mut extended: Vec<u8> = arr.clone();
+                    extended.push(value);
+                    next.push(extended);
+                }
+            }
+            result.extend(next.iter().cloned());
+            frontier = next;
+        }
+        result
+    }
+
+    #[test]
+    fn matches_linear_scan_exhaustively() {
+        for arr in all_sorted_arrays() {
+            // 目标值也覆盖比所有元素都小和都大的情况
+            for target in 0..=5 {
+                let lower = arr.iter().take_while(|&&x| x < target).count();
+                let upper = arr.iter().take_while(|&&x| x <= target).count();
+                assert_eq!(lower_bound(&arr, &target), lower, "{arr:?} {target}");
+                assert_eq!(upper_bound(&arr, &target), upper, "{arr:?} {target}");
+                assert_eq!(equal_range(&arr, &target), lower..upper);
+                let expected = arr.iter().position(|&x| x == target);
+                assert_eq!(binary_search(&arr, &target), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn partition_point_with_predicate() {
+        let arr = [1, 3, 5, 7, 2, 4, 6];
+        // 奇数在前、偶数在后
+        assert_eq!(partition_point(&arr, |x| x % 2 == 1), 4);
+        assert_eq!(partition_point(&arr, |_| true), arr.len());
+        assert_eq!(partition_point(&arr, |_| false), 0);
+        assert_eq!(partition_point::<i32, _>(&[], |_| true), 0);
+    }
+
+    #[test]
+    fn handles_extreme_values() {
+        let arr = [i64::MIN, -1, 0, 0, 0, i64::MAX];
+        assert_eq!(equal_range(&arr, &0), 2..5);
+        assert_eq!(binary_search(&arr, &i64::MIN), Some(0));
+        assert_eq!(binary_search(&arr, &i64::MAX), Some(5));
+        assert_eq!(binary_search(&arr, &1), None);
+        assert_eq!(lower_bound(&arr, &1), 5);
+    }
+}
@@ -0,0 +1,88 @@
+/*!
+定义：
+[指数搜索](https://en.wikipedia.org/wiki/Exponential_search)
+依次检查下标 1, 2, 4, 8, ... 直到越过目标，再在最后一段里二分，复杂度 O(log i)，i 是目标所在的位置。
+它不需要事先知道序列的长度，因此也适用于无界的有序序列；目标越靠前找得越快
+*/
+
+use std::cmp::Ordering;
+
+/// 在有序切片中查找 `target`，存在时返回第一个等于它的下标
+pub fn exponential_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    exponential_search_by(|i| arr.get(i).map(|x| x.cmp(target)))
+}
+
+/// 在有序迭代器中查找 `target`，通过 `clone().nth(i)` 按下标访问
+///
+/// 对切片迭代器、区间等 `nth` 为 O(1) 的迭代器只需要 O(log i) 次访问；迭代器可以是无限的
+pub fn exponential_search_iter<I>(iter: I, target: &I::Item) -> Option<usize>
+where
+    I: Iterator + Clone,
+    I::Item: Ord,
+{
+    exponential_search_by(|i| iter.clone().nth(i).map(|x| x.cmp(target)))
+}
+
+/// `compare(i)` 返回下标 i 处的元素和目标比较的结果，返回 None 表示 i 已经超出序列末尾
+///
+/// 序列的元素必须按 `Less`、`Equal`、`Greater` 的顺序排列，返回第一个 `Equal` 的下标
+pub fn exponential_search_by<F>(mut compare: F) -> Option<usize>
+where
+    F: FnMut(usize) -> Option<Ordering>,
+{
+    // 越过末尾当作比目标大处理
+    let mut is_less = |i| compare(i) == Some(Ordering::Less);
+
+    // 找到第一个不小于目标的 2 的幂次下标 bound（或 0），目标只可能在 [bound / 2, bound] 中
+    let mut bound = 0;
+    while is_less(bound) {
+        bound = if bound == 0 { 1 } else { bound.checked_mul(2)? };
+    }
+
+    let (mut low, mut high) = (bound / 2, bound);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if is_less(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    (compare(low) == Some(Ordering::Equal)).then_some(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{exponential_search, exponential_search_iter};
+    use crate::searching::binary_search;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn agrees_with_binary_search() {
+        let mut rng = Rng::new(1386);
+        for _ in 0..200 {
+            let len = rng.gen_index(100);
+            let mut arr: Vec<i64> = (0..len).map(|_| rng.gen_range(-50, 50)).collect();
+            arr.sort();
+            for target in -52..52 {
+                let expected = binary_search(&arr, &target);
+                assert_eq!(exponential_search(&arr, &target), expected);
+                assert_eq!(exponential_search_iter(arr.iter(), &&target), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn searches_unbounded_sequences() {
+        let squares = (0u64..).map(|x| x * x);
+        assert_eq!(exponential_search_iter(squares.clone(), &0), Some(0));
+        assert_eq!(
+            exponential_search_iter(squares.clone(), &1_000_000),
+            Some(1000)
+        );
+        assert_eq!(exponential_search_iter(squares, &999_999), None);
+
+        let odds = (1u64..).step_by(2);
+        assert_eq!(exponential_search_iter(odds, &12_345), Some(6172));
+    }
+}
@@ -0,0 +1,61 @@
+/*!
+定义：
+[跳跃搜索](https://en.wikipedia.org/wiki/Jump_search)
+把有序数组分成长度为 √n 的块，先按块跳跃找到目标所在的块，再在块内线性查找，复杂度 O(√n)。
+比二分查找慢，但只需要向前移动，适合回退代价较高的存储
+*/
+
+/// 在有序切片中查找 `target`，存在时返回第一个等于它的下标
+pub fn jump_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    let len = arr.len();
+    let step = len.isqrt().max(1);
+
+    // 跳到第一个末尾元素不小于目标的块
+    let mut start = 0;
+    while start < len && arr[(start + step).min(len) - 1] < *target {
+        start += step;
+    }
+    if start >= len {
+        return None;
+    }
+
+    arr[start..(start + step).min(len)]
+        .iter()
+        .position(|x| x >= target)
+        .map(|offset| start + offset)
+        .filter(|&i| arr[i] == *target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::jump_search;
+    use crate::searching::binary_search;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn agrees_with_binary_search() {
+        let mut rng = Rng::new(1386);
+        for _ in 0..200 {
+            let len = rng.gen_index(150);
+            let mut arr: Vec<i64> = (0..len).map(|_| rng.gen_range(-50, 50)).collect();
+            arr.sort();
+            for target in -52..52 {
+                assert_eq!(
+                    jump_search(&arr, &target),
+                    binary_search(&arr, &target),
+                    "{arr:?} {target}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finds_block_boundaries() {
+        let arr: Vec<u32> = (0..100).collect();
+        for target in 0..100 {
+            assert_eq!(jump_search(&arr, &target), Some(target as usize));
+        }
+        assert_eq!(jump_search(&arr, &100), None);
+        assert_eq!(jump_search(&[] as &[u32], &0), None);
+    }
+}
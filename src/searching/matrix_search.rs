@@ -0,0 +1,114 @@
+/*!
+在二维有序矩阵中查找：
+- 每行、每列都递增的矩阵使用[鞍背搜索](https://en.wikipedia.org/wiki/Saddleback_search)（阶梯搜索）：
+  从右上角出发，当前元素比目标大就左移一列，比目标小就下移一行，复杂度 O(n + m)
+- 按行展开后整体递增的矩阵（每行第一个元素大于上一行最后一个元素）可以看成一维有序数组，直接二分，O(log(nm))
+*/
+
+use std::cmp::Ordering;
+
+/// 在行、列都递增的矩阵中查找 `target`，返回任意一个等于它的位置 `(row, column)`
+pub fn search_sorted_matrix<T: Ord>(matrix: &[Vec<T>], target: &T) -> Option<(usize, usize)> {
+    let columns = matrix.first().map_or(0, |row| row.len());
+    let (mut row, mut column) = (0, columns);
+    // 右上角之外的部分已经排除：column 右边的列都比目标大，row 上面的行都比目标小
+    while row < matrix.len() && column > 0 {
+        match matrix[row][column - 1].cmp(target) {
+            Ordering::Equal => return Some((row, column - 1)),
+            Ordering::Greater => column -= 1,
+            Ordering::Less => row += 1,
+        }
+    }
+    None
+}
+
+/// 在按行展开后整体递增的矩阵中查找 `target`，返回第一个等于它的位置 `(row, column)`
+pub fn search_row_major_matrix<T: Ord>(matrix: &[Vec<T>], target: &T) -> Option<(usize, usize)> {
+    let columns = matrix.first().map_or(0, |row| row.len());
+    if columns == 0 {
+        return None;
+    }
+    let at = |index: usize| &matrix[index / columns][index % columns];
+
+    let (mut low, mut high) = (0, matrix.len() * columns);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if at(mid) < target {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    if low < matrix.len() * columns && at(low) == target {
+        Some((low / columns, low % columns))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{search_row_major_matrix, search_sorted_matrix};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn saddleback_finds_every_element() {
+        let matrix = vec![
+            vec![1, 4, 7, 11, 15],
+            vec![2, 5, 8, 12, 19],
+            vec![3, 6, 9, 16, 22],
+            vec![10, 13, 14, 17, 24],
+            vec![18, 21, 23, 26, 30],
+        ];
+        for (r, row) in matrix.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                assert_eq!(search_sorted_matrix(&matrix, value), Some((r, c)));
+            }
+        }
+        for missing in [0, 20, 25, 31] {
+            assert_eq!(search_sorted_matrix(&matrix, &missing), None);
+        }
+        assert_eq!(search_sorted_matrix::<i32>(&[], &1), None);
+        assert_eq!(search_sorted_matrix(&[vec![]], &1), None);
+    }
+
+    #[test]
+    fn saddleback_on_random_young_tableaux() {
+        let mut rng = Rng::new(1390);
+        for _ in 0..50 {
+            let (rows, columns) = (1 + rng.gen_index(8), 1 + rng.gen_index(8));
+            // 每个元素等于上方和左方的较大值加上一个非负增量，保证行列都递增（允许重复）
+            let mut matrix = vec![vec![0i64; columns]; rows];
+            for r in 0..rows {
+                for c in 0..columns {
+                    let up = if r > 0 { matrix[r - 1][c] } else { 0 };
+                    let left = if c > 0 { matrix[r][c - 1] } else { 0 };
+                    matrix[r][c] = up.max(left) + rng.gen_range(0, 3);
+                }
+            }
+            for target in -1..40 {
+                let found = search_sorted_matrix(&matrix, &target);
+                let exists = matrix.iter().flatten().any(|&x| x == target);
+                assert_eq!(found.is_some(), exists);
+                if let Some((r, c)) = found {
+                    assert_eq!(matrix[r][c], target);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn row_major_binary_search() {
+        let matrix = vec![vec![1, 3, 5, 7], vec![10, 11, 16, 20], vec![23, 30, 34, 60]];
+        assert_eq!(search_row_major_matrix(&matrix, &3), Some((0, 1)));
+        assert_eq!(search_row_major_matrix(&matrix, &10), Some((1, 0)));
+        assert_eq!(search_row_major_matrix(&matrix, &60), Some((2, 3)));
+        assert_eq!(search_row_major_matrix(&matrix, &13), None);
+        assert_eq!(search_row_major_matrix(&matrix, &0), None);
+        assert_eq!(search_row_major_matrix(&matrix, &61), None);
+        assert_eq!(search_row_major_matrix::<i32>(&[], &1), None);
+
+        let dupes = vec![vec![1, 2, 2], vec![2, 2, 3]];
+        assert_eq!(search_row_major_matrix(&dupes, &2), Some((0, 1)));
+    }
+}
@@ -0,0 +1,20 @@
+pub mod answer_search;
+pub mod binary_search;
+pub mod exponential_search;
+pub mod jump_search;
+pub mod matrix_search;
+pub mod ternary_search;
+
+pub use self::answer_search::binary_search_answer;
+pub use self::binary_search::{
+    binary_search, equal_range, lower_bound, partition_point, upper_bound,
+};
+pub use self::exponential_search::{
+    exponential_search, exponential_search_by, exponential_search_iter,
+};
+pub use self::jump_search::jump_search;
+pub use self::matrix_search::{search_row_major_matrix, search_sorted_matrix};
+pub use self::ternary_search::{
+    ternary_search_max, ternary_search_max_int, ternary_search_min, ternary_search_min_int,
+    StopCondition,
+};
@@ -0,0 +1,176 @@
+/*!
+定义：
+[三分搜索](https://zh.wikipedia.org/wiki/%E4%B8%89%E5%88%86%E6%90%9C%E5%B0%8B)
+在单峰函数（先严格增后严格减，或反过来）上找极值：取区间的两个三等分点 m1 < m2 比较函数值，
+极大值一定不在较小的那一侧之外，每次丢掉三分之一的区间
+*/
+
+/// 浮点数迭代算法的停止条件
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StopCondition {
+    /// 误差（三分搜索中是区间长度）不超过 epsilon 时停止。
+    /// epsilon 小于区间内相邻浮点数的间距时，区间无法再缩小也会停止
+    Epsilon(f64),
+    /// 固定迭代次数。三分搜索每次区间缩小为原来的 2/3，100 次足以收敛到 f64 的精度
+    Iterations(usize),
+}
+
+/// 整数区间 `[low, high]` 上单峰函数的极大值点
+pub fn ternary_search_max_int<T, F>(low: i64, high: i64, f: F) -> i64
+where
+    T: PartialOrd,
+    F: FnMut(i64) -> T,
+{
+    search_int(low, high, f, |a, b| a < b)
+}
+
+/// 整数区间 `[low, high]` 上单峰函数的极小值点
+pub fn ternary_search_min_int<T, F>(low: i64, high: i64, f: F) -> i64
+where
+    T: PartialOrd,
+    F: FnMut(i64) -> T,
+{
+    search_int(low, high, f, |a, b| a > b)
+}
+
+/// 浮点数区间 `[low, high]` 上单峰函数的极大值点
+pub fn ternary_search_max<F>(low: f64, high: f64, stop: StopCondition, f: F) -> f64
+where
+    F: FnMut(f64) -> f64,
+{
+    search_f64(low, high, stop, f, |a, b| a < b)
+}
+
+/// 浮点数区间 `[low, high]` 上单峰函数的极小值点
+pub fn ternary_search_min<F>(low: f64, high: f64, stop: StopCondition, f: F) -> f64
+where
+    F: FnMut(f64) -> f64,
+{
+    search_f64(low, high, stop, f, |a, b| a > b)
+}
+
+/// `worse(a, b)` 为 true 表示函数值 a 不如 b，极值点不会在 a 的外侧
+fn search_int<T, F>(mut low: i64, mut high: i64, mut f: F, worse: fn(&T, &T) -> bool) -> i64
+where
+    F: FnMut(i64) -> T,
+{
+    assert!(low <= high, "Empty range [{low}, {high}].");
+    // 用 i128 计算区间长度，low 和 high 取到 i64 的两端也不会溢出
+    let span = |low: i64, high: i64| high as i128 - low as i128;
+    while span(low, high) > 2 {
+        let third = (span(low, high) / 3) as i64;
+        let (m1, m2) = (low + third, high - third);
+        if worse(&f(m1), &f(m2)) {
+            low = m1 + 1;
+        } else {
+            high = m2 - 1;
+        }
+    }
+
+    // 剩下不超过 3 个点，逐个比较
+    let mut best = low;
+    let mut best_value = f(low);
+    for x in low + 1..=high {
+        let value = f(x);
+        if worse(&best_value, &value) {
+            best = x;
+            best_value = value;
+        }
+    }
+    best
+}
+
+fn search_f64<F>(
+    mut low: f64,
+    mut high: f64,
+    stop: StopCondition,
+    mut f: F,
+    worse: fn(f64, f64) -> bool,
+) -> f64
+where
+    F: FnMut(f64) -> f64,
+{
+    // 返回区间是否缩小了，三等分点因为舍入和端点重合时不再缩小
+    let mut step = |low: &mut f64, high: &mut f64| {
+        let third = (*high - *low) / 3.0;
+        let (m1, m2) = (*low + third, *high - third);
+        if worse(f(m1), f(m2)) {
+            let moved = m1 > *low;
+            *low = m1;
+            moved
+        } else {
+            let moved = m2 < *high;
+            *high = m2;
+            moved
+        }
+    };
+
+    match stop {
+        StopCondition::Epsilon(epsilon) => {
+            assert!(epsilon > 0.0, "Epsilon must be positive.");
+            while high - low > epsilon && step(&mut low, &mut high) {}
+        }
+        StopCondition::Iterations(iterations) => {
+            for _ in 0..iterations {
+                step(&mut low, &mut high);
+            }
+        }
+    }
+    low + (high - low) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_integer_extrema() {
+        for peak in -20..20 {
+            let f = |x: i64| -(x - peak).abs();
+            assert_eq!(ternary_search_max_int(-20, 20, f), peak);
+            let g = |x: i64| (x - peak) * (x - peak);
+            assert_eq!(ternary_search_min_int(-20, 20, g), peak);
+        }
+        // 极值在区间端点
+        assert_eq!(ternary_search_max_int(0, 100, |x| x), 100);
+        assert_eq!(ternary_search_min_int(0, 100, |x| x), 0);
+        assert_eq!(ternary_search_max_int(5, 5, |x| x), 5);
+    }
+
+    #[test]
+    fn handles_full_i64_range() {
+        let peak = 123_456_789_i64;
+        let f = |x: i64| -((x as i128 - peak as i128).abs());
+        assert_eq!(ternary_search_max_int(i64::MIN, i64::MAX, f), peak);
+    }
+
+    #[test]
+    fn finds_real_extrema() {
+        // sin 在 [0, π] 上的极大值点是 π/2
+        let pi = std::f64::consts::PI;
+        let x = ternary_search_max(0.0, pi, StopCondition::Epsilon(1e-9), f64::sin);
+        assert!((x - pi / 2.0).abs() < 1e-6);
+
+        let x = ternary_search_min(-10.0, 10.0, StopCondition::Iterations(100), |x| {
+            (x - 1.5) * (x - 1.5) + 2.0
+        });
+        // 极小值附近函数值的变化小于 f64 的精度，只能精确到 √ε 量级
+        assert!((x - 1.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn stops_when_epsilon_is_below_float_spacing() {
+        // 1e10 附近相邻浮点数相差约 2e-6，区间不可能缩小到 1e-9
+        let peak = 1e10 + 0.25;
+        let x = ternary_search_max(1e10, 1e10 + 1.0, StopCondition::Epsilon(1e-9), |x| {
+            -(x - peak).abs()
+        });
+        assert!((x - peak).abs() < 1e-4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_empty_range() {
+        ternary_search_max_int(1, 0, |x| x);
+    }
+}
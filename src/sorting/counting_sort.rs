@@ -0,0 +1,112 @@
+/*!
+定义：
+[计数排序的定义](https://zh.wikipedia.org/wiki/%E8%AE%A1%E6%95%B0%E6%8E%92%E5%BA%8F)
+统计每个键出现的次数，再通过前缀和算出每个元素的最终位置，时间复杂度 O(n + k)，k 为键的取值范围
+*/
+
+/// 对取值有界的整数排序，键的范围由数组中的最小值和最大值决定
+pub fn counting_sort(arr: &mut [u32]) {
+    let (min, max) = match (arr.iter().min(), arr.iter().max()) {
+        (Some(&min), Some(&max)) => (min, max),
+        // 空数组不需要排序
+        _ => return,
+    };
+
+    let mut counts = vec![0usize; (max - min) as usize + 1];
+    for &value in arr.iter() {
+        counts[(value - min) as usize] += 1;
+    }
+
+    // 整数本身就是键，按顺序把计数展开写回即可
+    let mut index = 0;
+    for (offset, &count) in counts.iter().enumerate() {
+        for slot in &mut arr[index..index + count] {
+            *slot = min + offset as u32;
+        }
+        index += count;
+    }
+}
+
+/// 按照 `key` 提取出的整数键进行稳定排序，键必须小于 `key_bound`
+///
+/// 用于按某个整数字段对结构体排序，相同键的元素保持原有的相对顺序
+pub fn counting_sort_by_key<T, F>(arr: &mut [T], key_bound: usize, key: F)
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    let mut counts = vec![0usize; key_bound + 1];
+    for item in arr.iter() {
+        let k = key(item);
+        assert!(k < key_bound, "Key {k} is out of bound {key_bound}.");
+        // 错开一位计数，前缀和之后 counts[k] 就是键 k 的起始位置
+        counts[k + 1] += 1;
+    }
+
+    for i in 1..counts.len() {
+        counts[i] += counts[i - 1];
+    }
+
+    // 从前向后放置元素，保证排序是稳定的
+    let mut output: Vec<Option<T>> = vec![None; arr.len()];
+    for item in arr.iter() {
+        let k = key(item);
+        output[counts[k]] = Some(item.clone());
+        counts[k] += 1;
+    }
+
+    for (slot, item) in arr.iter_mut().zip(output) {
+        *slot = item.unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{counting_sort, counting_sort_by_key};
+
+    #[test]
+    fn sorts_integers() {
+        let mut arr = vec![5, 3, 9, 3, 0, 7, 1, 9];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![0, 1, 3, 3, 5, 7, 9, 9]);
+    }
+
+    #[test]
+    fn handles_empty_and_offset_ranges() {
+        let mut empty: Vec<u32> = vec![];
+        counting_sort(&mut empty);
+        assert!(empty.is_empty());
+
+        let mut arr = vec![1_000_003, 1_000_001, 1_000_002, 1_000_001];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![1_000_001, 1_000_001, 1_000_002, 1_000_003]);
+    }
+
+    #[test]
+    fn sorts_structs_by_key_stably() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Student {
+            name: &'static str,
+            grade: usize,
+        }
+
+        let student = |name, grade| Student { name, grade };
+        let mut students = vec![
+            student("a", 3),
+            student("b", 1),
+            student("c", 3),
+            student("d", 0),
+            student("e", 1),
+        ];
+        counting_sort_by_key(&mut students, 4, |s| s.grade);
+        let names: Vec<_> = students.iter().map(|s| s.name).collect();
+        assert_eq!(names, vec!["d", "b", "e", "a", "c"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_key_out_of_bound() {
+        let mut arr = vec![1usize, 5];
+        counting_sort_by_key(&mut arr, 5, |x| *x);
+    }
+}
@@ -0,0 +1,324 @@
+/*!
+一些不常用但很有启发性的排序算法，复杂度大多是 O(n²)，适合用来理解排序的不同思路：
+- [梳排序](https://zh.wikipedia.org/wiki/%E6%A2%B3%E6%8E%92%E5%BA%8F)：间隔逐渐缩小的冒泡排序，先消灭远处的“乌龟”（靠后的小元素）
+- [侏儒排序](https://en.wikipedia.org/wiki/Gnome_sort)：只用一个指针的插入排序，遇到逆序就交换并后退一步
+- [鸡尾酒排序](https://zh.wikipedia.org/wiki/%E9%B8%A1%E5%B0%BE%E9%85%92%E6%8E%92%E5%BA%8F)：双向冒泡排序
+- [圈排序](https://en.wikipedia.org/wiki/Cycle_sort)：把排列拆成若干个环逐个放好，写入次数理论最少
+- [煎饼排序](https://en.wikipedia.org/wiki/Pancake_sorting)：只允许“翻转前 k 个元素”这一种操作
+
+它们都只靠比较和交换移动元素，`*_observed` 版本把每次比较和交换报告给 [`Observer`]，
+下标是元素在 `arr` 中的当前位置
+*/
+
+use super::sorter::Sorter;
+use crate::instrumented::Counters;
+use crate::visualization::Observer;
+
+/// 梳排序每轮间隔缩小的比例
+const COMB_SHRINK: f64 = 1.3;
+
+/// `arr[i] > arr[j]`，并报告这次比较
+fn greater<T: Ord, O: Observer + ?Sized>(arr: &[T], i: usize, j: usize, observer: &mut O) -> bool {
+    observer.on_compare(i, j);
+    arr[i] > arr[j]
+}
+
+fn swap<T, O: Observer + ?Sized>(arr: &mut [T], i: usize, j: usize, observer: &mut O) {
+    arr.swap(i, j);
+    observer.on_swap(i, j);
+}
+
+pub fn comb_sort<T: Ord>(arr: &mut [T]) {
+    comb_sort_observed(arr, &mut ());
+}
+
+pub fn comb_sort_observed<T: Ord, O: Observer + ?Sized>(arr: &mut [T], observer: &mut O) {
+    let mut gap = arr.len();
+    let mut sorted = false;
+    while !sorted {
+        gap = ((gap as f64 / COMB_SHRINK) as usize).max(1);
+        // 间隔缩小到 1 之后就是冒泡排序，一轮没有交换说明已经有序
+        sorted = gap == 1;
+        for i in 0..arr.len().saturating_sub(gap) {
+            if greater(arr, i, i + gap, observer) {
+                swap(arr, i, i + gap, observer);
+                sorted = false;
+            }
+        }
+    }
+}
+
+pub fn gnome_sort<T: Ord>(arr: &mut [T]) {
+    gnome_sort_observed(arr, &mut ());
+}
+
+pub fn gnome_sort_observed<T: Ord, O: Observer + ?Sized>(arr: &mut [T], observer: &mut O) {
+    let mut i = 1;
+    while i < arr.len() {
+        if i == 0 || !greater(arr, i - 1, i, observer) {
+            i += 1;
+        } else {
+            swap(arr, i - 1, i, observer);
+            i -= 1;
+        }
+    }
+}
+
+pub fn cocktail_shaker_sort<T: Ord>(arr: &mut [T]) {
+    cocktail_shaker_sort_observed(arr, &mut ());
+}
+
+pub fn cocktail_shaker_sort_observed<T: Ord, O: Observer + ?Sized>(
+    arr: &mut [T],
+    observer: &mut O,
+) {
+    if arr.is_empty() {
+        return;
+    }
+    // [start, end] 之外的元素已经就位
+    let (mut start, mut end) = (0, arr.len() - 1);
+    while start < end {
+        let mut last_swap = start;
+        // 正向把最大值冒泡到末尾
+        for i in start..end {
+            if greater(arr, i, i + 1, observer) {
+                swap(arr, i, i + 1, observer);
+                last_swap = i;
+            }
+        }
+        end = last_swap;
+
+        // 反向把最小值冒泡到开头
+        let mut first_swap = end;
+        for i in (start..end).rev() {
+            if greater(arr, i, i + 1, observer) {
+                swap(arr, i, i + 1, observer);
+                first_swap = i + 1;
+            }
+        }
+        start = first_swap;
+    }
+}
+
+/// 圈排序，返回写入（交换）的次数，已经在正确位置的元素不会被移动
+pub fn cycle_sort<T: Ord>(arr: &mut [T]) -> usize {
+    cycle_sort_observed(arr, &mut ())
+}
+
+pub fn cycle_sort_observed<T: Ord, O: Observer + ?Sized>(arr: &mut [T], observer: &mut O) -> usize {
+    let mut writes = 0;
+    for cycle_start in 0..arr.len() {
+        loop {
+            // arr[cycle_start] 的最终位置 = 起点 + 后面比它小的元素个数
+            let mut pos = cycle_start;
+            for i in cycle_start + 1..arr.len() {
+                if greater(arr, cycle_start, i, observer) {
+                    pos += 1;
+                }
+            }
+            if pos == cycle_start {
+                break;
+            }
+            // 跳过和它相等、已经放好的元素
+            while {
+                observer.on_compare(pos, cycle_start);
+                arr[pos] == arr[cycle_start]
+            } {
+                pos += 1;
+            }
+            swap(arr, cycle_start, pos, observer);
+            writes += 1;
+        }
+    }
+    writes
+}
+
+/// 煎饼排序，返回依次执行的翻转操作，每个值 k 表示翻转前 k 个元素
+pub fn pancake_sort<T: Ord>(arr: &mut [T]) -> Vec<usize> {
+    pancake_sort_observed(arr, &mut ())
+}
+
+/// 每次翻转报告为首尾两两交换
+pub fn pancake_sort_observed<T: Ord, O: Observer + ?Sized>(
+    arr: &mut [T],
+    observer: &mut O,
+) -> Vec<usize> {
+    let mut flips = vec![];
+    let mut flip = |arr: &mut [T], k: usize, observer: &mut O| {
+        for i in 0..k / 2 {
+            swap(arr, i, k - 1 - i, observer);
+        }
+        flips.push(k);
+    };
+    for size in (2..=arr.len()).rev() {
+        // 相等时取靠后的一个，和 max_by 一致
+        let max = (1..size).fold(0, |max, i| {
+            if greater(arr, max, i, observer) {
+                max
+            } else {
+                i
+            }
+        });
+        if max == size - 1 {
+            continue;
+        }
+        // 先把最大值翻到最上面，再整体翻到底部
+        if max > 0 {
+            flip(arr, max + 1, observer);
+        }
+        flip(arr, size, observer);
+    }
+    flips
+}
+
+macro_rules! esoteric_sorter {
+    ($name:ident, $label:expr, $stable:expr, $sort:ident, $observed:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name;
+
+        impl Sorter for $name {
+            fn name(&self) -> &'static str {
+                $label
+            }
+
+            fn is_stable(&self) -> bool {
+                $stable
+            }
+
+            fn is_in_place(&self) -> bool {
+                true
+            }
+
+            fn sort<T: Ord>(&self, arr: &mut [T]) {
+                $sort(arr);
+            }
+
+            fn sort_counted<T: Ord>(&self, arr: &mut [T], counters: &Counters) {
+                $observed(arr, &mut counters.clone());
+            }
+        }
+    };
+}
+
+esoteric_sorter!(CombSort, "comb_sort", false, comb_sort, comb_sort_observed);
+esoteric_sorter!(
+    GnomeSort,
+    "gnome_sort",
+    true,
+    gnome_sort,
+    gnome_sort_observed
+);
+esoteric_sorter!(
+    CocktailShakerSort,
+    "cocktail_shaker_sort",
+    true,
+    cocktail_shaker_sort,
+    cocktail_shaker_sort_observed
+);
+esoteric_sorter!(
+    CycleSort,
+    "cycle_sort",
+    false,
+    cycle_sort,
+    cycle_sort_observed
+);
+esoteric_sorter!(
+    PancakeSort,
+    "pancake_sort",
+    false,
+    pancake_sort,
+    pancake_sort_observed
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::visualization::Step;
+
+    #[test]
+    fn each_sort_handles_small_cases() {
+        let sorts: [fn(&mut [i32]); 5] = [
+            comb_sort,
+            gnome_sort,
+            cocktail_shaker_sort,
+            |arr| {
+                cycle_sort(arr);
+            },
+            |arr| {
+                pancake_sort(arr);
+            },
+        ];
+        for sort in sorts {
+            let mut empty: Vec<i32> = vec![];
+            sort(&mut empty);
+            let mut one = vec![1];
+            sort(&mut one);
+            assert_eq!(one, vec![1]);
+            let mut arr = vec![5, -1, 3, 3, 0, 9, -7];
+            sort(&mut arr);
+            assert_eq!(arr, vec![-7, -1, 0, 3, 3, 5, 9]);
+        }
+    }
+
+    #[test]
+    fn cycle_sort_minimizes_writes() {
+        // 已经有序时不需要任何写入
+        let mut sorted = vec![1, 2, 3, 4];
+        assert_eq!(cycle_sort(&mut sorted), 0);
+
+        // 一个长度为 4 的环需要 3 次交换，另一个元素不动
+        let mut arr = vec![2, 3, 4, 1, 5];
+        assert_eq!(cycle_sort(&mut arr), 3);
+        assert_eq!(arr, vec![1, 2, 3, 4, 5]);
+
+        let mut dupes = vec![2, 1, 2, 1];
+        cycle_sort(&mut dupes);
+        assert_eq!(dupes, vec![1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn pancake_flips_replay_to_sorted() {
+        let original = vec![3, 6, 1, 8, 2, 2, 7];
+        let mut arr = original.clone();
+        let flips = pancake_sort(&mut arr);
+        assert_eq!(arr, vec![1, 2, 2, 3, 6, 7, 8]);
+        // 每个大小最多两次翻转
+        assert!(flips.len() <= 2 * original.len());
+
+        let mut replay = original;
+        for k in flips {
+            replay[..k].reverse();
+        }
+        assert_eq!(replay, arr);
+    }
+
+    #[test]
+    fn observed_swaps_replay_the_sort() {
+        type Observed = fn(&mut [i32], &mut Vec<Step>);
+        let sorts: [Observed; 5] = [
+            |arr, steps| comb_sort_observed(arr, steps),
+            |arr, steps| gnome_sort_observed(arr, steps),
+            |arr, steps| cocktail_shaker_sort_observed(arr, steps),
+            |arr, steps| {
+                cycle_sort_observed(arr, steps);
+            },
+            |arr, steps| {
+                pancake_sort_observed(arr, steps);
+            },
+        ];
+        let original = vec![3, 6, 1, 8, 2, 2, 7, -4, 0, 6];
+        for sort in sorts {
+            let mut arr = original.clone();
+            let mut steps = vec![];
+            sort(&mut arr, &mut steps);
+            assert_eq!(arr, vec![-4, 0, 1, 2, 2, 3, 6, 6, 7, 8]);
+            let mut replay = original.clone();
+            for step in steps {
+                if let Step::Swap(i, j) = step {
+                    replay.swap(i, j);
+                }
+            }
+            assert_eq!(replay, arr);
+        }
+    }
+}
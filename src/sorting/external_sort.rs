@@ -0,0 +1,377 @@
+/*!
+定义：
+[外排序的定义](https://zh.wikipedia.org/wiki/%E5%A4%96%E6%8E%92%E5%BA%8F)
+数据量超过内存时使用的排序方法，分两个阶段：
+1. 按内存上限分块读入，每块在内存中排好序后写入临时文件，称为一个有序段（run）
+2. 用小顶堆对所有有序段做 k 路归并（同 `kway_merge`），每个段同一时刻只需要在内存中保留一行。
+   同时打开的文件数有上限（fan-in），有序段更多时先分批归并成更长的中间段，直到不超过上限
+
+这里按行排序：输入中的每一行是一条记录，输出不包含换行符
+*/
+
+use super::intro_sort::intro_sort;
+use crate::data_structures::heap::Heap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Lines, Read, Write};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::vec;
+
+/// 同一进程中多次排序时用于区分临时文件名
+static SORT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// 默认一次最多归并的有序段文件数
+const DEFAULT_FAN_IN: usize = 64;
+
+pub struct ExternalSorter {
+    /// 每个有序段在内存中允许占用的最大字节数
+    memory_limit: usize,
+    /// 一次最多同时打开的有序段文件数
+    fan_in: usize,
+    temp_dir: PathBuf,
+}
+
+impl ExternalSorter {
+    pub fn new(memory_limit: usize) -> Self {
+        ExternalSorter {
+            memory_limit: memory_limit.max(1),
+            fan_in: DEFAULT_FAN_IN,
+            temp_dir: std::env::temp_dir(),
+        }
+    }
+
+    /// 一次最多归并 `fan_in` 个有序段文件（至少为 2），用来避免超出进程能打开的文件数
+    pub fn with_fan_in(mut self, fan_in: usize) -> Self {
+        self.fan_in = fan_in.max(2);
+        self
+    }
+
+    /// 指定存放临时文件的目录
+    pub fn with_temp_dir(mut self, temp_dir: impl Into<PathBuf>) -> Self {
+        self.temp_dir = temp_dir.into();
+        self
+    }
+
+    /// 对输入的每一行排序，返回按顺序产生各行的迭代器
+    pub fn sort<R: Read>(&self, input: R) -> io::Result<SortedLines> {
+        let sort_id = SORT_ID.fetch_add(1, Ordering::Relaxed);
+        let mut runs = TempRuns { paths: vec![] };
+        let mut run_count = 0;
+        let mut next_path = || {
+            run_count += 1;
+            self.temp_dir.join(format!(
+                "rust-algo-external-sort-{}-{sort_id}-{run_count}.run",
+                process::id()
+            ))
+        };
+        let mut chunk: Vec<String> = vec![];
+        let mut used = 0;
+
+        for line in BufReader::new(input).lines() {
+            let line = line?;
+            used += line.len();
+            chunk.push(line);
+            if used >= self.memory_limit {
+                let path = next_path();
+                write_run(&mut chunk, &path)?;
+                runs.paths.push(path);
+                used = 0;
+            }
+        }
+
+        // 全部数据一次就能放进内存时不需要临时文件
+        if runs.paths.is_empty() {
+            intro_sort(&mut chunk);
+            return Ok(SortedLines {
+                inner: Inner::Memory(chunk.into_iter()),
+            });
+        }
+
+        // 从最前面取出 fan_in 个段归并成一个新段放到最后，每一轮的段长度大致相同
+        while runs.paths.len() > self.fan_in {
+            let group = TempRuns {
+                paths: runs.paths.drain(..self.fan_in).collect(),
+            };
+            let path = next_path();
+            runs.paths.push(path.clone());
+            let mut writer = BufWriter::new(File::create(&path)?);
+            for line in RunMerge::new(open_runs(&group.paths)?)? {
+                writer.write_all(line?.as_bytes())?;
+                writer.write_all(b"\n")?;
+            }
+            writer.flush()?;
+        }
+
+        let mut sources = open_runs(&runs.paths)?;
+        // 最后一块留在内存中，作为最后一个有序段参与归并
+        intro_sort(&mut chunk);
+        sources.push(Run::Memory(chunk.into_iter()));
+        Ok(SortedLines {
+            inner: Inner::Merge {
+                lines: RunMerge::new(sources)?,
+                _runs: runs,
+            },
+        })
+    }
+}
+
+/// 使用 `memory_limit` 字节的内存上限对输入的各行排序
+pub fn external_sort<R: Read>(input: R, memory_limit: usize) -> io::Result<SortedLines> {
+    ExternalSorter::new(memory_limit).sort(input)
+}
+
+fn open_runs(paths: &[PathBuf]) -> io::Result<Vec<Run>> {
+    paths
+        .iter()
+        .map(|path| Ok(Run::File(BufReader::new(File::open(path)?).lines())))
+        .collect()
+}
+
+fn write_run(chunk: &mut Vec<String>, path: &PathBuf) -> io::Result<()> {
+    intro_sort(chunk);
+    let mut writer = BufWriter::new(File::create(path)?);
+    for line in chunk.drain(..) {
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()
+}
+
+/// 排序结果的迭代器。读取临时文件失败时先产生已经确定的行，再产生一次 `Err`，之后结束
+pub struct SortedLines {
+    inner: Inner,
+}
+
+enum Inner {
+    Memory(vec::IntoIter<String>),
+    Merge {
+        lines: RunMerge,
+        /// 持有临时文件，迭代器被 drop 时删除
+        _runs: TempRuns,
+    },
+}
+
+impl Iterator for SortedLines {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Memory(lines) => lines.next().map(Ok),
+            Inner::Merge { lines, .. } => lines.next(),
+        }
+    }
+}
+
+/// 一个有序段：临时文件，或者留在内存中的最后一块
+enum Run {
+    File(Lines<BufReader<File>>),
+    Memory(vec::IntoIter<String>),
+}
+
+impl Iterator for Run {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Run::File(lines) => lines.next(),
+            Run::Memory(lines) => lines.next().map(Ok),
+        }
+    }
+}
+
+/// 对多个有序段做 k 路归并，相等的行按有序段的先后顺序输出
+struct RunMerge {
+    /// 堆中保存每个有序段当前的第一行和段的下标
+    heap: Heap<(String, usize)>,
+    runs: Vec<Run>,
+    /// 补充下一行时遇到的错误，在已经弹出的那一行之后产生
+    error: Option<io::Error>,
+}
+
+impl RunMerge {
+    fn new(mut runs: Vec<Run>) -> io::Result<Self> {
+        let mut heap = Heap::new_min();
+        for (i, run) in runs.iter_mut().enumerate() {
+            if let Some(line) = run.next() {
+                heap.push((line?, i));
+            }
+        }
+        Ok(RunMerge {
+            heap,
+            runs,
+            error: None,
+        })
+    }
+}
+
+impl Iterator for RunMerge {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.error.take() {
+            return Some(Err(err));
+        }
+        let (line, i) = self.heap.pop()?;
+        match self.runs[i].next() {
+            Some(Ok(next)) => self.heap.push((next, i)),
+            Some(Err(err)) => {
+                // 缺了一个段之后的结果不再完整，丢掉其余的行，产生错误后结束
+                self.heap = Heap::new_min();
+                self.runs.clear();
+                self.error = Some(err);
+            }
+            None => {}
+        }
+        Some(Ok(line))
+    }
+}
+
+struct TempRuns {
+    paths: Vec<PathBuf>,
+}
+
+impl Drop for TempRuns {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            // 删除失败只会留下临时文件，不影响结果
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{external_sort, ExternalSorter, SortedLines};
+    use crate::utils::random::Rng;
+    use std::fs;
+    use std::io::{Seek, SeekFrom, Write};
+
+    fn sort_lines(input: &str, memory_limit: usize) -> Vec<String> {
+        external_sort(input.as_bytes(), memory_limit)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn sorts_in_memory_when_input_is_small() {
+        assert_eq!(
+            sort_lines("pear\napple\nfig\n", 1024),
+            vec!["apple", "fig", "pear"]
+        );
+        assert!(sort_lines("", 1024).is_empty());
+    }
+
+    #[test]
+    fn merges_many_runs() {
+        let mut rng = Rng::new(1377);
+        let numbers: Vec<String> = (0..5000)
+            .map(|_| format!("{:08}", rng.gen_range(0, 100_000)))
+            .collect();
+        let input = numbers.join("\n");
+        // 每个有序段只有几十行，会产生上百个临时文件
+        let sorted = sort_lines(&input, 256);
+        let mut expected = numbers.clone();
+        expected.sort();
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn merges_in_passes_when_runs_exceed_fan_in() {
+        let dir =
+            std::env::temp_dir().join(format!("rust-algo-external-fan-in-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut rng = Rng::new(1377);
+        let numbers: Vec<String> = (0..3000)
+            .map(|_| format!("{:06}", rng.gen_range(0, 1000)))
+            .collect();
+        let mut expected = numbers.clone();
+        expected.sort();
+        // 每段约 10 行，共约 300 段，每次最多归并 3 段
+        let sorted = ExternalSorter::new(60)
+            .with_fan_in(3)
+            .with_temp_dir(&dir)
+            .sort(numbers.join("\n").as_bytes())
+            .unwrap();
+        // 分批归并之后只剩下不超过 fan-in 个文件
+        assert!(fs::read_dir(&dir).unwrap().count() <= 3);
+        let lines: Vec<String> = sorted.map(Result::unwrap).collect();
+        assert_eq!(lines, expected);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn cleans_up_temp_files() {
+        let dir = std::env::temp_dir().join(format!("rust-algo-external-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let input = "d\nc\nb\na\ne\nc\n";
+        let mut sorted = ExternalSorter::new(2)
+            .with_temp_dir(&dir)
+            .sort(input.as_bytes())
+            .unwrap();
+        assert_eq!(sorted.next().unwrap().unwrap(), "a");
+        assert!(fs::read_dir(&dir).unwrap().count() > 0);
+
+        let rest: Vec<String> = sorted.by_ref().map(Result::unwrap).collect();
+        assert_eq!(rest, vec!["b", "c", "c", "d", "e"]);
+        drop(sorted);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn sorted_lines_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<SortedLines>();
+    }
+
+    #[test]
+    fn read_error_is_reported_once_and_ends_the_output() {
+        let dir =
+            std::env::temp_dir().join(format!("rust-algo-external-error-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut rng = Rng::new(1382);
+        let numbers: Vec<String> = (0..20_000)
+            .map(|_| format!("{:08}", rng.gen_range(0, 100_000_000)))
+            .collect();
+        let mut expected = numbers.clone();
+        expected.sort();
+        // 每个有序段约 64 KiB，远大于 BufReader 的缓冲区
+        let mut sorted = ExternalSorter::new(64 * 1024)
+            .with_temp_dir(&dir)
+            .sort(numbers.join("\n").as_bytes())
+            .unwrap();
+
+        // 在第一个有序段的中间写入非法的 UTF-8，读到那里时失败
+        let run = fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+        let mut file = fs::OpenOptions::new().write(true).open(&run).unwrap();
+        file.seek(SeekFrom::Start(40_000)).unwrap();
+        file.write_all(&[0xff]).unwrap();
+        drop(file);
+
+        let mut lines = vec![];
+        let mut errors = 0;
+        for line in sorted.by_ref() {
+            match line {
+                Ok(line) => {
+                    assert_eq!(errors, 0, "no lines after the error");
+                    lines.push(line);
+                }
+                Err(_) => errors += 1,
+            }
+        }
+        assert_eq!(errors, 1);
+        // 出错之前产生的行都是正确结果的前缀
+        assert!(!lines.is_empty() && lines.len() < expected.len());
+        assert_eq!(lines, expected[..lines.len()]);
+        assert!(sorted.next().is_none());
+
+        drop(sorted);
+        fs::remove_dir(&dir).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+/*!
+定义：
+[堆排序的定义](https://zh.wikipedia.org/wiki/%E5%A0%86%E6%8E%92%E5%BA%8F)
+先把数组原地建成大顶堆，再不断把堆顶（最大值）交换到末尾并缩小堆，最坏也是 O(n log n)，但不稳定
+*/
+
+use super::sorter::Sorter;
+use crate::instrumented::Counters;
+use crate::visualization::Observer;
+use std::cmp::Ordering;
+
+pub fn heap_sort<T: Ord>(arr: &mut [T]) {
+    heap_sort_by(arr, |a, b| a.cmp(b));
+}
+
+pub fn heap_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heap_sort_impl(arr, compare, &mut ());
+}
+
+/// 把每次比较和交换报告给 `observer`，下标是元素在 `arr` 中的当前位置
+pub fn heap_sort_observed<T, O>(arr: &mut [T], observer: &mut O)
+where
+    T: Ord,
+    O: Observer + ?Sized,
+{
+    heap_sort_impl(arr, |a, b| a.cmp(b), observer);
+}
+
+pub(super) fn heap_sort_impl<T, F, O>(arr: &mut [T], mut compare: F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: Observer + ?Sized,
+{
+    let len = arr.len();
+    // 从最后一个非叶子节点开始向前下沉，完成建堆
+    for i in (0..len / 2).rev() {
+        sift_down(arr, i, len, &mut compare, observer);
+    }
+
+    for end in (1..len).rev() {
+        arr.swap(0, end);
+        observer.on_swap(0, end);
+        sift_down(arr, 0, end, &mut compare, observer);
+    }
+}
+
+/// 让 `arr[node]` 在 `arr[..end]` 构成的堆中下沉到合适位置
+fn sift_down<T, F, O>(arr: &mut [T], mut node: usize, end: usize, compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: Observer + ?Sized,
+{
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= end {
+            break;
+        }
+        // 选出较大的那个孩子
+        if child + 1 < end {
+            let ordering = compare(&arr[child], &arr[child + 1]);
+            observer.on_compare(child, child + 1);
+            if ordering == Ordering::Less {
+                child += 1;
+            }
+        }
+        let ordering = compare(&arr[node], &arr[child]);
+        observer.on_compare(node, child);
+        if ordering != Ordering::Less {
+            break;
+        }
+        arr.swap(node, child);
+        observer.on_swap(node, child);
+        node = child;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeapSort;
+
+impl Sorter for HeapSort {
+    fn name(&self) -> &'static str {
+        "heap_sort"
+    }
+
+    fn is_stable(&self) -> bool {
+        false
+    }
+
+    fn is_in_place(&self) -> bool {
+        true
+    }
+
+    fn sort<T: Ord>(&self, arr: &mut [T]) {
+        heap_sort(arr);
+    }
+
+    fn sort_counted<T: Ord>(&self, arr: &mut [T], counters: &Counters) {
+        heap_sort_observed(arr, &mut counters.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{heap_sort, heap_sort_by};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn sorts_random_input() {
+        let mut rng = Rng::new(1376);
+        for len in [0, 1, 2, 3, 10, 257, 1000] {
+            let mut arr: Vec<i64> = (0..len).map(|_| rng.gen_range(-30, 30)).collect();
+            let mut expected = arr.clone();
+            expected.sort();
+            heap_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn sorts_descending_with_comparator() {
+        let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6];
+        heap_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![9, 6, 5, 4, 3, 2, 1, 1]);
+    }
+}
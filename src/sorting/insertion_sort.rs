@@ -0,0 +1,103 @@
+/*!
+定义：
+[插入排序的定义](https://zh.wikipedia.org/wiki/%E6%8F%92%E5%85%A5%E6%8E%92%E5%BA%8F)
+像整理扑克牌一样，把每个新元素插入到前面已经有序的部分中，最坏 O(n²)，但对小数组和几乎有序的数组很快
+*/
+
+use super::sorter::Sorter;
+use crate::instrumented::Counters;
+use crate::visualization::Observer;
+use std::cmp::Ordering;
+
+pub fn insertion_sort<T: Ord>(arr: &mut [T]) {
+    insertion_sort_by(arr, |a, b| a.cmp(b));
+}
+
+pub fn insertion_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    insertion_sort_impl(arr, compare, &mut ());
+}
+
+/// 把每次比较和交换报告给 `observer`，下标是元素在 `arr` 中的当前位置
+pub fn insertion_sort_observed<T, O>(arr: &mut [T], observer: &mut O)
+where
+    T: Ord,
+    O: Observer + ?Sized,
+{
+    insertion_sort_impl(arr, |a, b| a.cmp(b), observer);
+}
+
+pub(super) fn insertion_sort_impl<T, F, O>(arr: &mut [T], mut compare: F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: Observer + ?Sized,
+{
+    for i in 1..arr.len() {
+        // 把 arr[i] 向前交换，直到前一个元素不比它大
+        let mut j = i;
+        while j > 0 {
+            let ordering = compare(&arr[j - 1], &arr[j]);
+            observer.on_compare(j - 1, j);
+            if ordering != Ordering::Greater {
+                break;
+            }
+            arr.swap(j - 1, j);
+            observer.on_swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsertionSort;
+
+impl Sorter for InsertionSort {
+    fn name(&self) -> &'static str {
+        "insertion_sort"
+    }
+
+    fn is_stable(&self) -> bool {
+        true
+    }
+
+    fn is_in_place(&self) -> bool {
+        true
+    }
+
+    fn sort<T: Ord>(&self, arr: &mut [T]) {
+        insertion_sort(arr);
+    }
+
+    fn sort_counted<T: Ord>(&self, arr: &mut [T], counters: &Counters) {
+        insertion_sort_observed(arr, &mut counters.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{insertion_sort, insertion_sort_by};
+
+    #[test]
+    fn sorts_numbers_and_strings() {
+        let mut arr = vec![5, 2, 9, 1, 5, 6];
+        insertion_sort(&mut arr);
+        assert_eq!(arr, vec![1, 2, 5, 5, 6, 9]);
+
+        let mut words = vec!["pear", "apple", "fig"];
+        insertion_sort(&mut words);
+        assert_eq!(words, vec!["apple", "fig", "pear"]);
+
+        let mut empty: Vec<i32> = vec![];
+        insertion_sort(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn is_stable_and_supports_custom_order() {
+        let mut arr = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        insertion_sort_by(&mut arr, |a, b| b.0.cmp(&a.0));
+        assert_eq!(arr, vec![(2, 'a'), (2, 'c'), (1, 'b'), (1, 'd')]);
+    }
+}
@@ -0,0 +1,313 @@
+/*!
+定义：
+[内省排序的定义](https://zh.wikipedia.org/wiki/%E5%86%85%E7%9C%81%E6%8E%92%E5%BA%8F)
+以快速排序开始，当递归深度超过 2·log₂(n) 时说明 pivot 选得很差，改用堆排序处理这一段；
+分区足够小时用插入排序收尾。这样既保留了快速排序的平均性能，又保证最坏 O(n log n)
+*/
+
+use super::heap_sort::heap_sort_impl;
+use super::insertion_sort::insertion_sort_impl;
+use super::sorter::Sorter;
+use crate::instrumented::Counters;
+use crate::visualization::Observer;
+use std::cmp::Ordering;
+
+/// 小于等于这个长度的分区直接使用插入排序
+const INSERTION_THRESHOLD: usize = 16;
+
+pub fn intro_sort<T: Ord>(arr: &mut [T]) {
+    intro_sort_by(arr, |a, b| a.cmp(b));
+}
+
+pub fn intro_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    intro_sort_impl(arr, &mut compare, &mut ());
+}
+
+/// 把每次比较和交换报告给 `observer`，下标是元素在 `arr` 中的当前位置，
+/// 包括收尾时的插入排序和退化时的堆排序
+pub fn intro_sort_observed<T, O>(arr: &mut [T], observer: &mut O)
+where
+    T: Ord,
+    O: Observer + ?Sized,
+{
+    intro_sort_impl(arr, &mut |a: &T, b: &T| a.cmp(b), observer);
+}
+
+fn intro_sort_impl<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering + ?Sized,
+    O: Observer + ?Sized,
+{
+    if arr.len() < 2 {
+        return;
+    }
+    let depth_limit = 2 * arr.len().ilog2() as usize;
+    intro_sort_loop(arr, 0, depth_limit, compare, observer);
+}
+
+/// 对 `arr` 排序，`arr` 是从整个数组的下标 `offset` 开始的一段，报告的下标要加上它
+fn intro_sort_loop<T, F, O>(
+    mut arr: &mut [T],
+    mut offset: usize,
+    mut depth_limit: usize,
+    compare: &mut F,
+    observer: &mut O,
+) where
+    F: FnMut(&T, &T) -> Ordering + ?Sized,
+    O: Observer + ?Sized,
+{
+    loop {
+        let mut shifted = Shifted { observer, offset };
+        if arr.len() <= INSERTION_THRESHOLD {
+            insertion_sort_impl(arr, &mut *compare, &mut shifted);
+            return;
+        }
+        if depth_limit == 0 {
+            heap_sort_impl(arr, &mut *compare, &mut shifted);
+            return;
+        }
+        depth_limit -= 1;
+
+        let pivot = partition(arr, compare, &mut shifted);
+        let (left, right) = arr.split_at_mut(pivot);
+        let right = &mut right[1..];
+        // 递归处理较短的一侧，较长的一侧继续循环，栈深度最多 O(log n)
+        if left.len() < right.len() {
+            intro_sort_loop(left, offset, depth_limit, compare, observer);
+            offset += pivot + 1;
+            arr = right;
+        } else {
+            intro_sort_loop(right, offset + pivot + 1, depth_limit, compare, observer);
+            arr = left;
+        }
+    }
+}
+
+/// 把下标加上 `offset` 之后转发给 `observer`，用于对数组的一段排序时
+struct Shifted<'a, O: ?Sized> {
+    observer: &'a mut O,
+    offset: usize,
+}
+
+impl<O: Observer + ?Sized> Observer for Shifted<'_, O> {
+    fn on_compare(&mut self, i: usize, j: usize) {
+        self.observer.on_compare(self.offset + i, self.offset + j);
+    }
+
+    fn on_swap(&mut self, i: usize, j: usize) {
+        self.observer.on_swap(self.offset + i, self.offset + j);
+    }
+}
+
+/// 三数取中选出 pivot 放到开头再分区，返回 pivot 的最终位置
+pub(super) fn partition<T, F, O>(arr: &mut [T], compare: &mut F, observer: &mut O) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering + ?Sized,
+    O: Observer + ?Sized,
+{
+    let last = arr.len() - 1;
+    let mid = last / 2;
+    let mut less = |arr: &[T], i: usize, j: usize, observer: &mut O| {
+        observer.on_compare(i, j);
+        compare(&arr[i], &arr[j]) == Ordering::Less
+    };
+    let swap = |arr: &mut [T], i: usize, j: usize, observer: &mut O| {
+        arr.swap(i, j);
+        observer.on_swap(i, j);
+    };
+    // 让 arr[0] <= arr[mid] <= arr[last]
+    if less(arr, mid, 0, observer) {
+        swap(arr, mid, 0, observer);
+    }
+    if less(arr, last, mid, observer) {
+        swap(arr, last, mid, observer);
+        if less(arr, mid, 0, observer) {
+            swap(arr, mid, 0, observer);
+        }
+    }
+    swap(arr, 0, mid, observer);
+    partition_around_first(arr, compare, observer)
+}
+
+/// 以 `arr[0]` 为 pivot 做 Hoare 分区，返回 pivot 的最终位置
+pub(super) fn partition_around_first<T, F, O>(
+    arr: &mut [T],
+    compare: &mut F,
+    observer: &mut O,
+) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering + ?Sized,
+    O: Observer + ?Sized,
+{
+    let mut compare_to_pivot = |arr: &[T], i: usize, observer: &mut O| {
+        observer.on_compare(i, 0);
+        compare(&arr[i], &arr[0])
+    };
+    let (mut i, mut j) = (1, arr.len() - 1);
+    loop {
+        // 遇到等于 pivot 的元素两边都会停下来交换，重复元素多时分区依然均衡
+        while i <= j && compare_to_pivot(arr, i, observer) == Ordering::Less {
+            i += 1;
+        }
+        while i <= j && compare_to_pivot(arr, j, observer) == Ordering::Greater {
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        }
+        arr.swap(i, j);
+        observer.on_swap(i, j);
+        i += 1;
+        j -= 1;
+    }
+    arr.swap(0, j);
+    observer.on_swap(0, j);
+    j
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntroSort;
+
+impl Sorter for IntroSort {
+    fn name(&self) -> &'static str {
+        "intro_sort"
+    }
+
+    fn is_stable(&self) -> bool {
+        false
+    }
+
+    fn is_in_place(&self) -> bool {
+        true
+    }
+
+    fn sort<T: Ord>(&self, arr: &mut [T]) {
+        intro_sort(arr);
+    }
+
+    fn sort_counted<T: Ord>(&self, arr: &mut [T], counters: &Counters) {
+        intro_sort_observed(arr, &mut counters.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{intro_sort, intro_sort_by, intro_sort_loop, intro_sort_observed};
+    use crate::utils::random::Rng;
+    use crate::visualization::Step;
+    use std::cmp::Ordering;
+
+    /// McIlroy 的“快速排序杀手”：元素的值在比较时才确定，使每次选中的 pivot 都尽可能差。
+    /// 返回排序过程中的比较次数以及据此生成的对抗输入
+    fn adversary<S>(n: usize, sort: S) -> (usize, Vec<usize>)
+    where
+        S: FnOnce(&mut [usize], &mut dyn FnMut(&usize, &usize) -> Ordering),
+    {
+        // gas 表示还没确定的值，比所有已确定的值都大
+        let gas = n;
+        let mut values = vec![gas; n];
+        let mut solid = 0;
+        let mut candidate = 0;
+        let mut comparisons = 0;
+        let mut arr: Vec<usize> = (0..n).collect();
+        sort(&mut arr, &mut |&x, &y| {
+            comparisons += 1;
+            if values[x] == gas && values[y] == gas {
+                if x == candidate {
+                    values[x] = solid;
+                } else {
+                    values[y] = solid;
+                }
+                solid += 1;
+            }
+            if values[x] == gas {
+                candidate = x;
+            } else if values[y] == gas {
+                candidate = y;
+            }
+            values[x].cmp(&values[y])
+        });
+        for value in values.iter_mut().filter(|v| **v == gas) {
+            *value = solid;
+            solid += 1;
+        }
+        (comparisons, values)
+    }
+
+    #[test]
+    fn sorts_random_input() {
+        let mut rng = Rng::new(1376);
+        for len in [0, 1, 2, 16, 17, 100, 5000] {
+            let mut arr: Vec<i64> = (0..len).map(|_| rng.gen_range(-1000, 1000)).collect();
+            let mut expected = arr.clone();
+            expected.sort();
+            intro_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn handles_many_duplicates_and_sorted_runs() {
+        let mut same = vec![7; 1000];
+        intro_sort(&mut same);
+        assert!(same.iter().all(|&x| x == 7));
+
+        let mut organ_pipe: Vec<i32> = (0..500).chain((0..500).rev()).collect();
+        let mut expected = organ_pipe.clone();
+        expected.sort();
+        intro_sort(&mut organ_pipe);
+        assert_eq!(organ_pipe, expected);
+    }
+
+    #[test]
+    fn survives_anti_quicksort_input() {
+        let n: usize = 4000;
+        let quadratic = n * n / 4;
+        let n_log_n = n * n.ilog2() as usize;
+
+        // 没有深度限制的纯快速排序会被对抗输入拖到平方级别
+        let (quick, _) = adversary(n, |arr, compare| {
+            intro_sort_loop(arr, 0, usize::MAX, compare, &mut ())
+        });
+        assert!(
+            quick > quadratic / 4,
+            "quicksort used only {quick} comparisons"
+        );
+
+        let (intro, killer) = adversary(n, |arr, compare| intro_sort_by(arr, compare));
+        assert!(intro < 8 * n_log_n, "introsort used {intro} comparisons");
+
+        // 用生成的对抗输入重放一次，结果依然正确且比较次数有上界
+        let mut arr = killer;
+        let mut comparisons = 0;
+        intro_sort_by(&mut arr, |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert_eq!(arr, (0..n).collect::<Vec<_>>());
+        assert!(comparisons < 8 * n_log_n);
+    }
+
+    #[test]
+    fn observed_steps_replay_the_sort() {
+        // 对抗输入会让内省排序退化到堆排序，三种子过程的报告都要换算成整个数组的下标
+        let (_, killer) = adversary(500, |arr, compare| intro_sort_by(arr, compare));
+        let mut arr = killer.clone();
+        let mut steps: Vec<Step> = vec![];
+        intro_sort_observed(&mut arr, &mut steps);
+        assert_eq!(arr, (0..500).collect::<Vec<_>>());
+
+        let mut replay = killer;
+        for step in steps {
+            match step {
+                Step::Swap(i, j) => replay.swap(i, j),
+                Step::Compare(i, j) => assert!(i < 500 && j < 500),
+                step => panic!("unexpected {step:?}"),
+            }
+        }
+        assert_eq!(replay, arr);
+    }
+}
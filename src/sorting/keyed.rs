@@ -0,0 +1,28 @@
+use std::cmp::Ordering;
+
+/// 只按 key 比较的元素，index 记录原始位置（或来源），用来检查稳定性和元素是否丢失
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Keyed<K> {
+    pub key: K,
+    pub index: usize,
+}
+
+impl<K: Ord> PartialEq for Keyed<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord> Eq for Keyed<K> {}
+
+impl<K: Ord> PartialOrd for Keyed<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord> Ord for Keyed<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
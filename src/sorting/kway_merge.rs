@@ -0,0 +1,121 @@
+/*!
+定义：
+[k 路归并](https://en.wikipedia.org/wiki/K-way_merge_algorithm)
+把 k 个已经有序的序列合并成一个有序序列。小顶堆中始终保存每个序列当前的第一个元素，
+每次弹出最小值后再从它所在的序列补充一个，总复杂度 O(n log k)
+*/
+
+use crate::data_structures::heap::Heap;
+
+/// 惰性地合并多个有序迭代器，相等的元素按输入序列的先后顺序输出
+pub struct KWayMerge<I>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    /// 堆中保存 (元素, 来源序列的下标)
+    heap: Heap<(I::Item, usize)>,
+    sources: Vec<I>,
+}
+
+impl<I> KWayMerge<I>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    pub fn new(mut sources: Vec<I>) -> Self {
+        let mut heap = Heap::new_min();
+        for (i, source) in sources.iter_mut().enumerate() {
+            if let Some(item) = source.next() {
+                heap.push((item, i));
+            }
+        }
+        KWayMerge { heap, sources }
+    }
+}
+
+impl<I> Iterator for KWayMerge<I>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, i) = self.heap.pop()?;
+        if let Some(next) = self.sources[i].next() {
+            self.heap.push((next, i));
+        }
+        Some(item)
+    }
+}
+
+/// 合并多个有序迭代器
+pub fn kway_merge<I>(sources: Vec<I>) -> KWayMerge<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: Ord,
+{
+    KWayMerge::new(sources.into_iter().map(IntoIterator::into_iter).collect())
+}
+
+/// 合并多个有序切片，返回新的数组
+pub fn kway_merge_slices<T: Ord + Clone>(slices: &[&[T]]) -> Vec<T> {
+    let total = slices.iter().map(|s| s.len()).sum();
+    let mut merged = Vec::with_capacity(total);
+    merged.extend(kway_merge(slices.iter().map(|s| s.iter()).collect()).cloned());
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{kway_merge, kway_merge_slices};
+    use crate::sorting::keyed::Keyed;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn merges_iterators() {
+        let merged: Vec<i32> =
+            kway_merge(vec![vec![1, 4, 7], vec![2, 5, 8], vec![], vec![0, 9]]).collect();
+        assert_eq!(merged, vec![0, 1, 2, 4, 5, 7, 8, 9]);
+
+        let empty: Vec<Vec<i32>> = vec![];
+        assert_eq!(kway_merge(empty).count(), 0);
+    }
+
+    #[test]
+    fn is_lazy_over_infinite_sources() {
+        let evens = (0..).step_by(2);
+        let threes = (0..).step_by(3);
+        let merged: Vec<u32> = kway_merge(vec![evens, threes]).take(8).collect();
+        assert_eq!(merged, vec![0, 0, 2, 3, 4, 6, 6, 8]);
+    }
+
+    #[test]
+    fn equal_elements_keep_source_order() {
+        // index 是来源序号
+        let run = |index| (1..=2).map(move |key| Keyed { key, index });
+        let merged: Vec<usize> = kway_merge(vec![run(0), run(1), run(2)])
+            .map(|k| k.index)
+            .collect();
+        assert_eq!(merged, vec![0, 1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn merges_random_slices() {
+        let mut rng = Rng::new(1382);
+        let mut runs: Vec<Vec<i64>> = (0..20)
+            .map(|_| {
+                let len = rng.gen_index(50);
+                (0..len).map(|_| rng.gen_range(-100, 100)).collect()
+            })
+            .collect();
+        for run in runs.iter_mut() {
+            run.sort();
+        }
+        let slices: Vec<&[i64]> = runs.iter().map(|r| r.as_slice()).collect();
+        let mut expected: Vec<i64> = runs.concat();
+        expected.sort();
+        assert_eq!(kway_merge_slices(&slices), expected);
+    }
+}
@@ -0,0 +1,82 @@
+/*!
+定义：
+[归并排序的定义](https://zh.wikipedia.org/wiki/%E5%BD%92%E5%B9%B6%E6%8E%92%E5%BA%8F)
+把数组分成两半分别排序，再把两个有序的半段合并。稳定，最坏 O(n log n)，
+比较次数不超过 n⌈log₂n⌉ - 2^⌈log₂n⌉ + 1，需要 n/2 的额外空间
+*/
+
+use super::sorter::Sorter;
+use super::tim_sort::merge;
+use std::cmp::Ordering;
+
+pub fn merge_sort<T: Ord>(arr: &mut [T]) {
+    merge_sort_by(arr, |a, b| a.cmp(b));
+}
+
+pub fn merge_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // 所有合并共用一个缓冲区
+    let mut buffer = Vec::with_capacity(arr.len() / 2);
+    sort_rec(arr, &mut buffer, &mut compare);
+}
+
+fn sort_rec<T, F>(arr: &mut [T], buffer: &mut Vec<T>, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() < 2 {
+        return;
+    }
+    let mid = arr.len() / 2;
+    sort_rec(&mut arr[..mid], buffer, compare);
+    sort_rec(&mut arr[mid..], buffer, compare);
+    merge(arr, mid, buffer, compare);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergeSort;
+
+impl Sorter for MergeSort {
+    fn name(&self) -> &'static str {
+        "merge_sort"
+    }
+
+    fn is_stable(&self) -> bool {
+        true
+    }
+
+    fn is_in_place(&self) -> bool {
+        false
+    }
+
+    fn sort<T: Ord>(&self, arr: &mut [T]) {
+        merge_sort(arr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_sort, merge_sort_by};
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn sorts_random_input() {
+        let mut rng = Rng::new(1490);
+        for len in [0, 1, 2, 3, 10, 100, 1000] {
+            let mut arr: Vec<i64> = (0..len).map(|_| rng.gen_range(-50, 50)).collect();
+            let mut expected = arr.clone();
+            expected.sort();
+            merge_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn is_stable_and_supports_custom_order() {
+        let mut arr = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        merge_sort_by(&mut arr, |a, b| b.0.cmp(&a.0));
+        assert_eq!(arr, vec![(2, 'a'), (2, 'c'), (1, 'b'), (1, 'd')]);
+    }
+}
@@ -0,0 +1,45 @@
+pub mod counting_sort;
+pub mod esoteric_sorts;
+pub mod external_sort;
+pub mod heap_sort;
+pub mod insertion_sort;
+pub mod intro_sort;
+#[cfg(any(test, feature = "arbitrary"))]
+pub(crate) mod keyed;
+pub mod kway_merge;
+pub mod merge_sort;
+#[cfg(feature = "parallel")]
+pub mod parallel_sort;
+pub mod quickselect;
+pub mod radix_sort;
+pub mod shell_sort;
+pub mod sortedness;
+pub mod sorter;
+pub mod tim_sort;
+
+pub use self::counting_sort::{counting_sort, counting_sort_by_key};
+pub use self::esoteric_sorts::{
+    cocktail_shaker_sort, cocktail_shaker_sort_observed, comb_sort, comb_sort_observed, cycle_sort,
+    cycle_sort_observed, gnome_sort, gnome_sort_observed, pancake_sort, pancake_sort_observed,
+    CocktailShakerSort, CombSort, CycleSort, GnomeSort, PancakeSort,
+};
+pub use self::external_sort::{external_sort, ExternalSorter, SortedLines};
+pub use self::heap_sort::{heap_sort, heap_sort_by, heap_sort_observed, HeapSort};
+pub use self::insertion_sort::{
+    insertion_sort, insertion_sort_by, insertion_sort_observed, InsertionSort,
+};
+pub use self::intro_sort::{intro_sort, intro_sort_by, intro_sort_observed, IntroSort};
+pub use self::kway_merge::{kway_merge, kway_merge_slices, KWayMerge};
+pub use self::merge_sort::{merge_sort, merge_sort_by, MergeSort};
+#[cfg(feature = "parallel")]
+pub use self::parallel_sort::{par_merge_sort, par_quick_sort};
+pub use self::quickselect::{partial_sort, quickselect};
+pub use self::radix_sort::{
+    msd_radix_sort, msd_radix_sort_strings, radix_sort, radix_sort_by_key, RadixKey,
+};
+pub use self::shell_sort::{
+    shell_sort, shell_sort_by, shell_sort_observed, GapSequence, ShellSort,
+};
+pub use self::sortedness::{count_inversions, is_sorted, is_sorted_by};
+pub use self::sorter::{all, SortAlgorithm, Sorter};
+pub use self::tim_sort::{tim_sort, tim_sort_by, TimSort};
@@ -0,0 +1,112 @@
+/*!
+多线程排序，需要开启 `parallel` feature
+使用 `std::thread::scope` 把数组拆成互不重叠的可变切片交给不同线程，不依赖额外的线程池：
+- `par_merge_sort`：两半并行排序后再合并，稳定
+- `par_quick_sort`：分区后左右两侧并行排序，不稳定
+
+数据量小于 `SEQUENTIAL_CUTOFF` 或者线程数已经足够时退化为单线程排序，避免创建线程的开销超过收益
+*/
+
+use super::intro_sort::{intro_sort, partition};
+use super::tim_sort::{merge, tim_sort};
+use std::thread;
+
+/// 小于这个长度的切片直接在当前线程排序
+pub const SEQUENTIAL_CUTOFF: usize = 1 << 13;
+
+pub fn par_merge_sort<T: Ord + Send>(arr: &mut [T]) {
+    merge_sort_rec(arr, split_depth());
+}
+
+pub fn par_quick_sort<T: Ord + Send>(arr: &mut [T]) {
+    quick_sort_rec(arr, split_depth());
+}
+
+/// 允许继续拆分的层数：比 CPU 核数多拆一层，让先完成的线程不至于空等
+fn split_depth() -> usize {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    threads.next_power_of_two().trailing_zeros() as usize + 1
+}
+
+fn merge_sort_rec<T: Ord + Send>(arr: &mut [T], depth: usize) {
+    if arr.len() <= SEQUENTIAL_CUTOFF || depth == 0 {
+        tim_sort(arr);
+        return;
+    }
+
+    let mid = arr.len() / 2;
+    let (left, right) = arr.split_at_mut(mid);
+    thread::scope(|s| {
+        s.spawn(|| merge_sort_rec(left, depth - 1));
+        merge_sort_rec(right, depth - 1);
+    });
+    merge(arr, mid, &mut Vec::new(), &mut |a: &T, b: &T| a.cmp(b));
+}
+
+fn quick_sort_rec<T: Ord + Send>(arr: &mut [T], depth: usize) {
+    // 深度用完之后交给内省排序，它本身保证了最坏 O(n log n)
+    if arr.len() <= SEQUENTIAL_CUTOFF || depth == 0 {
+        intro_sort(arr);
+        return;
+    }
+
+    let pivot = partition(arr, &mut |a: &T, b: &T| a.cmp(b), &mut ());
+    let (left, right) = arr.split_at_mut(pivot);
+    let right = &mut right[1..];
+    thread::scope(|s| {
+        s.spawn(|| quick_sort_rec(left, depth - 1));
+        quick_sort_rec(right, depth - 1);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{par_merge_sort, par_quick_sort, SEQUENTIAL_CUTOFF};
+    use crate::sorting::keyed::Keyed;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn sorts_large_random_input() {
+        let mut rng = Rng::new(1378);
+        for len in [0, 1, 100, SEQUENTIAL_CUTOFF + 1, 200_000] {
+            let data: Vec<i64> = (0..len).map(|_| rng.gen_range(-1000, 1000)).collect();
+            let mut expected = data.clone();
+            expected.sort();
+
+            let mut merged = data.clone();
+            par_merge_sort(&mut merged);
+            assert_eq!(merged, expected);
+
+            let mut quick = data;
+            par_quick_sort(&mut quick);
+            assert_eq!(quick, expected);
+        }
+    }
+
+    #[test]
+    fn merge_sort_is_stable() {
+        let mut rng = Rng::new(8);
+        let mut arr: Vec<Keyed<i64>> = (0..100_000)
+            .map(|index| Keyed {
+                key: rng.gen_range(0, 16),
+                index,
+            })
+            .collect();
+        par_merge_sort(&mut arr);
+        assert!(arr
+            .windows(2)
+            .all(|w| w[0].key < w[1].key || (w[0].key == w[1].key && w[0].index < w[1].index)));
+    }
+
+    #[test]
+    fn handles_presorted_and_duplicates() {
+        let mut sorted: Vec<u32> = (0..100_000).collect();
+        par_quick_sort(&mut sorted);
+        assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut same = vec![3u8; 100_000];
+        par_merge_sort(&mut same);
+        par_quick_sort(&mut same);
+        assert!(same.iter().all(|&x| x == 3));
+    }
+}
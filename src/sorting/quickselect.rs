@@ -0,0 +1,145 @@
+/*!
+定义：
+[快速选择的定义](https://zh.wikipedia.org/wiki/%E5%BF%AB%E9%80%9F%E9%80%89%E6%8B%A9)
+和快速排序一样分区，但每次只需要进入第 k 小元素所在的一侧，平均 O(n)。
+分区次数过多时说明 pivot 一直选得很差，改用[中位数的中位数](https://en.wikipedia.org/wiki/Median_of_medians)
+选 pivot，它保证 pivot 两侧至少各有 30% 的元素，最坏情况也是 O(n)（类似 C++ 的 `nth_element`）
+*/
+
+use super::insertion_sort::insertion_sort;
+use super::intro_sort::{intro_sort, partition, partition_around_first};
+use std::cmp::Ordering;
+
+/// 小于等于这个长度的区间直接插入排序
+const INSERTION_THRESHOLD: usize = 16;
+
+/// 返回第 `k` 小（从 0 开始）的元素
+///
+/// 结束后 `arr[k]` 就是排好序时应在该位置的元素，左边的都不大于它，右边的都不小于它
+pub fn quickselect<T: Ord>(arr: &mut [T], k: usize) -> &T {
+    assert!(
+        k < arr.len(),
+        "Index {k} out of bounds for length {}.",
+        arr.len()
+    );
+    let budget = 2 * arr.len().ilog2() as usize;
+    select(arr, k, budget);
+    &arr[k]
+}
+
+/// 只把最小的 `k` 个元素按顺序放到 `arr[..k]`，其余元素的顺序不做保证
+pub fn partial_sort<T: Ord>(arr: &mut [T], k: usize) {
+    let k = k.min(arr.len());
+    if k == 0 {
+        return;
+    }
+    quickselect(arr, k - 1);
+    intro_sort(&mut arr[..k - 1]);
+}
+
+/// `budget` 是还允许使用普通 pivot 的分区次数，用完之后改用中位数的中位数
+fn select<T: Ord>(mut arr: &mut [T], mut k: usize, mut budget: usize) {
+    let compare = &mut |a: &T, b: &T| a.cmp(b);
+    loop {
+        if arr.len() <= INSERTION_THRESHOLD {
+            insertion_sort(arr);
+            return;
+        }
+
+        let pivot = if budget == 0 {
+            let pivot = median_of_medians(arr);
+            arr.swap(0, pivot);
+            partition_around_first(arr, compare, &mut ())
+        } else {
+            budget -= 1;
+            partition(arr, compare, &mut ())
+        };
+
+        match k.cmp(&pivot) {
+            Ordering::Equal => return,
+            Ordering::Less => arr = &mut std::mem::take(&mut arr)[..pivot],
+            Ordering::Greater => {
+                arr = &mut std::mem::take(&mut arr)[pivot + 1..];
+                k -= pivot + 1;
+            }
+        }
+    }
+}
+
+/// 每 5 个元素一组取中位数，再递归地求这些中位数的中位数，返回它的下标
+fn median_of_medians<T: Ord>(arr: &mut [T]) -> usize {
+    let groups = arr.len() / 5;
+    for g in 0..groups {
+        let group = &mut arr[g * 5..g * 5 + 5];
+        insertion_sort(group);
+        // 把每组的中位数集中到数组开头
+        arr.swap(g, g * 5 + 2);
+    }
+    let mid = groups / 2;
+    select(&mut arr[..groups], mid, 0);
+    mid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{partial_sort, quickselect, select};
+    use crate::utils::random::Rng;
+
+    fn check_partitioned(arr: &[i64], k: usize) {
+        assert!(arr[..k].iter().all(|x| *x <= arr[k]));
+        assert!(arr[k + 1..].iter().all(|x| *x >= arr[k]));
+    }
+
+    #[test]
+    fn selects_kth_smallest() {
+        let mut rng = Rng::new(1381);
+        for len in [1, 2, 5, 17, 100, 1001] {
+            let data: Vec<i64> = (0..len).map(|_| rng.gen_range(-50, 50)).collect();
+            let mut sorted = data.clone();
+            sorted.sort();
+            for k in [0, len / 3, len / 2, len - 1] {
+                let mut arr = data.clone();
+                assert_eq!(*quickselect(&mut arr, k), sorted[k]);
+                check_partitioned(&arr, k);
+            }
+        }
+    }
+
+    #[test]
+    fn median_of_medians_path_is_correct() {
+        let mut rng = Rng::new(42);
+        let data: Vec<i64> = (0..2000).map(|_| rng.gen_range(0, 1_000_000)).collect();
+        let mut sorted = data.clone();
+        sorted.sort();
+        for k in [0, 1, 999, 1998, 1999] {
+            let mut arr = data.clone();
+            // budget 为 0 时每一轮都使用中位数的中位数
+            select(&mut arr, k, 0);
+            assert_eq!(arr[k], sorted[k]);
+            check_partitioned(&arr, k);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_out_of_bounds() {
+        quickselect(&mut [1, 2, 3], 3);
+    }
+
+    #[test]
+    fn partial_sort_orders_prefix() {
+        let mut rng = Rng::new(7);
+        let data: Vec<i64> = (0..500).map(|_| rng.gen_range(0, 100)).collect();
+        let mut sorted = data.clone();
+        sorted.sort();
+        for k in [0, 1, 10, 499, 500, 600] {
+            let mut arr = data.clone();
+            partial_sort(&mut arr, k);
+            let k = k.min(arr.len());
+            assert_eq!(arr[..k], sorted[..k]);
+            let mut rest = arr[k..].to_vec();
+            rest.sort();
+            assert_eq!(rest, sorted[k..]);
+        }
+    }
+}
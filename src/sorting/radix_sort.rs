@@ -0,0 +1,193 @@
+/*!
+定义：
+[基数排序的定义](https://zh.wikipedia.org/wiki/%E5%9F%BA%E6%95%B0%E6%8E%92%E5%BA%8F)
+按位（这里以一个字节为一位，即基数 256）逐位分配到桶中完成排序
+LSD 从最低位开始，每一轮都必须是稳定的；MSD 从最高位开始，按桶递归，适合字符串这种变长的键
+*/
+
+/// 桶的数量，下标 0 留给“键已经结束”的元素（字符串比较短的情况），其余对应字节 0..=255
+const BUCKETS: usize = 257;
+
+/// 可以按字节拆分的整数键
+pub trait RadixKey: Copy {
+    /// 键占用的字节数
+    const BYTES: usize;
+
+    /// 取出第 `i` 个字节，0 表示最低位
+    fn byte(self, i: usize) -> usize;
+}
+
+macro_rules! impl_radix_key {
+    ($($t:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BYTES: usize = std::mem::size_of::<$t>();
+
+                fn byte(self, i: usize) -> usize {
+                    ((self >> (8 * i)) & 0xff) as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_key!(u8, u16, u32, u64, u128, usize);
+
+/// LSD 基数排序
+pub fn radix_sort<K: RadixKey>(arr: &mut [K]) {
+    radix_sort_by_key(arr, |&k| k);
+}
+
+/// 按照 `key` 提取出的整数键做 LSD 基数排序，排序是稳定的
+pub fn radix_sort_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    T: Clone,
+    K: RadixKey,
+    F: Fn(&T) -> K,
+{
+    if arr.len() < 2 {
+        return;
+    }
+
+    let mut buffer = arr.to_vec();
+    for byte in 0..K::BYTES {
+        let mut starts = [0usize; BUCKETS];
+        for item in arr.iter() {
+            starts[key(item).byte(byte) + 1] += 1;
+        }
+        // 这一位上所有元素都落在同一个桶里，这一轮不会改变顺序，直接跳过
+        if starts.contains(&arr.len()) {
+            continue;
+        }
+        for b in 1..BUCKETS {
+            starts[b] += starts[b - 1];
+        }
+
+        // 顺序遍历放入桶中，保证稳定性
+        for item in arr.iter() {
+            let b = key(item).byte(byte);
+            buffer[starts[b]] = item.clone();
+            starts[b] += 1;
+        }
+        arr.clone_from_slice(&buffer);
+    }
+}
+
+/// MSD 基数排序，原地完成（American flag sort），不需要额外的缓冲区
+pub fn msd_radix_sort<K: RadixKey>(arr: &mut [K]) {
+    american_flag_sort(arr, 0, &|k: &K, depth| {
+        // depth 从最高位开始计数
+        (depth < K::BYTES).then(|| k.byte(K::BYTES - 1 - depth))
+    });
+}
+
+/// 对字符串（或任何字节序列）做 MSD 基数排序，结果为字节序的字典序
+pub fn msd_radix_sort_strings<S: AsRef<[u8]>>(arr: &mut [S]) {
+    american_flag_sort(arr, 0, &|s: &S, depth| {
+        s.as_ref().get(depth).map(|&b| b as usize)
+    });
+}
+
+/// 按第 `depth` 位把元素原地交换到各自的桶中，然后递归地对每个桶按下一位排序
+///
+/// `digit` 返回 `None` 表示键已经用完，这些元素彼此相等，会被放在最前面且不再递归
+fn american_flag_sort<T, F>(arr: &mut [T], depth: usize, digit: &F)
+where
+    F: Fn(&T, usize) -> Option<usize>,
+{
+    if arr.len() < 2 {
+        return;
+    }
+
+    let bucket_of = |item: &T| digit(item, depth).map_or(0, |d| d + 1);
+
+    let mut counts = [0usize; BUCKETS];
+    for item in arr.iter() {
+        counts[bucket_of(item)] += 1;
+    }
+
+    // starts/ends 是每个桶在数组中的区间
+    let mut starts = [0usize; BUCKETS];
+    let mut ends = [0usize; BUCKETS];
+    let mut sum = 0;
+    for b in 0..BUCKETS {
+        starts[b] = sum;
+        sum += counts[b];
+        ends[b] = sum;
+    }
+
+    // 每个位置上的元素不属于当前桶时，把它换到目标桶的下一个空位
+    let mut next = starts;
+    for b in 0..BUCKETS {
+        while next[b] < ends[b] {
+            let target = bucket_of(&arr[next[b]]);
+            if target == b {
+                next[b] += 1;
+            } else {
+                arr.swap(next[b], next[target]);
+                next[target] += 1;
+            }
+        }
+    }
+
+    // 桶 0 中的键已经结束，无需继续
+    for b in 1..BUCKETS {
+        american_flag_sort(&mut arr[starts[b]..ends[b]], depth + 1, digit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{msd_radix_sort, msd_radix_sort_strings, radix_sort, radix_sort_by_key};
+
+    #[test]
+    fn lsd_sorts_u32_and_u64() {
+        let mut small: Vec<u32> = vec![170, 45, 75, 90, 802, 24, 2, 66, u32::MAX, 0];
+        radix_sort(&mut small);
+        assert_eq!(small, vec![0, 2, 24, 45, 66, 75, 90, 170, 802, u32::MAX]);
+
+        let mut big: Vec<u64> = vec![1 << 40, 3, 1 << 63, 42, 1 << 20, 42];
+        radix_sort(&mut big);
+        assert_eq!(big, vec![3, 42, 42, 1 << 20, 1 << 40, 1 << 63]);
+    }
+
+    #[test]
+    fn msd_matches_lsd() {
+        let mut a: Vec<u64> = (0..500u64)
+            .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> (i % 48))
+            .collect();
+        let mut b = a.clone();
+        let mut expected = a.clone();
+        expected.sort();
+        radix_sort(&mut a);
+        msd_radix_sort(&mut b);
+        assert_eq!(a, expected);
+        assert_eq!(b, expected);
+    }
+
+    #[test]
+    fn sorts_strings() {
+        let mut words = vec![
+            "she", "sells", "sea", "shells", "by", "the", "", "sea", "shore",
+        ];
+        msd_radix_sort_strings(&mut words);
+        assert_eq!(
+            words,
+            vec!["", "by", "sea", "sea", "sells", "she", "shells", "shore", "the"]
+        );
+
+        let mut owned: Vec<String> = vec!["b".into(), "ab".into(), "a".into(), "abc".into()];
+        msd_radix_sort_strings(&mut owned);
+        assert_eq!(owned, vec!["a", "ab", "abc", "b"]);
+    }
+
+    #[test]
+    fn sorts_structs_by_key_stably() {
+        let mut items = vec![(3u32, 'a'), (1, 'b'), (300, 'c'), (1, 'd'), (3, 'e')];
+        radix_sort_by_key(&mut items, |&(k, _)| k);
+        assert_eq!(
+            items,
+            vec![(1, 'b'), (1, 'd'), (3, 'a'), (3, 'e'), (300, 'c')]
+        );
+    }
+}
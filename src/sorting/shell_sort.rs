@@ -0,0 +1,193 @@
+/*!
+定义：
+[希尔排序的定义](https://zh.wikipedia.org/wiki/%E5%B8%8C%E5%B0%94%E6%8E%92%E5%BA%8F)
+先对相隔 gap 的元素做插入排序，再逐步缩小 gap 直到 1。gap 序列的选择决定了算法的复杂度：
+- Shell 原始序列 n/2, n/4, ..., 1，最坏 O(n²)
+- Knuth 序列 1, 4, 13, 40, ...（3h + 1），最坏 O(n^1.5)
+- Ciura 序列 1, 4, 10, 23, 57, 132, 301, 701, 1750，实验得出，实际表现最好
+*/
+
+use super::sorter::Sorter;
+use crate::instrumented::Counters;
+use crate::visualization::Observer;
+use std::cmp::Ordering;
+
+/// 希尔排序使用的间隔序列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapSequence {
+    Shell,
+    Knuth,
+    Ciura,
+}
+
+/// Ciura 实验得出的序列，超出部分按 2.25 倍扩展
+const CIURA_GAPS: [usize; 9] = [1, 4, 10, 23, 57, 132, 301, 701, 1750];
+
+impl GapSequence {
+    /// 返回长度为 `n` 的数组要用到的间隔，从大到小排列，最后一个总是 1
+    pub fn gaps(&self, n: usize) -> Vec<usize> {
+        let mut gaps = match self {
+            GapSequence::Shell => {
+                let mut gaps = vec![];
+                let mut gap = n / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+                gaps.reverse();
+                gaps
+            }
+            GapSequence::Knuth => {
+                let mut gaps = vec![1];
+                let mut gap = 4;
+                while gap < n.div_ceil(3) {
+                    gaps.push(gap);
+                    gap = gap * 3 + 1;
+                }
+                gaps
+            }
+            GapSequence::Ciura => {
+                let mut gaps: Vec<usize> = CIURA_GAPS.iter().copied().filter(|&g| g < n).collect();
+                let mut gap = (*CIURA_GAPS.last().unwrap() as f64 * 2.25) as usize;
+                while gap < n {
+                    gaps.push(gap);
+                    gap = (gap as f64 * 2.25) as usize;
+                }
+                gaps
+            }
+        };
+        if gaps.is_empty() {
+            gaps.push(1);
+        }
+        gaps.reverse();
+        gaps
+    }
+}
+
+pub fn shell_sort<T: Ord>(arr: &mut [T], sequence: GapSequence) {
+    shell_sort_by(arr, sequence, |a, b| a.cmp(b));
+}
+
+/// 使用自定义比较函数的希尔排序，方便统计比较次数
+pub fn shell_sort_by<T, F>(arr: &mut [T], sequence: GapSequence, compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    shell_sort_impl(arr, sequence, compare, &mut ());
+}
+
+/// 把每次比较和交换报告给 `observer`，下标是元素在 `arr` 中的当前位置
+pub fn shell_sort_observed<T, O>(arr: &mut [T], sequence: GapSequence, observer: &mut O)
+where
+    T: Ord,
+    O: Observer + ?Sized,
+{
+    shell_sort_impl(arr, sequence, |a, b| a.cmp(b), observer);
+}
+
+fn shell_sort_impl<T, F, O>(arr: &mut [T], sequence: GapSequence, mut compare: F, observer: &mut O)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    O: Observer + ?Sized,
+{
+    for gap in sequence.gaps(arr.len()) {
+        // 对每个以 gap 为间隔的子序列做插入排序
+        for i in gap..arr.len() {
+            let mut j = i;
+            while j >= gap {
+                let ordering = compare(&arr[j - gap], &arr[j]);
+                observer.on_compare(j - gap, j);
+                if ordering != Ordering::Greater {
+                    break;
+                }
+                arr.swap(j - gap, j);
+                observer.on_swap(j - gap, j);
+                j -= gap;
+            }
+        }
+    }
+}
+
+/// 使用指定间隔序列的希尔排序
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShellSort(pub GapSequence);
+
+impl Sorter for ShellSort {
+    fn name(&self) -> &'static str {
+        match self.0 {
+            GapSequence::Shell => "shell_sort_shell",
+            GapSequence::Knuth => "shell_sort_knuth",
+            GapSequence::Ciura => "shell_sort_ciura",
+        }
+    }
+
+    fn is_stable(&self) -> bool {
+        false
+    }
+
+    fn is_in_place(&self) -> bool {
+        true
+    }
+
+    fn sort<T: Ord>(&self, arr: &mut [T]) {
+        shell_sort(arr, self.0);
+    }
+
+    fn sort_counted<T: Ord>(&self, arr: &mut [T], counters: &Counters) {
+        shell_sort_observed(arr, self.0, &mut counters.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shell_sort, shell_sort_by, GapSequence};
+    use crate::utils::random::Rng;
+
+    const SEQUENCES: [GapSequence; 3] =
+        [GapSequence::Shell, GapSequence::Knuth, GapSequence::Ciura];
+
+    #[test]
+    fn gap_sequences() {
+        assert_eq!(GapSequence::Shell.gaps(20), vec![10, 5, 2, 1]);
+        assert_eq!(GapSequence::Knuth.gaps(100), vec![13, 4, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(100), vec![57, 23, 10, 4, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(5000)[0], 3937);
+        for sequence in SEQUENCES {
+            assert_eq!(sequence.gaps(0), vec![1]);
+        }
+    }
+
+    #[test]
+    fn sorts_with_every_sequence() {
+        let mut rng = Rng::new(1374);
+        for sequence in SEQUENCES {
+            for len in [0, 1, 2, 17, 300] {
+                let mut arr: Vec<i64> = (0..len).map(|_| rng.gen_range(-50, 50)).collect();
+                let mut expected = arr.clone();
+                expected.sort();
+                shell_sort(&mut arr, sequence);
+                assert_eq!(arr, expected, "{sequence:?} failed on length {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn better_gaps_need_fewer_comparisons() {
+        let mut rng = Rng::new(2024);
+        let data: Vec<u64> = (0..5000).map(|_| rng.next_u64()).collect();
+        let comparisons = |sequence| {
+            let mut arr = data.clone();
+            let mut count = 0usize;
+            shell_sort_by(&mut arr, sequence, |a, b| {
+                count += 1;
+                a.cmp(b)
+            });
+            count
+        };
+        let shell = comparisons(GapSequence::Shell);
+        let knuth = comparisons(GapSequence::Knuth);
+        let ciura = comparisons(GapSequence::Ciura);
+        assert!(ciura < knuth, "ciura {ciura} vs knuth {knuth}");
+        assert!(ciura < shell, "ciura {ciura} vs shell {shell}");
+    }
+}
@@ -0,0 +1,105 @@
+/*!
+判断数组是否有序，以及统计[逆序对](https://zh.wikipedia.org/wiki/%E9%80%86%E5%BA%8F%E5%AF%B9)的个数
+逆序对是满足 i < j 且 arr[i] > arr[j] 的下标对，它衡量数组离有序还有多远：
+冒泡排序、插入排序交换相邻元素的次数恰好等于逆序对的个数
+*/
+
+pub fn is_sorted<T: Ord>(arr: &[T]) -> bool {
+    is_sorted_by(arr, |a, b| a <= b)
+}
+
+/// `in_order(a, b)` 返回 true 表示 a 可以排在 b 前面
+pub fn is_sorted_by<T, F>(arr: &[T], mut in_order: F) -> bool
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    arr.windows(2).all(|w| in_order(&w[0], &w[1]))
+}
+
+/// 用归并排序统计逆序对的个数，O(n log n)，不会修改输入
+pub fn count_inversions<T: Ord>(arr: &[T]) -> u64 {
+    // 对引用排序，不需要 T: Clone
+    let mut refs: Vec<&T> = arr.iter().collect();
+    let mut buffer = refs.clone();
+    sort_and_count(&mut refs, &mut buffer)
+}
+
+fn sort_and_count<'a, T: Ord>(arr: &mut [&'a T], buffer: &mut [&'a T]) -> u64 {
+    let len = arr.len();
+    if len < 2 {
+        return 0;
+    }
+
+    let mid = len / 2;
+    let mut count = sort_and_count(&mut arr[..mid], &mut buffer[..mid])
+        + sort_and_count(&mut arr[mid..], &mut buffer[mid..]);
+
+    let (mut i, mut j) = (0, mid);
+    for slot in buffer[..len].iter_mut() {
+        if j == len || (i < mid && arr[i] <= arr[j]) {
+            *slot = arr[i];
+            i += 1;
+        } else {
+            // 右边的元素比左边剩下的 mid - i 个元素都小，每个都构成一个逆序对
+            *slot = arr[j];
+            count += (mid - i) as u64;
+            j += 1;
+        }
+    }
+    arr.copy_from_slice(&buffer[..len]);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_inversions, is_sorted, is_sorted_by};
+    use crate::utils::random::Rng;
+
+    /// 冒泡排序并返回交换次数
+    fn bubble_sort_swaps(arr: &mut [i64]) -> u64 {
+        let mut swaps = 0;
+        for end in (1..arr.len()).rev() {
+            for i in 0..end {
+                if arr[i] > arr[i + 1] {
+                    arr.swap(i, i + 1);
+                    swaps += 1;
+                }
+            }
+        }
+        swaps
+    }
+
+    #[test]
+    fn checks_sortedness() {
+        assert!(is_sorted::<i32>(&[]));
+        assert!(is_sorted(&[1]));
+        assert!(is_sorted(&[1, 2, 2, 3]));
+        assert!(!is_sorted(&[1, 3, 2]));
+        assert!(is_sorted_by(&[3, 2, 2, 1], |a, b| a >= b));
+        assert!(!is_sorted_by(&[1, 1], |a, b| a < b));
+    }
+
+    #[test]
+    fn counts_known_inversions() {
+        assert_eq!(count_inversions::<i32>(&[]), 0);
+        assert_eq!(count_inversions(&[1, 2, 3]), 0);
+        assert_eq!(count_inversions(&[2, 4, 1, 3, 5]), 3);
+        assert_eq!(count_inversions(&[1, 1, 1]), 0);
+        // 完全逆序时是 n(n-1)/2
+        let reversed: Vec<u32> = (0..100).rev().collect();
+        assert_eq!(count_inversions(&reversed), 100 * 99 / 2);
+    }
+
+    #[test]
+    fn inversions_equal_bubble_sort_swaps() {
+        let mut rng = Rng::new(1383);
+        for _ in 0..200 {
+            let len = rng.gen_index(60);
+            let mut arr: Vec<i64> = (0..len).map(|_| rng.gen_range(-10, 10)).collect();
+            let inversions = count_inversions(&arr);
+            assert_eq!(inversions, bubble_sort_swaps(&mut arr));
+            assert!(is_sorted(&arr));
+            assert_eq!(count_inversions(&arr), 0);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+/*!
+统一的排序接口
+每个基于比较的排序算法都有一个实现了 `Sorter` 的类型，`all()` 返回所有算法，
+基准测试、模糊测试和演示程序可以用同样的方式遍历它们。
+
+计数排序、基数排序只能处理整数键，外排序的输入是 `Read`，并行排序要求 `T: Send`，
+它们的接口和 `Sorter` 不同，所以不在注册表中
+*/
+
+use crate::instrumented::{Counters, CountingOrd};
+
+use super::esoteric_sorts::{CocktailShakerSort, CombSort, CycleSort, GnomeSort, PancakeSort};
+use super::heap_sort::HeapSort;
+use super::insertion_sort::InsertionSort;
+use super::intro_sort::IntroSort;
+use super::merge_sort::MergeSort;
+use super::shell_sort::{GapSequence, ShellSort};
+use super::tim_sort::TimSort;
+use std::cmp::Ordering;
+
+pub trait Sorter {
+    /// 算法名称，用于输出和基准测试的分组
+    fn name(&self) -> &'static str;
+
+    /// 相等的元素排序后是否保持原有的相对顺序
+    fn is_stable(&self) -> bool;
+
+    /// 是否只需要 O(1) 的额外空间（递归栈除外）
+    fn is_in_place(&self) -> bool;
+
+    fn sort<T: Ord>(&self, arr: &mut [T]);
+
+    /// 排序并把操作次数记到 `counters` 中。默认实现对包装过的引用排序，只能数出比较次数，
+    /// 支持 [`crate::visualization::Observer`] 的算法会覆盖它，同时数出交换次数
+    fn sort_counted<T: Ord>(&self, arr: &mut [T], counters: &Counters) {
+        let mut wrapped: Vec<CountingOrd<Indexed<T>>> = arr
+            .iter()
+            .enumerate()
+            .map(|(index, value)| counters.wrap(Indexed { value, index }))
+            .collect();
+        self.sort(&mut wrapped);
+        let order: Vec<usize> = wrapped.iter().map(|w| w.get().index).collect();
+        apply_permutation(arr, order);
+    }
+}
+
+/// 只按 value 比较，index 记录元素原来的位置
+struct Indexed<'a, T> {
+    value: &'a T,
+    index: usize,
+}
+
+impl<T: Ord> PartialEq for Indexed<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Ord> Eq for Indexed<'_, T> {}
+
+impl<T: Ord> PartialOrd for Indexed<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Indexed<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(other.value)
+    }
+}
+
+/// 原地重排，使新的 `arr[k]` 是原来的 `arr[order[k]]`
+fn apply_permutation<T>(arr: &mut [T], mut order: Vec<usize>) {
+    for i in 0..arr.len() {
+        // 沿着置换的环依次把元素换到位，换好的位置标记为不动点
+        let mut current = i;
+        while order[current] != i {
+            let next = order[current];
+            arr.swap(current, next);
+            order[current] = current;
+            current = next;
+        }
+        order[current] = current;
+    }
+}
+
+/// 注册表中的排序算法，把各个 `Sorter` 实现统一成一个类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortAlgorithm {
+    Insertion(InsertionSort),
+    Shell(ShellSort),
+    Heap(HeapSort),
+    Intro(IntroSort),
+    Merge(MergeSort),
+    Tim(TimSort),
+    Comb(CombSort),
+    Gnome(GnomeSort),
+    CocktailShaker(CocktailShakerSort),
+    Cycle(CycleSort),
+    Pancake(PancakeSort),
+}
+
+/// 把调用转发给枚举中具体的排序算法
+macro_rules! dispatch {
+    ($algorithm:expr, $sorter:ident => $call:expr) => {
+        match $algorithm {
+            SortAlgorithm::Insertion($sorter) => $call,
+            SortAlgorithm::Shell($sorter) => $call,
+            SortAlgorithm::Heap($sorter) => $call,
+            SortAlgorithm::Intro($sorter) => $call,
+            SortAlgorithm::Merge($sorter) => $call,
+            SortAlgorithm::Tim($sorter) => $call,
+            SortAlgorithm::Comb($sorter) => $call,
+            SortAlgorithm::Gnome($sorter) => $call,
+            SortAlgorithm::CocktailShaker($sorter) => $call,
+            SortAlgorithm::Cycle($sorter) => $call,
+            SortAlgorithm::Pancake($sorter) => $call,
+        }
+    };
+}
+
+impl Sorter for SortAlgorithm {
+    fn name(&self) -> &'static str {
+        dispatch!(self, s => s.name())
+    }
+
+    fn is_stable(&self) -> bool {
+        dispatch!(self, s => s.is_stable())
+    }
+
+    fn is_in_place(&self) -> bool {
+        dispatch!(self, s => s.is_in_place())
+    }
+
+    fn sort<T: Ord>(&self, arr: &mut [T]) {
+        dispatch!(self, s => s.sort(arr))
+    }
+
+    fn sort_counted<T: Ord>(&self, arr: &mut [T], counters: &Counters) {
+        dispatch!(self, s => s.sort_counted(arr, counters))
+    }
+}
+
+/// 返回所有实现了 `Sorter` 的排序算法
+pub fn all() -> Vec<SortAlgorithm> {
+    vec![
+        SortAlgorithm::Insertion(InsertionSort),
+        SortAlgorithm::Shell(ShellSort(GapSequence::Shell)),
+        SortAlgorithm::Shell(ShellSort(GapSequence::Knuth)),
+        SortAlgorithm::Shell(ShellSort(GapSequence::Ciura)),
+        SortAlgorithm::Heap(HeapSort),
+        SortAlgorithm::Intro(IntroSort),
+        SortAlgorithm::Merge(MergeSort),
+        SortAlgorithm::Tim(TimSort),
+        SortAlgorithm::Comb(CombSort),
+        SortAlgorithm::Gnome(GnomeSort),
+        SortAlgorithm::CocktailShaker(CocktailShakerSort),
+        SortAlgorithm::Cycle(CycleSort),
+        SortAlgorithm::Pancake(PancakeSort),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all, Sorter};
+    use crate::sorting::keyed::Keyed;
+    use crate::utils::random::Rng;
+    use std::collections::HashSet;
+
+    /// 各种典型的输入：随机、已排序、逆序、大量重复、锯齿形
+    fn inputs() -> Vec<Vec<i64>> {
+        let mut rng = Rng::new(1379);
+        let mut inputs = vec![vec![], vec![1], vec![2, 1], vec![1, 1, 1]];
+        for len in [10, 100, 1000] {
+            inputs.push((0..len).map(|_| rng.gen_range(-1000, 1000)).collect());
+            inputs.push((0..len).collect());
+            inputs.push((0..len).rev().collect());
+            inputs.push((0..len).map(|_| rng.gen_range(0, 4)).collect());
+            inputs.push((0..len).map(|i| i % 17).collect());
+        }
+        inputs
+    }
+
+    #[test]
+    fn every_algorithm_sorts_every_input() {
+        for sorter in all() {
+            for input in inputs() {
+                let mut arr = input.clone();
+                let mut expected = input;
+                expected.sort();
+                sorter.sort(&mut arr);
+                assert_eq!(arr, expected, "{} failed", sorter.name());
+            }
+        }
+    }
+
+    #[test]
+    fn stable_algorithms_keep_equal_elements_in_order() {
+        for sorter in all().into_iter().filter(|s| s.is_stable()) {
+            for input in inputs() {
+                let mut arr: Vec<Keyed<i64>> = input
+                    .iter()
+                    .enumerate()
+                    .map(|(index, &key)| Keyed {
+                        key: key % 5,
+                        index,
+                    })
+                    .collect();
+                sorter.sort(&mut arr);
+                assert!(
+                    arr.windows(2)
+                        .all(|w| w[0] < w[1] || w[0].index < w[1].index),
+                    "{} is not stable",
+                    sorter.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn registry_metadata() {
+        let sorters = all();
+        let names: HashSet<_> = sorters.iter().map(|s| s.name()).collect();
+        assert_eq!(names.len(), sorters.len(), "names must be unique");
+        let tim = sorters.iter().find(|s| s.name() == "tim_sort").unwrap();
+        assert!(tim.is_stable());
+        assert!(!tim.is_in_place());
+        let heap = sorters.iter().find(|s| s.name() == "heap_sort").unwrap();
+        assert!(!heap.is_stable());
+        assert!(heap.is_in_place());
+    }
+}
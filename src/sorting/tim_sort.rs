@@ -0,0 +1,344 @@
+/*!
+定义：
+[Timsort 的定义](https://zh.wikipedia.org/wiki/Timsort)
+这里实现的是一个简化版本（没有 galloping 模式）：
+1. 从左到右找出天然有序的段（run），严格递减的段原地翻转
+2. 太短的段用二分插入排序补齐到最小长度 minrun
+3. 把段压入栈中，维持 |Z| > |Y| + |X| 且 |Y| > |X| 的不变式，否则合并
+
+整个排序是稳定的，并且对部分有序的输入比较次数明显更少
+*/
+
+use super::sorter::Sorter;
+use std::cmp::Ordering;
+use std::ptr;
+
+pub fn tim_sort<T: Ord>(arr: &mut [T]) {
+    tim_sort_by(arr, |a, b| a.cmp(b));
+}
+
+/// 使用自定义比较函数的 Timsort，可以传入带计数的比较函数观察自适应效果
+pub fn tim_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = arr.len();
+    if len < 2 {
+        return;
+    }
+
+    let min_run = min_run_length(len);
+    // 合并用的缓冲区，只借用其容量，元素的所有权始终在 arr 中
+    let mut buffer: Vec<T> = Vec::with_capacity(len / 2 + 1);
+    // 栈中保存每个段的 (起点, 长度)
+    let mut runs: Vec<(usize, usize)> = vec![];
+
+    let mut start = 0;
+    while start < len {
+        let mut run = count_run_and_make_ascending(&mut arr[start..], &mut compare);
+        if run < min_run {
+            let forced = min_run.min(len - start);
+            binary_insertion_sort(&mut arr[start..start + forced], run, &mut compare);
+            run = forced;
+        }
+        runs.push((start, run));
+        start += run;
+        collapse(arr, &mut runs, &mut buffer, &mut compare);
+    }
+
+    // 最后把栈中剩余的段全部合并
+    while runs.len() > 1 {
+        let n = runs.len();
+        merge_at(arr, &mut runs, n - 2, &mut buffer, &mut compare);
+    }
+}
+
+/// 计算最小段长度：取 n 的最高 6 位，如果其余位中有 1 则再加 1，结果落在 [32, 64]
+fn min_run_length(mut n: usize) -> usize {
+    let mut r = 0;
+    while n >= 64 {
+        r |= n & 1;
+        n >>= 1;
+    }
+    n + r
+}
+
+/// 返回从开头开始的有序段长度，严格递减的段会被翻转为递增
+fn count_run_and_make_ascending<T, F>(arr: &mut [T], compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() < 2 {
+        return arr.len();
+    }
+
+    let mut end = 2;
+    if compare(&arr[1], &arr[0]) == Ordering::Less {
+        // 只能翻转严格递减的段，否则会破坏稳定性
+        while end < arr.len() && compare(&arr[end], &arr[end - 1]) == Ordering::Less {
+            end += 1;
+        }
+        arr[..end].reverse();
+    } else {
+        while end < arr.len() && compare(&arr[end], &arr[end - 1]) != Ordering::Less {
+            end += 1;
+        }
+    }
+    end
+}
+
+/// `arr[..sorted]` 已经有序，把其余元素逐个二分查找位置后插入
+fn binary_insertion_sort<T, F>(arr: &mut [T], sorted: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in sorted.max(1)..arr.len() {
+        // 找到第一个大于 arr[i] 的位置，插在它前面以保证稳定
+        let (mut lo, mut hi) = (0, i);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if compare(&arr[i], &arr[mid]) == Ordering::Less {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        arr[lo..=i].rotate_right(1);
+    }
+}
+
+/// 检查栈顶的段是否满足不变式，不满足就合并
+fn collapse<T, F>(
+    arr: &mut [T],
+    runs: &mut Vec<(usize, usize)>,
+    buffer: &mut Vec<T>,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        let n = runs.len();
+        let len_of = |i: usize| runs[i].1;
+        if (n >= 3 && len_of(n - 3) <= len_of(n - 2) + len_of(n - 1))
+            || (n >= 4 && len_of(n - 4) <= len_of(n - 3) + len_of(n - 2))
+        {
+            // 和两侧中较短的那个合并
+            let at = if len_of(n - 3) < len_of(n - 1) {
+                n - 3
+            } else {
+                n - 2
+            };
+            merge_at(arr, runs, at, buffer, compare);
+        } else if n >= 2 && len_of(n - 2) <= len_of(n - 1) {
+            merge_at(arr, runs, n - 2, buffer, compare);
+        } else {
+            break;
+        }
+    }
+}
+
+/// 合并栈中第 `i` 和第 `i + 1` 个相邻的段
+fn merge_at<T, F>(
+    arr: &mut [T],
+    runs: &mut Vec<(usize, usize)>,
+    i: usize,
+    buffer: &mut Vec<T>,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (start, left_len) = runs[i];
+    let (_, right_len) = runs.remove(i + 1);
+    runs[i] = (start, left_len + right_len);
+
+    merge(
+        &mut arr[start..start + left_len + right_len],
+        left_len,
+        buffer,
+        compare,
+    );
+}
+
+/// 稳定地合并两个已经有序的相邻段 `arr[..mid]` 和 `arr[mid..]`，`buffer` 只用来提供临时空间
+pub(super) fn merge<T, F>(arr: &mut [T], mid: usize, buffer: &mut Vec<T>, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if mid == 0 || mid == arr.len() {
+        return;
+    }
+    // 左段最大值不大于右段最小值时两段已经整体有序
+    if compare(&arr[mid], &arr[mid - 1]) != Ordering::Less {
+        return;
+    }
+    buffer.reserve(mid);
+    // SAFETY: 缓冲区容量足够放下左段，merge_lo 保证结束或 panic 时每个元素都恰好回到 arr 中
+    unsafe { merge_lo(arr, mid, buffer.as_mut_ptr(), compare) }
+}
+
+/// 合并 `arr[..mid]` 和 `arr[mid..]`：先把左段搬到缓冲区，再从前往后归并回 arr
+unsafe fn merge_lo<T, F>(arr: &mut [T], mid: usize, buf: *mut T, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let v = arr.as_mut_ptr();
+    let end = v.add(arr.len());
+    ptr::copy_nonoverlapping(v, buf, mid);
+
+    // 比较函数可能 panic，hole 被 drop 时会把缓冲区中剩余的元素放回空洞中
+    let mut hole = MergeHole {
+        start: buf,
+        end: buf.add(mid),
+        dest: v,
+    };
+    let mut right = v.add(mid);
+
+    while hole.start < hole.end && right < end {
+        // 只有右边严格更小时才取右边，保证稳定性
+        let take_right = compare(&*right, &*hole.start) == Ordering::Less;
+        let src = if take_right { right } else { hole.start };
+        ptr::copy_nonoverlapping(src, hole.dest, 1);
+        if take_right {
+            right = right.add(1);
+        } else {
+            hole.start = hole.start.add(1);
+        }
+        hole.dest = hole.dest.add(1);
+    }
+    // 右段剩下的元素已经在正确的位置上，左段剩下的由 hole 的 drop 搬回
+}
+
+/// 缓冲区 [start, end) 中的元素需要被搬回 arr 中从 dest 开始的空洞
+struct MergeHole<T> {
+    start: *mut T,
+    end: *mut T,
+    dest: *mut T,
+}
+
+impl<T> Drop for MergeHole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let remaining = self.end.offset_from(self.start) as usize;
+            ptr::copy_nonoverlapping(self.start, self.dest, remaining);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimSort;
+
+impl Sorter for TimSort {
+    fn name(&self) -> &'static str {
+        "tim_sort"
+    }
+
+    fn is_stable(&self) -> bool {
+        true
+    }
+
+    fn is_in_place(&self) -> bool {
+        false
+    }
+
+    fn sort<T: Ord>(&self, arr: &mut [T]) {
+        tim_sort(arr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{min_run_length, tim_sort, tim_sort_by};
+    use crate::utils::random::Rng;
+
+    fn count_comparisons(arr: &[i64]) -> usize {
+        let mut arr = arr.to_vec();
+        let mut count = 0;
+        tim_sort_by(&mut arr, |a, b| {
+            count += 1;
+            a.cmp(b)
+        });
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+        count
+    }
+
+    #[test]
+    fn min_run_length_is_in_range() {
+        assert_eq!(min_run_length(63), 63);
+        assert_eq!(min_run_length(64), 32);
+        assert_eq!(min_run_length(65), 33);
+        for n in 64..5000 {
+            assert!((32..=64).contains(&min_run_length(n)));
+        }
+    }
+
+    #[test]
+    fn sorts_random_input() {
+        let mut rng = Rng::new(1375);
+        for len in [0, 1, 2, 31, 64, 65, 1000, 4321] {
+            let mut arr: Vec<i64> = (0..len).map(|_| rng.gen_range(-100, 100)).collect();
+            let mut expected = arr.clone();
+            expected.sort();
+            tim_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn is_stable() {
+        let mut rng = Rng::new(7);
+        let mut arr: Vec<(i64, usize)> = (0..2000).map(|i| (rng.gen_range(0, 10), i)).collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|&(k, _)| k);
+        tim_sort_by(&mut arr, |a, b| a.0.cmp(&b.0));
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn adapts_to_presorted_input() {
+        let n = 10_000;
+        let sorted: Vec<i64> = (0..n).collect();
+        let reversed: Vec<i64> = (0..n).rev().collect();
+        assert_eq!(count_comparisons(&sorted), n as usize - 1);
+        assert_eq!(count_comparisons(&reversed), n as usize - 1);
+
+        // 由几段有序序列拼接而成，以及只有少量元素被扰乱
+        let mut rng = Rng::new(99);
+        let random: Vec<i64> = (0..n).map(|_| rng.gen_range(0, n)).collect();
+        let mut runs = random.clone();
+        for chunk in runs.chunks_mut(n as usize / 4) {
+            chunk.sort();
+        }
+        let mut nearly_sorted = sorted.clone();
+        for _ in 0..10 {
+            let (i, j) = (rng.gen_index(n as usize), rng.gen_index(n as usize));
+            nearly_sorted.swap(i, j);
+        }
+
+        let random_cost = count_comparisons(&random);
+        assert!(count_comparisons(&runs) * 4 < random_cost);
+        assert!(count_comparisons(&nearly_sorted) * 2 < random_cost);
+    }
+
+    #[test]
+    fn panicking_comparator_does_not_lose_elements() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let mut rng = Rng::new(3);
+        let values: Vec<Rc<i64>> = (0..500).map(|_| Rc::new(rng.gen_range(0, 1000))).collect();
+        let mut arr = values.clone();
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            tim_sort_by(&mut arr, |a, b| {
+                calls += 1;
+                if calls == 3000 {
+                    panic!("comparator failed");
+                }
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+        // 每个元素仍然只被持有一次（arr 和 values 各一份）
+        assert!(values.iter().all(|v| Rc::strong_count(v) == 2));
+    }
+}
@@ -0,0 +1,204 @@
+/*!
+定义：
+[序列比对](https://zh.wikipedia.org/wiki/%E5%BA%8F%E5%88%97%E6%AF%94%E5%AF%B9)
+在两个序列中插入空位（gap）使它们对齐，对齐后每一列按相同、不同、空位计分，求总分最高的对齐方式。
+- [Needleman–Wunsch](https://en.wikipedia.org/wiki/Needleman%E2%80%93Wunsch_algorithm)：全局比对，两个序列都要完整对齐
+- [Smith–Waterman](https://en.wikipedia.org/wiki/Smith%E2%80%93Waterman_algorithm)：局部比对，
+  分数低于 0 时从 0 重新开始，找出得分最高的一对子串
+
+`score[i][j]` 是 `a[..i]` 和 `b[..j]` 的最优得分，和编辑距离一样逐格转移，再从终点回溯出对齐结果
+*/
+
+use std::ops::Range;
+
+/// 对齐结果中表示空位的字符
+pub const GAP: u8 = b'-';
+
+/// 计分规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scoring {
+    pub match_score: i64,
+    pub mismatch: i64,
+    pub gap: i64,
+}
+
+impl Scoring {
+    pub fn new(match_score: i64, mismatch: i64, gap: i64) -> Self {
+        Scoring {
+            match_score,
+            mismatch,
+            gap,
+        }
+    }
+
+    fn pair(&self, x: u8, y: u8) -> i64 {
+        if x == y {
+            self.match_score
+        } else {
+            self.mismatch
+        }
+    }
+}
+
+impl Default for Scoring {
+    /// 相同 +1，不同 -1，空位 -1
+    fn default() -> Self {
+        Scoring::new(1, -1, -1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    pub score: i64,
+    /// 对齐后的 a，空位用 [`GAP`] 表示，和 `aligned_b` 等长
+    pub aligned_a: Vec<u8>,
+    pub aligned_b: Vec<u8>,
+    /// 参与对齐的部分在 a 中的区间，全局比对时是整个 a
+    pub a_range: Range<usize>,
+    pub b_range: Range<usize>,
+}
+
+/// 全局比对
+pub fn needleman_wunsch(a: &[u8], b: &[u8], scoring: &Scoring) -> Alignment {
+    let mut score = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in score.iter_mut().enumerate() {
+        row[0] = i as i64 * scoring.gap;
+    }
+    score[0] = (0..=b.len()).map(|j| j as i64 * scoring.gap).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            score[i][j] = (score[i - 1][j - 1] + scoring.pair(a[i - 1], b[j - 1]))
+                .max(score[i - 1][j] + scoring.gap)
+                .max(score[i][j - 1] + scoring.gap);
+        }
+    }
+    traceback(a, b, scoring, &score, (a.len(), b.len()), false)
+}
+
+/// 局部比对，所有格子得分都不超过 0 时返回空的对齐
+pub fn smith_waterman(a: &[u8], b: &[u8], scoring: &Scoring) -> Alignment {
+    let mut score = vec![vec![0; b.len() + 1]; a.len() + 1];
+    let mut best = (0, 0);
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            score[i][j] = (score[i - 1][j - 1] + scoring.pair(a[i - 1], b[j - 1]))
+                .max(score[i - 1][j] + scoring.gap)
+                .max(score[i][j - 1] + scoring.gap)
+                .max(0);
+            if score[i][j] > score[best.0][best.1] {
+                best = (i, j);
+            }
+        }
+    }
+    traceback(a, b, scoring, &score, best, true)
+}
+
+/// 从 `end` 回溯到起点；局部比对遇到得分为 0 的格子就停下
+fn traceback(
+    a: &[u8],
+    b: &[u8],
+    scoring: &Scoring,
+    score: &[Vec<i64>],
+    end: (usize, usize),
+    local: bool,
+) -> Alignment {
+    let (mut i, mut j) = end;
+    let (mut aligned_a, mut aligned_b) = (vec![], vec![]);
+    while (i > 0 || j > 0) && !(local && score[i][j] == 0) {
+        if i > 0 && j > 0 && score[i][j] == score[i - 1][j - 1] + scoring.pair(a[i - 1], b[j - 1]) {
+            aligned_a.push(a[i - 1]);
+            aligned_b.push(b[j - 1]);
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && score[i][j] == score[i - 1][j] + scoring.gap {
+            aligned_a.push(a[i - 1]);
+            aligned_b.push(GAP);
+            i -= 1;
+        } else {
+            aligned_a.push(GAP);
+            aligned_b.push(b[j - 1]);
+            j -= 1;
+        }
+    }
+    aligned_a.reverse();
+    aligned_b.reverse();
+    Alignment {
+        score: score[end.0][end.1],
+        aligned_a,
+        aligned_b,
+        a_range: i..end.0,
+        b_range: j..end.1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{needleman_wunsch, smith_waterman, Alignment, Scoring, GAP};
+    use crate::utils::random::Rng;
+
+    /// 按计分规则重新计算对齐的得分，并检查去掉空位后就是原来的子串
+    fn rescore(alignment: &Alignment, a: &[u8], b: &[u8], scoring: &Scoring) -> i64 {
+        let strip = |s: &[u8]| s.iter().copied().filter(|&c| c != GAP).collect::<Vec<_>>();
+        assert_eq!(strip(&alignment.aligned_a), a[alignment.a_range.clone()]);
+        assert_eq!(strip(&alignment.aligned_b), b[alignment.b_range.clone()]);
+        assert_eq!(alignment.aligned_a.len(), alignment.aligned_b.len());
+        alignment
+            .aligned_a
+            .iter()
+            .zip(&alignment.aligned_b)
+            .map(|(&x, &y)| match (x, y) {
+                (GAP, GAP) => panic!("column with two gaps"),
+                (GAP, _) | (_, GAP) => scoring.gap,
+                (x, y) if x == y => scoring.match_score,
+                _ => scoring.mismatch,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn global_alignment() {
+        let scoring = Scoring::default();
+        let alignment = needleman_wunsch(b"GATTACA", b"GCATGCU", &scoring);
+        assert_eq!(alignment.score, 0);
+        assert_eq!(rescore(&alignment, b"GATTACA", b"GCATGCU", &scoring), 0);
+        assert_eq!((alignment.a_range, alignment.b_range), (0..7, 0..7));
+
+        let empty = needleman_wunsch(b"", b"ACG", &scoring);
+        assert_eq!(empty.score, -3);
+        assert_eq!(empty.aligned_a, b"---");
+    }
+
+    #[test]
+    fn local_alignment() {
+        let scoring = Scoring::new(3, -3, -2);
+        let alignment = smith_waterman(b"TGTTACGG", b"GGTTGACTA", &scoring);
+        assert_eq!(alignment.score, 13);
+        assert_eq!(alignment.aligned_a, b"GTT-AC");
+        assert_eq!(alignment.aligned_b, b"GTTGAC");
+        assert_eq!((alignment.a_range, alignment.b_range), (1..6, 1..7));
+
+        let nothing = smith_waterman(b"AAA", b"CCC", &scoring);
+        assert_eq!(nothing.score, 0);
+        assert!(nothing.aligned_a.is_empty());
+    }
+
+    #[test]
+    fn scores_are_consistent_on_random_sequences() {
+        let mut rng = Rng::new(1436);
+        let scoring = Scoring::new(2, -1, -2);
+        for _ in 0..200 {
+            let random_dna = |rng: &mut Rng| -> Vec<u8> {
+                (0..rng.gen_index(15))
+                    .map(|_| b"ACGT"[rng.gen_index(4)])
+                    .collect()
+            };
+            let (a, b) = (random_dna(&mut rng), random_dna(&mut rng));
+            let global = needleman_wunsch(&a, &b, &scoring);
+            assert_eq!(rescore(&global, &a, &b, &scoring), global.score);
+            let local = smith_waterman(&a, &b, &scoring);
+            assert_eq!(rescore(&local, &a, &b, &scoring), local.score);
+            // 局部比对可以只取一部分，得分不会低于全局比对，也不会低于 0
+            assert!(local.score >= global.score.max(0));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+/*!
+定义：
+[Lyndon 分解](https://en.wikipedia.org/wiki/Lyndon_word#Standard_factorization)
+Lyndon 串是严格小于自己所有非平凡旋转的串。任何串都能唯一地分解成一串字典序不增的 Lyndon 串，
+Duval 算法用两个指针扫描一遍得到分解，O(n) 时间、O(1) 额外空间。
+
+[最小表示](https://en.wikipedia.org/wiki/Lexicographically_minimal_string_rotation)
+Booth 算法在 s + s 上计算类似 KMP 的失配函数，求出字典序最小的旋转的起点，O(n)
+*/
+
+use std::ops::Range;
+
+/// Lyndon 分解，返回每个因子的下标区间
+pub fn lyndon_factorization<T: Ord>(s: &[T]) -> Vec<Range<usize>> {
+    let n = s.len();
+    let mut factors = vec![];
+    let mut i = 0;
+    while i < n {
+        // s[i..j] 是若干个相同的 Lyndon 串加上它的一个前缀，k 指向与 s[j] 比较的位置
+        let (mut j, mut k) = (i + 1, i);
+        while j < n && s[k] <= s[j] {
+            if s[k] < s[j] {
+                k = i;
+            } else {
+                k += 1;
+            }
+            j += 1;
+        }
+        let period = j - k;
+        while i <= k {
+            factors.push(i..i + period);
+            i += period;
+        }
+    }
+    factors
+}
+
+/// 字典序最小的旋转的起点，有多个时返回最小的下标
+pub fn least_rotation<T: Ord>(s: &[T]) -> usize {
+    let n = s.len();
+    if n == 0 {
+        return 0;
+    }
+    let at = |i: usize| &s[i % n];
+    let mut failure: Vec<Option<usize>> = vec![None; 2 * n];
+    let mut best = 0;
+    for j in 1..2 * n {
+        let mut i = failure[j - best - 1];
+        while let Some(p) = i {
+            if at(j) == at(best + p + 1) {
+                break;
+            }
+            if at(j) < at(best + p + 1) {
+                best = j - p - 1;
+            }
+            i = failure[p];
+        }
+        match i {
+            None if at(j) != at(best) => {
+                if at(j) < at(best) {
+                    best = j;
+                }
+                failure[j - best] = None;
+            }
+            None => failure[j - best] = Some(0),
+            Some(p) => failure[j - best] = Some(p + 1),
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{least_rotation, lyndon_factorization};
+    use crate::utils::random::Rng;
+
+    fn is_lyndon(s: &[u8]) -> bool {
+        !s.is_empty() && (1..s.len()).all(|i| s < &[&s[i..], &s[..i]].concat()[..])
+    }
+
+    fn brute_least_rotation(s: &[u8]) -> usize {
+        (0..s.len().max(1))
+            .min_by_key(|&i| [&s[i.min(s.len())..], &s[..i.min(s.len())]].concat())
+            .unwrap()
+    }
+
+    #[test]
+    fn factorizes_known_strings() {
+        let s = b"abracadabra";
+        let factors: Vec<&[u8]> = lyndon_factorization(s).into_iter().map(|r| &s[r]).collect();
+        assert_eq!(factors, vec![&b"abracad"[..], b"abr", b"a"]);
+        assert_eq!(lyndon_factorization(b"aaa"), vec![0..1, 1..2, 2..3]);
+        assert_eq!(lyndon_factorization(b"cba").len(), 3);
+        assert!(lyndon_factorization::<u8>(b"").is_empty());
+    }
+
+    #[test]
+    fn finds_least_rotation() {
+        assert_eq!(least_rotation(b"bbaaccaadd"), 2);
+        assert_eq!(least_rotation(b"cabbage"), 1);
+        assert_eq!(least_rotation(b"aaaa"), 0);
+        assert_eq!(least_rotation::<u8>(b""), 0);
+    }
+
+    #[test]
+    fn properties_on_random_strings() {
+        let mut rng = Rng::new(1435);
+        for _ in 0..500 {
+            let s: Vec<u8> = (0..rng.gen_index(30))
+                .map(|_| b'a' + rng.gen_index(3) as u8)
+                .collect();
+
+            let factors = lyndon_factorization(&s);
+            // 因子首尾相接覆盖整个串，每个都是 Lyndon 串，并且字典序不增
+            let mut end = 0;
+            for range in &factors {
+                assert_eq!(range.start, end);
+                end = range.end;
+                assert!(is_lyndon(&s[range.clone()]));
+            }
+            assert_eq!(end, s.len());
+            for pair in factors.windows(2) {
+                assert!(s[pair[0].clone()] >= s[pair[1].clone()]);
+            }
+
+            assert_eq!(least_rotation(&s), brute_least_rotation(&s));
+        }
+    }
+}
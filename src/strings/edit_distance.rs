@@ -0,0 +1,243 @@
+/*!
+定义：
+[编辑距离的定义](https://zh.wikipedia.org/wiki/%E7%B7%A8%E8%BC%AF%E8%B7%9D%E9%9B%A2)
+Levenshtein 距离是把 a 变成 b 所需的最少插入、删除、替换次数。
+`d[i][j]` 表示 `a[..i]` 变成 `b[..j]` 的距离，只依赖上一行，所以只求距离时保留两行即可。
+[Damerau–Levenshtein 距离](https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance)
+额外允许交换相邻的两个字符，并且允许对交换后的字符继续编辑
+*/
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// 编辑脚本中的一步，按从左到右的顺序排列
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit<T> {
+    /// 保留 a 中的元素
+    Keep(T),
+    /// 把 a 中的 `from` 替换成 `to`
+    Substitute { from: T, to: T },
+    /// 插入 b 中的元素
+    Insert(T),
+    /// 删除 a 中的元素
+    Delete(T),
+}
+
+/// 完整的 DP 表，`table[i][j]` 是 `a[..i]` 和 `b[..j]` 的距离
+pub fn levenshtein_table<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    table[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitute = table[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            table[i][j] = substitute.min(table[i - 1][j] + 1).min(table[i][j - 1] + 1);
+        }
+    }
+    table
+}
+
+/// Levenshtein 距离，只保留较短序列长度的一行，O(min(n, m)) 空间
+pub fn levenshtein<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    // 距离是对称的，让 b 是较短的一个
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.iter().enumerate() {
+        // diagonal 是上一行的 row[j - 1]
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + usize::from(x != y))
+                .min(above + 1)
+                .min(row[j] + 1);
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// 距离以及一份最短的编辑脚本
+pub fn edit_script<T: PartialEq + Clone>(a: &[T], b: &[T]) -> (usize, Vec<Edit<T>>) {
+    let table = levenshtein_table(a, b);
+    let mut script = vec![];
+    let (mut i, mut j) = (a.len(), b.len());
+    // 从右下角沿着取到最小值的方向走回左上角
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && table[i][j] == table[i - 1][j - 1] {
+            script.push(Edit::Keep(a[i - 1].clone()));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && table[i][j] == table[i - 1][j - 1] + 1 {
+            script.push(Edit::Substitute {
+                from: a[i - 1].clone(),
+                to: b[j - 1].clone(),
+            });
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && table[i][j] == table[i - 1][j] + 1 {
+            script.push(Edit::Delete(a[i - 1].clone()));
+            i -= 1;
+        } else {
+            script.push(Edit::Insert(b[j - 1].clone()));
+            j -= 1;
+        }
+    }
+    script.reverse();
+    (table[a.len()][b.len()], script)
+}
+
+/// Damerau–Levenshtein 距离（允许交换相邻字符，交换后的子串还可以继续编辑）
+pub fn damerau_levenshtein<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    let infinity = n + m;
+    // 多加一行一列作为哨兵，d[i + 1][j + 1] 对应 a[..i] 和 b[..j]
+    let mut d = vec![vec![0; m + 2]; n + 2];
+    d[0][0] = infinity;
+    for i in 0..=n {
+        d[i + 1][0] = infinity;
+        d[i + 1][1] = i;
+    }
+    for j in 0..=m {
+        d[0][j + 1] = infinity;
+        d[1][j + 1] = j;
+    }
+    // 每个元素最后一次出现在 a 中的行号
+    let mut last_row: HashMap<&T, usize> = HashMap::new();
+    for i in 1..=n {
+        // 本行中最后一次和 a[i - 1] 相等的 b 的列号
+        let mut last_match_column = 0;
+        for j in 1..=m {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_match_column;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_column = j;
+                0
+            } else {
+                1
+            };
+            d[i + 1][j + 1] = (d[i][j] + cost)
+                .min(d[i + 1][j] + 1)
+                .min(d[i][j + 1] + 1)
+                // 交换 a[k - 1] 和 a[i - 1]，中间的元素删除或插入
+                .min(d[k][l] + (i - k - 1) + 1 + (j - l - 1));
+        }
+        last_row.insert(&a[i - 1], i);
+    }
+    d[n + 1][m + 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{damerau_levenshtein, edit_script, levenshtein, levenshtein_table, Edit};
+    use crate::utils::random::Rng;
+
+    /// 按脚本还原出 (a, b)
+    fn apply(script: &[Edit<u8>]) -> (Vec<u8>, Vec<u8>) {
+        let (mut a, mut b) = (vec![], vec![]);
+        for edit in script {
+            match edit {
+                Edit::Keep(x) => {
+                    a.push(*x);
+                    b.push(*x);
+                }
+                Edit::Substitute { from, to } => {
+                    assert_ne!(from, to);
+                    a.push(*from);
+                    b.push(*to);
+                }
+                Edit::Insert(x) => b.push(*x),
+                Edit::Delete(x) => a.push(*x),
+            }
+        }
+        (a, b)
+    }
+
+    /// 最优字符串对齐距离：只能交换之后不再被编辑的相邻字符
+    fn optimal_string_alignment(a: &[u8], b: &[u8]) -> usize {
+        let mut d = levenshtein_table(a, b);
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                let cost = usize::from(a[i - 1] != b[j - 1]);
+                d[i][j] = (d[i - 1][j - 1] + cost)
+                    .min(d[i - 1][j] + 1)
+                    .min(d[i][j - 1] + 1);
+                if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                    d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+                }
+            }
+        }
+        d[a.len()][b.len()]
+    }
+
+    #[test]
+    fn known_distances() {
+        assert_eq!(levenshtein(b"kitten", b"sitting"), 3);
+        assert_eq!(levenshtein(b"flaw", b"lawn"), 2);
+        assert_eq!(levenshtein(b"", b"abc"), 3);
+        assert_eq!(levenshtein(b"abc", b""), 3);
+        assert_eq!(levenshtein::<u8>(b"", b""), 0);
+        assert_eq!(levenshtein_table(b"kitten", b"sitting")[6][7], 3);
+
+        assert_eq!(damerau_levenshtein(b"ab", b"ba"), 1);
+        // 交换之后还要在中间插入，最优字符串对齐做不到
+        assert_eq!(damerau_levenshtein(b"ca", b"abc"), 2);
+        assert_eq!(optimal_string_alignment(b"ca", b"abc"), 3);
+        assert_eq!(levenshtein(b"ca", b"abc"), 3);
+    }
+
+    #[test]
+    fn builds_edit_script() {
+        let (distance, script) = edit_script(b"kitten", b"sitting");
+        assert_eq!(distance, 3);
+        assert_eq!(
+            script,
+            vec![
+                Edit::Substitute {
+                    from: b'k',
+                    to: b's'
+                },
+                Edit::Keep(b'i'),
+                Edit::Keep(b't'),
+                Edit::Keep(b't'),
+                Edit::Substitute {
+                    from: b'e',
+                    to: b'i'
+                },
+                Edit::Keep(b'n'),
+                Edit::Insert(b'g'),
+            ]
+        );
+    }
+
+    #[test]
+    fn variants_agree_on_random_input() {
+        let mut rng = Rng::new(1427);
+        for _ in 0..300 {
+            let random_word = |rng: &mut Rng| -> Vec<u8> {
+                (0..rng.gen_index(12))
+                    .map(|_| b'a' + rng.gen_index(3) as u8)
+                    .collect()
+            };
+            let (a, b) = (random_word(&mut rng), random_word(&mut rng));
+            let distance = levenshtein(&a, &b);
+            assert_eq!(distance, levenshtein(&b, &a));
+            assert_eq!(distance, levenshtein_table(&a, &b)[a.len()][b.len()]);
+
+            let (scripted, script) = edit_script(&a, &b);
+            assert_eq!(scripted, distance);
+            let changes = script
+                .iter()
+                .filter(|e| !matches!(e, Edit::Keep(_)))
+                .count();
+            assert_eq!(changes, distance);
+            assert_eq!(apply(&script), (a.clone(), b.clone()));
+
+            let damerau = damerau_levenshtein(&a, &b);
+            assert!(damerau <= optimal_string_alignment(&a, &b));
+            assert!(optimal_string_alignment(&a, &b) <= distance);
+        }
+    }
+}
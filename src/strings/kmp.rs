@@ -0,0 +1,133 @@
+/*!
+定义：
+[KMP 算法的定义](https://zh.wikipedia.org/wiki/KMP%E7%AE%97%E6%B3%95)
+先求模式串的失配函数：`failure[i]` 是 `pattern[..=i]` 最长的、既是真前缀又是后缀的长度。
+匹配时文本指针从不回退，失配时模式指针跳到 `failure` 给出的位置，总复杂度 O(n + m)
+*/
+
+/// 模式串的失配函数（前缀函数）
+pub fn failure_function<T: PartialEq>(pattern: &[T]) -> Vec<usize> {
+    let mut failure = vec![0; pattern.len()];
+    let mut k = 0;
+    for i in 1..pattern.len() {
+        while k > 0 && pattern[i] != pattern[k] {
+            k = failure[k - 1];
+        }
+        if pattern[i] == pattern[k] {
+            k += 1;
+        }
+        failure[i] = k;
+    }
+    failure
+}
+
+/// 返回 `pattern` 在 `text` 中所有出现的起始下标，允许重叠；空模式串在每个位置都匹配
+pub fn find_all<T: PartialEq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    let mut matches = vec![];
+    search(text, pattern, |start| {
+        matches.push(start);
+        true
+    });
+    matches
+}
+
+/// 第一次出现的起始下标
+pub fn find_first<T: PartialEq>(text: &[T], pattern: &[T]) -> Option<usize> {
+    let mut first = None;
+    search(text, pattern, |start| {
+        first = Some(start);
+        false
+    });
+    first
+}
+
+/// 每找到一次匹配就调用 `on_match`，它返回 false 时停止搜索
+fn search<T, F>(text: &[T], pattern: &[T], mut on_match: F)
+where
+    T: PartialEq,
+    F: FnMut(usize) -> bool,
+{
+    if pattern.is_empty() {
+        for start in 0..=text.len() {
+            if !on_match(start) {
+                return;
+            }
+        }
+        return;
+    }
+    let failure = failure_function(pattern);
+    let mut k = 0;
+    for (i, item) in text.iter().enumerate() {
+        while k > 0 && *item != pattern[k] {
+            k = failure[k - 1];
+        }
+        if *item == pattern[k] {
+            k += 1;
+        }
+        if k == pattern.len() {
+            if !on_match(i + 1 - k) {
+                return;
+            }
+            // 保留已经匹配上的最长边界继续匹配，这样重叠的出现也能找到
+            k = failure[k - 1];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{failure_function, find_all, find_first};
+    use crate::utils::random::Rng;
+
+    fn brute_force(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        (0..=text.len().saturating_sub(pattern.len()))
+            .filter(|&i| text.len() >= pattern.len() && &text[i..i + pattern.len()] == pattern)
+            .collect()
+    }
+
+    #[test]
+    fn builds_failure_function() {
+        assert_eq!(failure_function(b"abcabd"), vec![0, 0, 0, 1, 2, 0]);
+        assert_eq!(failure_function(b"aabaaab"), vec![0, 1, 0, 1, 2, 2, 3]);
+        assert_eq!(failure_function::<u8>(b""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn finds_overlapping_matches() {
+        assert_eq!(find_all(b"aaaaa", b"aa"), vec![0, 1, 2, 3]);
+        assert_eq!(find_all(b"abababa", b"aba"), vec![0, 2, 4]);
+        assert_eq!(find_all(b"abc", b"abcd"), Vec::<usize>::new());
+        assert_eq!(find_all(b"ab", b""), vec![0, 1, 2]);
+        assert_eq!(find_first(b"hello world", b"o"), Some(4));
+        assert_eq!(find_first(b"hello", b"z"), None);
+    }
+
+    #[test]
+    fn periodic_patterns() {
+        // 周期为 p 的串，失配函数最后一项是 len - p
+        let pattern = b"abcabcabcab";
+        assert_eq!(
+            *failure_function(pattern).last().unwrap(),
+            pattern.len() - 3
+        );
+        let text = b"abc".repeat(10);
+        assert_eq!(
+            find_all(&text, b"abcabc"),
+            (0..=24).step_by(3).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1421);
+        for _ in 0..300 {
+            let text: Vec<u8> = (0..rng.gen_index(60))
+                .map(|_| b'a' + rng.gen_index(2) as u8)
+                .collect();
+            let pattern: Vec<u8> = (0..1 + rng.gen_index(5))
+                .map(|_| b'a' + rng.gen_index(2) as u8)
+                .collect();
+            assert_eq!(find_all(&text, &pattern), brute_force(&text, &pattern));
+        }
+    }
+}
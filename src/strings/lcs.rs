@@ -0,0 +1,184 @@
+/*!
+定义：
+[最长公共子序列的定义](https://zh.wikipedia.org/wiki/%E6%9C%80%E9%95%BF%E5%85%AC%E5%85%B1%E5%AD%90%E5%BA%8F%E5%88%97)
+`d[i][j]` 是 `a[..i]` 和 `b[..j]` 的最长公共子序列长度，从完整的表可以回溯出一个子序列，需要 O(nm) 空间。
+[Hirschberg 算法](https://en.wikipedia.org/wiki/Hirschberg%27s_algorithm)把 a 从中间切开，
+用正向和反向各一行 DP 找到 b 的最佳切分点，再递归求两半，时间仍是 O(nm)，空间降到 O(n + m)。
+
+[最长公共子串](https://zh.wikipedia.org/wiki/%E6%9C%80%E9%95%BF%E5%85%AC%E5%85%B1%E5%AD%90%E4%B8%B2)
+要求连续，`d[i][j]` 改为以 `a[i - 1]`、`b[j - 1]` 结尾的公共后缀长度；也可以对 b 建后缀自动机后扫描 a，O(n + m)
+*/
+
+use super::suffix_automaton::SuffixAutomaton;
+use std::ops::Range;
+
+/// 最长公共子序列的长度和其中一个子序列
+pub fn lcs<T: PartialEq + Clone>(a: &[T], b: &[T]) -> (usize, Vec<T>) {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i][j] = if a[i - 1] == b[j - 1] {
+                table[i - 1][j - 1] + 1
+            } else {
+                table[i - 1][j].max(table[i][j - 1])
+            };
+        }
+    }
+
+    let mut subsequence = vec![];
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            subsequence.push(a[i - 1].clone());
+            i -= 1;
+            j -= 1;
+        } else if table[i - 1][j] >= table[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    subsequence.reverse();
+    (table[a.len()][b.len()], subsequence)
+}
+
+/// 用 Hirschberg 算法求最长公共子序列，只需要线性空间
+pub fn lcs_hirschberg<T: PartialEq + Clone>(a: &[T], b: &[T]) -> (usize, Vec<T>) {
+    let mut subsequence = vec![];
+    hirschberg(a, b, &mut subsequence);
+    (subsequence.len(), subsequence)
+}
+
+fn hirschberg<T: PartialEq + Clone>(a: &[T], b: &[T], out: &mut Vec<T>) {
+    if a.is_empty() || b.is_empty() {
+        return;
+    }
+    if a.len() == 1 {
+        if b.contains(&a[0]) {
+            out.push(a[0].clone());
+        }
+        return;
+    }
+    let mid = a.len() / 2;
+    let forward = last_row(a[..mid].iter(), b.iter());
+    let mut backward = last_row(a[mid..].iter().rev(), b.iter().rev());
+    backward.reverse();
+    // b 切在 split 处时，左半部分和右半部分的公共子序列长度之和最大
+    let split = (0..=b.len())
+        .max_by_key(|&j| (forward[j] + backward[j], std::cmp::Reverse(j)))
+        .unwrap();
+    hirschberg(&a[..mid], &b[..split], out);
+    hirschberg(&a[mid..], &b[split..], out);
+}
+
+/// DP 表的最后一行：`row[j]` 是 a 和 `b` 的前 j 个元素的最长公共子序列长度
+fn last_row<'a, T, A, B>(a: A, b: B) -> Vec<usize>
+where
+    T: PartialEq + 'a,
+    A: Iterator<Item = &'a T>,
+    B: Iterator<Item = &'a T> + Clone,
+{
+    let mut row = vec![0; b.clone().count() + 1];
+    for x in a {
+        let mut diagonal = 0;
+        for (j, y) in b.clone().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if x == y {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+/// 最长公共子串在 a 中的下标区间，有多个时返回在 a 中结束得最早的
+pub fn longest_common_substring<T: PartialEq>(a: &[T], b: &[T]) -> Range<usize> {
+    // 只保留上一行
+    let mut previous = vec![0; b.len() + 1];
+    let mut current = vec![0; b.len() + 1];
+    let mut best = 0..0;
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            current[j] = if a[i - 1] == b[j - 1] {
+                previous[j - 1] + 1
+            } else {
+                0
+            };
+            if current[j] > best.len() {
+                best = i - current[j]..i;
+            }
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    best
+}
+
+/// 对 b 建后缀自动机求最长公共子串，返回它在 a 中的下标区间
+pub fn longest_common_substring_automaton<T: Ord + Clone>(a: &[T], b: &[T]) -> Range<usize> {
+    SuffixAutomaton::new(b).longest_common_substring(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        lcs, lcs_hirschberg, longest_common_substring, longest_common_substring_automaton,
+    };
+    use crate::utils::random::Rng;
+
+    fn is_subsequence(sub: &[u8], of: &[u8]) -> bool {
+        let mut rest = of.iter();
+        sub.iter().all(|x| rest.any(|y| y == x))
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        needle.is_empty() || haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[test]
+    fn known_subsequences() {
+        assert_eq!(lcs(b"ABCBDAB", b"BDCABA").0, 4);
+        assert_eq!(lcs(b"AGGTAB", b"GXTXAYB"), (4, b"GTAB".to_vec()));
+        assert_eq!(lcs_hirschberg(b"AGGTAB", b"GXTXAYB"), (4, b"GTAB".to_vec()));
+        assert_eq!(lcs(b"", b"abc"), (0, vec![]));
+        assert_eq!(lcs_hirschberg(b"abc", b""), (0, vec![]));
+    }
+
+    #[test]
+    fn known_substrings() {
+        let a = b"xabxac";
+        assert_eq!(&a[longest_common_substring(a, b"abcabxabcd")], b"abxa");
+        assert_eq!(longest_common_substring_automaton(b"abcabxabcd", a), 3..7);
+        assert_eq!(longest_common_substring(b"abc", b"xyz"), 0..0);
+    }
+
+    #[test]
+    fn variants_agree_on_random_input() {
+        let mut rng = Rng::new(1428);
+        for _ in 0..300 {
+            let random_word = |rng: &mut Rng| -> Vec<u8> {
+                (0..rng.gen_index(25))
+                    .map(|_| b'a' + rng.gen_index(3) as u8)
+                    .collect()
+            };
+            let (a, b) = (random_word(&mut rng), random_word(&mut rng));
+
+            let (len, subsequence) = lcs(&a, &b);
+            let (linear_len, linear) = lcs_hirschberg(&a, &b);
+            assert_eq!(len, linear_len);
+            for s in [&subsequence, &linear] {
+                assert_eq!(s.len(), len);
+                assert!(is_subsequence(s, &a) && is_subsequence(s, &b));
+            }
+
+            let by_dp = longest_common_substring(&a, &b);
+            let by_automaton = longest_common_substring_automaton(&a, &b);
+            assert_eq!(by_dp.len(), by_automaton.len());
+            for range in [by_dp, by_automaton] {
+                assert!(contains(&b, &a[range]));
+            }
+        }
+    }
+}
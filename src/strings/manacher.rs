@@ -0,0 +1,125 @@
+/*!
+定义：
+[Manacher 算法](https://en.wikipedia.org/wiki/Longest_palindromic_substring#Manacher's_algorithm)
+以每个位置为中心求最长回文半径。维护右端点最靠右的回文 [l, r)，中心 i 落在里面时，
+它关于这个回文的镜像位置的半径可以直接作为起点，只有超出 r 的部分需要逐个比较，
+r 只会向右移动，所以总复杂度 O(n)
+*/
+
+use std::ops::Range;
+
+/// 每个中心的回文半径
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PalindromeRadii {
+    /// `odd[i]` 是以 i 为中心的最长奇数长度回文的半径（含中心），`s[i + 1 - k..i + k]` 是回文
+    pub odd: Vec<usize>,
+    /// `even[i]` 是以 i - 1 和 i 之间为中心的最长偶数长度回文的半径，`s[i - k..i + k]` 是回文
+    pub even: Vec<usize>,
+}
+
+pub fn palindrome_radii<T: PartialEq>(s: &[T]) -> PalindromeRadii {
+    PalindromeRadii {
+        odd: radii(s, 1),
+        even: radii(s, 0),
+    }
+}
+
+/// `offset` 为 1 时求奇数回文，为 0 时求偶数回文
+fn radii<T: PartialEq>(s: &[T], offset: usize) -> Vec<usize> {
+    let n = s.len();
+    let mut radius = vec![0; n];
+    // 当前右端点最靠右的回文是 [left, right)
+    let (mut left, mut right) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < right {
+            // 镜像位置 left + right - i - offset 的半径，不能超出 [left, right)
+            radius[left + right - i - offset].min(right - i)
+        } else {
+            offset
+        };
+        while i + k < n && i + offset > k && s[i + k] == s[i + offset - k - 1] {
+            k += 1;
+        }
+        radius[i] = k;
+        if i + k > right {
+            left = i + offset - k;
+            right = i + k;
+        }
+    }
+    radius
+}
+
+/// 最长回文子串的下标区间，有多个时返回最靠左的
+pub fn longest_palindrome<T: PartialEq>(s: &[T]) -> Range<usize> {
+    let PalindromeRadii { odd, even } = palindrome_radii(s);
+    let mut best = 0..0;
+    for i in 0..s.len() {
+        if 2 * odd[i] - 1 > best.len() {
+            best = i + 1 - odd[i]..i + odd[i];
+        }
+        if 2 * even[i] > best.len() {
+            best = i - even[i]..i + even[i];
+        }
+    }
+    best
+}
+
+/// 回文子串的个数，位置不同的相同子串分别计数
+pub fn count_palindromic_substrings<T: PartialEq>(s: &[T]) -> u64 {
+    let PalindromeRadii { odd, even } = palindrome_radii(s);
+    odd.iter().chain(&even).map(|&k| k as u64).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_palindromic_substrings, longest_palindrome, palindrome_radii};
+    use crate::utils::random::Rng;
+
+    fn is_palindrome(s: &[u8]) -> bool {
+        s.iter().eq(s.iter().rev())
+    }
+
+    #[test]
+    fn computes_radii() {
+        let radii = palindrome_radii(b"abaaba");
+        assert_eq!(radii.odd, vec![1, 2, 1, 1, 2, 1]);
+        assert_eq!(radii.even, vec![0, 0, 0, 3, 0, 0]);
+        assert_eq!(palindrome_radii::<u8>(b"").odd, Vec::<usize>::new());
+    }
+
+    #[test]
+    fn finds_longest_palindrome() {
+        let s = b"forgeeksskeegfor";
+        assert_eq!(&s[longest_palindrome(s)], b"geeksskeeg");
+        assert_eq!(longest_palindrome(b"babad"), 0..3);
+        assert_eq!(longest_palindrome(b"abc"), 0..1);
+        assert_eq!(longest_palindrome::<u8>(b""), 0..0);
+        let chars: Vec<char> = "上海自来水来自海上".chars().collect();
+        assert_eq!(longest_palindrome(&chars), 0..9);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(1426);
+        for _ in 0..300 {
+            let s: Vec<u8> = (0..rng.gen_index(40))
+                .map(|_| b'a' + rng.gen_index(2) as u8)
+                .collect();
+            let n = s.len();
+            let mut count = 0;
+            let mut best = 0;
+            for i in 0..n {
+                for j in i + 1..=n {
+                    if is_palindrome(&s[i..j]) {
+                        count += 1;
+                        best = best.max(j - i);
+                    }
+                }
+            }
+            assert_eq!(count_palindromic_substrings(&s), count);
+            let range = longest_palindrome(&s);
+            assert_eq!(range.len(), best);
+            assert!(is_palindrome(&s[range]));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod alignment;
+pub mod duval;
+pub mod edit_distance;
+pub mod kmp;
+pub mod lcs;
+pub mod manacher;
+pub mod rabin_karp;
+pub mod similarity;
+pub mod string_hasher;
+pub mod suffix_automaton;
+pub mod util;
+
+pub use self::rabin_karp::RollingHash;
+pub use self::string_hasher::StringHasher;
+pub use self::suffix_automaton::SuffixAutomaton;
@@ -0,0 +1,214 @@
+/*!
+定义：
+[Rabin–Karp 算法的定义](https://zh.wikipedia.org/wiki/%E6%8B%89%E5%AE%BE-%E5%8D%A1%E6%99%AE%E7%AE%97%E6%B3%95)
+把长度为 m 的窗口看成 B 进制数并对大素数取模得到哈希值。窗口右移一位时，减去移出字符的贡献、
+乘以 B 再加上移入的字符，O(1) 就能得到新窗口的哈希。哈希相等时再逐字节比较，排除哈希冲突
+*/
+
+use std::collections::HashMap;
+
+/// 模数取梅森素数 2^61 - 1，乘法用 u128 不会溢出
+const MODULUS: u64 = (1 << 61) - 1;
+const BASE: u64 = 131;
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % MODULUS as u128) as u64
+}
+
+/// 固定长度窗口的多项式哈希，窗口可以向右滚动
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RollingHash {
+    value: u64,
+    len: usize,
+    /// BASE^(len - 1)，滚动时用来去掉最左边的字符
+    high_power: u64,
+}
+
+impl RollingHash {
+    /// 计算窗口 `window` 的哈希
+    pub fn new(window: &[u8]) -> Self {
+        let mut high_power = 1;
+        for _ in 1..window.len() {
+            high_power = mul_mod(high_power, BASE);
+        }
+        RollingHash {
+            value: Self::hash(window),
+            len: window.len(),
+            high_power,
+        }
+    }
+
+    /// 一段字节的哈希，和同样内容的窗口的 `value()` 相等
+    pub fn hash(bytes: &[u8]) -> u64 {
+        bytes
+            .iter()
+            .fold(0, |h, &b| (mul_mod(h, BASE) + b as u64) % MODULUS)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 窗口右移一位：移出最左边的 `outgoing`，移入 `incoming`
+    pub fn roll(&mut self, outgoing: u8, incoming: u8) {
+        assert!(self.len > 0, "Cannot roll an empty window.");
+        let without = (self.value + MODULUS - mul_mod(outgoing as u64, self.high_power)) % MODULUS;
+        self.value = (mul_mod(without, BASE) + incoming as u64) % MODULUS;
+    }
+}
+
+/// 返回 `pattern` 在 `text` 中所有出现的起始下标，允许重叠
+pub fn rabin_karp(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    rabin_karp_multi(text, &[pattern])
+        .into_iter()
+        .map(|(start, _)| start)
+        .collect()
+}
+
+/// 同时搜索多个模式串，返回按起始下标排序的 (起始下标, 模式串下标)
+///
+/// 相同长度的模式串共用一次滚动，长度为 m 的一组模式串只需要扫描文本一遍
+pub fn rabin_karp_multi(text: &[u8], patterns: &[&[u8]]) -> Vec<(usize, usize)> {
+    // 长度 -> (哈希 -> 模式串下标)
+    let mut groups: HashMap<usize, HashMap<u64, Vec<usize>>> = HashMap::new();
+    for (i, pattern) in patterns.iter().enumerate() {
+        groups
+            .entry(pattern.len())
+            .or_default()
+            .entry(RollingHash::hash(pattern))
+            .or_default()
+            .push(i);
+    }
+
+    let mut matches = vec![];
+    for (len, by_hash) in groups {
+        if len == 0 {
+            for candidates in by_hash.values() {
+                for start in 0..=text.len() {
+                    matches.extend(candidates.iter().map(|&i| (start, i)));
+                }
+            }
+            continue;
+        }
+        if len > text.len() {
+            continue;
+        }
+        let mut window = RollingHash::new(&text[..len]);
+        for start in 0..=text.len() - len {
+            if start > 0 {
+                window.roll(text[start - 1], text[start + len - 1]);
+            }
+            if let Some(candidates) = by_hash.get(&window.value()) {
+                // 哈希相等不代表内容相等，需要逐字节确认
+                let slice = &text[start..start + len];
+                matches.extend(
+                    candidates
+                        .iter()
+                        .filter(|&&i| patterns[i] == slice)
+                        .map(|&i| (start, i)),
+                );
+            }
+        }
+    }
+    matches.sort_unstable();
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rabin_karp, rabin_karp_multi, RollingHash};
+    use crate::strings::kmp::find_all;
+    use crate::utils::random::Rng;
+    use std::collections::HashMap;
+
+    #[test]
+    fn rolling_matches_direct_hash() {
+        let text = b"the quick brown fox jumps over the lazy dog";
+        let len = 7;
+        let mut window = RollingHash::new(&text[..len]);
+        assert_eq!(window.len(), len);
+        for start in 1..=text.len() - len {
+            window.roll(text[start - 1], text[start + len - 1]);
+            assert_eq!(window.value(), RollingHash::hash(&text[start..start + len]));
+        }
+    }
+
+    #[test]
+    fn finds_single_pattern() {
+        assert_eq!(rabin_karp(b"abracadabra", b"abra"), vec![0, 7]);
+        assert_eq!(rabin_karp(b"aaaa", b"aa"), vec![0, 1, 2]);
+        assert_eq!(rabin_karp(b"abc", b"abcd"), Vec::<usize>::new());
+        assert_eq!(rabin_karp(b"ab", b""), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn finds_multiple_patterns() {
+        let patterns: [&[u8]; 4] = [b"he", b"she", b"his", b"hers"];
+        assert_eq!(
+            rabin_karp_multi(b"ushers", &patterns),
+            vec![(1, 1), (2, 0), (2, 3)]
+        );
+        // 重复的模式串各自报告
+        assert_eq!(
+            rabin_karp_multi(b"aXa", &[b"a", b"a"]),
+            vec![(0, 0), (0, 1), (2, 0), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn matches_kmp_on_random_text() {
+        let mut rng = Rng::new(1422);
+        for _ in 0..200 {
+            let text: Vec<u8> = (0..rng.gen_index(80))
+                .map(|_| b'a' + rng.gen_index(3) as u8)
+                .collect();
+            let patterns: Vec<Vec<u8>> = (0..3)
+                .map(|_| {
+                    (0..1 + rng.gen_index(4))
+                        .map(|_| b'a' + rng.gen_index(3) as u8)
+                        .collect()
+                })
+                .collect();
+            let refs: Vec<&[u8]> = patterns.iter().map(|p| p.as_slice()).collect();
+            let mut expected: Vec<(usize, usize)> = refs
+                .iter()
+                .enumerate()
+                .flat_map(|(i, p)| find_all(&text, p).into_iter().map(move |s| (s, i)))
+                .collect();
+            expected.sort_unstable();
+            assert_eq!(rabin_karp_multi(&text, &refs), expected);
+        }
+    }
+
+    #[test]
+    fn detects_duplicate_windows() {
+        // 用滚动哈希找出长度为 3 的重复子串
+        let text = b"banana bandana";
+        let len = 3;
+        let mut seen: HashMap<u64, usize> = HashMap::new();
+        let mut window = RollingHash::new(&text[..len]);
+        let mut duplicates = vec![];
+        for start in 0..=text.len() - len {
+            if start > 0 {
+                window.roll(text[start - 1], text[start + len - 1]);
+            }
+            match seen.get(&window.value()) {
+                Some(&first) if text[first..first + len] == text[start..start + len] => {
+                    duplicates.push(&text[start..start + len])
+                }
+                _ => {
+                    seen.insert(window.value(), start);
+                }
+            }
+        }
+        assert_eq!(duplicates, vec![b"ana", b"ban", b"ana"]);
+    }
+}
@@ -0,0 +1,155 @@
+/*!
+字符串相似度，用于模糊匹配。除汉明距离外都归一化到 [0, 1]，1 表示完全相同：
+- [汉明距离](https://zh.wikipedia.org/wiki/%E6%B1%89%E6%98%8E%E8%B7%9D%E7%A6%BB)：等长序列中对应位置不同的个数
+- [Jaro 相似度](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)：
+  在一定窗口内能配对的字符越多、配对后顺序颠倒的越少，相似度越高；
+  Jaro–Winkler 再奖励相同的前缀，适合比较人名等短串
+- n-gram 余弦相似度：把字符串看成长度为 n 的子串的计数向量，求两个向量夹角的余弦
+*/
+
+use std::collections::HashMap;
+
+/// Winkler 对公共前缀的奖励系数
+const WINKLER_SCALE: f64 = 0.1;
+/// 最多奖励的前缀长度
+const WINKLER_PREFIX: usize = 4;
+
+/// 汉明距离，长度不同时返回 None
+pub fn hamming_distance<T: PartialEq>(a: &[T], b: &[T]) -> Option<usize> {
+    (a.len() == b.len()).then(|| a.iter().zip(b).filter(|(x, y)| x != y).count())
+}
+
+/// Jaro 相似度，按 Unicode 字符比较
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    // 相距不超过 window 的相同字符才能配对
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, x) in a.iter().enumerate() {
+        let low = i.saturating_sub(window);
+        let high = (i + window + 1).min(b.len());
+        for j in low..high {
+            if !b_matched[j] && b[j] == *x {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // 按顺序比较两边配对上的字符，不相同的个数的一半是换位数
+    let a_sequence = a.iter().zip(&a_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+    let b_sequence = b.iter().zip(&b_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+    let half_transpositions = a_sequence.zip(b_sequence).filter(|(x, y)| x != y).count();
+
+    let m = matches as f64;
+    let t = half_transpositions as f64 / 2.0;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+/// Jaro–Winkler 相似度，公共前缀最多奖励 4 个字符
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let similarity = jaro(a, b);
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take(WINKLER_PREFIX)
+        .take_while(|(x, y)| x == y)
+        .count();
+    similarity + prefix as f64 * WINKLER_SCALE * (1.0 - similarity)
+}
+
+/// 字符 n-gram 计数向量的余弦相似度。比 n 短的字符串整体作为一个 n-gram
+pub fn ngram_cosine_similarity(a: &str, b: &str, n: usize) -> f64 {
+    assert!(n > 0, "n-gram length must be positive.");
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let count = |s: &[char]| -> HashMap<Vec<char>, u64> {
+        let mut grams = HashMap::new();
+        if s.is_empty() {
+            return grams;
+        }
+        if s.len() < n {
+            grams.insert(s.to_vec(), 1);
+        }
+        for gram in s.windows(n) {
+            *grams.entry(gram.to_vec()).or_insert(0) += 1;
+        }
+        grams
+    };
+    let (a_grams, b_grams) = (count(&a), count(&b));
+    if a_grams.is_empty() || b_grams.is_empty() {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+    let dot: u64 = a_grams
+        .iter()
+        .filter_map(|(gram, x)| b_grams.get(gram).map(|y| x * y))
+        .sum();
+    let norm = |grams: &HashMap<Vec<char>, u64>| {
+        (grams.values().map(|c| c * c).sum::<u64>() as f64).sqrt()
+    };
+    dot as f64 / (norm(&a_grams) * norm(&b_grams))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hamming_distance, jaro, jaro_winkler, ngram_cosine_similarity};
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn hamming() {
+        assert_eq!(hamming_distance(b"karolin", b"kathrin"), Some(3));
+        assert_eq!(hamming_distance(&[1, 0, 1, 1], &[1, 0, 0, 1]), Some(1));
+        assert_eq!(hamming_distance(b"abc", b"ab"), None);
+        assert_eq!(hamming_distance::<u8>(&[], &[]), Some(0));
+    }
+
+    #[test]
+    fn jaro_known_values() {
+        assert!(close(jaro("MARTHA", "MARHTA"), 0.944));
+        assert!(close(jaro("DIXON", "DICKSONX"), 0.767));
+        assert!(close(jaro("JELLYFISH", "SMELLYFISH"), 0.896));
+        assert_eq!(jaro("", ""), 1.0);
+        assert_eq!(jaro("abc", ""), 0.0);
+        assert_eq!(jaro("abc", "xyz"), 0.0);
+        assert_eq!(jaro("同一个", "同一个"), 1.0);
+    }
+
+    #[test]
+    fn jaro_winkler_rewards_common_prefix() {
+        assert!(close(jaro_winkler("MARTHA", "MARHTA"), 0.961));
+        assert!(close(jaro_winkler("DIXON", "DICKSONX"), 0.813));
+        assert!(close(jaro_winkler("DWAYNE", "DUANE"), 0.840));
+        for (a, b) in [("CRATE", "TRACE"), ("hello", "help"), ("abc", "abd")] {
+            let (j, jw) = (jaro(a, b), jaro_winkler(a, b));
+            assert!(jw >= j && jw <= 1.0);
+            assert_eq!(jaro(a, b), jaro(b, a));
+        }
+    }
+
+    #[test]
+    fn ngram_cosine() {
+        assert!(close(ngram_cosine_similarity("night", "night", 2), 1.0));
+        // ni ig gh ht 和 na ac ch ht 只有 ht 相同
+        assert!(close(ngram_cosine_similarity("night", "nacht", 2), 0.25));
+        assert_eq!(ngram_cosine_similarity("abc", "xyz", 2), 0.0);
+        assert!(close(ngram_cosine_similarity("a", "a", 3), 1.0));
+        assert_eq!(ngram_cosine_similarity("", "", 2), 1.0);
+        assert_eq!(ngram_cosine_similarity("", "ab", 2), 0.0);
+        // 重复的 n-gram 按次数计入
+        assert!(close(ngram_cosine_similarity("aaa", "aa", 2), 1.0));
+    }
+}
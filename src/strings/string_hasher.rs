@@ -0,0 +1,149 @@
+/*!
+定义：
+[多项式哈希](https://en.wikipedia.org/wiki/Rolling_hash#Polynomial_rolling_hash)
+预先求出所有前缀的哈希 `prefix[i] = hash(s[..i])` 以及基数的幂，
+子串 `s[l..r]` 的哈希就是 `prefix[r] - prefix[l] * BASE^(r - l)`，O(1) 得到。
+同时使用两个模数，两个哈希都相等才认为相等，冲突的概率约为 1 / (p1 · p2)
+*/
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+const MODULI: [u64; 2] = [1_000_000_007, 998_244_353];
+const BASES: [u64; 2] = [131, 137];
+
+/// 一个字节串所有子串的哈希
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringHasher {
+    /// `prefix[k][i]` 是第 k 个模数下 `s[..i]` 的哈希
+    prefix: [Vec<u64>; 2],
+    /// `powers[k][i]` 是第 k 个模数下 BASE^i
+    powers: [Vec<u64>; 2],
+}
+
+impl StringHasher {
+    pub fn new(s: &[u8]) -> Self {
+        let build = |k: usize| {
+            let (modulus, base) = (MODULI[k], BASES[k]);
+            let mut prefix = Vec::with_capacity(s.len() + 1);
+            let mut powers = Vec::with_capacity(s.len() + 1);
+            prefix.push(0);
+            powers.push(1);
+            for (i, &byte) in s.iter().enumerate() {
+                prefix.push((prefix[i] * base + byte as u64 + 1) % modulus);
+                powers.push(powers[i] * base % modulus);
+            }
+            (prefix, powers)
+        };
+        let (first_prefix, first_powers) = build(0);
+        let (second_prefix, second_powers) = build(1);
+        StringHasher {
+            prefix: [first_prefix, second_prefix],
+            powers: [first_powers, second_powers],
+        }
+    }
+
+    /// 字节串的长度
+    pub fn len(&self) -> usize {
+        self.prefix[0].len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 子串 `s[range]` 的哈希，不同 `StringHasher` 之间的哈希可以直接比较
+    pub fn hash(&self, range: Range<usize>) -> (u64, u64) {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "Range {range:?} out of bounds for length {}.",
+            self.len()
+        );
+        let part = |k: usize| {
+            let modulus = MODULI[k];
+            let shifted = self.prefix[k][range.start] * self.powers[k][range.len()] % modulus;
+            (self.prefix[k][range.end] + modulus - shifted) % modulus
+        };
+        (part(0), part(1))
+    }
+
+    /// 两个子串是否相等（按哈希判断）
+    pub fn substrings_equal(&self, a: Range<usize>, b: Range<usize>) -> bool {
+        a.len() == b.len() && self.hash(a) == self.hash(b)
+    }
+
+    /// 不同的非空子串个数，O(n²) 个哈希
+    pub fn distinct_substrings(&self) -> usize {
+        let n = self.len();
+        (1..=n)
+            .map(|len| {
+                (0..=n - len)
+                    .map(|start| self.hash(start..start + len))
+                    .collect::<HashSet<_>>()
+                    .len()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StringHasher;
+    use crate::strings::suffix_automaton::SuffixAutomaton;
+    use crate::utils::random::Rng;
+
+    #[test]
+    fn compares_substrings() {
+        let hasher = StringHasher::new(b"abracadabra");
+        assert_eq!(hasher.len(), 11);
+        assert!(hasher.substrings_equal(0..4, 7..11));
+        assert!(!hasher.substrings_equal(0..4, 6..10));
+        assert!(!hasher.substrings_equal(0..1, 0..2));
+        assert!(hasher.substrings_equal(3..3, 5..5));
+        // 不同字节串中相同的子串哈希相同
+        let other = StringHasher::new(b"cadabra");
+        assert_eq!(hasher.hash(4..11), other.hash(0..7));
+        // 前导的 0 字节不会被忽略
+        let zeros = StringHasher::new(&[0, 0, 1]);
+        assert_ne!(zeros.hash(0..2), zeros.hash(1..2));
+    }
+
+    #[test]
+    fn matches_direct_comparison() {
+        let mut rng = Rng::new(1434);
+        let s: Vec<u8> = (0..60).map(|_| b'a' + rng.gen_index(2) as u8).collect();
+        let hasher = StringHasher::new(&s);
+        for _ in 0..2000 {
+            let len = rng.gen_index(8);
+            let a = rng.gen_index(s.len() - len + 1);
+            let b = rng.gen_index(s.len() - len + 1);
+            assert_eq!(
+                hasher.substrings_equal(a..a + len, b..b + len),
+                s[a..a + len] == s[b..b + len]
+            );
+        }
+    }
+
+    #[test]
+    fn counts_distinct_substrings() {
+        assert_eq!(StringHasher::new(b"").distinct_substrings(), 0);
+        assert_eq!(StringHasher::new(b"aaa").distinct_substrings(), 3);
+        assert_eq!(StringHasher::new(b"abc").distinct_substrings(), 6);
+        let mut rng = Rng::new(1434);
+        for _ in 0..50 {
+            let s: Vec<u8> = (0..rng.gen_index(40))
+                .map(|_| b'a' + rng.gen_index(3) as u8)
+                .collect();
+            assert_eq!(
+                StringHasher::new(&s).distinct_substrings() as u64,
+                SuffixAutomaton::new(&s).distinct_substrings()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_out_of_bounds() {
+        StringHasher::new(b"abc").hash(1..4);
+    }
+}
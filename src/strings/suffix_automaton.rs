@@ -0,0 +1,183 @@
+/*!
+定义：
+[后缀自动机](https://en.wikipedia.org/wiki/Suffix_automaton)
+接受且仅接受 s 的所有后缀的最小确定有限自动机。从初始状态出发能走通的路径恰好对应 s 的所有子串，
+每个状态代表一组结束位置集合相同的子串，它们的长度构成区间 (len(link), len]。
+逐个追加字符在线构造，状态数不超过 2n - 1，总复杂度 O(n log σ)
+*/
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+struct State<T> {
+    /// 这个状态代表的最长子串的长度
+    len: usize,
+    /// 后缀链接，指向代表较短后缀的状态，初始状态没有
+    link: Option<usize>,
+    next: BTreeMap<T, usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SuffixAutomaton<T> {
+    states: Vec<State<T>>,
+    /// 代表整个串的状态
+    last: usize,
+}
+
+impl<T: Ord + Clone> SuffixAutomaton<T> {
+    pub fn new(s: &[T]) -> Self {
+        let mut automaton = SuffixAutomaton {
+            states: vec![State {
+                len: 0,
+                link: None,
+                next: BTreeMap::new(),
+            }],
+            last: 0,
+        };
+        for item in s {
+            automaton.extend(item.clone());
+        }
+        automaton
+    }
+
+    /// 在串的末尾追加一个元素
+    pub fn extend(&mut self, item: T) {
+        let current = self.states.len();
+        self.states.push(State {
+            len: self.states[self.last].len + 1,
+            link: None,
+            next: BTreeMap::new(),
+        });
+        let mut p = Some(self.last);
+        while let Some(state) = p {
+            if self.states[state].next.contains_key(&item) {
+                break;
+            }
+            self.states[state].next.insert(item.clone(), current);
+            p = self.states[state].link;
+        }
+        self.states[current].link = Some(match p {
+            None => 0,
+            Some(p) => {
+                let q = self.states[p].next[&item];
+                if self.states[p].len + 1 == self.states[q].len {
+                    q
+                } else {
+                    // q 代表的子串有一部分不再共享结束位置，拆出一个较短的克隆状态
+                    let clone = self.states.len();
+                    self.states.push(State {
+                        len: self.states[p].len + 1,
+                        link: self.states[q].link,
+                        next: self.states[q].next.clone(),
+                    });
+                    let mut p = Some(p);
+                    while let Some(state) = p {
+                        if self.states[state].next.get(&item) != Some(&q) {
+                            break;
+                        }
+                        self.states[state].next.insert(item.clone(), clone);
+                        p = self.states[state].link;
+                    }
+                    self.states[q].link = Some(clone);
+                    clone
+                }
+            }
+        });
+        self.last = current;
+    }
+
+    pub fn state_count(&self) -> usize {
+        self.states.len()
+    }
+
+    /// `pattern` 是否是子串
+    pub fn contains(&self, pattern: &[T]) -> bool {
+        let mut state = 0;
+        for item in pattern {
+            match self.states[state].next.get(item) {
+                Some(&next) => state = next,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// 不同的非空子串个数
+    pub fn distinct_substrings(&self) -> u64 {
+        self.states
+            .iter()
+            .filter_map(|state| Some(state.len - self.states[state.link?].len))
+            .map(|count| count as u64)
+            .sum()
+    }
+
+    /// `other` 中同时也是本串子串的最长一段，返回它在 `other` 中的下标区间
+    pub fn longest_common_substring(&self, other: &[T]) -> Range<usize> {
+        let (mut state, mut len) = (0, 0);
+        let mut best = 0..0;
+        for (i, item) in other.iter().enumerate() {
+            // 沿后缀链接缩短当前匹配，直到可以接上 item
+            while state != 0 && !self.states[state].next.contains_key(item) {
+                state = self.states[state].link.unwrap();
+                len = self.states[state].len;
+            }
+            if let Some(&next) = self.states[state].next.get(item) {
+                state = next;
+                len += 1;
+            }
+            if len > best.len() {
+                best = i + 1 - len..i + 1;
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SuffixAutomaton;
+    use crate::utils::random::Rng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn recognizes_substrings() {
+        let automaton = SuffixAutomaton::new(b"abcbc");
+        for pattern in [&b""[..], b"a", b"bcb", b"cbc", b"abcbc"] {
+            assert!(automaton.contains(pattern));
+        }
+        for pattern in [&b"ac"[..], b"cc", b"abcbcd", b"d"] {
+            assert!(!automaton.contains(pattern));
+        }
+        assert!(automaton.state_count() < 2 * 5);
+    }
+
+    #[test]
+    fn counts_distinct_substrings() {
+        let mut rng = Rng::new(1428);
+        for _ in 0..100 {
+            let s: Vec<u8> = (0..rng.gen_index(30))
+                .map(|_| b'a' + rng.gen_index(3) as u8)
+                .collect();
+            let mut distinct = HashSet::new();
+            for i in 0..s.len() {
+                for j in i + 1..=s.len() {
+                    distinct.insert(&s[i..j]);
+                }
+            }
+            let automaton = SuffixAutomaton::new(&s);
+            assert_eq!(automaton.distinct_substrings(), distinct.len() as u64);
+            // n >= 2 时最多 2n - 1 个状态
+            assert!(automaton.state_count() <= (2 * s.len()).saturating_sub(1).max(s.len() + 1));
+        }
+    }
+
+    #[test]
+    fn finds_longest_common_substring() {
+        let automaton = SuffixAutomaton::new(b"xabxac");
+        let other = b"abcabxabcd";
+        let range = automaton.longest_common_substring(other);
+        assert_eq!(&other[range], b"abxa");
+        assert_eq!(automaton.longest_common_substring(b"zzz"), 0..0);
+    }
+}
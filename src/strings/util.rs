@@ -0,0 +1,120 @@
+/*!
+常用的字符串小工具：变位词、回文、子序列判断以及第一个不重复的字符。
+除 `is_subsequence` 外都按 Unicode 字符（`char`）处理，而不是按字节
+*/
+
+use std::collections::HashMap;
+
+/// 两个字符串是否由相同的字符组成（每个字符的个数也相同）
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    char_counts(a) == char_counts(b)
+}
+
+fn char_counts(s: &str) -> HashMap<char, usize> {
+    let mut counts = HashMap::new();
+    for c in s.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// 按字符判断是否是回文，区分大小写，空格和标点也参与比较
+pub fn is_palindrome(s: &str) -> bool {
+    s.chars().eq(s.chars().rev())
+}
+
+/// 忽略大小写和非字母数字的字符后判断是否是回文，例如 "A man, a plan, a canal: Panama"
+pub fn is_palindrome_normalized(s: &str) -> bool {
+    let normalized: Vec<char> = s
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    normalized.iter().eq(normalized.iter().rev())
+}
+
+/// `sub` 是否是 `of` 的子序列（可以不连续）
+pub fn is_subsequence<T: PartialEq>(sub: &[T], of: &[T]) -> bool {
+    let mut rest = of.iter();
+    sub.iter().all(|x| rest.any(|y| y == x))
+}
+
+/// 把互为变位词的单词分到一组，组按第一次出现的顺序排列，组内保持输入顺序
+pub fn group_anagrams<'a>(words: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let mut groups: Vec<Vec<&str>> = vec![];
+    // 排序后的字符作为 key
+    let mut index: HashMap<Vec<char>, usize> = HashMap::new();
+    for &word in words {
+        let mut key: Vec<char> = word.chars().collect();
+        key.sort_unstable();
+        let group = *index.entry(key).or_insert_with(|| {
+            groups.push(vec![]);
+            groups.len() - 1
+        });
+        groups[group].push(word);
+    }
+    groups
+}
+
+/// 第一个只出现一次的字符，返回它的字节下标和字符本身
+pub fn first_unique_char(s: &str) -> Option<(usize, char)> {
+    let counts = char_counts(s);
+    s.char_indices().find(|(_, c)| counts[c] == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anagrams() {
+        assert!(is_anagram("listen", "silent"));
+        assert!(is_anagram("", ""));
+        assert!(!is_anagram("aab", "abb"));
+        assert!(is_anagram("上海", "海上"));
+    }
+
+    #[test]
+    fn palindromes() {
+        assert!(is_palindrome("racecar"));
+        assert!(is_palindrome(""));
+        assert!(!is_palindrome("Racecar"));
+        // 按字符而不是字节比较
+        assert!(is_palindrome("上海自来水来自海上"));
+        assert!(!"上海海上".bytes().eq("上海海上".bytes().rev()));
+
+        assert!(is_palindrome_normalized("A man, a plan, a canal: Panama"));
+        assert!(is_palindrome_normalized("Ésope reste ici et se reposé"));
+        assert!(!is_palindrome_normalized("race a car"));
+    }
+
+    #[test]
+    fn subsequences() {
+        assert!(is_subsequence(b"ace", b"abcde"));
+        assert!(!is_subsequence(b"aec", b"abcde"));
+        assert!(is_subsequence::<u8>(b"", b""));
+        assert!(!is_subsequence(b"aa", b"a"));
+    }
+
+    #[test]
+    fn groups_anagrams() {
+        let groups = group_anagrams(&["eat", "tea", "tan", "ate", "nat", "bat"]);
+        assert_eq!(
+            groups,
+            vec![vec!["eat", "tea", "ate"], vec!["tan", "nat"], vec!["bat"]]
+        );
+        assert!(group_anagrams(&[]).is_empty());
+    }
+
+    #[test]
+    fn finds_first_unique_char() {
+        assert_eq!(first_unique_char("leetcode"), Some((0, 'l')));
+        assert_eq!(first_unique_char("loveleetcode"), Some((2, 'v')));
+        assert_eq!(first_unique_char("aabb"), None);
+        // 返回的是字节下标，可以直接切片
+        let s = "重重复";
+        let (at, c) = first_unique_char(s).unwrap();
+        assert_eq!((at, c), (6, '复'));
+        assert_eq!(&s[at..], "复");
+    }
+}
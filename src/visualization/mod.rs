@@ -0,0 +1,25 @@
+/*!
+算法执行过程的逐步记录，供可视化、教学和调试使用。
+部分排序、树和图搜索算法提供 `*_observed` 版本，把比较、交换、访问节点和松弛边报告给一个 [`Observer`]，
+不需要复制算法代码就能观察它的执行过程；不带后缀的版本传入什么也不做的 `()`。目前支持的有：
+- 排序：插入、希尔、堆、内省排序，以及梳排序、侏儒排序、鸡尾酒排序、圈排序、煎饼排序，
+  它们只靠比较和交换移动元素。归并排序、TimSort、计数排序、基数排序等借助缓冲区搬运元素，
+  无法表示成交换序列，不提供这个接口
+- 树：二叉搜索树的插入
+- 图：BFS、Dijkstra，以及迷宫的 Dijkstra 和 A* 求解
+
+`Vec<Step>` 实现了 [`Observer`]，按顺序记下每一步；[`record_sort`] 在此基础上生成排序每一步的数组快照。
+开启 `wasm` 特性后，`wasm` 模块把这些记录通过 wasm-bindgen 导出给浏览器中的可视化页面
+*/
+
+pub mod observer;
+#[cfg(feature = "sorting")]
+pub mod recording;
+pub mod steps;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use self::observer::Observer;
+#[cfg(feature = "sorting")]
+pub use self::recording::{record_sort, SortFrame, TracedSort};
+pub use self::steps::Step;
@@ -0,0 +1,129 @@
+use super::steps::Step;
+use alloc::vec::Vec;
+
+/// 算法执行时报告基本操作的接收方，所有方法默认什么也不做，只需要实现关心的那几个。
+/// 下标和顶点编号的含义见报告它的算法的文档
+pub trait Observer {
+    /// 比较了下标为 `i` 和 `j` 的两个元素
+    fn on_compare(&mut self, _i: usize, _j: usize) {}
+
+    /// 交换了下标为 `i` 和 `j` 的两个元素
+    fn on_swap(&mut self, _i: usize, _j: usize) {}
+
+    /// 访问了一个节点
+    fn on_visit_node(&mut self, _node: usize) {}
+
+    /// 经过从 `from` 出发的边把 `to` 的距离更新为 `distance`
+    fn on_relax_edge(&mut self, _from: usize, _to: usize, _distance: i64) {}
+}
+
+/// 不需要观察时使用
+impl Observer for () {}
+
+/// 按顺序记下每一步
+impl Observer for Vec<Step> {
+    fn on_compare(&mut self, i: usize, j: usize) {
+        self.push(Step::Compare(i, j));
+    }
+
+    fn on_swap(&mut self, i: usize, j: usize) {
+        self.push(Step::Swap(i, j));
+    }
+
+    fn on_visit_node(&mut self, node: usize) {
+        self.push(Step::Visit(node));
+    }
+
+    fn on_relax_edge(&mut self, from: usize, to: usize, distance: i64) {
+        self.push(Step::Relax { from, to, distance });
+    }
+}
+
+/// 同时报告给两个观察者
+impl<A: Observer, B: Observer> Observer for (A, B) {
+    fn on_compare(&mut self, i: usize, j: usize) {
+        self.0.on_compare(i, j);
+        self.1.on_compare(i, j);
+    }
+
+    fn on_swap(&mut self, i: usize, j: usize) {
+        self.0.on_swap(i, j);
+        self.1.on_swap(i, j);
+    }
+
+    fn on_visit_node(&mut self, node: usize) {
+        self.0.on_visit_node(node);
+        self.1.on_visit_node(node);
+    }
+
+    fn on_relax_edge(&mut self, from: usize, to: usize, distance: i64) {
+        self.0.on_relax_edge(from, to, distance);
+        self.1.on_relax_edge(from, to, distance);
+    }
+}
+
+impl<O: Observer + ?Sized> Observer for &mut O {
+    fn on_compare(&mut self, i: usize, j: usize) {
+        (**self).on_compare(i, j);
+    }
+
+    fn on_swap(&mut self, i: usize, j: usize) {
+        (**self).on_swap(i, j);
+    }
+
+    fn on_visit_node(&mut self, node: usize) {
+        (**self).on_visit_node(node);
+    }
+
+    fn on_relax_edge(&mut self, from: usize, to: usize, distance: i64) {
+        (**self).on_relax_edge(from, to, distance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Observer;
+    use crate::visualization::Step;
+
+    /// 只关心访问顺序
+    #[derive(Default)]
+    struct Visits(Vec<usize>);
+
+    impl Observer for Visits {
+        fn on_visit_node(&mut self, node: usize) {
+            self.0.push(node);
+        }
+    }
+
+    #[test]
+    fn records_and_combines() {
+        let mut both = (Vec::<Step>::new(), Visits::default());
+        both.on_compare(0, 1);
+        both.on_visit_node(3);
+        both.on_relax_edge(3, 4, 7);
+        both.on_swap(1, 0);
+        assert_eq!(
+            both.0,
+            vec![
+                Step::Compare(0, 1),
+                Step::Visit(3),
+                Step::Relax {
+                    from: 3,
+                    to: 4,
+                    distance: 7
+                },
+                Step::Swap(1, 0),
+            ]
+        );
+        assert_eq!(both.1 .0, vec![3]);
+
+        // 按值接收观察者的代码也可以传入可变引用，报告转发给原来的观察者
+        fn visit_five(mut observer: impl Observer) {
+            observer.on_visit_node(5);
+        }
+        let mut visits = Visits::default();
+        visit_five(&mut visits);
+        visit_five(());
+        assert_eq!(visits.0, vec![5]);
+    }
+}
@@ -0,0 +1,139 @@
+/*!
+把排序过程记录成一帧一帧的快照，每一帧是一次比较或交换以及之后整个数组的状态，
+可视化时按顺序播放即可，不需要在前端重新实现排序算法。
+排序时只记下步骤，之后在原数组的副本上重放交换得到快照。
+快照占用 O(n) 空间，总共 O(n · 步数)，只适合可视化用的小数组
+*/
+
+use super::steps::Step;
+use crate::sorting::{
+    heap_sort_observed, insertion_sort_observed, intro_sort_observed, shell_sort_observed,
+    GapSequence,
+};
+
+/// 排序的一步以及这一步之后的数组
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortFrame<T> {
+    pub step: Step,
+    pub state: Vec<T>,
+}
+
+/// 支持逐步记录的排序算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TracedSort {
+    Insertion,
+    Shell(GapSequence),
+    Heap,
+    Intro,
+}
+
+impl TracedSort {
+    /// 和 [`crate::sorting::Sorter::name`] 一致的名字
+    pub fn name(&self) -> &'static str {
+        match self {
+            TracedSort::Insertion => "insertion_sort",
+            TracedSort::Shell(_) => "shell_sort",
+            TracedSort::Heap => "heap_sort",
+            TracedSort::Intro => "intro_sort",
+        }
+    }
+
+    /// 按名字查找，希尔排序使用 Ciura 序列
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            TracedSort::Insertion,
+            TracedSort::Shell(GapSequence::Ciura),
+            TracedSort::Heap,
+            TracedSort::Intro,
+        ]
+        .into_iter()
+        .find(|algorithm| algorithm.name() == name)
+    }
+}
+
+/// 排序 `arr` 并返回每一步的快照
+pub fn record_sort<T: Ord + Clone>(algorithm: TracedSort, arr: &mut [T]) -> Vec<SortFrame<T>> {
+    let mut state = arr.to_vec();
+    let mut steps: Vec<Step> = vec![];
+    match algorithm {
+        TracedSort::Insertion => insertion_sort_observed(arr, &mut steps),
+        TracedSort::Shell(sequence) => shell_sort_observed(arr, sequence, &mut steps),
+        TracedSort::Heap => heap_sort_observed(arr, &mut steps),
+        TracedSort::Intro => intro_sort_observed(arr, &mut steps),
+    }
+    steps
+        .into_iter()
+        .map(|step| {
+            if let Step::Swap(i, j) = step {
+                state.swap(i, j);
+            }
+            SortFrame {
+                step,
+                state: state.clone(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{record_sort, TracedSort};
+    use crate::sorting::{count_inversions, GapSequence};
+    use crate::utils::random::Rng;
+    use crate::visualization::Step;
+
+    #[test]
+    fn snapshots_follow_the_swaps() {
+        let mut rng = Rng::new(1497);
+        // 内省排序要分区几次才会交给插入排序
+        let original: Vec<i32> = (0..100).map(|_| rng.gen_range(0, 50) as i32).collect();
+        for algorithm in [
+            TracedSort::Insertion,
+            TracedSort::Shell(GapSequence::Knuth),
+            TracedSort::Heap,
+            TracedSort::Intro,
+        ] {
+            let mut arr = original.clone();
+            let frames = record_sort(algorithm, &mut arr);
+            assert_eq!(frames.last().unwrap().state, arr);
+            let mut replay = original.clone();
+            for frame in &frames {
+                if let Step::Swap(i, j) = frame.step {
+                    replay.swap(i, j);
+                }
+                assert_eq!(frame.state, replay, "{}", algorithm.name());
+            }
+            let mut expected = original.clone();
+            expected.sort();
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn insertion_sort_swaps_once_per_inversion() {
+        let mut arr = vec![3, 1, 2, 5, 4];
+        let inversions = count_inversions(&arr);
+        let frames = record_sort(TracedSort::Insertion, &mut arr);
+        let swaps = frames
+            .iter()
+            .filter(|frame| matches!(frame.step, Step::Swap(..)))
+            .count();
+        assert_eq!(swaps as u64, inversions);
+        assert_eq!(frames[0].step, Step::Compare(0, 1));
+        assert_eq!(frames[1].step, Step::Swap(0, 1));
+        assert_eq!(frames[1].state, vec![1, 3, 2, 5, 4]);
+
+        assert!(record_sort(TracedSort::Heap, &mut [1]).is_empty());
+    }
+
+    #[test]
+    fn finds_algorithms_by_name() {
+        assert_eq!(TracedSort::from_name("heap_sort"), Some(TracedSort::Heap));
+        assert_eq!(
+            TracedSort::from_name("shell_sort"),
+            Some(TracedSort::Shell(GapSequence::Ciura))
+        );
+        assert_eq!(TracedSort::from_name("intro_sort"), Some(TracedSort::Intro));
+        assert_eq!(TracedSort::from_name("bogo_sort"), None);
+    }
+}
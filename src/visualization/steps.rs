@@ -0,0 +1,16 @@
+/// 算法执行中的一步，下标和顶点编号都由报告它的算法解释
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// 比较了数组中下标为 i 和 j 的两个元素
+    Compare(usize, usize),
+    /// 交换了数组中下标为 i 和 j 的两个元素
+    Swap(usize, usize),
+    /// 访问了一个顶点，最短路算法中表示它的距离已经确定
+    Visit(usize),
+    /// 经过从 `from` 出发的边把 `to` 的距离更新为 `distance`
+    Relax {
+        from: usize,
+        to: usize,
+        distance: i64,
+    },
+}
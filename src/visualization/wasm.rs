@@ -0,0 +1,237 @@
+/*!
+通过 [wasm-bindgen](https://rustwasm.github.io/docs/wasm-bindgen/) 导出给浏览器的可视化接口：
+- [`trace_sort`]：排序每一步的快照
+- [`BstVisualizer`]：二叉搜索树的插入路径
+- [`trace_maze`]：网格迷宫上 Dijkstra 和 A* 的搜索过程
+
+crate 本身不是 cdylib，构建 wasm 时用
+`cargo rustc --lib --release --crate-type cdylib --target wasm32-unknown-unknown --features wasm`，
+再对生成的 `.wasm` 文件运行 `wasm-bindgen --target web`。
+下标越界时 Rust 会 panic，在 wasm 中表现为 `RuntimeError: unreachable`
+*/
+
+use super::recording::{record_sort, SortFrame, TracedSort};
+use super::steps::Step;
+use crate::data_structures::BinarySearchTree;
+use crate::maze::{solve_astar_observed, solve_dijkstra_observed, Grid, GridPath};
+use wasm_bindgen::prelude::*;
+
+/// 排序过程的全部快照
+#[wasm_bindgen]
+pub struct SortTrace {
+    frames: Vec<SortFrame<i32>>,
+}
+
+#[wasm_bindgen]
+impl SortTrace {
+    /// 步数
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// 第 `i` 步的类型：`"compare"` 或 `"swap"`
+    pub fn kind(&self, i: usize) -> String {
+        match self.frames[i].step {
+            Step::Compare(..) => "compare".to_string(),
+            Step::Swap(..) => "swap".to_string(),
+            _ => unreachable!("sorting only compares and swaps"),
+        }
+    }
+
+    /// 第 `i` 步涉及的两个下标
+    pub fn indices(&self, i: usize) -> Vec<u32> {
+        match self.frames[i].step {
+            Step::Compare(a, b) | Step::Swap(a, b) => vec![a as u32, b as u32],
+            _ => unreachable!("sorting only compares and swaps"),
+        }
+    }
+
+    /// 第 `i` 步之后的数组
+    pub fn state(&self, i: usize) -> Vec<i32> {
+        self.frames[i].state.clone()
+    }
+}
+
+/// 用名为 `algorithm` 的排序算法（`insertion_sort`、`shell_sort`、`heap_sort`）排序并记录每一步
+#[wasm_bindgen(js_name = traceSort)]
+pub fn trace_sort(algorithm: &str, mut values: Vec<i32>) -> Result<SortTrace, JsError> {
+    let algorithm = TracedSort::from_name(algorithm)
+        .ok_or_else(|| JsError::new(&format!("unknown sorting algorithm {algorithm:?}")))?;
+    Ok(SortTrace {
+        frames: record_sort(algorithm, &mut values),
+    })
+}
+
+/// 逐个插入整数的二叉搜索树
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct BstVisualizer {
+    tree: BinarySearchTree<i32>,
+}
+
+#[wasm_bindgen]
+impl BstVisualizer {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        BstVisualizer::default()
+    }
+
+    /// 插入 `value`，返回从根开始依次比较过的节点值
+    pub fn insert(&mut self, value: i32) -> Vec<i32> {
+        let path = self
+            .tree
+            .insertion_path(&value)
+            .into_iter()
+            .copied()
+            .collect();
+        self.tree.insert(value);
+        path
+    }
+
+    pub fn contains(&self, value: i32) -> bool {
+        self.tree.search(&value)
+    }
+
+    pub fn height(&self) -> usize {
+        self.tree.height()
+    }
+
+    /// 中序遍历的结果，即从小到大排列的所有值
+    #[wasm_bindgen(js_name = inOrder)]
+    pub fn in_order(&self) -> Vec<i32> {
+        self.tree.iter().copied().collect()
+    }
+}
+
+/// 迷宫搜索的过程和结果，格子编号为 `行 * width + 列`
+#[wasm_bindgen]
+pub struct MazeTrace {
+    width: usize,
+    steps: Vec<Step>,
+    path: Option<GridPath>,
+}
+
+#[wasm_bindgen]
+impl MazeTrace {
+    #[wasm_bindgen(js_name = stepCount)]
+    pub fn step_count(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// 第 `i` 步的类型：`"visit"` 表示展开了一个格子，`"relax"` 表示更新了一个格子的代价
+    #[wasm_bindgen(js_name = stepKind)]
+    pub fn step_kind(&self, i: usize) -> String {
+        match self.steps[i] {
+            Step::Visit(_) => "visit".to_string(),
+            Step::Relax { .. } => "relax".to_string(),
+            _ => unreachable!("path finding only visits and relaxes"),
+        }
+    }
+
+    /// 第 `i` 步展开或者更新的格子
+    #[wasm_bindgen(js_name = stepCell)]
+    pub fn step_cell(&self, i: usize) -> u32 {
+        match self.steps[i] {
+            Step::Visit(cell) | Step::Relax { to: cell, .. } => cell as u32,
+            _ => unreachable!("path finding only visits and relaxes"),
+        }
+    }
+
+    /// 找到的路径依次经过的格子，不可达时为空
+    pub fn path(&self) -> Vec<u32> {
+        self.path.as_ref().map_or(vec![], |path| {
+            path.cells
+                .iter()
+                .map(|&(row, column)| (row * self.width + column) as u32)
+                .collect()
+        })
+    }
+
+    /// 路径的总代价，不可达时为 undefined
+    pub fn cost(&self) -> Option<f64> {
+        self.path.as_ref().map(|path| path.cost as f64)
+    }
+}
+
+/// 解析迷宫文本（格式见 [`Grid::parse`]），用 `dijkstra` 或 `astar` 从起点搜索到终点
+#[wasm_bindgen(js_name = traceMaze)]
+pub fn trace_maze(
+    maze: &str,
+    algorithm: &str,
+    start_row: usize,
+    start_column: usize,
+    goal_row: usize,
+    goal_column: usize,
+) -> Result<MazeTrace, JsError> {
+    let grid = Grid::parse(maze)?;
+    let (start, goal) = ((start_row, start_column), (goal_row, goal_column));
+    for (row, column) in [start, goal] {
+        if row >= grid.height() || column >= grid.width() {
+            return Err(JsError::new(&format!(
+                "cell ({row}, {column}) is outside the maze"
+            )));
+        }
+    }
+    let mut steps = vec![];
+    let path = match algorithm {
+        "dijkstra" => solve_dijkstra_observed(&grid, start, goal, &mut steps),
+        "astar" => solve_astar_observed(&grid, start, goal, &mut steps),
+        _ => {
+            return Err(JsError::new(&format!(
+                "unknown path finding algorithm {algorithm:?}"
+            )))
+        }
+    };
+    Ok(MazeTrace {
+        width: grid.width(),
+        steps,
+        path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    // JsError 只能在 wasm 中构造，这里只测试成功的调用
+    use super::{trace_maze, trace_sort, BstVisualizer};
+
+    #[test]
+    fn sort_trace_ends_sorted() {
+        let trace = trace_sort("heap_sort", vec![3, 1, 2]).unwrap();
+        assert!(!trace.is_empty());
+        assert_eq!(trace.state(trace.len() - 1), vec![1, 2, 3]);
+        assert_eq!(trace.kind(0), "compare");
+        assert_eq!(trace.indices(0).len(), 2);
+    }
+
+    #[test]
+    fn bst_reports_insertion_paths() {
+        let mut bst = BstVisualizer::new();
+        assert!(bst.insert(5).is_empty());
+        assert_eq!(bst.insert(3), vec![5]);
+        assert_eq!(bst.insert(4), vec![5, 3]);
+        assert!(bst.contains(4));
+        assert_eq!(bst.height(), 3);
+        assert_eq!(bst.in_order(), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn maze_trace_uses_cell_numbers() {
+        let maze = "...\n.#.\n...\n";
+        let trace = trace_maze(maze, "astar", 0, 0, 2, 2).unwrap();
+        assert_eq!(trace.cost(), Some(4.0));
+        let path = trace.path();
+        assert_eq!((path[0], path[path.len() - 1]), (0, 8));
+        assert!(!path.contains(&4));
+        assert_eq!(trace.step_kind(0), "visit");
+        assert_eq!(trace.step_cell(0), 0);
+
+        let blocked = trace_maze(".#.\n", "dijkstra", 0, 0, 0, 2).unwrap();
+        assert!(blocked.path().is_empty());
+        assert_eq!(blocked.cost(), None);
+    }
+}